@@ -1,33 +1,261 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-mod repairing;
+mod auditing;
+mod capabilities;
+mod demo;
+pub mod diff;
+pub mod errors;
+pub mod events;
+pub mod formats;
+pub mod oneshot;
+pub mod patterns;
+pub mod repairing;
+pub mod reporting;
+pub mod review_load;
+pub mod reviewers;
+mod scaffolding;
+mod serving;
+pub mod snapshot;
+mod statistics;
+pub mod stats;
 pub mod validation;
 
+use crate::canopus::auditing::{AuditReport, AuditStatus};
+use crate::canopus::capabilities::Capabilities;
+use crate::canopus::diff::{DiffFormat, OwnershipDiff};
+use crate::canopus::errors::CanopusError;
+use crate::canopus::formats::{
+    OutputFormat, ProjectValidation, ReportPipeline, RunDetails, annotations, compact, json, junit, pr_comment, text,
+};
+use crate::canopus::oneshot::{OneShotSettings, OneShotStatus};
+use crate::canopus::patterns::{ExplainFormat, PatternExplanation};
+use crate::canopus::repairing::RepairScope;
+use crate::canopus::reporting::heatmap::{HeatmapFormat, OwnershipHeatmap};
+use crate::canopus::reporting::{OwnershipReport, ReportFormat};
+use crate::canopus::review_load::ReviewLoad;
+use crate::canopus::reviewers::{RequestedReviewers, ReviewersFormat};
+use crate::canopus::serving::ServedProject;
+use crate::canopus::serving::grpc::OwnershipService;
+use crate::canopus::snapshot::{DEFAULT_SNAPSHOT_FILE, OwnershipSnapshot};
+use crate::canopus::statistics::StatisticsCollector;
+use crate::canopus::stats::export::ExportFormat;
+use crate::canopus::stats::{OwnershipStats, StatsFormat, export};
 use crate::canopus::validation::CodeOwnersValidator;
 use crate::core::models::ValidationOutcome;
-use crate::core::models::codeowners::CodeOwnersContext;
-use crate::core::models::config::CanopusConfig;
+use crate::core::models::aggregation::LineIssues;
+use crate::core::models::codeowners::{CodeOwners, CodeOwnersContext};
+use crate::core::models::config::{CanopusConfig, ConfigFormat, ConfigLayer};
+use crate::core::models::deprecations::DeprecationPolicy;
+use crate::core::models::versions::Version;
+use crate::infra::checks::CheckConclusion;
+use crate::infra::fixtures::FixturesMode;
+use crate::infra::github::GithubApiChecker;
+use crate::infra::progress::ProgressEvent;
+use crate::infra::prometheus;
+use crate::infra::pulls::{CommentPosting, CommitState};
+use crate::infra::remote::RemoteRepository;
+use crate::infra::retries::RetryPolicy;
+use crate::infra::server;
+use crate::infra::storage;
+use crate::infra::storage::{AuditStorage, StoreAuditResults};
+use crate::infra::{checks, cli, git, progress, pulls, releases, remote};
+use anyhow::{Context, anyhow, bail};
+use chrono::Utc;
+use clap_complete::Shell;
 use console::style;
 use itertools::Itertools;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::io::IsTerminal;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use temp_dir::TempDir;
+use tokio::net::TcpListener;
+
+/// Where validation results are published, besides the standard output
+struct PublishingTarget {
+    repository: RemoteRepository,
+    pull_request: Option<u64>,
+    head_commit: Option<String>,
+}
+
+/// Where the project under validation lives
+#[derive(Debug, PartialEq)]
+pub enum ProjectLocation {
+    Local(PathBuf),
+    Remote(RemoteRepository),
+}
+
+impl Display for ProjectLocation {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectLocation::Local(project_root) => write!(formatter, "{}", project_root.display()),
+            ProjectLocation::Remote(repository) => write!(formatter, "{repository}"),
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum CanopusCommand {
-    ValidateCodeowners(PathBuf),
+    ValidateCodeowners {
+        projects: Vec<ProjectLocation>,
+        min_coverage: Option<f64>,
+        coverage_base: Option<String>,
+        max_issues: Option<usize>,
+        strict: bool,
+        formats: Vec<OutputFormat>,
+        output_dir: Option<PathBuf>,
+        quiet: bool,
+        post_to_pr: Option<u64>,
+        commit_status: bool,
+        check_run: bool,
+        fixtures: Option<FixturesMode>,
+        metrics_out: Option<PathBuf>,
+    },
     RepairCodeowners {
         project_root: PathBuf,
+        scope: RepairScope,
         dry_run: bool,
         remove_lines: bool,
     },
+    AuditOrganization {
+        organization: String,
+        config_file: Option<PathBuf>,
+        metrics_listen: Option<SocketAddr>,
+    },
+    RunDemo,
+    RunOneShot,
+    ReportCoverage(PathBuf),
+    GenerateReport {
+        project_root: PathBuf,
+        format: ReportFormat,
+    },
+    GenerateHeatmap {
+        project_root: PathBuf,
+        depth: usize,
+        format: HeatmapFormat,
+    },
+    ComputeStatistics {
+        project_root: PathBuf,
+        format: StatsFormat,
+        review_load_days: Option<u32>,
+    },
+    ExportOwnership {
+        project_root: PathBuf,
+        format: ExportFormat,
+        output: Option<PathBuf>,
+    },
+    DiffOwnership {
+        project_root: PathBuf,
+        from: String,
+        to: String,
+        format: DiffFormat,
+    },
+    ListReviewers {
+        project_root: PathBuf,
+        changed_since: Option<String>,
+        files: Vec<PathBuf>,
+        format: ReviewersFormat,
+    },
+    ExplainPattern {
+        project_root: PathBuf,
+        pattern: String,
+        format: ExplainFormat,
+    },
+    SnapshotOwnership {
+        project_root: PathBuf,
+        snapshot_file: Option<PathBuf>,
+    },
+    VerifyOwnershipSnapshot {
+        project_root: PathBuf,
+        snapshot_file: Option<PathBuf>,
+    },
+    CheckConfiguration(PathBuf),
+    SyncRoster {
+        organization: String,
+        roster_file: PathBuf,
+    },
+    InitProject {
+        project_root: PathBuf,
+        organization: Option<String>,
+        team: Option<String>,
+        force: bool,
+    },
+    ShowCapabilities {
+        json: bool,
+    },
+    ShowReportSchema,
+    GenerateCompletions(Shell),
+    GenerateManPage,
+    DescribeChecks,
+    SelfUpdate {
+        check_only: bool,
+    },
+    ServeValidation {
+        project_root: PathBuf,
+        address: SocketAddr,
+        grpc_address: Option<SocketAddr>,
+        max_body_bytes: usize,
+    },
+}
+
+impl CanopusCommand {
+    /// Machine-readable formats and quiet runs must not be decorated with any extra output
+    pub fn is_decorated(&self) -> bool {
+        match self {
+            CanopusCommand::ValidateCodeowners { formats, quiet, .. } => {
+                !formats.iter().any(OutputFormat::is_machine_readable) && !quiet
+            },
+            CanopusCommand::ComputeStatistics { format, .. } => *format == StatsFormat::Table,
+            CanopusCommand::GenerateHeatmap { format, .. } => *format == HeatmapFormat::Table,
+            CanopusCommand::DiffOwnership { format, .. } => *format == DiffFormat::Text,
+            CanopusCommand::ListReviewers { format, .. } => *format == ReviewersFormat::Text,
+            CanopusCommand::ExplainPattern { format, .. } => *format == ExplainFormat::Text,
+            CanopusCommand::ShowReportSchema
+            | CanopusCommand::GenerateCompletions(_)
+            | CanopusCommand::GenerateManPage
+            | CanopusCommand::DescribeChecks
+            | CanopusCommand::ServeValidation { .. }
+            | CanopusCommand::RunOneShot => false,
+            CanopusCommand::ExportOwnership { output, .. } => output.is_some(),
+            _ => true,
+        }
+    }
 }
 
 impl Display for CanopusCommand {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         let formatted = match self {
-            CanopusCommand::ValidateCodeowners(_) => "Validates the CODEOWNERS configuration for a project",
+            CanopusCommand::ValidateCodeowners { .. } => "Validates the CODEOWNERS configuration for a project",
             CanopusCommand::RepairCodeowners { .. } => "Repairs the CODEOWNERS configuration for a project",
+            CanopusCommand::AuditOrganization { .. } => "Validates CODEOWNERS configuration across an organization",
+            CanopusCommand::ReportCoverage(_) => "Reports ownership coverage for a project",
+            CanopusCommand::GenerateReport { .. } => "Generates a human-readable ownership report for a project",
+            CanopusCommand::GenerateHeatmap { .. } => "Rolls up ownership of a project per directory",
+            CanopusCommand::ComputeStatistics { .. } => "Computes per-owner ownership statistics for a project",
+            CanopusCommand::ExportOwnership { .. } => "Exports ownership of a project as tabular data",
+            CanopusCommand::DiffOwnership { .. } => "Reports ownership changes between two revisions of a project",
+            CanopusCommand::ListReviewers { .. } => "Lists reviewers Github would request for a set of changes",
+            CanopusCommand::ExplainPattern { .. } => "Explains which project files a CODEOWNERS pattern matches",
+            CanopusCommand::SnapshotOwnership { .. } => "Records effective ownership of a project to a snapshot",
+            CanopusCommand::VerifyOwnershipSnapshot { .. } => {
+                "Verifies effective ownership of a project against a snapshot"
+            },
+            CanopusCommand::CheckConfiguration(_) => "Checks the canopus configuration for a project",
+            CanopusCommand::SyncRoster { .. } => "Syncs members and teams of an organization to a roster file",
+            CanopusCommand::InitProject { .. } => "Scaffolds canopus configuration for a project",
+            CanopusCommand::RunDemo => "Validates an example project with a broken CODEOWNERS configuration",
+            CanopusCommand::RunOneShot => "Validates a project configured through environment variables only",
+            CanopusCommand::ShowCapabilities { .. } => "Describes the capabilities of this canopus version",
+            CanopusCommand::ShowReportSchema => "Prints the JSON schema of validation reports",
+            CanopusCommand::GenerateCompletions(_) => "Generates a shell completion script for canopus",
+            CanopusCommand::GenerateManPage => "Generates the canopus man page",
+            CanopusCommand::DescribeChecks => "Generates a reference of all checks supported by canopus",
+            CanopusCommand::SelfUpdate { .. } => "Updates canopus to its latest release",
+            CanopusCommand::ServeValidation { .. } => "Serves validation and ownership queries over HTTP and gRPC",
         };
 
         formatter.write_str(formatted)
@@ -35,56 +263,160 @@ impl Display for CanopusCommand {
 }
 
 pub struct Canopus {
-    codeowners_validator: CodeOwnersValidator,
+    codeowners_validator: Arc<CodeOwnersValidator>,
+    github_client: octocrab::Octocrab,
+    deprecation_policy: DeprecationPolicy,
+    trust_extended_checks: bool,
+    statistics: StatisticsCollector,
+    config_digests: Mutex<HashMap<String, String>>,
 }
 
 impl Canopus {
-    pub fn new(codeowners_validator: CodeOwnersValidator) -> Self {
-        Self { codeowners_validator }
+    pub fn new(
+        mut codeowners_validator: CodeOwnersValidator,
+        github_client: octocrab::Octocrab,
+        deprecation_policy: DeprecationPolicy,
+        trust_extended_checks: bool,
+    ) -> Self {
+        let statistics = StatisticsCollector::default();
+        codeowners_validator.register_observer(Box::new(statistics.clone()));
+
+        Self {
+            codeowners_validator: Arc::new(codeowners_validator),
+            github_client,
+            deprecation_policy,
+            trust_extended_checks,
+            statistics,
+            config_digests: Mutex::default(),
+        }
     }
 
-    pub async fn execute(&self, requested: CanopusCommand) -> anyhow::Result<()> {
-        println!();
+    pub async fn execute(&self, requested: CanopusCommand) -> Result<(), CanopusError> {
+        self.run(requested).await.map_err(CanopusError::from)
+    }
+
+    async fn run(&self, requested: CanopusCommand) -> anyhow::Result<()> {
+        if requested.is_decorated() {
+            println!();
+        }
 
         match requested {
-            CanopusCommand::ValidateCodeowners(project_path) => {
-                let (context, config) = Self::evaluate(project_path)?;
-                let outcome = self.codeowners_validator.validate(&context, &config).await?;
+            CanopusCommand::ValidateCodeowners {
+                projects,
+                min_coverage,
+                coverage_base,
+                max_issues,
+                strict,
+                formats,
+                output_dir,
+                quiet,
+                post_to_pr,
+                commit_status,
+                check_run,
+                metrics_out,
+                ..
+            } => {
+                let started = Instant::now();
+                let total_projects = projects.len();
 
-                match outcome {
-                    ValidationOutcome::NoIssues => println!("{}", style("No issues found!").cyan()),
-                    ValidationOutcome::IssuesDetected(issues) => {
-                        issues.iter().for_each(|issue| {
-                            println!("→ {issue}");
+                // Results are published to the repository of the first project, detected before validation
+                let publishing = match projects.first() {
+                    Some(project) if post_to_pr.is_some() || check_run => {
+                        Some(Self::publishing_target(project, post_to_pr)?)
+                    },
+                    _ => None,
+                };
+
+                let validations = self
+                    .validate_projects(projects, min_coverage, coverage_base, max_issues, strict)
+                    .await;
+
+                // Metrics are recorded whatever the outcome, since failing runs matter the most for trends
+                if let Some(metrics_file) = &metrics_out {
+                    self.statistics
+                        .collected()
+                        .metrics(total_projects, started.elapsed())
+                        .write(metrics_file)?;
+                }
+
+                if let Some(target) = &publishing {
+                    if let Some(number) = target.pull_request {
+                        self.post_to_pull_request(&target.repository, number, commit_status, &validations)
+                            .await?;
+                    }
+
+                    if check_run {
+                        self.publish_check_run(target, &validations).await?;
+                    }
+                }
+
+                match output_dir {
+                    // Without an output dir, a single format is printed to the standard output
+                    None => {
+                        let format = formats.first().copied().unwrap_or_default();
+                        self.print_report(format, validations, quiet)?;
+                    },
+                    Some(output_dir) => {
+                        // Every report is rendered from the same results, validating projects only once
+                        let report_files =
+                            ReportPipeline::new(&formats, &output_dir).emit(&validations, &self.run_details())?;
+
+                        report_files.iter().for_each(|report_file| {
+                            println!("Report written to {}", report_file.display());
                         });
-                        println!();
-                        println!("{}", style("Some issues were found").red());
-                        println!();
+
+                        if formats.contains(&OutputFormat::Text) {
+                            self.print_report(OutputFormat::Text, validations, quiet)?;
+                        } else {
+                            validations.iter().for_each(|(project_name, validation)| {
+                                if let Err(error) = validation {
+                                    eprintln!("{project_name}: cannot validate project : {error}");
+                                }
+                            });
+
+                            Self::ensure_validated(validations)?;
+                        }
                     },
                 }
             },
             CanopusCommand::RepairCodeowners {
                 project_root,
+                scope,
                 dry_run,
                 remove_lines,
             } => {
-                let (context, config) = Self::evaluate(project_root)?;
+                let (context, config) = self.evaluate(project_root).await?;
                 let outcome = self.codeowners_validator.validate(&context, &config).await?;
 
                 match outcome {
                     ValidationOutcome::NoIssues => println!("{}", style("Nothing to repair!").cyan()),
                     ValidationOutcome::IssuesDetected(issues) => {
-                        let unique_issues_per_line = issues.into_iter().unique_by(|issue| issue.line).collect_vec();
+                        let issues_per_line = repairing::issues_to_repair(issues, &scope);
+
+                        if issues_per_line.is_empty() {
+                            println!("{}", style("Nothing to repair for the selected checks!").cyan());
+                            return Ok(());
+                        }
 
                         if dry_run {
                             println!("{}", style("Dry run repairing ...").cyan());
                             println!();
 
-                            unique_issues_per_line.iter().for_each(|issue| {
+                            issues_per_line.iter().for_each(|line_issues| {
+                                let fixes = line_issues.fixes();
+
+                                let repaired_by = if fixes.is_empty() {
+                                    String::new()
+                                } else {
+                                    format!(" by {}", fixes.iter().join(", "))
+                                };
+
                                 println!(
-                                    "→  L{} has issues and will be repaired {}",
-                                    issue.line + 1,
-                                    style(issue.context.to_string()).magenta()
+                                    "→  L{} has issues ({}) and will be repaired{} {}",
+                                    line_issues.line + 1,
+                                    line_issues.check_names().join(", "),
+                                    repaired_by,
+                                    style(line_issues.issues.iter().map(|issue| &issue.context).join(" ; ")).magenta()
                                 );
                             });
 
@@ -94,8 +426,14 @@ impl Canopus {
 
                         println!("Repairing CodeOwners...");
 
-                        let lines_to_repair = unique_issues_per_line.into_iter().map(|issue| issue.line).collect_vec();
-                        repairing::repair_code_owners(&context, lines_to_repair, remove_lines)?;
+                        let lines_to_repair = issues_per_line
+                            .iter()
+                            .filter(|line_issues| line_issues.is_flagged())
+                            .map(|line_issues| line_issues.line)
+                            .collect_vec();
+
+                        let fixes = issues_per_line.iter().flat_map(LineIssues::fixes).collect_vec();
+                        repairing::repair_code_owners(&context, lines_to_repair, fixes, remove_lines)?;
 
                         println!();
                         println!("{}", style("CODEOWNERS file repaired with success").cyan());
@@ -103,14 +441,893 @@ impl Canopus {
                     },
                 }
             },
+            CanopusCommand::ReportCoverage(project_root) => {
+                let (context, config) = self.evaluate(project_root).await?;
+                let coverage = self.codeowners_validator.ownership_coverage(&context, &config)?;
+
+                println!(
+                    "Ownership coverage : {} ({} of {} files)",
+                    style(format!("{:.2}%", coverage.percentage())).cyan(),
+                    coverage.owned_files,
+                    coverage.total_files
+                );
+                println!();
+            },
+            CanopusCommand::GenerateReport { project_root, format } => {
+                let project_name = project_root.display().to_string();
+                let (context, config) = self.evaluate(project_root).await?;
+                let outcome = self.codeowners_validator.validate(&context, &config).await?;
+                let code_owners = CodeOwners::try_from(context.contents.as_str())?;
+                let project_files = self
+                    .codeowners_validator
+                    .coverage_files(&context, &config)?
+                    .to_path_bufs();
+
+                let report = OwnershipReport::new(&project_name, &code_owners, &project_files, outcome);
+                println!("{}", report.render(format));
+            },
+            CanopusCommand::GenerateHeatmap {
+                project_root,
+                depth,
+                format,
+            } => {
+                let project_name = project_root.display().to_string();
+                let (context, config) = self.evaluate(project_root).await?;
+                let code_owners = CodeOwners::try_from(context.contents.as_str())?;
+                let project_files = self
+                    .codeowners_validator
+                    .coverage_files(&context, &config)?
+                    .to_path_bufs();
+
+                let heatmap = OwnershipHeatmap::compute(&project_name, &code_owners, &project_files, depth);
+                println!("{}", heatmap.render(format));
+            },
+            CanopusCommand::ComputeStatistics {
+                project_root,
+                format,
+                review_load_days: Some(days),
+            } => {
+                let project_name = project_root.display().to_string();
+                let context = CodeOwnersContext::try_from(project_root.clone()).map_err(CanopusError::user_error)?;
+                let code_owners = CodeOwners::try_from(context.contents.as_str())?;
+
+                let repository = Self::detect_repository(&project_root)?;
+
+                let since = Utc::now().date_naive() - chrono::Days::new(days.into());
+                let pull_requests = pulls::recent_pull_requests(&self.github_client, &repository, since).await?;
+
+                let mut changes_per_pull_request = Vec::with_capacity(pull_requests.len());
+
+                for number in pull_requests {
+                    changes_per_pull_request
+                        .push(pulls::changed_files(&self.github_client, &repository, number).await?);
+                }
+
+                let load = ReviewLoad::compute(&project_name, &code_owners, &changes_per_pull_request);
+                println!("{}", load.render(format));
+            },
+            CanopusCommand::ComputeStatistics {
+                project_root, format, ..
+            } => {
+                let project_name = project_root.display().to_string();
+                let (context, config) = self.evaluate(project_root).await?;
+                let code_owners = CodeOwners::try_from(context.contents.as_str())?;
+                let project_files = self
+                    .codeowners_validator
+                    .coverage_files(&context, &config)?
+                    .to_path_bufs();
+
+                let stats = OwnershipStats::compute(&project_name, &code_owners, &project_files);
+                println!("{}", stats.render(format));
+            },
+            CanopusCommand::ExportOwnership {
+                project_root,
+                format,
+                output,
+            } => {
+                let (context, config) = self.evaluate(project_root).await?;
+                let code_owners = CodeOwners::try_from(context.contents.as_str())?;
+                let project_files = self
+                    .codeowners_validator
+                    .coverage_files(&context, &config)?
+                    .to_path_bufs();
+
+                let rows = export::ownership_rows(&code_owners, &project_files);
+
+                match output {
+                    Some(output) => {
+                        let exported = std::fs::File::create(&output)
+                            .with_context(|| format!("cannot create {}", output.display()))?;
+
+                        export::export(&rows, format, exported)?;
+                        println!(
+                            "{} {} rows to {}",
+                            style("Exported").green(),
+                            rows.len(),
+                            output.display()
+                        );
+                        println!();
+                    },
+                    None if format.is_binary() && std::io::stdout().is_terminal() => {
+                        bail!("refusing to print binary contents on a terminal, use --output instead");
+                    },
+                    None => export::export(&rows, format, std::io::stdout())?,
+                }
+            },
+            CanopusCommand::DiffOwnership {
+                project_root,
+                from,
+                to,
+                format,
+            } => {
+                let diff = OwnershipDiff::between_revisions(&project_root, &from, &to)?;
+                println!("{}", diff.render(format));
+            },
+            CanopusCommand::ListReviewers {
+                project_root,
+                changed_since,
+                files,
+                format,
+            } => {
+                let context = CodeOwnersContext::try_from(project_root.clone()).map_err(CanopusError::user_error)?;
+                let code_owners = CodeOwners::try_from(context.contents.as_str())?;
+
+                let changed_files = match changed_since {
+                    Some(revision) => git::changed_files(&project_root, &revision)?,
+                    None => files,
+                };
+
+                let requested = RequestedReviewers::compute(&code_owners, &changed_files);
+                println!("{}", requested.render(format));
+            },
+            CanopusCommand::ExplainPattern {
+                project_root,
+                pattern,
+                format,
+            } => {
+                let project_files = git::tracked_files(&project_root)?.into_iter().sorted().collect_vec();
+                let explanation = PatternExplanation::compute(&pattern, &project_files)?;
+                println!("{}", explanation.render(format));
+            },
+            CanopusCommand::SnapshotOwnership {
+                project_root,
+                snapshot_file,
+            } => {
+                let snapshot_file = snapshot_file.unwrap_or(project_root.join(DEFAULT_SNAPSHOT_FILE));
+                let snapshot = OwnershipSnapshot::of_project(&project_root)?;
+                std::fs::write(&snapshot_file, snapshot.render()?)?;
+
+                println!(
+                    "{} ownership of {} owners to {}",
+                    style("Recorded").green(),
+                    snapshot.owners.len(),
+                    snapshot_file.display()
+                );
+                println!();
+            },
+            CanopusCommand::VerifyOwnershipSnapshot {
+                project_root,
+                snapshot_file,
+            } => {
+                let snapshot_file = snapshot_file.unwrap_or(project_root.join(DEFAULT_SNAPSHOT_FILE));
+                let contents = std::fs::read_to_string(&snapshot_file)
+                    .with_context(|| format!("cannot read ownership snapshot at {}", snapshot_file.display()))?;
+
+                let recorded = OwnershipSnapshot::parse(&contents)?;
+                let drift = OwnershipSnapshot::of_project(&project_root)?.drift_from(&recorded);
+
+                if !drift.is_empty() {
+                    println!("{}", snapshot::render_drift(&drift));
+                    println!();
+                    return Err(CanopusError::findings(format!(
+                        "ownership drifted from {}; run canopus snapshot to record it",
+                        snapshot_file.display()
+                    )));
+                }
+
+                println!("{}", style("Ownership matches the recorded snapshot").green());
+                println!();
+            },
+            CanopusCommand::CheckConfiguration(project_root) => {
+                let config_layer = ConfigLayer::try_from(project_root.as_path()).map_err(CanopusError::user_error)?;
+                let config =
+                    remote::resolve_config(&self.github_client, config_layer, self.trust_extended_checks).await?;
+                self.deprecation_policy.enforce(&config.deprecated_keys)?;
+                warn_diagnostics(&config);
+
+                println!("{}", style("Configuration is valid").green());
+                println!();
+                println!("Effective configuration :");
+                println!();
+                println!("{}", config.effective());
+            },
+            CanopusCommand::SyncRoster {
+                organization,
+                roster_file,
+            } => {
+                // We resolve the format upfront, failing before reaching Github API
+                let format = ConfigFormat::from_path(&roster_file)?;
+                let checker = GithubApiChecker::new(self.github_client.clone());
+                let roster = checker.fetch_roster(&RetryPolicy::default(), &organization).await?;
+                std::fs::write(&roster_file, roster.render(format)?)?;
+
+                println!(
+                    "{} {} members and {} teams of {} to {}",
+                    style("Synced").green(),
+                    roster.members.len(),
+                    roster.teams.len(),
+                    organization,
+                    roster_file.display()
+                );
+                println!();
+            },
+            CanopusCommand::InitProject {
+                project_root,
+                organization,
+                team,
+                force,
+            } => {
+                let organization = match organization {
+                    Some(organization) => organization,
+                    None => Self::detect_organization(&project_root)?,
+                };
+
+                let created = scaffolding::scaffold(&project_root, &organization, team.as_deref(), force)?;
+
+                created
+                    .iter()
+                    .for_each(|path| println!("{} {}", style("Created").green(), path.display()));
+
+                println!();
+                println!("Run {} to check your setup", style("canopus validate").cyan());
+                println!();
+            },
+            CanopusCommand::AuditOrganization {
+                organization,
+                config_file,
+                metrics_listen,
+            } => {
+                // We resolve storage upfront, failing fast on misconfiguration
+                let audit_storage = match config_file {
+                    Some(config_file) => {
+                        let contents = std::fs::read_to_string(&config_file)?;
+                        let config = CanopusConfig::parse(&contents, ConfigFormat::from_path(&config_file)?)?;
+                        self.deprecation_policy.enforce(&config.deprecated_keys)?;
+                        AuditStorage::from_config(&config.storage)?
+                    },
+                    None => None,
+                };
+
+                if let Some(address) = metrics_listen {
+                    let bound = prometheus::serve(address).await?;
+                    log::info!("Exposing audit metrics at http://{bound}/metrics");
+                }
+
+                let repositories = remote::list_repositories(&self.github_client, &organization).await?;
+                let mut audit_report = AuditReport::new(&organization);
+                prometheus::repositories_listed(repositories.len());
+
+                for repository in repositories {
+                    let status = AuditStatus::from(self.audit_repository(&repository, &organization).await);
+                    prometheus::repository_audited(status.label());
+
+                    progress::report(ProgressEvent::RepositoryAudited {
+                        repository: repository.to_string(),
+                    });
+
+                    audit_report.record(repository.to_string(), status);
+                }
+
+                audit_report.print();
+
+                if let Some(audit_storage) = audit_storage {
+                    let results_name = storage::audit_results_name(&organization, Utc::now());
+                    let serialized = serde_json::to_string_pretty(&audit_report)?;
+                    let location = audit_storage.store(&results_name, serialized).await?;
+
+                    println!("Audit results stored at : {}", style(location).cyan());
+                    println!();
+                }
+            },
+            CanopusCommand::RunDemo => self.run_demo().await?,
+            CanopusCommand::RunOneShot => self.run_one_shot().await,
+            CanopusCommand::ShowCapabilities { json } => {
+                let capabilities = Capabilities::current();
+
+                if json {
+                    println!("{}", capabilities.to_json()?);
+                } else {
+                    capabilities.print();
+                }
+            },
+            CanopusCommand::ShowReportSchema => {
+                println!("{}", serde_json::to_string_pretty(&json::schema())?);
+            },
+            CanopusCommand::GenerateCompletions(shell) => {
+                clap_complete::generate(shell, &mut cli::command(), "canopus", &mut std::io::stdout())
+            },
+            CanopusCommand::GenerateManPage => clap_mangen::Man::new(cli::command()).render(&mut std::io::stdout())?,
+            CanopusCommand::DescribeChecks => print!("{}", Capabilities::current().checks_reference()),
+            CanopusCommand::SelfUpdate { check_only } => self.self_update(check_only).await?,
+            CanopusCommand::ServeValidation {
+                project_root,
+                address,
+                grpc_address,
+                max_body_bytes,
+            } => self.serve(project_root, address, grpc_address, max_body_bytes).await?,
+        }
+
+        Ok(())
+    }
+
+    async fn serve(
+        &self,
+        project_root: PathBuf,
+        address: SocketAddr,
+        grpc_address: Option<SocketAddr>,
+        max_body_bytes: usize,
+    ) -> anyhow::Result<()> {
+        let served_project = Arc::new(ServedProject::new(
+            project_root,
+            self.codeowners_validator.clone(),
+            self.github_client.clone(),
+            self.deprecation_policy,
+            self.trust_extended_checks,
+        ));
+
+        // We fail fast when the configuration of the served project is broken
+        warn_diagnostics(&served_project.config().await?);
+
+        let listener = TcpListener::bind(address)
+            .await
+            .with_context(|| format!("cannot serve canopus API on {address}"))?;
+
+        println!(
+            "Serving canopus API for {} at http://{}",
+            style(served_project.project_root().display()).cyan(),
+            listener.local_addr()?
+        );
+
+        let serving = server::serve(listener, max_body_bytes, |request| served_project.answer(request));
+
+        let grpc_serving = async {
+            let Some(grpc_address) = grpc_address else {
+                return std::future::pending().await;
+            };
+
+            println!("Serving canopus gRPC API at {grpc_address}");
+
+            tonic::transport::Server::builder()
+                .add_service(OwnershipService::new(served_project.clone()))
+                .serve(grpc_address)
+                .await
+                .with_context(|| format!("cannot serve canopus gRPC API on {grpc_address}"))
+        };
+
+        // Owners verification takes over Ctrl-C once it runs, hence we stop serving by ourselves
+        tokio::select! {
+            served = serving => served,
+            served = grpc_serving => served,
+            _ = tokio::signal::ctrl_c() => Ok(()),
+        }
+    }
+
+    async fn self_update(&self, check_only: bool) -> anyhow::Result<()> {
+        let current = Version::current();
+        let release = releases::latest_release(&self.github_client).await?;
+        let latest = release.version()?;
+
+        if latest <= current {
+            println!("canopus {} is up to date", style(current).cyan());
+            return Ok(());
+        }
+
+        println!("canopus {} is available (current : {current})", style(&latest).cyan());
+
+        if check_only {
+            return Ok(());
+        }
+
+        let artifact = releases::platform_artifact()?;
+        let binary = releases::download_verified(&self.github_client, &release, &artifact).await?;
+        let location = releases::replace_current_binary(&binary)?;
+
+        println!("Updated {} to canopus {}", location.display(), style(latest).cyan());
+        Ok(())
+    }
+
+    /// Never fails, exiting with the status of the validation instead, so that CI systems
+    /// can tell issues apart from misconfigured runs
+    async fn run_one_shot(&self) {
+        let status = self.validate_one_shot().await.unwrap_or_else(|error| {
+            eprintln!("Error: {error:#}");
+            OneShotStatus::Failed
+        });
+
+        if status != OneShotStatus::Validated {
+            std::process::exit(status.exit_code());
+        }
+    }
+
+    async fn validate_one_shot(&self) -> anyhow::Result<OneShotStatus> {
+        let settings = OneShotSettings::from_env_vars(std::env::vars())?;
+        let project_name = settings.project_root.display().to_string();
+
+        let validation = async {
+            let context =
+                CodeOwnersContext::try_from(settings.project_root.clone()).map_err(CanopusError::user_error)?;
+            let config = remote::resolve_config(
+                &self.github_client,
+                settings.config_layer.clone(),
+                self.trust_extended_checks,
+            )
+            .await?;
+            self.deprecation_policy.enforce(&config.deprecated_keys)?;
+            warn_diagnostics(&config);
+
+            self.config_digests
+                .lock()
+                .unwrap()
+                .insert(project_name.clone(), json::config_digest(&config));
+
+            let outcome = self.codeowners_validator.validate(&context, &config).await?;
+            Ok((context, outcome))
+        }
+        .await;
+
+        let validations = vec![(project_name, validation)];
+
+        let report = settings.format.render(&validations, &self.run_details())?;
+
+        let report_file = settings.report_file();
+
+        std::fs::write(&report_file, report)
+            .with_context(|| format!("cannot write report to {}", report_file.display()))?;
+
+        println!("Report written to {}", report_file.display());
+        Ok(OneShotStatus::of(&validations))
+    }
+
+    async fn run_demo(&self) -> anyhow::Result<()> {
+        // Dropping the temporary dir at the end cleans up the example project
+        let demo_dir = TempDir::new()?;
+        demo::prepare_demo_project(demo_dir.path())?;
+
+        println!("{}", style("Welcome to the canopus demo!").cyan());
+        println!();
+        println!("We created an example project with the following CODEOWNERS file:");
+        println!();
+
+        demo::demo_codeowners().lines().enumerate().for_each(|(line, content)| {
+            println!("{}  {}", style(format!("L{}", line + 1)).dim(), content);
+        });
+
+        println!();
+        println!(
+            "Running {} against it (offline checks only) ...",
+            style("canopus validate").cyan()
+        );
+        println!();
+
+        let (context, config) = self.evaluate(demo_dir.path().to_path_buf()).await?;
+        let outcome = self.codeowners_validator.validate(&context, &config).await?;
+
+        if let ValidationOutcome::IssuesDetected(issues) = outcome {
+            issues.iter().for_each(|issue| {
+                println!("→ {issue}");
+                println!("  {}", style(demo::explain(issue)).italic());
+                println!();
+            });
+        }
+
+        println!(
+            "Try it on your own project with {}",
+            style("canopus validate -p <project-root>").cyan()
+        );
+        println!();
+        Ok(())
+    }
+
+    async fn validate_project(
+        &self,
+        project: ProjectLocation,
+        min_coverage: Option<f64>,
+        coverage_base: Option<String>,
+        max_issues: Option<usize>,
+        strict: bool,
+    ) -> anyhow::Result<(CodeOwnersContext, ValidationOutcome)> {
+        let is_remote = matches!(project, ProjectLocation::Remote(_));
+        let project_name = project.to_string();
+
+        let (context, mut config) = match project {
+            ProjectLocation::Local(project_root) => self.evaluate(project_root).await?,
+            ProjectLocation::Remote(repository) => {
+                let (context, mut config) = remote::fetch_project(&self.github_client, &repository).await?;
+                self.deprecation_policy
+                    .enforce(&config.deprecated_keys)
+                    .map_err(CanopusError::user_error)?;
+                warn_diagnostics(&config);
+
+                // Commands declared by repositories we don't have locally never run on this machine
+                config.checks.external.clear();
+                (context, config)
+            },
+        };
+
+        // Command line options take precedence over the configuration file
+        if min_coverage.is_some() {
+            config.coverage.minimum = min_coverage;
+        }
+
+        if coverage_base.is_some() {
+            config.coverage.base_ref = coverage_base;
+        }
+
+        if max_issues.is_some() {
+            config.general.max_issues = max_issues;
+        }
+
+        if strict {
+            config.enforce_strict_mode().map_err(CanopusError::user_error)?;
+        }
+
+        if is_remote && config.coverage.base_ref.is_some() {
+            return Err(CanopusError::user_error(anyhow!(
+                "comparing ownership coverage with a base revision requires a local project"
+            )));
+        }
+
+        self.config_digests
+            .lock()
+            .unwrap()
+            .insert(project_name, json::config_digest(&config));
+
+        let outcome = self.codeowners_validator.validate(&context, &config).await?;
+        Ok((context, outcome))
+    }
+
+    async fn validate_projects(
+        &self,
+        projects: Vec<ProjectLocation>,
+        min_coverage: Option<f64>,
+        coverage_base: Option<String>,
+        max_issues: Option<usize>,
+        strict: bool,
+    ) -> Vec<ProjectValidation> {
+        let validations = projects.into_iter().map(|project| {
+            let project_name = project.to_string();
+            let coverage_base = coverage_base.clone();
+
+            async move {
+                let validation = self
+                    .validate_project(project, min_coverage, coverage_base, max_issues, strict)
+                    .await;
+                (project_name, validation)
+            }
+        });
+
+        futures::future::join_all(validations).await
+    }
+
+    fn print_report(
+        &self,
+        format: OutputFormat,
+        validations: Vec<ProjectValidation>,
+        quiet: bool,
+    ) -> anyhow::Result<()> {
+        match format {
+            OutputFormat::Text if validations.len() == 1 => {
+                if let (_, Ok((context, outcome))) = &validations[0] {
+                    Self::print_outcome(context, outcome.clone(), quiet);
+                    self.print_summary(quiet);
+                }
+
+                Self::ensure_validated(validations)?;
+            },
+            OutputFormat::Text => {
+                let printed = Self::print_validations(validations, quiet);
+                self.print_summary(quiet);
+                printed?;
+            },
+            OutputFormat::Compact => {
+                print!("{}", compact::render(&validations));
+
+                validations.iter().for_each(|(project_name, validation)| {
+                    if let Err(error) = validation {
+                        eprintln!("{project_name}: cannot validate project : {error}");
+                    }
+                });
+
+                Self::ensure_validated(validations)?;
+            },
+            OutputFormat::Junit => {
+                println!("{}", junit::render(&validations, &self.run_details().skipped_checks));
+                Self::ensure_validated(validations)?;
+            },
+            OutputFormat::PrComment => {
+                print!(
+                    "{}",
+                    pr_comment::render(&validations, &self.run_details().skipped_checks)
+                );
+                Self::ensure_validated(validations)?;
+            },
+            OutputFormat::Json => {
+                println!("{}", json::render(&validations, &self.run_details()));
+                Self::ensure_validated(validations)?;
+            },
+        }
+
+        Ok(())
+    }
+
+    fn print_validations(validations: Vec<ProjectValidation>, quiet: bool) -> anyhow::Result<()> {
+        for (project_name, validation) in &validations {
+            if quiet && matches!(validation, Ok((_, ValidationOutcome::NoIssues))) {
+                continue;
+            }
+
+            println!("Project : {}", style(project_name).bold());
+            println!();
+
+            match validation {
+                Ok((context, outcome)) => Self::print_outcome(context, outcome.clone(), quiet),
+                Err(error) => {
+                    println!("{}", style(format!("Cannot validate project : {error}")).red());
+                    println!();
+                },
+            }
+        }
+
+        Self::ensure_validated(validations)
+    }
+
+    /// Fails when a single project cannot be validated or when any of several projects has issues
+    fn ensure_validated(validations: Vec<ProjectValidation>) -> anyhow::Result<()> {
+        let total_projects = validations.len();
+        let mut outcomes = Vec::with_capacity(total_projects);
+
+        // Projects that could not be validated tell more about what went wrong than issues found elsewhere
+        for (project_name, validation) in validations {
+            match validation {
+                Ok((_, outcome)) => outcomes.push((project_name, outcome)),
+                Err(error) => return Err(anyhow::Error::new(CanopusError::from(error))),
+            }
+        }
+
+        let failed_projects = outcomes
+            .iter()
+            .filter(|(_, outcome)| matches!(outcome, ValidationOutcome::IssuesDetected(_)))
+            .map(|(project_name, _)| project_name)
+            .collect_vec();
+
+        match failed_projects.as_slice() {
+            [] => Ok(()),
+            [project_name] if total_projects == 1 => {
+                Err(CanopusError::findings(format!("found issues in {project_name}")))
+            },
+            _ => Err(CanopusError::findings(format!(
+                "found issues in {} of {total_projects} projects",
+                failed_projects.len()
+            ))),
+        }
+    }
+
+    fn print_outcome(context: &CodeOwnersContext, outcome: ValidationOutcome, quiet: bool) {
+        match outcome {
+            ValidationOutcome::NoIssues if quiet => {},
+            ValidationOutcome::NoIssues => {
+                println!("{}", style("No issues found!").cyan());
+                println!();
+            },
+            ValidationOutcome::IssuesDetected(issues) => {
+                println!("{}", text::render(context, &issues));
+
+                if !quiet {
+                    println!("{}", style("Some issues were found").red());
+                    println!();
+                }
+            },
+        }
+    }
+
+    // Reports carry what validation gathered about the whole run, besides results of each project
+    fn run_details(&self) -> RunDetails {
+        RunDetails {
+            config_digests: self.config_digests.lock().unwrap().clone(),
+            skipped_checks: self.statistics.collected().skipped_checks,
+        }
+    }
+
+    fn print_summary(&self, quiet: bool) {
+        if !quiet {
+            self.statistics.collected().print();
         }
+    }
+
+    // Repositories are audited even without a canopus configuration, skipping only those without CODEOWNERS
+    async fn audit_repository(
+        &self,
+        repository: &RemoteRepository,
+        organization: &str,
+    ) -> anyhow::Result<ValidationOutcome> {
+        let (context, mut config) =
+            remote::fetch_project_or_default(&self.github_client, repository, organization).await?;
+
+        // Base revisions cannot be compared without a local project, while audited repositories
+        // never get to run their commands on the auditor's machine
+        config.coverage.base_ref = None;
+        config.checks.external.clear();
+
+        self.codeowners_validator.validate(&context, &config).await
+    }
+
+    // Organizations are guessed from the origin remote, asking users only as a last resort
+    fn detect_repository(project_root: &Path) -> anyhow::Result<RemoteRepository> {
+        let Some(repository) = git::origin_url(project_root)
+            .ok()
+            .and_then(|remote_url| RemoteRepository::from_remote_url(&remote_url))
+        else {
+            bail!("cannot detect the Github repository for this project from its origin remote");
+        };
+
+        Ok(repository)
+    }
+
+    fn publishing_target(project: &ProjectLocation, pull_request: Option<u64>) -> anyhow::Result<PublishingTarget> {
+        let (repository, head_commit) = match project {
+            ProjectLocation::Local(project_root) => (
+                Self::detect_repository(project_root)?,
+                Some(git::head_commit(project_root)?),
+            ),
+            ProjectLocation::Remote(repository) => (repository.clone(), None),
+        };
+
+        Ok(PublishingTarget {
+            repository,
+            pull_request,
+            head_commit,
+        })
+    }
+
+    /// Publishes validation results as a check run annotating CODEOWNERS lines,
+    /// on the head of the pull request if any, otherwise on the commit checked out
+    async fn publish_check_run(
+        &self,
+        target: &PublishingTarget,
+        validations: &[ProjectValidation],
+    ) -> anyhow::Result<()> {
+        let head_sha = match (target.pull_request, &target.head_commit) {
+            (Some(number), _) => pulls::head_sha(&self.github_client, &target.repository, number).await?,
+            (None, Some(head_commit)) => head_commit.clone(),
+            (None, None) => bail!("publishing a check run for a remote repository requires --post-to-pr"),
+        };
+
+        let validated = validations
+            .iter()
+            .all(|(_, validation)| matches!(validation, Ok((_, ValidationOutcome::NoIssues))));
+
+        let conclusion = if validated {
+            CheckConclusion::Success
+        } else {
+            CheckConclusion::Failure
+        };
+
+        let summary = pr_comment::render(validations, &self.run_details().skipped_checks);
+        let annotations = annotations::render(validations);
+
+        checks::publish_check_run(
+            &self.github_client,
+            &target.repository,
+            &head_sha,
+            conclusion,
+            &summary,
+            &annotations,
+        )
+        .await?;
+
+        eprintln!(
+            "Published check run with {} annotations on {}/{}",
+            annotations.len(),
+            target.repository.owner,
+            target.repository.name
+        );
 
         Ok(())
     }
 
-    fn evaluate(project_path: PathBuf) -> anyhow::Result<(CodeOwnersContext, CanopusConfig)> {
-        let codeowners_context = CodeOwnersContext::try_from(project_path.clone())?;
-        let canopus_config = CanopusConfig::try_from(project_path.as_path())?;
+    /// Comments validation results on a pull request, also setting a commit status on its head when asked
+    async fn post_to_pull_request(
+        &self,
+        repository: &RemoteRepository,
+        number: u64,
+        commit_status: bool,
+        validations: &[ProjectValidation],
+    ) -> anyhow::Result<()> {
+        let comment = pr_comment::render(validations, &self.run_details().skipped_checks);
+        let posting = pulls::upsert_comment(
+            &self.github_client,
+            repository,
+            number,
+            pr_comment::PR_COMMENT_MARKER,
+            &comment,
+        )
+        .await?;
+
+        let action = match posting {
+            CommentPosting::Created => "Commented",
+            CommentPosting::Updated => "Updated comment",
+        };
+
+        eprintln!(
+            "{action} on pull request #{number} of {}/{}",
+            repository.owner, repository.name
+        );
+
+        if !commit_status {
+            return Ok(());
+        }
+
+        let total_issues = validations
+            .iter()
+            .map(|(_, validation)| match validation {
+                Ok((_, ValidationOutcome::IssuesDetected(issues))) => issues.len(),
+                _ => 0,
+            })
+            .sum::<usize>();
+
+        let validated = validations.iter().all(|(_, validation)| validation.is_ok());
+
+        let (state, description) = if !validated {
+            (CommitState::Failure, "Cannot validate CODEOWNERS".to_string())
+        } else if total_issues > 0 {
+            (CommitState::Failure, format!("Found {total_issues} CODEOWNERS issues"))
+        } else {
+            (CommitState::Success, "No CODEOWNERS issues found".to_string())
+        };
+
+        pulls::set_head_status(&self.github_client, repository, number, state, &description).await
+    }
+
+    fn detect_organization(project_root: &Path) -> anyhow::Result<String> {
+        let detected = git::origin_url(project_root)
+            .ok()
+            .and_then(|remote_url| scaffolding::github_organization(&remote_url));
+
+        if let Some(organization) = detected {
+            return Ok(organization);
+        }
+
+        if !console::user_attended() {
+            bail!("cannot detect the Github organization for this project, use --org to define it");
+        }
+
+        let terminal = console::Term::stdout();
+        terminal.write_str("Github organization owning this project : ")?;
+        let organization = terminal.read_line()?.trim().to_string();
+
+        if organization.is_empty() {
+            bail!("expecting a Github organization");
+        }
+
+        Ok(organization)
+    }
+
+    async fn evaluate(&self, project_path: PathBuf) -> anyhow::Result<(CodeOwnersContext, CanopusConfig)> {
+        let codeowners_context = CodeOwnersContext::try_from(project_path.clone()).map_err(CanopusError::user_error)?;
+        let config_layer = ConfigLayer::try_from(project_path.as_path()).map_err(CanopusError::user_error)?;
+        let canopus_config =
+            remote::resolve_config(&self.github_client, config_layer, self.trust_extended_checks).await?;
+        self.deprecation_policy
+            .enforce(&canopus_config.deprecated_keys)
+            .map_err(CanopusError::user_error)?;
+        warn_diagnostics(&canopus_config);
         Ok((codeowners_context, canopus_config))
     }
 }
+
+// Configuration diagnostics never stop validation, hence they are only printed as warnings
+fn warn_diagnostics(config: &CanopusConfig) {
+    config.diagnostics.iter().for_each(|diagnostic| {
+        eprintln!("{} {}", style("warning:").yellow(), diagnostic);
+    });
+}
@@ -0,0 +1,140 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::ValidationOutcome;
+use console::style;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "kebab-case")]
+pub enum AuditStatus {
+    Healthy,
+    IssuesDetected { issues: usize },
+    Skipped { reason: String },
+}
+
+impl From<anyhow::Result<ValidationOutcome>> for AuditStatus {
+    fn from(validation: anyhow::Result<ValidationOutcome>) -> Self {
+        match validation {
+            Ok(ValidationOutcome::NoIssues) => AuditStatus::Healthy,
+            Ok(ValidationOutcome::IssuesDetected(issues)) => AuditStatus::IssuesDetected { issues: issues.len() },
+            Err(error) => AuditStatus::Skipped {
+                reason: error.to_string(),
+            },
+        }
+    }
+}
+
+impl AuditStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AuditStatus::Healthy => "healthy",
+            AuditStatus::IssuesDetected { .. } => "issues-detected",
+            AuditStatus::Skipped { .. } => "skipped",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RepositoryAudit {
+    pub repository: String,
+    #[serde(flatten)]
+    pub status: AuditStatus,
+}
+
+/// Aggregated validation results for all repositories within an organization
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AuditReport {
+    pub organization: String,
+    pub repositories: Vec<RepositoryAudit>,
+}
+
+impl AuditReport {
+    pub fn new(organization: &str) -> Self {
+        Self {
+            organization: organization.to_string(),
+            repositories: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, repository: String, status: AuditStatus) {
+        self.repositories.push(RepositoryAudit { repository, status });
+    }
+
+    fn count_matching(&self, predicate: impl Fn(&AuditStatus) -> bool) -> usize {
+        self.repositories
+            .iter()
+            .filter(|audit| predicate(&audit.status))
+            .count()
+    }
+
+    pub fn healthy(&self) -> usize {
+        self.count_matching(|status| matches!(status, AuditStatus::Healthy))
+    }
+
+    pub fn with_issues(&self) -> usize {
+        self.count_matching(|status| matches!(status, AuditStatus::IssuesDetected { .. }))
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.count_matching(|status| matches!(status, AuditStatus::Skipped { .. }))
+    }
+
+    pub fn print(&self) {
+        println!(
+            "Audited {} repositories within {}",
+            self.repositories.len(),
+            style(&self.organization).cyan()
+        );
+        println!();
+
+        self.repositories.iter().for_each(|audit| {
+            let formatted_status = match &audit.status {
+                AuditStatus::Healthy => style("no issues".to_string()).cyan(),
+                AuditStatus::IssuesDetected { issues } => style(format!("{issues} issues")).red(),
+                AuditStatus::Skipped { reason } => style(format!("skipped ({reason})")).dim(),
+            };
+
+            println!("→ {} : {}", audit.repository, formatted_status);
+        });
+
+        println!();
+        println!(
+            "{} healthy, {} with issues, {} skipped",
+            self.healthy(),
+            self.with_issues(),
+            self.skipped()
+        );
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::auditing::{AuditReport, AuditStatus};
+    use crate::core::models::ValidationOutcome;
+    use assertor::EqualityAssertion;
+
+    #[test]
+    fn should_aggregate_repository_audits() {
+        let mut report = AuditReport::new("dotanuki-labs");
+
+        report.record("dotanuki-labs/canopus@main".to_string(), AuditStatus::Healthy);
+        report.record(
+            "dotanuki-labs/gradle-plugins@main".to_string(),
+            AuditStatus::IssuesDetected { issues: 2 },
+        );
+        report.record(
+            "dotanuki-labs/dotfiles@main".to_string(),
+            AuditStatus::from(Err(anyhow::anyhow!("no CODEOWNERS definition found"))),
+        );
+        report.record(
+            "dotanuki-labs/homebrew-taps@main".to_string(),
+            AuditStatus::from(Ok(ValidationOutcome::NoIssues)),
+        );
+
+        let summary = (report.healthy(), report.with_issues(), report.skipped());
+
+        assertor::assert_that!(summary).is_equal_to((2, 1, 1));
+    }
+}
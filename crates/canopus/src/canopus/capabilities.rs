@@ -0,0 +1,150 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::formats::json::REPORT_SCHEMA_VERSION;
+use crate::core::models::checks::{CHECKS, CheckDefinition};
+use crate::core::models::config::SUPPORTED_CONFIG_KEYS;
+use crate::core::models::deprecations::{DEPRECATIONS, Deprecation};
+use console::style;
+use itertools::Itertools;
+use serde::Serialize;
+
+static SUPPORTED_FLAVORS: [&str; 1] = ["github"];
+static SUPPORTED_OUTPUT_FORMATS: [&str; 7] = ["text", "compact", "junit", "pr-comment", "json", "md", "html"];
+
+/// Pre-release versions are shipped through the preview channel, where deprecated
+/// flags and config keys may be removed without further notice
+fn release_channel(version: &str) -> &'static str {
+    if version.contains('-') { "preview" } else { "stable" }
+}
+
+/// Describes what the installed version of canopus is able to do
+#[derive(Debug, Serialize)]
+pub struct Capabilities {
+    pub version: &'static str,
+    pub channel: &'static str,
+    pub flavors: &'static [&'static str],
+    pub checks: &'static [CheckDefinition],
+    #[serde(rename = "output-formats")]
+    pub output_formats: &'static [&'static str],
+    #[serde(rename = "report-schema-version")]
+    pub report_schema_version: &'static str,
+    #[serde(rename = "config-keys")]
+    pub config_keys: &'static [&'static str],
+    pub deprecations: &'static [Deprecation],
+}
+
+impl Capabilities {
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            channel: release_channel(env!("CARGO_PKG_VERSION")),
+            flavors: &SUPPORTED_FLAVORS,
+            checks: &CHECKS,
+            output_formats: &SUPPORTED_OUTPUT_FORMATS,
+            report_schema_version: REPORT_SCHEMA_VERSION,
+            config_keys: &SUPPORTED_CONFIG_KEYS,
+            deprecations: &DEPRECATIONS,
+        }
+    }
+
+    /// Renders every check as a Markdown reference, generated from the registry so it never drifts
+    pub fn checks_reference(&self) -> String {
+        let mut lines = vec![
+            format!("# canopus {} checks", self.version),
+            String::new(),
+            "| Code | Name | Category | Default severity | Mode | Description | Config keys |".to_string(),
+            "|------|------|----------|------------------|------|-------------|-------------|".to_string(),
+        ];
+
+        self.checks.iter().for_each(|check| {
+            let config_keys = check.config_keys.iter().map(|key| format!("`{key}`")).join(", ");
+
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                check.code,
+                check.name,
+                check.category,
+                check.default_severity,
+                if check.offline { "offline" } else { "online" },
+                check.description,
+                if config_keys.is_empty() {
+                    "-".to_string()
+                } else {
+                    config_keys
+                }
+            ));
+        });
+
+        lines.join("\n") + "\n"
+    }
+
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn print(&self) {
+        println!("canopus {} ({} channel)", style(self.version).cyan(), self.channel);
+        println!();
+        println!("Flavors : {}", self.flavors.join(", "));
+        println!("Output formats : {}", self.output_formats.join(", "));
+        println!("JSON report schema : {}", self.report_schema_version);
+        println!();
+        println!("Checks :");
+        self.checks.iter().for_each(|check| {
+            println!(
+                "→ {} {} ({}, {}, {})",
+                style(check.code).cyan(),
+                check.name,
+                check.category,
+                check.default_severity,
+                if check.offline { "offline" } else { "online" }
+            );
+        });
+        println!();
+        println!("Configuration keys :");
+        self.config_keys.iter().for_each(|key| println!("→ {key}"));
+        println!();
+
+        if !self.deprecations.is_empty() {
+            println!("Deprecations :");
+            self.deprecations
+                .iter()
+                .for_each(|deprecation| println!("→ {deprecation}"));
+            println!();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::capabilities;
+    use crate::canopus::capabilities::Capabilities;
+    use assertor::{EqualityAssertion, StringAssertion};
+
+    #[test]
+    fn should_describe_capabilities_as_json() {
+        let json = Capabilities::current().to_json().unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assertor::assert_that!(parsed["checks"][1]["code"].as_str()).is_equal_to(Some("CNP002"));
+        assertor::assert_that!(parsed["checks"][1]["default-severity"].as_str()).is_equal_to(Some("error"));
+        assertor::assert_that!(json).contains("general.github-organization");
+    }
+
+    #[test]
+    fn should_render_checks_reference_from_registry() {
+        let reference = Capabilities::current().checks_reference();
+
+        assertor::assert_that!(reference).contains(
+            "| CNP002 | dangling-glob | structure | error | offline | Glob pattern does not match any project path | `paths.include`, `paths.exclude` |",
+        );
+        assertor::assert_that!(reference.lines().filter(|line| line.starts_with("| CNP")).count()).is_equal_to(40);
+    }
+
+    #[test]
+    fn should_derive_release_channel_from_version() {
+        assertor::assert_that!(capabilities::release_channel("0.5.0")).is_equal_to("stable");
+        assertor::assert_that!(capabilities::release_channel("0.6.0-beta.1")).is_equal_to("preview");
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::{ConfigurationIssue, ConsistencyIssue, IssueKind, StructuralIssue, ValidationIssue};
+use std::path::Path;
+
+static DEMO_CODEOWNERS: &str = "\
+# Example ownership rules generated by canopus demo
+*.rs          @demo-org/rustaceans
+*.md          @demo-org/writers
+*.py          @demo-org/pythonistas
+*.rs          @demo-org/crabbers
+*.toml        demo-org/devops
+*.yml         ci@demo-org.dev
+";
+
+static DEMO_CONFIG: &str = "\
+[general]
+github-organization = \"demo-org\"
+offline-checks-only = true
+
+[ownership]
+forbid-email-owners = true
+";
+
+static DEMO_PROJECT_FILES: [(&str, &str); 4] = [
+    ("src/main.rs", "fn main() {}\n"),
+    ("README.md", "# Demo project\n"),
+    ("Cargo.toml", "[package]\nname = \"demo\"\n"),
+    (".github/workflows/ci.yml", "name: CI\n"),
+];
+
+/// Writes a small example project with a deliberately broken CODEOWNERS
+pub fn prepare_demo_project(project_root: &Path) -> anyhow::Result<()> {
+    let github_dir = project_root.join(".github");
+    std::fs::create_dir_all(&github_dir)?;
+    std::fs::write(github_dir.join("CODEOWNERS"), DEMO_CODEOWNERS)?;
+    std::fs::write(github_dir.join("canopus.toml"), DEMO_CONFIG)?;
+
+    for (relative_path, contents) in DEMO_PROJECT_FILES {
+        let file_path = project_root.join(relative_path);
+
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(file_path, contents)?;
+    }
+
+    log::info!("Demo project prepared at : {}", project_root.to_string_lossy());
+    Ok(())
+}
+
+pub fn demo_codeowners() -> &'static str {
+    DEMO_CODEOWNERS
+}
+
+/// A beginner-friendly explanation for a given finding
+pub fn explain(issue: &ValidationIssue) -> &'static str {
+    match &issue.kind {
+        IssueKind::Structural(structural) => match structural {
+            StructuralIssue::InvalidSyntax => {
+                "Github ignores lines it cannot parse. Owners must be @user, @org/team or an email address."
+            },
+            StructuralIssue::DanglingGlobPattern => {
+                "This pattern matches no file in the project, so it protects nothing. It is usually a typo or a leftover."
+            },
+            StructuralIssue::DuplicateOwnership => {
+                "The same pattern appears more than once. Github applies the last matching rule, silently discarding the others."
+            },
+            StructuralIssue::NearDuplicateOwnership => {
+                "These patterns look alike but differ in anchoring, like docs/ and /docs/. Check which one you meant."
+            },
+            StructuralIssue::FileSizeLimitExceeded => {
+                "Github silently ignores CODEOWNERS files over 3 MB, disabling ownership for the whole project."
+            },
+            StructuralIssue::UnhonoredLocation => {
+                "Github only reads CODEOWNERS from .github/, docs/ or the repository root, never from nested folders."
+            },
+            StructuralIssue::RepeatedOwner => {
+                "This rule lists the same owner twice, maybe with a different case. Github counts it once anyway."
+            },
+            StructuralIssue::IgnoredPathsOnly => {
+                "This pattern only matches files ignored by git, like build outputs, which never reach Github pull requests."
+            },
+        },
+        IssueKind::Consistency(consistency) => match consistency {
+            ConsistencyIssue::CannotListMembersInTheOrganization(_)
+            | ConsistencyIssue::CannotVerifyUser(_)
+            | ConsistencyIssue::CannotVerifyTeam(_) => {
+                "Canopus could not reach Github to confirm this owner. Check your GITHUB_TOKEN and network access."
+            },
+            ConsistencyIssue::OrganizationDoesNotExist(_)
+            | ConsistencyIssue::TeamDoesNotExist(_)
+            | ConsistencyIssue::UserDoesNotExist(_) => {
+                "This owner does not exist on Github, so nobody will be requested for reviews."
+            },
+            ConsistencyIssue::OutsiderUser(_) | ConsistencyIssue::TeamDoesNotMatchOrganization(_) => {
+                "This owner does not belong to the organization configured in canopus.toml."
+            },
+            ConsistencyIssue::UserNotInOwningTeam(_) => {
+                "Nobody else backs this owner in an owning team, making reviews depend on a single person."
+            },
+            ConsistencyIssue::TeamReviewAssignmentDisabled(_) => {
+                "Review requests for this team notify everyone, hence nobody in particular is expected to review."
+            },
+            ConsistencyIssue::CannotVerifyBranchProtection(_) => {
+                "Canopus could not read branch protection. Reading it requires admin access to the repository."
+            },
+            ConsistencyIssue::CodeOwnersReviewNotRequired(_)
+            | ConsistencyIssue::RequiredApprovalsBelowPolicies { .. } => {
+                "Github only enforces CODEOWNERS when branch protection or rulesets require reviews from code owners."
+            },
+            ConsistencyIssue::UserRoleBelowRequirement { .. } | ConsistencyIssue::TeamRoleBelowRequirement { .. } => {
+                "Your canopus.toml requires owners of these paths to hold a role (required-role). Grant it or pick other owners."
+            },
+        },
+        IssueKind::Configuration(configuration) => match configuration {
+            ConfigurationIssue::EmailOwnerForbidden => {
+                "Your canopus.toml forbids email owners (forbid-email-owners). Prefer Github users or teams."
+            },
+            ConfigurationIssue::OnlyGithubTeamOwnerAllowed => {
+                "Your canopus.toml only accepts Github teams as owners (enforce-github-teams-owners)."
+            },
+            ConfigurationIssue::OnlyOneOwnerPerEntry => {
+                "Your canopus.toml requires a single owner per rule (enforce-one-owner-per-line)."
+            },
+            ConfigurationIssue::OwnershipCoverageBelowMinimum | ConfigurationIssue::OwnershipCoverageDecreased => {
+                "Too many project files have no owner. Add rules covering them or lower coverage.minimum."
+            },
+            ConfigurationIssue::TooManyRules | ConfigurationIssue::LineTooLong => {
+                "Your canopus.toml limits how large CODEOWNERS grows (limits section), keeping it far from Github limits."
+            },
+            ConfigurationIssue::ExternalCheckViolation => {
+                "A command configured in canopus.toml (checks.external) enforces a policy specific to your company."
+            },
+            ConfigurationIssue::UnownedCodeOwners => {
+                "Anyone could change ownership without a gatekeeper. Add a rule owning CODEOWNERS itself."
+            },
+            ConfigurationIssue::OwnersNotSorted | ConfigurationIssue::RulesNotSorted => {
+                "Your canopus.toml requires a canonical order, keeping reviews of large CODEOWNERS files readable."
+            },
+            ConfigurationIssue::RuleOutsideSection => {
+                "Your canopus.toml requires rules to live under sections like `# === Backend ===`, grouping them per area."
+            },
+            ConfigurationIssue::NotEnoughOwners => {
+                "Your canopus.toml requires several owners for critical paths, so reviews never depend on a single owner."
+            },
+        },
+        IssueKind::Style(_) => {
+            "Your canopus.toml enforces a whitespace style, keeping diffs small when many teams edit CODEOWNERS."
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::demo;
+    use crate::canopus::validation::CodeOwnersValidator;
+    use crate::core::models::ValidationOutcome;
+    use crate::core::models::codeowners::CodeOwnersContext;
+    use crate::core::models::config::CanopusConfig;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::infra::github::ConsistentGithubState;
+    use crate::infra::paths::PathWalker;
+    use assertor::EqualityAssertion;
+    use itertools::Itertools;
+    use temp_dir::TempDir;
+
+    #[tokio::test]
+    async fn should_detect_all_demo_issues() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        demo::prepare_demo_project(temp_dir.path()).unwrap();
+
+        let context = CodeOwnersContext::try_from(temp_dir.path().to_path_buf()).unwrap();
+        let config = CanopusConfig::try_from(temp_dir.path()).unwrap();
+
+        let path_walker = PathWalker::with_paths(vec!["src/main.rs", "README.md", "Cargo.toml", "ci.yml"]);
+        let validator = CodeOwnersValidator::new(Box::new(ConsistentGithubState), path_walker);
+
+        let ValidationOutcome::IssuesDetected(issues) = validator.validate(&context, &config).await.unwrap() else {
+            panic!("demo project must have issues");
+        };
+
+        let lines_and_kinds = issues
+            .iter()
+            .map(|issue| (issue.line, issue.kind.clone()))
+            .collect_vec();
+
+        let expected = vec![
+            (1, ValidationIssueKindFactory::duplicate_ownership()),
+            (3, ValidationIssueKindFactory::dangling_glob_pattern()),
+            (5, ValidationIssueKindFactory::invalid_syntax()),
+            (6, ValidationIssueKindFactory::github_owners_only()),
+        ];
+
+        assertor::assert_that!(lines_and_kinds).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_write_demo_project_files() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        demo::prepare_demo_project(temp_dir.path()).unwrap();
+
+        let written = ["src/main.rs", "README.md", "Cargo.toml", ".github/workflows/ci.yml"]
+            .iter()
+            .filter(|path| temp_dir.path().join(path).is_file())
+            .collect_vec();
+
+        assertor::assert_that!(written.len()).is_equal_to(4);
+    }
+}
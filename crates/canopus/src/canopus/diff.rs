@@ -0,0 +1,322 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners::{CODEOWNERS_LOCATIONS, CodeOwners, CodeOwnersEntry, OwnershipRule};
+use crate::core::ownership::OwnershipResolver;
+use crate::infra::git;
+use itertools::Itertools;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum DiffFormat {
+    Text,
+    Json,
+}
+
+/// A project file whose effective owners differ between two revisions
+#[derive(Debug, PartialEq, Serialize)]
+pub struct FileOwnershipChange {
+    pub path: String,
+    #[serde(rename = "owners-before")]
+    pub owners_before: Vec<String>,
+    #[serde(rename = "owners-after")]
+    pub owners_after: Vec<String>,
+}
+
+/// How many files an owner gained or lost between two revisions
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OwnerChanges {
+    pub owner: String,
+    #[serde(rename = "gained-files")]
+    pub gained_files: usize,
+    #[serde(rename = "lost-files")]
+    pub lost_files: usize,
+}
+
+/// Ownership changes between two revisions of a project
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OwnershipDiff {
+    pub from: String,
+    pub to: String,
+    #[serde(rename = "added-rules")]
+    pub added_rules: Vec<String>,
+    #[serde(rename = "removed-rules")]
+    pub removed_rules: Vec<String>,
+    pub owners: Vec<OwnerChanges>,
+    #[serde(rename = "changed-files")]
+    pub changed_files: Vec<FileOwnershipChange>,
+}
+
+fn rule_definitions(code_owners: &CodeOwners) -> Vec<String> {
+    code_owners
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            CodeOwnersEntry::Rule(rule) => Some(rule_definition(rule)),
+            _ => None,
+        })
+        .collect_vec()
+}
+
+// Rules are compared regardless of their line numbers, hence moving a rule does not change it
+fn rule_definition(rule: &OwnershipRule) -> String {
+    format!("{} {}", rule.glob.glob(), rule.owners.iter().join(" "))
+}
+
+fn effective_owners(resolver: &OwnershipResolver<'_>, file: &Path) -> Vec<String> {
+    resolver
+        .resolve(file)
+        .map(|rule| {
+            rule.owners
+                .iter()
+                .map(|owner| owner.to_string())
+                .unique()
+                .sorted()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn describe_owners(owners: &[String]) -> String {
+    if owners.is_empty() {
+        return "(unowned)".to_string();
+    }
+
+    owners.join(" ")
+}
+
+impl OwnershipDiff {
+    /// Reads CODEOWNERS at both revisions, comparing ownership over files recorded by any of them
+    pub fn between_revisions(project_root: &Path, from: &str, to: &str) -> anyhow::Result<Self> {
+        let files_before = git::files_at_revision(project_root, from)?;
+        let files_after = git::files_at_revision(project_root, to)?;
+
+        let before = codeowners_at_revision(project_root, from, &files_before)?;
+        let after = codeowners_at_revision(project_root, to, &files_after)?;
+
+        let project_files = files_before.union(&files_after).cloned().sorted().collect_vec();
+        Ok(Self::compute(from, &before, to, &after, &project_files))
+    }
+
+    pub fn compute(from: &str, before: &CodeOwners, to: &str, after: &CodeOwners, project_files: &[PathBuf]) -> Self {
+        let rules_before = rule_definitions(before);
+        let rules_after = rule_definitions(after);
+
+        let added_rules = rules_after
+            .iter()
+            .filter(|rule| !rules_before.contains(rule))
+            .cloned()
+            .collect_vec();
+
+        let removed_rules = rules_before
+            .iter()
+            .filter(|rule| !rules_after.contains(rule))
+            .cloned()
+            .collect_vec();
+
+        let resolver_before = OwnershipResolver::new(before);
+        let resolver_after = OwnershipResolver::new(after);
+
+        let mut changes_per_owner: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+
+        let changed_files = project_files
+            .iter()
+            .filter_map(|file| {
+                let owners_before = effective_owners(&resolver_before, file);
+                let owners_after = effective_owners(&resolver_after, file);
+
+                if owners_before == owners_after {
+                    return None;
+                }
+
+                owners_after
+                    .iter()
+                    .filter(|owner| !owners_before.contains(owner))
+                    .for_each(|owner| changes_per_owner.entry(owner.clone()).or_default().0 += 1);
+
+                owners_before
+                    .iter()
+                    .filter(|owner| !owners_after.contains(owner))
+                    .for_each(|owner| changes_per_owner.entry(owner.clone()).or_default().1 += 1);
+
+                Some(FileOwnershipChange {
+                    path: file.to_string_lossy().to_string(),
+                    owners_before,
+                    owners_after,
+                })
+            })
+            .collect_vec();
+
+        let owners = changes_per_owner
+            .into_iter()
+            .map(|(owner, (gained_files, lost_files))| OwnerChanges {
+                owner,
+                gained_files,
+                lost_files,
+            })
+            .collect_vec();
+
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            added_rules,
+            removed_rules,
+            owners,
+            changed_files,
+        }
+    }
+
+    pub fn has_changes(&self) -> bool {
+        !self.added_rules.is_empty() || !self.removed_rules.is_empty() || !self.changed_files.is_empty()
+    }
+
+    pub fn render(&self, format: DiffFormat) -> String {
+        match format {
+            DiffFormat::Text => self.to_text(),
+            DiffFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        if !self.has_changes() {
+            return format!("No ownership changes from {} to {}\n", self.from, self.to);
+        }
+
+        let mut lines = vec![format!("Ownership changes from {} to {}", self.from, self.to)];
+
+        if !self.added_rules.is_empty() || !self.removed_rules.is_empty() {
+            lines.extend([String::new(), "Rules".to_string(), String::new()]);
+            lines.extend(self.added_rules.iter().map(|rule| format!("+ {rule}")));
+            lines.extend(self.removed_rules.iter().map(|rule| format!("- {rule}")));
+        }
+
+        if !self.owners.is_empty() {
+            lines.extend([String::new(), "Owners".to_string(), String::new()]);
+
+            lines.extend(self.owners.iter().map(|changes| {
+                format!(
+                    "{} : gained {} files, lost {} files",
+                    changes.owner, changes.gained_files, changes.lost_files
+                )
+            }));
+        }
+
+        if !self.changed_files.is_empty() {
+            lines.extend([String::new(), "Files changing ownership".to_string(), String::new()]);
+
+            lines.extend(self.changed_files.iter().map(|change| {
+                format!(
+                    "{} : {} → {}",
+                    change.path,
+                    describe_owners(&change.owners_before),
+                    describe_owners(&change.owners_after)
+                )
+            }));
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+// Revisions without CODEOWNERS are compared as if no path had owners
+fn codeowners_at_revision(
+    project_root: &Path,
+    revision: &str,
+    recorded_files: &HashSet<PathBuf>,
+) -> anyhow::Result<CodeOwners> {
+    let Some(location) = CODEOWNERS_LOCATIONS
+        .iter()
+        .map(Path::new)
+        .find(|location| recorded_files.contains(*location))
+    else {
+        log::info!("No CODEOWNERS definition found at {}", revision);
+        return CodeOwners::try_from("");
+    };
+
+    let contents = git::file_at_revision(project_root, revision, location)?;
+    CodeOwners::try_from(contents.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::diff::{DiffFormat, FileOwnershipChange, OwnerChanges, OwnershipDiff};
+    use crate::core::models::codeowners::CodeOwners;
+    use crate::infra::git::test_helpers;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use std::path::PathBuf;
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_report_ownership_changes_between_revisions() {
+        let before = CodeOwners::try_from(indoc! {"
+            *.rs        @dotanuki-labs/rustaceans
+            docs/*      @ubiratansoares
+        "})
+        .unwrap();
+
+        let after = CodeOwners::try_from(indoc! {"
+            docs/*      @ubiratansoares
+            *.rs        @dotanuki-labs/rustaceans
+            src/cli/*   @dotanuki-labs/cli
+        "})
+        .unwrap();
+
+        let project_files = ["src/cli/args.rs", "src/main.rs", "docs/using.md"]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+
+        let diff = OwnershipDiff::compute("main", &before, "HEAD", &after, &project_files);
+
+        let expected_files = vec![FileOwnershipChange {
+            path: "src/cli/args.rs".to_string(),
+            owners_before: vec!["@dotanuki-labs/rustaceans".to_string()],
+            owners_after: vec!["@dotanuki-labs/cli".to_string()],
+        }];
+
+        let expected_owners = vec![
+            OwnerChanges {
+                owner: "@dotanuki-labs/cli".to_string(),
+                gained_files: 1,
+                lost_files: 0,
+            },
+            OwnerChanges {
+                owner: "@dotanuki-labs/rustaceans".to_string(),
+                gained_files: 0,
+                lost_files: 1,
+            },
+        ];
+
+        assertor::assert_that!(diff.added_rules).is_equal_to(vec!["src/cli/* @dotanuki-labs/cli".to_string()]);
+        assertor::assert_that!(diff.removed_rules).is_equal_to(Vec::<String>::new());
+        assertor::assert_that!(diff.changed_files).is_equal_to(expected_files);
+        assertor::assert_that!(diff.owners).is_equal_to(expected_owners);
+        assertor::assert_that!(diff.render(DiffFormat::Text))
+            .contains("src/cli/args.rs : @dotanuki-labs/rustaceans → @dotanuki-labs/cli");
+    }
+
+    #[test]
+    fn should_compare_codeowners_recorded_by_git() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let project_root = temp_dir.path();
+
+        std::fs::write(project_root.join("main.rs"), "fn main() {}").unwrap();
+        test_helpers::git(project_root, &["init", "-q"]);
+        test_helpers::git(project_root, &["add", "-A"]);
+        test_helpers::git(project_root, &["commit", "-q", "-m", "first"]);
+
+        std::fs::create_dir(project_root.join(".github")).unwrap();
+        std::fs::write(project_root.join(".github/CODEOWNERS"), "*.rs @dotanuki-labs/devs\n").unwrap();
+        test_helpers::git(project_root, &["add", "-A"]);
+        test_helpers::git(project_root, &["commit", "-q", "-m", "second"]);
+
+        let diff = OwnershipDiff::between_revisions(project_root, "HEAD~1", "HEAD").unwrap();
+
+        assertor::assert_that!(diff.added_rules).is_equal_to(vec!["*.rs @dotanuki-labs/devs".to_string()]);
+        assertor::assert_that!(diff.render(DiffFormat::Text)).contains("main.rs : (unowned) → @dotanuki-labs/devs");
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use std::fmt::{Display, Formatter};
+
+pub static EXIT_CODE_FINDINGS: i32 = 1;
+pub static EXIT_CODE_USER_ERROR: i32 = 2;
+pub static EXIT_CODE_INFRASTRUCTURE_FAILURE: i32 = 3;
+
+/// Why a command did not succeed, mapped to exit codes telling automation whether someone
+/// has to fix ownership, fix the invocation or the configuration, or simply retry later
+#[derive(Debug)]
+pub enum CanopusError {
+    /// Commands ran fine, but found issues with ownership, like validation issues or drifts
+    Findings(String),
+    /// Commands could not run due to their inputs, like invalid configuration or missing files
+    UserError(anyhow::Error),
+    /// Commands could not run due to external services, like network failures or rejected credentials
+    InfrastructureFailure(anyhow::Error),
+}
+
+impl CanopusError {
+    pub fn findings(message: impl Into<String>) -> anyhow::Error {
+        anyhow::Error::new(CanopusError::Findings(message.into()))
+    }
+
+    pub fn user_error(error: anyhow::Error) -> anyhow::Error {
+        anyhow::Error::new(CanopusError::UserError(error))
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CanopusError::Findings(_) => EXIT_CODE_FINDINGS,
+            CanopusError::UserError(_) => EXIT_CODE_USER_ERROR,
+            CanopusError::InfrastructureFailure(_) => EXIT_CODE_INFRASTRUCTURE_FAILURE,
+        }
+    }
+}
+
+// Findings and user errors are classified where they happen, keeping any context added afterwards,
+// while anything left unclassified comes from the environment canopus runs in
+impl From<anyhow::Error> for CanopusError {
+    fn from(error: anyhow::Error) -> Self {
+        let exit_code = error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<CanopusError>())
+            .map(CanopusError::exit_code);
+
+        match exit_code {
+            Some(code) if code == EXIT_CODE_FINDINGS => CanopusError::Findings(format!("{error:#}")),
+            Some(code) if code == EXIT_CODE_USER_ERROR => CanopusError::UserError(error),
+            _ => CanopusError::InfrastructureFailure(error),
+        }
+    }
+}
+
+impl Display for CanopusError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CanopusError::Findings(message) => write!(formatter, "{message}"),
+            CanopusError::UserError(error) => write!(formatter, "{error:#}"),
+            CanopusError::InfrastructureFailure(error) => write!(formatter, "{error:#}"),
+        }
+    }
+}
+
+impl std::error::Error for CanopusError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::errors::CanopusError;
+    use anyhow::Context;
+    use assertor::EqualityAssertion;
+
+    #[test]
+    fn should_tell_findings_from_user_errors_and_infrastructure_failures() {
+        let findings = CanopusError::from(CanopusError::findings("found issues in 1 of 2 projects"));
+
+        let user_error = CanopusError::from(
+            Err::<(), _>(CanopusError::user_error(anyhow::anyhow!("expecting a CODEOWNERS file")))
+                .context("cannot validate dotanuki-labs/canopus")
+                .unwrap_err(),
+        );
+
+        let unreachable_github = octocrab::Error::Other {
+            source: "connection refused".into(),
+            backtrace: std::backtrace::Backtrace::disabled(),
+        };
+
+        let infrastructure_failure = CanopusError::from(
+            Err::<(), _>(unreachable_github)
+                .context("cannot list teams of dotanuki-labs")
+                .unwrap_err(),
+        );
+
+        assertor::assert_that!(findings.exit_code()).is_equal_to(1);
+        assertor::assert_that!(findings.to_string()).is_equal_to("found issues in 1 of 2 projects".to_string());
+        assertor::assert_that!(user_error.exit_code()).is_equal_to(2);
+        assertor::assert_that!(user_error.to_string())
+            .is_equal_to("cannot validate dotanuki-labs/canopus: expecting a CODEOWNERS file".to_string());
+        assertor::assert_that!(infrastructure_failure.exit_code()).is_equal_to(3);
+        assertor::assert_that!(infrastructure_failure.to_string())
+            .is_equal_to("cannot list teams of dotanuki-labs: Other: connection refused".to_string());
+    }
+}
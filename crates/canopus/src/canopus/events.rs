@@ -0,0 +1,297 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::ValidationIssue;
+use crate::core::offline::ContentCheck;
+use crate::infra::progress;
+use crate::infra::progress::ProgressEvent;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValidationPhase {
+    Syntax,
+    Location,
+    Limits,
+    SelfOwnership,
+    DanglingGlobs,
+    DuplicateOwnership,
+    RepeatedOwners,
+    OwnersPerEntry,
+    AllowedOwners,
+    OwnersPerPath,
+    OwnershipCoverage,
+    Ordering,
+    Whitespace,
+    ExternalChecks,
+    GithubConsistency,
+}
+
+impl From<ContentCheck> for ValidationPhase {
+    fn from(check: ContentCheck) -> Self {
+        match check {
+            ContentCheck::Syntax => ValidationPhase::Syntax,
+            ContentCheck::Limits => ValidationPhase::Limits,
+            ContentCheck::DuplicateOwnership => ValidationPhase::DuplicateOwnership,
+            ContentCheck::RepeatedOwners => ValidationPhase::RepeatedOwners,
+            ContentCheck::OwnersPerEntry => ValidationPhase::OwnersPerEntry,
+            ContentCheck::AllowedOwners => ValidationPhase::AllowedOwners,
+            ContentCheck::Ordering => ValidationPhase::Ordering,
+            ContentCheck::Whitespace => ValidationPhase::Whitespace,
+        }
+    }
+}
+
+impl Display for ValidationPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let formatted = match self {
+            ValidationPhase::Syntax => "syntax",
+            ValidationPhase::Location => "location",
+            ValidationPhase::Limits => "limits",
+            ValidationPhase::SelfOwnership => "self-ownership",
+            ValidationPhase::DanglingGlobs => "dangling-globs",
+            ValidationPhase::DuplicateOwnership => "duplicate-ownership",
+            ValidationPhase::RepeatedOwners => "repeated-owners",
+            ValidationPhase::OwnersPerEntry => "owners-per-entry",
+            ValidationPhase::AllowedOwners => "allowed-owners",
+            ValidationPhase::OwnersPerPath => "owners-per-path",
+            ValidationPhase::OwnershipCoverage => "ownership-coverage",
+            ValidationPhase::Ordering => "ordering",
+            ValidationPhase::Whitespace => "whitespace",
+            ValidationPhase::ExternalChecks => "external-checks",
+            ValidationPhase::GithubConsistency => "github-consistency",
+        };
+
+        f.write_str(formatted)
+    }
+}
+
+/// Emitted once the CODEOWNERS file has been parsed
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseCompleteEvent {
+    pub total_lines: usize,
+    pub total_rules: usize,
+    pub unique_owners: usize,
+}
+
+/// Emitted before walking project paths
+#[derive(Clone, Debug, PartialEq)]
+pub struct WalkStartEvent {
+    pub origin: PathBuf,
+}
+
+/// Emitted once project paths have been walked, up to the point every glob matched one of them
+#[derive(Clone, Debug, PartialEq)]
+pub struct WalkCompleteEvent {
+    pub origin: PathBuf,
+    pub total_paths: usize,
+}
+
+/// Emitted before verifying owners against Github
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsistencyStartEvent {
+    pub total_owners: usize,
+}
+
+/// Emitted right before and right after verifying one owner against Github
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnerCheckEvent {
+    pub owner: String,
+}
+
+/// Emitted when a check cannot run at all, rather than reporting every owner it cannot verify
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckSkippedEvent {
+    pub check: &'static str,
+    pub reason: &'static str,
+}
+
+/// Emitted once each validation phase finishes
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhaseEndEvent {
+    pub phase: ValidationPhase,
+    pub issues_found: usize,
+    pub elapsed: Duration,
+}
+
+/// Hooks into the validation lifecycle, letting consumers stream results
+/// as soon as they are available instead of waiting for the final outcome
+pub trait ValidationObserver: Send + Sync {
+    fn on_parse_complete(&self, _event: &ParseCompleteEvent) {}
+
+    fn on_walk_start(&self, _event: &WalkStartEvent) {}
+
+    fn on_walk_complete(&self, _event: &WalkCompleteEvent) {}
+
+    fn on_consistency_start(&self, _event: &ConsistencyStartEvent) {}
+
+    fn on_owner_check_start(&self, _event: &OwnerCheckEvent) {}
+
+    fn on_owner_check_end(&self, _event: &OwnerCheckEvent) {}
+
+    fn on_issue(&self, _phase: ValidationPhase, _issue: &ValidationIssue) {}
+
+    fn on_check_skipped(&self, _event: &CheckSkippedEvent) {}
+
+    fn on_phase_end(&self, _event: &PhaseEndEvent) {}
+}
+
+/// Forwards every lifecycle event to debug logs
+pub struct LoggingObserver;
+
+impl ValidationObserver for LoggingObserver {
+    fn on_parse_complete(&self, event: &ParseCompleteEvent) {
+        log::debug!(
+            "Parsed {} lines : {} rules, {} unique owners",
+            event.total_lines,
+            event.total_rules,
+            event.unique_owners
+        );
+    }
+
+    fn on_walk_complete(&self, event: &WalkCompleteEvent) {
+        log::debug!("Walked {} project paths", event.total_paths);
+    }
+
+    fn on_issue(&self, phase: ValidationPhase, issue: &ValidationIssue) {
+        log::debug!("Issue found during {} phase at L{}", phase, issue.line + 1);
+    }
+
+    fn on_check_skipped(&self, event: &CheckSkippedEvent) {
+        log::warn!("Skipped {} check, since {}", event.check, event.reason);
+    }
+
+    fn on_phase_end(&self, event: &PhaseEndEvent) {
+        log::debug!(
+            "Phase {} finished in {}ms with {} issues",
+            event.phase,
+            event.elapsed.as_millis(),
+            event.issues_found
+        );
+    }
+}
+
+/// Shows how many owners were already verified against Github, meant for interactive runs
+#[derive(Default)]
+pub struct OwnersProgressBar {
+    progress_bar: Mutex<Option<ProgressBar>>,
+}
+
+impl ValidationObserver for OwnersProgressBar {
+    fn on_consistency_start(&self, event: &ConsistencyStartEvent) {
+        if event.total_owners == 0 {
+            return;
+        }
+
+        let mut progress_bar = self.progress_bar.lock().unwrap();
+
+        // Several projects may be validated concurrently, sharing the same bar
+        match progress_bar.as_ref() {
+            Some(existing) => existing.inc_length(event.total_owners as u64),
+            None => {
+                let created = ProgressBar::new(event.total_owners as u64).with_style(
+                    ProgressStyle::with_template("{spinner} Verifying owners {pos}/{len} {wide_msg}")
+                        .expect("FATAL: invalid progress bar template"),
+                );
+
+                *progress_bar = Some(created);
+            },
+        }
+    }
+
+    fn on_owner_check_start(&self, event: &OwnerCheckEvent) {
+        if let Some(progress_bar) = self.progress_bar.lock().unwrap().as_ref() {
+            progress_bar.set_message(format!("checking {}", event.owner));
+        }
+    }
+
+    fn on_owner_check_end(&self, _event: &OwnerCheckEvent) {
+        let mut progress_bar = self.progress_bar.lock().unwrap();
+
+        if let Some(existing) = progress_bar.as_ref() {
+            existing.inc(1);
+
+            if Some(existing.position()) == existing.length() {
+                existing.finish_and_clear();
+                *progress_bar = None;
+            }
+        }
+    }
+}
+
+/// Forwards walking and phase milestones as progress events
+pub struct ProgressObserver;
+
+impl ValidationObserver for ProgressObserver {
+    fn on_walk_start(&self, event: &WalkStartEvent) {
+        progress::report(ProgressEvent::WalkStarted {
+            project: event.origin.clone(),
+        });
+    }
+
+    fn on_walk_complete(&self, event: &WalkCompleteEvent) {
+        progress::report(ProgressEvent::PathsFound {
+            project: event.origin.clone(),
+            total: event.total_paths,
+        });
+    }
+
+    fn on_phase_end(&self, event: &PhaseEndEvent) {
+        progress::report(ProgressEvent::CheckFinished {
+            check: event.phase.to_string(),
+            issues: event.issues_found,
+            elapsed_ms: event.elapsed.as_millis(),
+        });
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use crate::canopus::events::{
+        CheckSkippedEvent, ParseCompleteEvent, PhaseEndEvent, ValidationObserver, ValidationPhase, WalkCompleteEvent,
+    };
+    use crate::core::models::ValidationIssue;
+    use std::sync::{Arc, Mutex};
+
+    /// Records a textual trace of all received events
+    #[derive(Clone, Default)]
+    pub struct RecordingObserver {
+        pub events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RecordingObserver {
+        pub fn recorded(&self) -> Vec<String> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    impl ValidationObserver for RecordingObserver {
+        fn on_parse_complete(&self, event: &ParseCompleteEvent) {
+            let formatted = format!("parsed:{}:{}", event.total_rules, event.unique_owners);
+            self.events.lock().unwrap().push(formatted);
+        }
+
+        fn on_walk_complete(&self, event: &WalkCompleteEvent) {
+            let formatted = format!("walked:{}", event.total_paths);
+            self.events.lock().unwrap().push(formatted);
+        }
+
+        fn on_issue(&self, phase: ValidationPhase, issue: &ValidationIssue) {
+            let formatted = format!("issue:{}:{}", phase, issue.line);
+            self.events.lock().unwrap().push(formatted);
+        }
+
+        fn on_check_skipped(&self, event: &CheckSkippedEvent) {
+            let formatted = format!("skipped:{}", event.check);
+            self.events.lock().unwrap().push(formatted);
+        }
+
+        fn on_phase_end(&self, event: &PhaseEndEvent) {
+            let formatted = format!("phase:{}:{}", event.phase, event.issues_found);
+            self.events.lock().unwrap().push(formatted);
+        }
+    }
+}
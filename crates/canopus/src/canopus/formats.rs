@@ -0,0 +1,171 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+pub mod annotations;
+pub mod compact;
+pub mod json;
+pub mod junit;
+pub mod pr_comment;
+pub mod text;
+
+use crate::core::models::ValidationOutcome;
+use crate::core::models::codeowners::CodeOwnersContext;
+use anyhow::{Context, bail};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// How validation results are written to the standard output
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Compact,
+    Junit,
+    PrComment,
+    Json,
+}
+
+impl OutputFormat {
+    /// Machine-readable formats must not be decorated with any extra output
+    pub fn is_machine_readable(&self) -> bool {
+        !matches!(self, OutputFormat::Text)
+    }
+
+    pub fn report_file_name(&self) -> String {
+        let extension = match self {
+            OutputFormat::Junit => "xml",
+            OutputFormat::PrComment => "md",
+            OutputFormat::Json => "json",
+            OutputFormat::Text | OutputFormat::Compact => "txt",
+        };
+
+        format!("canopus-report.{extension}")
+    }
+
+    /// Renders results as a report, which only machine-readable formats support
+    pub fn render(&self, validations: &[ProjectValidation], details: &RunDetails) -> anyhow::Result<String> {
+        let report = match self {
+            OutputFormat::Compact => compact::render(validations),
+            OutputFormat::Junit => junit::render(validations, &details.skipped_checks),
+            OutputFormat::PrComment => pr_comment::render(validations, &details.skipped_checks),
+            OutputFormat::Json => json::render(validations, details),
+            OutputFormat::Text => bail!("cannot write text reports"),
+        };
+
+        Ok(report)
+    }
+}
+
+/// Writes one report per machine-readable format into a directory, rendering them all from
+/// the same results, so that validation runs once whatever the number of formats
+#[derive(Debug)]
+pub struct ReportPipeline {
+    formats: Vec<OutputFormat>,
+    output_dir: PathBuf,
+}
+
+impl ReportPipeline {
+    pub fn new(formats: &[OutputFormat], output_dir: &Path) -> Self {
+        let formats = formats
+            .iter()
+            .filter(|format| format.is_machine_readable())
+            .unique()
+            .copied()
+            .collect_vec();
+
+        Self {
+            formats,
+            output_dir: output_dir.to_path_buf(),
+        }
+    }
+
+    /// Writes every report, returning where each one was written to
+    pub fn emit(&self, validations: &[ProjectValidation], details: &RunDetails) -> anyhow::Result<Vec<PathBuf>> {
+        std::fs::create_dir_all(&self.output_dir)
+            .with_context(|| format!("cannot create output dir at {}", self.output_dir.display()))?;
+
+        self.formats
+            .iter()
+            .map(|format| {
+                let report = format.render(validations, details)?;
+                let report_file = self.output_dir.join(format.report_file_name());
+
+                std::fs::write(&report_file, report)
+                    .with_context(|| format!("cannot write report to {}", report_file.display()))?;
+
+                Ok(report_file)
+            })
+            .collect()
+    }
+}
+
+/// The result of validating one project, named after its location
+pub type ProjectValidation = (String, anyhow::Result<(CodeOwnersContext, ValidationOutcome)>);
+
+/// A check validation did not run, alongside why
+pub type SkippedCheck = (&'static str, &'static str);
+
+/// What reports carry about the whole run, besides the result of each project
+#[derive(Clone, Debug, Default)]
+pub struct RunDetails {
+    /// Digest of the effective configuration each project was validated with, keyed by project
+    pub config_digests: HashMap<String, String>,
+    pub skipped_checks: Vec<SkippedCheck>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::formats::{OutputFormat, ReportPipeline, RunDetails, compact, junit};
+    use crate::core::models::codeowners::CodeOwnersContext;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{ValidationIssue, ValidationOutcome};
+    use assertor::EqualityAssertion;
+    use std::path::PathBuf;
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_write_one_report_per_machine_readable_format() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let output_dir = temp_dir.path().join("reports");
+
+        let context = CodeOwnersContext {
+            project_path: PathBuf::from("canopus"),
+            codeowners_path: PathBuf::from("canopus/.github/CODEOWNERS"),
+            contents: "docs/*.md   @ubiratansoares\n".to_string(),
+        };
+
+        let issues = vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+                .line_number(0)
+                .description("docs/*.md does not match any project path")
+                .build(),
+        ];
+
+        let validations = vec![(
+            "canopus".to_string(),
+            Ok((context, ValidationOutcome::IssuesDetected(issues))),
+        )];
+
+        let formats = [
+            OutputFormat::Junit,
+            OutputFormat::Text,
+            OutputFormat::Compact,
+            OutputFormat::Junit,
+        ];
+
+        let report_files = ReportPipeline::new(&formats, &output_dir)
+            .emit(&validations, &RunDetails::default())
+            .unwrap();
+
+        let written = |name: &str| std::fs::read_to_string(output_dir.join(name)).unwrap();
+
+        assertor::assert_that!(report_files).is_equal_to(vec![
+            output_dir.join("canopus-report.xml"),
+            output_dir.join("canopus-report.txt"),
+        ]);
+        assertor::assert_that!(written("canopus-report.xml")).is_equal_to(junit::render(&validations, &[]));
+        assertor::assert_that!(written("canopus-report.txt")).is_equal_to(compact::render(&validations));
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::formats::ProjectValidation;
+use crate::core::models::ValidationOutcome;
+use crate::core::models::checks::IssueSeverity;
+use crate::infra::checks::CheckRunAnnotation;
+
+/// Maps issues to check run annotations on CODEOWNERS, whose path is relative to the project root.
+/// Project-wide issues are not bound to any line, hence they annotate the first one
+pub fn render(validations: &[ProjectValidation]) -> Vec<CheckRunAnnotation> {
+    validations
+        .iter()
+        .filter_map(|(_, validation)| match validation {
+            Ok((context, ValidationOutcome::IssuesDetected(issues))) => Some((context, issues)),
+            _ => None,
+        })
+        .flat_map(|(context, issues)| {
+            let path = context
+                .codeowners_path
+                .strip_prefix(&context.project_path)
+                .unwrap_or(&context.codeowners_path)
+                .to_string_lossy()
+                .to_string();
+
+            issues.iter().map(move |issue| {
+                let line = if issue.line == usize::MAX { 1 } else { issue.line + 1 };
+
+                CheckRunAnnotation {
+                    path: path.clone(),
+                    start_line: line,
+                    end_line: line,
+                    annotation_level: match issue.severity {
+                        IssueSeverity::Error => "failure",
+                        IssueSeverity::Warning => "warning",
+                    },
+                    title: issue.kind.definition().name.to_string(),
+                    message: console::strip_ansi_codes(&issue.context).to_string(),
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::formats::annotations;
+    use crate::core::models::codeowners::CodeOwnersContext;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{ValidationIssue, ValidationOutcome};
+    use crate::infra::checks::CheckRunAnnotation;
+    use assertor::EqualityAssertion;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_annotate_codeowners_lines() {
+        let context = CodeOwnersContext {
+            project_path: PathBuf::from("canopus"),
+            codeowners_path: PathBuf::from("canopus/.github/CODEOWNERS"),
+            contents: "docs/*.md   @ubiratansoares\n".to_string(),
+        };
+
+        let issues = vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+                .line_number(0)
+                .description("docs/*.md does not match any project path")
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::coverage_below_minimum())
+                .line_number(usize::MAX)
+                .description("ownership coverage is 50.00%, below minimum of 90.00%")
+                .build(),
+        ];
+
+        let validations = vec![(
+            "canopus".to_string(),
+            Ok((context, ValidationOutcome::IssuesDetected(issues))),
+        )];
+
+        let expected = vec![
+            CheckRunAnnotation {
+                path: ".github/CODEOWNERS".to_string(),
+                start_line: 1,
+                end_line: 1,
+                annotation_level: "failure",
+                title: "dangling-glob".to_string(),
+                message: "docs/*.md does not match any project path".to_string(),
+            },
+            CheckRunAnnotation {
+                path: ".github/CODEOWNERS".to_string(),
+                start_line: 1,
+                end_line: 1,
+                annotation_level: "failure",
+                title: "coverage-below-minimum".to_string(),
+                message: "ownership coverage is 50.00%, below minimum of 90.00%".to_string(),
+            },
+        ];
+
+        assertor::assert_that!(annotations::render(&validations)).is_equal_to(expected);
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::formats::ProjectValidation;
+use crate::core::models::codeowners::CodeOwnersContext;
+use crate::core::models::{ValidationIssue, ValidationOutcome};
+use itertools::Itertools;
+
+/// Renders one issue per line as `path:line:col: severity: kind: message`,
+/// a stable layout meant for problem matchers and grep-based tooling
+pub fn render(validations: &[ProjectValidation]) -> String {
+    validations
+        .iter()
+        .filter_map(|(_, validation)| match validation {
+            Ok((context, ValidationOutcome::IssuesDetected(issues))) => Some((context, issues)),
+            _ => None,
+        })
+        .flat_map(|(context, issues)| issues.iter().map(move |issue| render_issue(context, issue)))
+        .map(|line| format!("{line}\n"))
+        .join("")
+}
+
+fn render_issue(context: &CodeOwnersContext, issue: &ValidationIssue) -> String {
+    let (line, column) = position(context, issue);
+
+    format!(
+        "{}:{}:{}: {}: {}: {}",
+        context.codeowners_path.to_string_lossy(),
+        line,
+        column,
+        issue.severity,
+        issue.kind.definition().name,
+        console::strip_ansi_codes(&issue.context).replace('\n', " ")
+    )
+}
+
+// Positions are 1-based, pointing to the first non-blank character of the offending line.
+// Project-wide issues are not bound to any line, hence they point to the start of the file
+fn position(context: &CodeOwnersContext, issue: &ValidationIssue) -> (usize, usize) {
+    if issue.line == usize::MAX {
+        return (1, 1);
+    }
+
+    let column = context
+        .contents
+        .lines()
+        .nth(issue.line)
+        .and_then(|line| line.find(|character: char| !character.is_whitespace()))
+        .unwrap_or_default();
+
+    (issue.line + 1, column + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::formats::compact;
+    use crate::core::models::codeowners::CodeOwnersContext;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{ValidationIssue, ValidationOutcome};
+    use assertor::EqualityAssertion;
+    use indoc::indoc;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_render_one_issue_per_line() {
+        let context = CodeOwnersContext {
+            project_path: PathBuf::from("canopus"),
+            codeowners_path: PathBuf::from("canopus/.github/CODEOWNERS"),
+            contents: indoc! {"
+                *.rs          @dotanuki-labs/rustaceans
+                  docs/*.md   @ubiratansoares
+            "}
+            .to_string(),
+        };
+
+        let issues = vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+                .line_number(1)
+                .description("docs/*.md does not match any project path")
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::coverage_below_minimum())
+                .line_number(usize::MAX)
+                .description("ownership coverage is 50.00%, below minimum of 90.00%")
+                .build(),
+        ];
+
+        let validations = vec![
+            (
+                "canopus".to_string(),
+                Ok((context, ValidationOutcome::IssuesDetected(issues))),
+            ),
+            (
+                "dotfiles".to_string(),
+                Err(anyhow::anyhow!("no CODEOWNERS definition found")),
+            ),
+        ];
+
+        let expected = indoc! {"
+            canopus/.github/CODEOWNERS:2:3: error: dangling-glob: docs/*.md does not match any project path
+            canopus/.github/CODEOWNERS:1:1: error: coverage-below-minimum: ownership coverage is 50.00%, below minimum of 90.00%
+        "};
+
+        assertor::assert_that!(compact::render(&validations)).is_equal_to(expected.to_string());
+    }
+}
@@ -0,0 +1,279 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::formats::{ProjectValidation, RunDetails};
+use crate::core::models::checks::IssueSeverity;
+use crate::core::models::config::CanopusConfig;
+use crate::core::models::{ValidationIssue, ValidationOutcome};
+use serde::Serialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+/// Version of the JSON report layout. Within the same major version, fields are only ever added,
+/// hence consumers must ignore fields they do not know. Removing or changing fields bumps the major version
+pub static REPORT_SCHEMA_VERSION: &str = "1.1";
+
+static REPORT_SCHEMA_ID: &str = "https://dotanuki-labs.github.io/canopus/schemas/validation-report-v1.json";
+
+#[derive(Debug, Serialize)]
+struct ToolReport {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct IssueReport {
+    code: &'static str,
+    check: &'static str,
+    severity: IssueSeverity,
+    line: Option<usize>,
+    message: String,
+    fix: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProjectReport {
+    project: String,
+    status: &'static str,
+    codeowners: Option<String>,
+    #[serde(rename = "config-digest")]
+    config_digest: Option<String>,
+    error: Option<String>,
+    issues: Vec<IssueReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct SkippedCheckReport {
+    check: &'static str,
+    reason: &'static str,
+}
+
+/// Validation results for every project, in a layout described by `canopus schema`
+#[derive(Debug, Serialize)]
+struct ValidationReport {
+    #[serde(rename = "schema-version")]
+    schema_version: &'static str,
+    tool: ToolReport,
+    projects: Vec<ProjectReport>,
+    skipped: Vec<SkippedCheckReport>,
+}
+
+/// Identifies the effective configuration a project was validated with
+pub fn config_digest(config: &CanopusConfig) -> String {
+    format!("sha256:{}", hex::encode(Sha256::digest(config.effective().as_bytes())))
+}
+
+fn issue_report(issue: &ValidationIssue) -> IssueReport {
+    let definition = issue.kind.definition();
+
+    IssueReport {
+        code: definition.code,
+        check: definition.name,
+        severity: issue.severity,
+        line: (issue.line != usize::MAX).then(|| issue.line + 1),
+        message: console::strip_ansi_codes(&issue.context).to_string(),
+        fix: issue.fix.as_ref().map(|fix| fix.to_string()),
+    }
+}
+
+/// Renders validation results as a versioned JSON report, with the configuration digest of each project
+/// and checks skipped during the run
+pub fn render(validations: &[ProjectValidation], details: &RunDetails) -> String {
+    let projects = validations
+        .iter()
+        .map(|(project, validation)| {
+            let config_digest = details.config_digests.get(project).cloned();
+
+            match validation {
+                Ok((context, outcome)) => {
+                    let issues = match outcome {
+                        ValidationOutcome::NoIssues => vec![],
+                        ValidationOutcome::IssuesDetected(issues) => issues.iter().map(issue_report).collect(),
+                    };
+
+                    ProjectReport {
+                        project: project.clone(),
+                        status: if issues.is_empty() { "validated" } else { "issues" },
+                        codeowners: Some(context.codeowners_path.to_string_lossy().to_string()),
+                        config_digest,
+                        error: None,
+                        issues,
+                    }
+                },
+                Err(error) => ProjectReport {
+                    project: project.clone(),
+                    status: "failed",
+                    codeowners: None,
+                    config_digest,
+                    error: Some(error.to_string()),
+                    issues: vec![],
+                },
+            }
+        })
+        .collect();
+
+    let report = ValidationReport {
+        schema_version: REPORT_SCHEMA_VERSION,
+        tool: ToolReport {
+            name: "canopus",
+            version: env!("CARGO_PKG_VERSION"),
+        },
+        projects,
+        skipped: details
+            .skipped_checks
+            .iter()
+            .map(|(check, reason)| SkippedCheckReport { check, reason })
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+
+/// JSON Schema describing the report rendered by `--format json`
+pub fn schema() -> serde_json::Value {
+    let nullable_string = json!({ "type": ["string", "null"] });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": REPORT_SCHEMA_ID,
+        "title": "canopus validation report",
+        "type": "object",
+        "required": ["schema-version", "tool", "projects", "skipped"],
+        "properties": {
+            "schema-version": {
+                "type": "string",
+                "description": "major.minor version of this layout; fields are only added within a major version",
+                "pattern": "^1\\.[0-9]+$"
+            },
+            "tool": {
+                "type": "object",
+                "required": ["name", "version"],
+                "properties": {
+                    "name": { "const": "canopus" },
+                    "version": { "type": "string" }
+                }
+            },
+            "projects": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["project", "status", "codeowners", "config-digest", "error", "issues"],
+                    "properties": {
+                        "project": { "type": "string" },
+                        "status": { "enum": ["validated", "issues", "failed"] },
+                        "codeowners": nullable_string,
+                        "config-digest": {
+                            "type": ["string", "null"],
+                            "description": "sha256 over the effective configuration the project was validated with"
+                        },
+                        "error": nullable_string,
+                        "issues": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "required": ["code", "check", "severity", "line", "message", "fix"],
+                                "properties": {
+                                    "code": { "type": "string", "pattern": "^CNP[0-9]{3}$" },
+                                    "check": { "type": "string" },
+                                    "severity": { "enum": ["error", "warning"] },
+                                    "line": {
+                                        "type": ["integer", "null"],
+                                        "minimum": 1,
+                                        "description": "1-based CODEOWNERS line, null for project-wide issues"
+                                    },
+                                    "message": { "type": "string" },
+                                    "fix": nullable_string
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "skipped": {
+                "type": "array",
+                "description": "checks not run for any project, since 1.1",
+                "items": {
+                    "type": "object",
+                    "required": ["check", "reason"],
+                    "properties": {
+                        "check": { "type": "string" },
+                        "reason": { "type": "string" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::formats::{RunDetails, json};
+    use crate::core::models::codeowners::CodeOwnersContext;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{ValidationIssue, ValidationOutcome};
+    use assertor::{EqualityAssertion, VecAssertion};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn required_keys(schema: &serde_json::Value) -> Vec<String> {
+        schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|key| key.as_str().unwrap().to_string())
+            .collect()
+    }
+
+    fn object_keys(value: &serde_json::Value) -> Vec<String> {
+        value.as_object().unwrap().keys().cloned().collect()
+    }
+
+    #[test]
+    fn should_render_report_described_by_schema() {
+        let context = CodeOwnersContext {
+            project_path: PathBuf::from("canopus"),
+            codeowners_path: PathBuf::from("canopus/.github/CODEOWNERS"),
+            contents: "docs/*.md   @ubiratansoares\n".to_string(),
+        };
+
+        let issues = vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+                .line_number(0)
+                .description("docs/*.md does not match any project path")
+                .build(),
+        ];
+
+        let validations = vec![
+            (
+                "canopus".to_string(),
+                Ok((context, ValidationOutcome::IssuesDetected(issues))),
+            ),
+            ("dotanuki".to_string(), Err(anyhow::anyhow!("no CODEOWNERS found"))),
+        ];
+
+        let details = RunDetails {
+            config_digests: HashMap::from([("canopus".to_string(), "sha256:cafebabe".to_string())]),
+            skipped_checks: vec![("outsider-user", "no token was provided through GITHUB_TOKEN")],
+        };
+
+        let report: serde_json::Value = serde_json::from_str(&json::render(&validations, &details)).unwrap();
+        let schema = json::schema();
+
+        let project_schema = &schema["properties"]["projects"]["items"];
+        let issue_schema = &project_schema["properties"]["issues"]["items"];
+        let skipped_schema = &schema["properties"]["skipped"]["items"];
+
+        assertor::assert_that!(report["schema-version"].as_str().unwrap()).is_equal_to(json::REPORT_SCHEMA_VERSION);
+        assertor::assert_that!(report["projects"][0]["config-digest"].as_str().unwrap()).is_equal_to("sha256:cafebabe");
+        assertor::assert_that!(report["projects"][0]["issues"][0]["line"].as_u64().unwrap()).is_equal_to(1);
+        assertor::assert_that!(report["projects"][1]["status"].as_str().unwrap()).is_equal_to("failed");
+        assertor::assert_that!(report["skipped"][0]["check"].as_str().unwrap()).is_equal_to("outsider-user");
+
+        assertor::assert_that!(object_keys(&report)).contains_exactly(required_keys(&schema));
+        assertor::assert_that!(object_keys(&report["projects"][1])).contains_exactly(required_keys(project_schema));
+        assertor::assert_that!(object_keys(&report["projects"][0]["issues"][0]))
+            .contains_exactly(required_keys(issue_schema));
+        assertor::assert_that!(object_keys(&report["skipped"][0])).contains_exactly(required_keys(skipped_schema));
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::formats::{ProjectValidation, SkippedCheck};
+use crate::core::models::checks::{CHECKS, CheckDefinition};
+use crate::core::models::{ValidationIssue, ValidationOutcome};
+use itertools::Itertools;
+use std::path::Path;
+
+/// Renders validation results as JUnit XML, one test suite per project
+/// and one test case per check registered by canopus, skipped checks included
+pub fn render(validations: &[ProjectValidation], skipped_checks: &[SkippedCheck]) -> String {
+    let suites = validations.iter().map(|(project, validation)| match validation {
+        Ok((context, outcome)) => render_checks(project, &context.codeowners_path, outcome, skipped_checks),
+        Err(error) => render_error(project, &error.to_string()),
+    });
+
+    let (failures, errors) = validations
+        .iter()
+        .fold((0, 0), |(failures, errors), (_, validation)| match validation {
+            Ok((_, ValidationOutcome::IssuesDetected(issues))) => (
+                failures + issues.iter().unique_by(|issue| issue.kind.code()).count(),
+                errors,
+            ),
+            Ok((_, ValidationOutcome::NoIssues)) => (failures, errors),
+            Err(_) => (failures, errors + 1),
+        });
+
+    let tests = validations
+        .iter()
+        .map(|(_, validation)| if validation.is_ok() { CHECKS.len() } else { 1 })
+        .sum::<usize>();
+
+    let skipped_per_project = CHECKS
+        .iter()
+        .filter(|check| skipped_checks.iter().any(|(skipped, _)| *skipped == check.name))
+        .count();
+
+    let skipped = validations.iter().filter(|(_, validation)| validation.is_ok()).count() * skipped_per_project;
+
+    let mut junit = vec![
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+        format!(
+            r#"<testsuites name="canopus" tests="{tests}" failures="{failures}" errors="{errors}" skipped="{skipped}">"#
+        ),
+    ];
+
+    junit.extend(suites);
+    junit.push("</testsuites>".to_string());
+    junit.join("\n")
+}
+
+fn render_checks(
+    project: &str,
+    codeowners_path: &Path,
+    outcome: &ValidationOutcome,
+    skipped_checks: &[SkippedCheck],
+) -> String {
+    let issues = match outcome {
+        ValidationOutcome::NoIssues => vec![],
+        ValidationOutcome::IssuesDetected(issues) => issues.iter().collect_vec(),
+    };
+
+    let file = escape_xml(&codeowners_path.to_string_lossy());
+
+    let test_cases = CHECKS
+        .iter()
+        .map(|check| {
+            let check_issues = issues
+                .iter()
+                .filter(|issue| issue.kind.code() == check.code)
+                .copied()
+                .collect_vec();
+
+            match skipped_checks.iter().find(|(skipped, _)| *skipped == check.name) {
+                Some((_, reason)) => render_skipped_check(check, &file, reason),
+                None => render_check(check, &file, &check_issues),
+            }
+        })
+        .collect_vec();
+
+    let failures = test_cases
+        .iter()
+        .filter(|test_case| test_case.contains("<failure"))
+        .count();
+
+    let skipped = test_cases
+        .iter()
+        .filter(|test_case| test_case.contains("<skipped"))
+        .count();
+
+    let mut suite = vec![format!(
+        r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0" skipped="{}">"#,
+        escape_xml(project),
+        CHECKS.len(),
+        failures,
+        skipped
+    )];
+
+    suite.extend(test_cases);
+    suite.push("  </testsuite>".to_string());
+    suite.join("\n")
+}
+
+fn render_check(check: &CheckDefinition, file: &str, issues: &[&ValidationIssue]) -> String {
+    let test_case = format!(
+        r#"    <testcase name="{} {}" classname="canopus.{}" file="{}""#,
+        check.code, check.name, check.category, file
+    );
+
+    let Some(first_issue) = issues.first() else {
+        return format!("{test_case} />");
+    };
+
+    // Global issues are not bound to any CODEOWNERS line
+    let line_attribute = match first_issue.line {
+        usize::MAX => String::new(),
+        line => format!(r#" line="{}""#, line + 1),
+    };
+
+    let details = issues
+        .iter()
+        .map(|issue| format!("{}{} : {}", file, location(issue), escape_xml(&message(issue))))
+        .join("\n");
+
+    [
+        format!("{test_case}{line_attribute}>"),
+        format!(
+            r#"      <failure type="{}" message="{}">{}</failure>"#,
+            issue_severity(issues),
+            escape_xml(&message(first_issue)),
+            details
+        ),
+        "    </testcase>".to_string(),
+    ]
+    .join("\n")
+}
+
+fn render_skipped_check(check: &CheckDefinition, file: &str, reason: &str) -> String {
+    [
+        format!(
+            r#"    <testcase name="{} {}" classname="canopus.{}" file="{}">"#,
+            check.code, check.name, check.category, file
+        ),
+        format!(r#"      <skipped message="{}" />"#, escape_xml(reason)),
+        "    </testcase>".to_string(),
+    ]
+    .join(
+        "
+",
+    )
+}
+
+fn render_error(project: &str, reason: &str) -> String {
+    [
+        format!(
+            r#"  <testsuite name="{}" tests="1" failures="0" errors="1">"#,
+            escape_xml(project)
+        ),
+        r#"    <testcase name="validation" classname="canopus">"#.to_string(),
+        format!(r#"      <error message="{}" />"#, escape_xml(reason)),
+        "    </testcase>".to_string(),
+        "  </testsuite>".to_string(),
+    ]
+    .join("\n")
+}
+
+fn issue_severity(issues: &[&ValidationIssue]) -> String {
+    issues
+        .first()
+        .map(|issue| issue.severity.to_string())
+        .unwrap_or_default()
+}
+
+fn location(issue: &ValidationIssue) -> String {
+    match issue.line {
+        usize::MAX => String::new(),
+        line => format!(":{}", line + 1),
+    }
+}
+
+fn message(issue: &ValidationIssue) -> String {
+    console::strip_ansi_codes(&issue.context).to_string()
+}
+
+fn escape_xml(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::formats::junit;
+    use crate::core::models::codeowners::CodeOwnersContext;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{ValidationIssue, ValidationOutcome};
+    use assertor::StringAssertion;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_render_checks_as_test_cases() {
+        let issue = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+            .line_number(2)
+            .description("docs/*.md does not match any project path")
+            .build();
+
+        let context = CodeOwnersContext {
+            project_path: PathBuf::from("canopus"),
+            codeowners_path: PathBuf::from("canopus/.github/CODEOWNERS"),
+            contents: "docs/*.md @ubiratansoares".to_string(),
+        };
+
+        let validations = vec![
+            (
+                "canopus".to_string(),
+                Ok((context, ValidationOutcome::IssuesDetected(vec![issue]))),
+            ),
+            (
+                "dotfiles".to_string(),
+                Err(anyhow::anyhow!("no CODEOWNERS definition found")),
+            ),
+        ];
+
+        let junit = junit::render(
+            &validations,
+            &[("outsider-user", "no token was provided through GITHUB_TOKEN")],
+        );
+
+        assertor::assert_that!(junit)
+            .contains(r#"<testsuites name="canopus" tests="41" failures="1" errors="1" skipped="1">"#);
+        assertor::assert_that!(junit).contains(
+            r#"<testcase name="CNP002 dangling-glob" classname="canopus.structure" file="canopus/.github/CODEOWNERS" line="3">"#,
+        );
+        assertor::assert_that!(junit).contains(
+            r#"<failure type="error" message="docs/*.md does not match any project path">canopus/.github/CODEOWNERS:3 : docs/*.md does not match any project path</failure>"#,
+        );
+        assertor::assert_that!(junit).contains(r#"<error message="no CODEOWNERS definition found" />"#);
+        assertor::assert_that!(junit).contains(r#"<skipped message="no token was provided through GITHUB_TOKEN" />"#);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::formats::{ProjectValidation, SkippedCheck};
+use crate::core::models::{ConfigurationIssue, IssueKind, ValidationIssue, ValidationOutcome};
+use itertools::Itertools;
+
+/// Hidden marker identifying comments posted by canopus, so CI can update them instead of adding new ones
+pub static PR_COMMENT_MARKER: &str = "<!-- canopus:validation -->";
+
+/// Renders validation results as a single Markdown block meant to be posted as a pull request comment,
+/// with one collapsible section per project, followed by checks skipped during the run
+pub fn render(validations: &[ProjectValidation], skipped_checks: &[SkippedCheck]) -> String {
+    let total_issues = validations
+        .iter()
+        .map(|(_, validation)| match validation {
+            Ok((_, ValidationOutcome::IssuesDetected(issues))) => issues.len(),
+            _ => 0,
+        })
+        .sum::<usize>();
+
+    let failed_projects = validations.iter().filter(|(_, validation)| validation.is_err()).count();
+
+    let headline = match (total_issues, failed_projects) {
+        (0, 0) => "### :white_check_mark: canopus found no CODEOWNERS issues".to_string(),
+        (0, _) => format!("### :x: canopus could not validate {failed_projects} projects"),
+        (_, _) => format!("### :x: canopus found {total_issues} CODEOWNERS issues"),
+    };
+
+    let mut comment = vec![PR_COMMENT_MARKER.to_string(), headline];
+
+    comment.extend(validations.iter().map(|(project, validation)| match validation {
+        Ok((_, outcome)) => render_project(project, outcome),
+        Err(error) => render_section(
+            project,
+            "cannot validate",
+            vec![format!("`{}`", sanitize(&error.to_string()))],
+        ),
+    }));
+
+    if !skipped_checks.is_empty() {
+        let skipped = skipped_checks
+            .iter()
+            .map(|(check, reason)| format!("- `{check}` : {}", sanitize(reason)))
+            .join("\n");
+
+        comment.push(format!("**Skipped checks**\n\n{skipped}"));
+    }
+
+    format!("{}\n", comment.join("\n\n"))
+}
+
+fn render_project(project: &str, outcome: &ValidationOutcome) -> String {
+    let ValidationOutcome::IssuesDetected(issues) = outcome else {
+        return render_section(project, "no issues", vec!["Nothing to report.".to_string()]);
+    };
+
+    let mut table = vec![
+        "| Line | Check | Message |".to_string(),
+        "| --- | --- | --- |".to_string(),
+    ];
+    table.extend(issues.iter().map(|issue| {
+        format!(
+            "| {} | `{}` | {} |",
+            line(issue),
+            issue.kind.definition().name,
+            sanitize(&issue.context)
+        )
+    }));
+
+    let mut blocks = vec![table.join("\n")];
+
+    let fixes = issues
+        .iter()
+        .filter_map(|issue| {
+            issue
+                .fix
+                .as_ref()
+                .map(|fix| format!("- L{} : fix by {}", line(issue), sanitize(&fix.to_string())))
+        })
+        .collect_vec();
+
+    if !fixes.is_empty() {
+        blocks.push(format!(
+            "**Quick fixes**, applied by `canopus repair`\n\n{}",
+            fixes.join("\n")
+        ));
+    }
+
+    // Coverage is gated through issues, hence they also carry how coverage evolved
+    let coverage = issues
+        .iter()
+        .filter(|issue| is_coverage_issue(&issue.kind))
+        .map(|issue| format!("- {}", sanitize(&issue.context)))
+        .collect_vec();
+
+    if !coverage.is_empty() {
+        blocks.push(format!("**Coverage**\n\n{}", coverage.join("\n")));
+    }
+
+    render_section(project, &format!("{} issues", issues.len()), blocks)
+}
+
+fn render_section(project: &str, summary: &str, blocks: Vec<String>) -> String {
+    let mut section = vec![format!(
+        "<details>\n<summary><b>{}</b> : {}</summary>",
+        sanitize(project),
+        summary
+    )];
+
+    section.extend(blocks);
+    section.push("</details>".to_string());
+    section.join("\n\n")
+}
+
+fn is_coverage_issue(kind: &IssueKind) -> bool {
+    matches!(
+        kind,
+        IssueKind::Configuration(
+            ConfigurationIssue::OwnershipCoverageBelowMinimum | ConfigurationIssue::OwnershipCoverageDecreased
+        )
+    )
+}
+
+// Lines are 1-based, while project-wide issues are not bound to any line
+fn line(issue: &ValidationIssue) -> String {
+    if issue.line == usize::MAX {
+        return "-".to_string();
+    }
+
+    (issue.line + 1).to_string()
+}
+
+// Messages must fit in a single table cell
+fn sanitize(message: &str) -> String {
+    console::strip_ansi_codes(message)
+        .replace('\n', " ")
+        .replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::formats::pr_comment;
+    use crate::core::models::codeowners::CodeOwnersContext;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{IssueFix, ValidationIssue, ValidationOutcome};
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_render_collapsible_pr_comment() {
+        let context = CodeOwnersContext {
+            project_path: PathBuf::from("canopus"),
+            codeowners_path: PathBuf::from("canopus/.github/CODEOWNERS"),
+            contents: "*.rs @dotanuki-labs/rustaceans @dotanuki-labs/rustaceans\n".to_string(),
+        };
+
+        let issues = vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::repeated_owner())
+                .line_number(0)
+                .description("@dotanuki-labs/rustaceans is repeated")
+                .fix(IssueFix::RewriteLine(0, "*.rs @dotanuki-labs/rustaceans".to_string()))
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::coverage_below_minimum())
+                .line_number(usize::MAX)
+                .description("ownership coverage is 50.00%, below minimum of 90.00%")
+                .build(),
+        ];
+
+        let validations = vec![(
+            "canopus".to_string(),
+            Ok((context, ValidationOutcome::IssuesDetected(issues))),
+        )];
+
+        let expected = indoc! {"
+            <!-- canopus:validation -->
+
+            ### :x: canopus found 2 CODEOWNERS issues
+
+            <details>
+            <summary><b>canopus</b> : 2 issues</summary>
+
+            | Line | Check | Message |
+            | --- | --- | --- |
+            | 1 | `repeated-owner` | @dotanuki-labs/rustaceans is repeated |
+            | - | `coverage-below-minimum` | ownership coverage is 50.00%, below minimum of 90.00% |
+
+            **Quick fixes**, applied by `canopus repair`
+
+            - L1 : fix by rewriting it as '*.rs @dotanuki-labs/rustaceans'
+
+            **Coverage**
+
+            - ownership coverage is 50.00%, below minimum of 90.00%
+
+            </details>
+
+            **Skipped checks**
+
+            - `outsider-user` : no token was provided through GITHUB_TOKEN
+        "};
+
+        let skipped_checks = [("outsider-user", "no token was provided through GITHUB_TOKEN")];
+        let rendered = pr_comment::render(&validations, &skipped_checks);
+
+        assertor::assert_that!(rendered).starts_with(pr_comment::PR_COMMENT_MARKER);
+        assertor::assert_that!(rendered).is_equal_to(expected.to_string());
+    }
+}
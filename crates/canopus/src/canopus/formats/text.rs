@@ -0,0 +1,185 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::ValidationIssue;
+use crate::core::models::aggregation;
+use crate::core::models::aggregation::LineIssues;
+use crate::core::models::checks::{CHECKS, IssueSeverity};
+use crate::core::models::codeowners::CodeOwnersContext;
+use console::style;
+use itertools::Itertools;
+
+/// Renders issues grouped by check, showing each offending CODEOWNERS line
+/// with the problematic token underlined and the other checks flagging it
+pub fn render(context: &CodeOwnersContext, issues: &[ValidationIssue]) -> String {
+    let codeowners_lines = context.contents.lines().collect_vec();
+    let codeowners_path = context.codeowners_path.to_string_lossy();
+    let issues_per_line = aggregation::aggregate_per_line(issues.to_vec());
+
+    CHECKS
+        .iter()
+        .filter_map(|check| {
+            let check_issues = issues_per_line
+                .iter()
+                .flat_map(|line_issues| line_issues.issues.iter())
+                .filter(|issue| issue.kind.code() == check.code)
+                .sorted_by_key(|issue| issue.line)
+                .collect_vec();
+
+            let first_issue = check_issues.first()?;
+
+            let header = format!("{}[{}]: {}", first_issue.severity, check.code, check.name);
+            let styled_header = match first_issue.severity {
+                IssueSeverity::Error => style(header).red().bold(),
+                IssueSeverity::Warning => style(header).yellow().bold(),
+            };
+
+            let mut group = vec![styled_header.to_string(), String::new()];
+
+            check_issues.iter().for_each(|issue| {
+                group.extend(render_issue(&codeowners_path, &codeowners_lines, issue));
+                group.extend(render_other_checks(&issues_per_line, issue));
+                group.push(String::new());
+            });
+
+            Some(group.join("\n"))
+        })
+        .join("\n")
+}
+
+fn render_issue(codeowners_path: &str, codeowners_lines: &[&str], issue: &ValidationIssue) -> Vec<String> {
+    let Some(content) = codeowners_lines.get(issue.line) else {
+        return vec![format!("  {} {}", style("Preconditions :").dim(), issue.context)];
+    };
+
+    let message = console::strip_ansi_codes(&issue.context).to_string();
+    let (start, length) = underlined_token(content, &message);
+
+    let line_number = (issue.line + 1).to_string();
+    let gutter = " ".repeat(line_number.len());
+
+    vec![
+        format!(
+            "  {} {}",
+            style(format!("{codeowners_path}:{line_number}:{}", start + 1)).dim(),
+            issue.context
+        ),
+        format!("  {gutter} {}", style("|").blue()),
+        format!("  {} {} {content}", style(&line_number).blue(), style("|").blue()),
+        format!(
+            "  {gutter} {} {}{}",
+            style("|").blue(),
+            " ".repeat(start),
+            style("^".repeat(length)).red()
+        ),
+    ]
+}
+
+fn render_other_checks(issues_per_line: &[LineIssues], issue: &ValidationIssue) -> Option<String> {
+    let line_issues = issues_per_line
+        .iter()
+        .find(|line_issues| line_issues.line == issue.line)?;
+
+    let other_checks = line_issues
+        .check_names()
+        .into_iter()
+        .filter(|name| *name != issue.kind.definition().name)
+        .collect_vec();
+
+    if other_checks.is_empty() || issue.line == usize::MAX {
+        return None;
+    }
+
+    let gutter = " ".repeat((issue.line + 1).to_string().len());
+    Some(format!(
+        "  {gutter} {} also flagged by {}",
+        style("=").blue(),
+        other_checks.join(", ")
+    ))
+}
+
+// Issue messages usually mention the offending glob or owner, hence we underline the first
+// token of the line referenced by the message, falling back to the whole ownership rule
+fn underlined_token(content: &str, message: &str) -> (usize, usize) {
+    let mut offset = 0;
+
+    for token in content.split_whitespace() {
+        let start = offset + content[offset..].find(token).unwrap_or_default();
+        offset = start + token.len();
+
+        if mentions(message, token) {
+            return (start, token.chars().count());
+        }
+    }
+
+    let trimmed = content.trim();
+    let start = content.find(trimmed).unwrap_or_default();
+    (start, trimmed.chars().count().max(1))
+}
+
+// Team owners are usually mentioned with their names and organizations as separate words
+fn mentions(message: &str, token: &str) -> bool {
+    let bare_token = token.trim_start_matches('@');
+
+    if bare_token.is_empty() {
+        return false;
+    }
+
+    message.contains(bare_token) || (token.starts_with('@') && bare_token.split('/').all(|part| message.contains(part)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::formats::text;
+    use crate::core::models::ValidationIssue;
+    use crate::core::models::codeowners::CodeOwnersContext;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use assertor::StringAssertion;
+    use indoc::indoc;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_render_issues_with_code_frames() {
+        let context = CodeOwnersContext {
+            project_path: PathBuf::from("canopus"),
+            codeowners_path: PathBuf::from(".github/CODEOWNERS"),
+            contents: indoc! {"
+                *.rs          @dotanuki-labs/rustaceans
+                docs/*.md     @ubiratansoares @dotanuki-labs/writers
+            "}
+            .to_string(),
+        };
+
+        let issues = vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::team_does_not_exist(
+                    "dotanuki-labs",
+                    "writers",
+                ))
+                .line_number(1)
+                .description("writers team not found for dotanuki-labs organization")
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+                .line_number(1)
+                .description("docs/*.md does not match any project path")
+                .build(),
+        ];
+
+        let rendered = console::strip_ansi_codes(&text::render(&context, &issues)).to_string();
+
+        let dangling_glob = indoc! {"
+            error[CNP002]: dangling-glob
+
+              .github/CODEOWNERS:2:1 docs/*.md does not match any project path
+                |
+              2 | docs/*.md     @ubiratansoares @dotanuki-labs/writers
+                | ^^^^^^^^^
+        "};
+
+        assertor::assert_that!(rendered).contains(dangling_glob);
+        assertor::assert_that!(rendered).contains("error[CNP107]: team-does-not-exist");
+        assertor::assert_that!(rendered).contains("= also flagged by dangling-glob");
+        assertor::assert_that!(rendered).contains(format!("  | {}{}", " ".repeat(30), "^".repeat(22)).as_str());
+    }
+}
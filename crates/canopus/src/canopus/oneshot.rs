@@ -0,0 +1,154 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::formats::{OutputFormat, ProjectValidation};
+use crate::core::models::ValidationOutcome;
+use crate::core::models::config::ConfigLayer;
+use anyhow::{Context, bail};
+use clap::ValueEnum;
+use std::path::PathBuf;
+
+pub static ENV_VAR_PROJECT_PATH: &str = "CANOPUS_PROJECT_PATH";
+pub static ENV_VAR_OUTPUT_DIR: &str = "CANOPUS_OUTPUT_DIR";
+pub static ENV_VAR_REPORT_FORMAT: &str = "CANOPUS_REPORT_FORMAT";
+
+static ENV_VARS_PREFIX: &str = "CANOPUS_";
+
+/// Everything a single-shot validation needs, read from environment variables only, since
+/// container steps in CI systems rarely offer anything else
+#[derive(Debug)]
+pub struct OneShotSettings {
+    pub project_root: PathBuf,
+    pub output_dir: PathBuf,
+    pub format: OutputFormat,
+    pub config_layer: ConfigLayer,
+}
+
+impl OneShotSettings {
+    pub fn from_env_vars(vars: impl IntoIterator<Item = (String, String)>) -> anyhow::Result<Self> {
+        let (settings, config_vars): (Vec<_>, Vec<_>) = vars
+            .into_iter()
+            .filter(|(name, value)| name.starts_with(ENV_VARS_PREFIX) && !value.is_empty())
+            .partition(|(name, _)| {
+                [ENV_VAR_PROJECT_PATH, ENV_VAR_OUTPUT_DIR, ENV_VAR_REPORT_FORMAT].contains(&name.as_str())
+            });
+
+        let setting = |name: &str| {
+            settings
+                .iter()
+                .find(|(setting, _)| setting == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        let required = |name: &str| setting(name).with_context(|| format!("expecting {name} environment variable"));
+
+        let format = match setting(ENV_VAR_REPORT_FORMAT) {
+            Some(raw_format) => OutputFormat::from_str(raw_format, true)
+                .map_err(|_| anyhow::anyhow!("invalid {ENV_VAR_REPORT_FORMAT} : {raw_format}"))?,
+            None => OutputFormat::Json,
+        };
+
+        if !format.is_machine_readable() {
+            bail!("expecting a machine-readable {ENV_VAR_REPORT_FORMAT}, like json or junit");
+        }
+
+        Ok(Self {
+            project_root: PathBuf::from(required(ENV_VAR_PROJECT_PATH)?),
+            output_dir: PathBuf::from(required(ENV_VAR_OUTPUT_DIR)?),
+            format,
+            config_layer: ConfigLayer::from_env_vars(&config_vars)?,
+        })
+    }
+
+    pub fn report_file(&self) -> PathBuf {
+        self.output_dir.join(self.format.report_file_name())
+    }
+}
+
+/// How a single-shot validation ended, mapped to exit codes CI systems can rely on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OneShotStatus {
+    Validated,
+    IssuesDetected,
+    Failed,
+}
+
+impl OneShotStatus {
+    pub fn of(validations: &[ProjectValidation]) -> Self {
+        if validations.iter().any(|(_, validation)| validation.is_err()) {
+            return OneShotStatus::Failed;
+        }
+
+        if validations
+            .iter()
+            .all(|(_, validation)| matches!(validation, Ok((_, ValidationOutcome::NoIssues))))
+        {
+            return OneShotStatus::Validated;
+        }
+
+        OneShotStatus::IssuesDetected
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            OneShotStatus::Validated => 0,
+            OneShotStatus::IssuesDetected => 1,
+            OneShotStatus::Failed => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::formats::OutputFormat;
+    use crate::canopus::oneshot::OneShotSettings;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use std::path::PathBuf;
+
+    fn env_vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn should_read_single_shot_settings_from_env_vars() {
+        let vars = env_vars(&[
+            ("CANOPUS_PROJECT_PATH", "/project"),
+            ("CANOPUS_OUTPUT_DIR", "/output"),
+            ("CANOPUS_REPORT_FORMAT", "junit"),
+            ("CANOPUS_GENERAL_GITHUB_ORGANIZATION", "dotanuki-labs"),
+            ("CANOPUS_PATHS_INCLUDE_UNTRACKED", ""),
+            ("HOME", "/root"),
+        ]);
+
+        let settings = OneShotSettings::from_env_vars(vars).unwrap();
+        let config = settings.config_layer.clone().resolve().unwrap();
+
+        assertor::assert_that!(settings.project_root).is_equal_to(PathBuf::from("/project"));
+        assertor::assert_that!(settings.format).is_equal_to(OutputFormat::Junit);
+        assertor::assert_that!(settings.report_file()).is_equal_to(PathBuf::from("/output/canopus-report.xml"));
+        assertor::assert_that!(config.general.github_organization).is_equal_to("dotanuki-labs".to_string());
+    }
+
+    #[test]
+    fn should_refuse_incomplete_single_shot_settings() {
+        let without_output = env_vars(&[
+            ("CANOPUS_PROJECT_PATH", "/project"),
+            ("CANOPUS_GENERAL_GITHUB_ORGANIZATION", "dotanuki-labs"),
+        ]);
+
+        let with_text_format = env_vars(&[
+            ("CANOPUS_PROJECT_PATH", "/project"),
+            ("CANOPUS_OUTPUT_DIR", "/output"),
+            ("CANOPUS_REPORT_FORMAT", "text"),
+        ]);
+
+        let missing_output = OneShotSettings::from_env_vars(without_output).unwrap_err();
+        let unsupported_format = OneShotSettings::from_env_vars(with_text_format).unwrap_err();
+
+        assertor::assert_that!(missing_output.to_string())
+            .is_equal_to("expecting CANOPUS_OUTPUT_DIR environment variable".to_string());
+        assertor::assert_that!(unsupported_format.to_string()).contains("machine-readable");
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners;
+use anyhow::Context;
+use globset::GlobMatcher;
+use itertools::Itertools;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// How many matched files are listed when explaining a pattern
+static SAMPLED_FILES: usize = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum ExplainFormat {
+    Text,
+    Json,
+}
+
+/// A pattern written like the explained one, and how many files only one of them matches
+#[derive(Debug, PartialEq, Serialize)]
+pub struct SimilarPattern {
+    pub pattern: String,
+    #[serde(rename = "matches-more")]
+    pub matches_more: usize,
+    #[serde(rename = "matches-fewer")]
+    pub matches_fewer: usize,
+}
+
+/// Which project files a CODEOWNERS pattern matches, alongside common pitfalls when writing it
+#[derive(Debug, PartialEq, Serialize)]
+pub struct PatternExplanation {
+    pub pattern: String,
+    #[serde(rename = "matched-files")]
+    pub matched_files: usize,
+    #[serde(rename = "sampled-files")]
+    pub sampled_files: Vec<String>,
+    #[serde(rename = "similar-patterns")]
+    pub similar_patterns: Vec<SimilarPattern>,
+    pub warnings: Vec<String>,
+}
+
+fn compile_matcher(pattern: &str) -> anyhow::Result<GlobMatcher> {
+    let glob = codeowners::parse_glob(pattern).with_context(|| format!("invalid glob pattern : {pattern}"))?;
+    Ok(glob.compile_matcher())
+}
+
+// Variants toggling anchoring and directory semantics, which authors confuse the most
+fn similar_patterns(pattern: &str) -> Vec<String> {
+    let stem = codeowners::glob_stem(pattern);
+
+    [
+        format!("/{stem}"),
+        stem.to_string(),
+        format!("/{stem}/"),
+        format!("{stem}/**"),
+        format!("**/{stem}"),
+    ]
+    .into_iter()
+    .filter(|similar| similar != pattern)
+    .unique()
+    .collect_vec()
+}
+
+fn pattern_warnings(pattern: &str, matched_files: usize) -> Vec<String> {
+    let stem = codeowners::glob_stem(pattern);
+    let (anchored, _) = codeowners::glob_anchoring(pattern);
+    let mut warnings = vec![];
+
+    if pattern.starts_with('!') {
+        warnings.push("Github does not support negated patterns, hence `!` is matched literally".to_string());
+    }
+
+    if pattern.contains('[') {
+        warnings.push("Github does not support character ranges like `[a-z]` within patterns".to_string());
+    }
+
+    if !anchored && !pattern.starts_with("**/") {
+        warnings.push(format!(
+            "`{pattern}` has no leading `/`, hence Github matches it at any depth; use `/{stem}` to match from the repository root only"
+        ));
+    }
+
+    if pattern.ends_with("/**") {
+        warnings.push(format!(
+            "trailing `/**` makes Github match files nested at any depth under `{stem}`, just like `{stem}/`"
+        ));
+    }
+
+    if matched_files == 0 {
+        warnings.push("no project files match this pattern".to_string());
+    }
+
+    warnings
+}
+
+impl PatternExplanation {
+    pub fn compute(pattern: &str, project_files: &[PathBuf]) -> anyhow::Result<Self> {
+        let matcher = compile_matcher(pattern)?;
+
+        let matched = project_files.iter().filter(|file| matcher.is_match(file)).collect_vec();
+
+        let similar_patterns = similar_patterns(pattern)
+            .into_iter()
+            .map(|similar| {
+                let similar_matcher = compile_matcher(&similar)?;
+
+                let matches_fewer = matched.iter().filter(|file| !similar_matcher.is_match(file)).count();
+
+                let matches_more = project_files
+                    .iter()
+                    .filter(|file| similar_matcher.is_match(file) && !matcher.is_match(file))
+                    .count();
+
+                Ok(SimilarPattern {
+                    pattern: similar,
+                    matches_more,
+                    matches_fewer,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            matched_files: matched.len(),
+            sampled_files: matched
+                .iter()
+                .take(SAMPLED_FILES)
+                .map(|file| file.to_string_lossy().to_string())
+                .collect_vec(),
+            similar_patterns,
+            warnings: pattern_warnings(pattern, matched.len()),
+        })
+    }
+
+    pub fn render(&self, format: ExplainFormat) -> String {
+        match format {
+            ExplainFormat::Text => self.to_text(),
+            ExplainFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut lines = vec![format!("`{}` matches {} files", self.pattern, self.matched_files)];
+
+        if !self.sampled_files.is_empty() {
+            lines.push(String::new());
+            lines.extend(self.sampled_files.iter().map(|file| format!("  {file}")));
+
+            if self.matched_files > self.sampled_files.len() {
+                lines.push(format!(
+                    "  ... and {} more",
+                    self.matched_files - self.sampled_files.len()
+                ));
+            }
+        }
+
+        lines.extend([String::new(), "Similar patterns".to_string(), String::new()]);
+
+        lines.extend(self.similar_patterns.iter().map(|similar| {
+            if similar.matches_more == 0 && similar.matches_fewer == 0 {
+                return format!("  {} : matches the same files", similar.pattern);
+            }
+
+            format!(
+                "  {} : matches {} more, {} fewer files",
+                similar.pattern, similar.matches_more, similar.matches_fewer
+            )
+        }));
+
+        if !self.warnings.is_empty() {
+            lines.extend([String::new(), "Warnings".to_string(), String::new()]);
+            lines.extend(self.warnings.iter().map(|warning| format!("  {warning}")));
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::patterns::{ExplainFormat, PatternExplanation, SimilarPattern};
+    use assertor::{EqualityAssertion, StringAssertion, VecAssertion};
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_explain_directory_pattern() {
+        let project_files = ["docs/using.md", "docs/guides/setup.md", "README.md"]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+
+        let explanation = PatternExplanation::compute("docs/**", &project_files).unwrap();
+
+        let bare_directory = explanation
+            .similar_patterns
+            .iter()
+            .find(|similar| similar.pattern == "docs")
+            .unwrap();
+
+        let expected_bare_directory = SimilarPattern {
+            pattern: "docs".to_string(),
+            matches_more: 0,
+            matches_fewer: 2,
+        };
+
+        assertor::assert_that!(explanation.matched_files).is_equal_to(2);
+        assertor::assert_that!(explanation.sampled_files).contains("docs/using.md".to_string());
+        assertor::assert_that!(*bare_directory).is_equal_to(expected_bare_directory);
+        assertor::assert_that!(explanation.render(ExplainFormat::Text)).contains("trailing `/**` makes Github match");
+    }
+
+    #[test]
+    fn should_warn_about_unanchored_patterns() {
+        let project_files = ["main.rs", "src/lib.rs"].iter().map(PathBuf::from).collect::<Vec<_>>();
+
+        let explanation = PatternExplanation::compute("*.md", &project_files).unwrap();
+
+        assertor::assert_that!(explanation.matched_files).is_equal_to(0);
+        assertor::assert_that!(explanation.warnings.join("\n")).contains("has no leading `/`");
+        assertor::assert_that!(explanation.warnings.join("\n")).contains("no project files match");
+    }
+}
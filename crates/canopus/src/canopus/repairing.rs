@@ -1,26 +1,91 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+use crate::core::models::aggregation;
+use crate::core::models::aggregation::LineIssues;
+use crate::core::models::checks::CHECKS;
 use crate::core::models::codeowners::CodeOwnersContext;
+use crate::core::models::{IssueFix, IssueKind, ValidationIssue};
+use anyhow::bail;
 use itertools::Itertools;
 
+/// Kinds of issues a repair acts on, identified by check names
+#[derive(Debug, Default, PartialEq)]
+pub struct RepairScope {
+    only: Vec<String>,
+    except: Vec<String>,
+
+    /// Whether issues caused by Github API failures are repaired as well
+    force: bool,
+}
+
+impl RepairScope {
+    pub fn new(only: Vec<String>, except: Vec<String>, force: bool) -> anyhow::Result<Self> {
+        let unknown_checks = only
+            .iter()
+            .chain(except.iter())
+            .filter(|name| !CHECKS.iter().any(|check| check.name == name.as_str()))
+            .collect_vec();
+
+        if !unknown_checks.is_empty() {
+            bail!("cannot repair unknown checks : {}", unknown_checks.iter().join(", "));
+        }
+
+        Ok(Self { only, except, force })
+    }
+
+    pub fn includes(&self, kind: &IssueKind) -> bool {
+        // An outage says nothing about the flagged line, hence we never repair it unless asked to
+        if kind.is_transient() && !self.force {
+            return false;
+        }
+
+        let check = kind.definition().name;
+        let selected = self.only.is_empty() || self.only.iter().any(|name| name == check);
+        selected && !self.except.iter().any(|name| name == check)
+    }
+}
+
+/// Picks the issues a repair acts on, grouped per line so that each line is repaired once
+/// with every automatic fix found for it
+pub fn issues_to_repair(issues: Vec<ValidationIssue>, scope: &RepairScope) -> Vec<LineIssues> {
+    let selected = issues
+        .into_iter()
+        .filter(|issue| scope.includes(&issue.kind))
+        .collect_vec();
+
+    aggregation::aggregate_per_line(selected)
+}
+
 pub fn repair_code_owners(
     codeowners_context: &CodeOwnersContext,
     lines_to_repair: Vec<usize>,
+    fixes: Vec<IssueFix>,
     remove_lines: bool,
 ) -> anyhow::Result<()> {
-    let codeowners_lines = codeowners_context.contents.lines().collect_vec();
+    // Lines are tracked by their original positions until all fixes are applied,
+    // with removed lines becoming empty slots
+    let mut codeowners_lines = codeowners_context
+        .contents
+        .lines()
+        .map(|content| Some(content.to_string()))
+        .collect_vec();
+
+    rewrite_fixed_lines(&fixes, &mut codeowners_lines);
 
     // Evaluate lines to remove or patch
-    let new_lines = if remove_lines {
-        remove_flagged_lines(&lines_to_repair, &codeowners_lines)
+    if remove_lines {
+        remove_flagged_lines(&lines_to_repair, &mut codeowners_lines);
     } else {
-        patch_flagged_lines(lines_to_repair, codeowners_lines)
-    };
+        patch_flagged_lines(&lines_to_repair, &mut codeowners_lines);
+    }
+
+    remove_flagged_lines(&fixed_lines_to_drop(&fixes), &mut codeowners_lines);
+    reorder_fixed_lines(&fixes, &mut codeowners_lines);
 
     // Create a new CodeOwners using new lines
     // but also add a new line at the end of the file
-    let mut new_codeowners = new_lines.join("\n");
+    let mut new_codeowners = codeowners_lines.into_iter().flatten().join("\n");
     new_codeowners.push('\n');
 
     std::fs::write(&codeowners_context.codeowners_path, new_codeowners)?;
@@ -28,40 +93,73 @@ pub fn repair_code_owners(
     Ok(())
 }
 
-fn patch_flagged_lines(lines_to_repair: Vec<usize>, codeowners_lines: Vec<&str>) -> Vec<String> {
-    codeowners_lines
-        .into_iter()
-        .enumerate()
-        .map(|(line, content)| {
-            if lines_to_repair.contains(&line) {
-                format!("# {} (preserved by canopus)", content)
-            } else {
-                content.to_string()
-            }
+fn rewrite_fixed_lines(fixes: &[IssueFix], codeowners_lines: &mut [Option<String>]) {
+    fixes.iter().for_each(|fix| {
+        if let IssueFix::RewriteLine(line, contents) = fix
+            && let Some(slot) = codeowners_lines.get_mut(*line)
+        {
+            *slot = Some(contents.clone());
+        }
+    });
+}
+
+fn fixed_lines_to_drop(fixes: &[IssueFix]) -> Vec<usize> {
+    fixes
+        .iter()
+        .flat_map(|fix| match fix {
+            IssueFix::MergeDuplicates(duplicates) => duplicates.clone(),
+            _ => vec![],
         })
         .collect_vec()
 }
 
-fn remove_flagged_lines(lines_to_repair: &[usize], codeowners_lines: &Vec<&str>) -> Vec<String> {
+fn reorder_fixed_lines(fixes: &[IssueFix], codeowners_lines: &mut [Option<String>]) {
+    fixes.iter().for_each(|fix| {
+        if let IssueFix::ReorderLines(expected_order) = fix {
+            let reordered = expected_order
+                .iter()
+                .map(|line| codeowners_lines.get(*line).cloned().flatten())
+                .collect_vec();
+
+            expected_order
+                .iter()
+                .copied()
+                .sorted()
+                .zip(reordered)
+                .for_each(|(position, contents)| codeowners_lines[position] = contents);
+        }
+    });
+}
+
+fn patch_flagged_lines(lines_to_repair: &[usize], codeowners_lines: &mut [Option<String>]) {
     codeowners_lines
-        .iter()
+        .iter_mut()
         .enumerate()
-        .filter_map(|(line, content)| {
-            if !lines_to_repair.contains(&line) {
-                Some(content.to_string())
-            } else {
-                None
+        .filter(|(line, _)| lines_to_repair.contains(line))
+        .for_each(|(_, slot)| {
+            if let Some(content) = slot {
+                *slot = Some(format!("# {} (preserved by canopus)", content));
             }
-        })
-        .collect_vec()
+        });
+}
+
+fn remove_flagged_lines(lines_to_repair: &[usize], codeowners_lines: &mut [Option<String>]) {
+    codeowners_lines
+        .iter_mut()
+        .enumerate()
+        .filter(|(line, _)| lines_to_repair.contains(line))
+        .for_each(|(_, slot)| *slot = None);
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::canopus::repairing::repair_code_owners;
+    use crate::canopus::repairing::{RepairScope, issues_to_repair, repair_code_owners};
     use crate::core::models::codeowners::CodeOwnersContext;
-    use assertor::{EqualityAssertion, ResultAssertion};
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{IssueFix, ValidationIssue};
+    use assertor::{EqualityAssertion, ResultAssertion, VecAssertion};
     use indoc::indoc;
+    use itertools::Itertools;
     use temp_dir::TempDir;
 
     #[test]
@@ -84,7 +182,7 @@ mod tests {
 
         let remove_lines = true;
         let lines_to_repair = vec![2];
-        let repair = repair_code_owners(&codeowners_context, lines_to_repair, remove_lines);
+        let repair = repair_code_owners(&codeowners_context, lines_to_repair, vec![], remove_lines);
 
         assertor::assert_that!(repair).is_ok();
 
@@ -117,7 +215,7 @@ mod tests {
 
         let remove_lines = false;
         let lines_to_repair = vec![1];
-        let repair = repair_code_owners(&codeowners_context, lines_to_repair, remove_lines);
+        let repair = repair_code_owners(&codeowners_context, lines_to_repair, vec![], remove_lines);
 
         assertor::assert_that!(repair).is_ok();
 
@@ -130,4 +228,118 @@ mod tests {
 
         assertor::assert_that!(repaired).is_equal_to(expected_content.to_string());
     }
+
+    #[test]
+    fn should_repair_code_owners_by_merging_duplicated_rules() {
+        let codeowners = indoc! {"
+            docs/**     @dotanuki/writers
+            *.rs        @dotanuki/crabbers
+            *.js        dotanuki/frontend
+            /docs/      @dotanuki/writers
+        "};
+
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        let codeowners_context = CodeOwnersContext {
+            project_path: temp_dir.path().to_path_buf(),
+            codeowners_path: temp_dir.path().join("CODEOWNERS"),
+            contents: codeowners.to_string(),
+        };
+
+        let fixes = vec![IssueFix::MergeDuplicates(vec![3])];
+        let repair = repair_code_owners(&codeowners_context, vec![2], fixes, false);
+
+        assertor::assert_that!(repair).is_ok();
+
+        let repaired = std::fs::read_to_string(&codeowners_context.codeowners_path).unwrap();
+
+        let expected_content = indoc! {"
+            docs/**     @dotanuki/writers
+            *.rs        @dotanuki/crabbers
+            # *.js        dotanuki/frontend (preserved by canopus)
+        "};
+
+        assertor::assert_that!(repaired).is_equal_to(expected_content.to_string());
+    }
+
+    #[test]
+    fn should_repair_code_owners_by_sorting_rules_and_owners() {
+        let codeowners = indoc! {"
+            # Backend
+            src/**      @dotanuki/crabbers @dotanuki/admins
+            docs/**     @dotanuki/writers
+            *.js        dotanuki/frontend
+        "};
+
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        let codeowners_context = CodeOwnersContext {
+            project_path: temp_dir.path().to_path_buf(),
+            codeowners_path: temp_dir.path().join("CODEOWNERS"),
+            contents: codeowners.to_string(),
+        };
+
+        let fixes = vec![
+            IssueFix::RewriteLine(1, "src/**      @dotanuki/admins @dotanuki/crabbers".to_string()),
+            IssueFix::ReorderLines(vec![3, 2, 1]),
+        ];
+
+        let repair = repair_code_owners(&codeowners_context, vec![3], fixes, false);
+
+        assertor::assert_that!(repair).is_ok();
+
+        let repaired = std::fs::read_to_string(&codeowners_context.codeowners_path).unwrap();
+
+        let expected_content = indoc! {"
+            # Backend
+            # *.js        dotanuki/frontend (preserved by canopus)
+            docs/**     @dotanuki/writers
+            src/**      @dotanuki/admins @dotanuki/crabbers
+        "};
+
+        assertor::assert_that!(repaired).is_equal_to(expected_content.to_string());
+    }
+
+    #[test]
+    fn should_repair_only_lines_flagged_by_selected_checks() {
+        let issues = vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+                .line_number(1)
+                .description("docs/*.md does not match any project path")
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::cannot_verify_user("ubiratansoares"))
+                .line_number(2)
+                .description("cannot verify @ubiratansoares")
+                .build(),
+        ];
+
+        let scope = RepairScope::new(vec!["dangling-glob".to_string()], vec![], false).unwrap();
+        let lines = issues_to_repair(issues, &scope)
+            .iter()
+            .map(|issue| issue.line)
+            .collect_vec();
+
+        assertor::assert_that!(lines).is_equal_to(vec![1]);
+        assertor::assert_that!(RepairScope::new(vec![], vec!["dangling-globs".to_string()], false)).is_err();
+    }
+
+    #[test]
+    fn should_not_repair_transient_failures_unless_forced() {
+        let issue = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::cannot_verify_user("ubiratansoares"))
+            .line_number(2)
+            .description("cannot verify @ubiratansoares")
+            .build();
+
+        let default_scope = RepairScope::default();
+        let forced_scope = RepairScope::new(vec![], vec![], true).unwrap();
+
+        let repaired_by_default = issues_to_repair(vec![issue.clone()], &default_scope);
+        let repaired_when_forced = issues_to_repair(vec![issue], &forced_scope);
+
+        assertor::assert_that!(repaired_by_default).is_empty();
+        assertor::assert_that!(repaired_when_forced.len()).is_equal_to(1);
+    }
 }
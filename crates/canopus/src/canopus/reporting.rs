@@ -0,0 +1,375 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+pub mod heatmap;
+
+use crate::core::models::codeowners::CodeOwners;
+use crate::core::models::{ValidationIssue, ValidationOutcome};
+use crate::core::ownership::{OwnershipCoverage, OwnershipResolver};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+static MAX_LISTED_UNOWNED_PATHS: usize = 50;
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum ReportFormat {
+    #[value(name = "md")]
+    Markdown,
+    Html,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OwnerSummary {
+    pub owner: String,
+    pub rules: usize,
+    pub owned_files: usize,
+}
+
+/// Files owned through the rules of a CODEOWNERS section, like `# === Backend ===`
+#[derive(Debug, PartialEq)]
+pub struct SectionSummary {
+    pub section: String,
+    pub rules: usize,
+    pub owned_files: usize,
+}
+
+/// A human-readable view over ownership rules, project files and detected issues
+#[derive(Debug)]
+pub struct OwnershipReport {
+    pub project: String,
+    pub coverage: OwnershipCoverage,
+    pub owners: Vec<OwnerSummary>,
+    pub sections: Vec<SectionSummary>,
+    pub unowned_paths: Vec<PathBuf>,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl OwnershipReport {
+    pub fn new(project: &str, code_owners: &CodeOwners, project_files: &[PathBuf], outcome: ValidationOutcome) -> Self {
+        let resolver = OwnershipResolver::new(code_owners);
+        let mut files_per_owner: HashMap<String, usize> = HashMap::new();
+        let mut files_per_section: HashMap<&str, usize> = HashMap::new();
+        let section_per_line = code_owners.section_per_line();
+        let mut unowned_paths = Vec::new();
+
+        for file in project_files {
+            match resolver.resolve(file) {
+                Some(rule) => {
+                    rule.owners.iter().for_each(|owner| {
+                        *files_per_owner.entry(owner.to_string()).or_default() += 1;
+                    });
+
+                    if let Some(section) = section_per_line.get(&rule.line_number) {
+                        *files_per_section.entry(section).or_default() += 1;
+                    }
+                },
+                None => unowned_paths.push(file.strip_prefix(project).unwrap_or(file).to_path_buf()),
+            }
+        }
+
+        let owners = code_owners
+            .unique_owners()
+            .into_iter()
+            .map(|owner| OwnerSummary {
+                owner: owner.to_string(),
+                rules: code_owners.occurrences(owner).len(),
+                owned_files: files_per_owner.get(&owner.to_string()).copied().unwrap_or_default(),
+            })
+            .sorted_by(|first, second| {
+                second
+                    .owned_files
+                    .cmp(&first.owned_files)
+                    .then(first.owner.cmp(&second.owner))
+            })
+            .collect_vec();
+
+        let sections = code_owners
+            .sections()
+            .into_iter()
+            .filter_map(|section| {
+                let name = section.name?;
+
+                Some(SectionSummary {
+                    section: name.to_string(),
+                    rules: section.rules.len(),
+                    owned_files: files_per_section.get(name).copied().unwrap_or_default(),
+                })
+            })
+            .collect_vec();
+
+        let issues = match outcome {
+            ValidationOutcome::NoIssues => vec![],
+            ValidationOutcome::IssuesDetected(issues) => issues,
+        };
+
+        Self {
+            project: project.to_string(),
+            coverage: OwnershipCoverage::compute(code_owners, project_files),
+            owners,
+            sections,
+            unowned_paths: unowned_paths.into_iter().sorted().collect_vec(),
+            issues,
+        }
+    }
+
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Markdown => self.to_markdown(),
+            ReportFormat::Html => self.to_html(),
+        }
+    }
+
+    fn issue_location(issue: &ValidationIssue) -> String {
+        if issue.line == usize::MAX {
+            "Project".to_string()
+        } else {
+            format!("L{}", issue.line + 1)
+        }
+    }
+
+    fn coverage_summary(&self) -> String {
+        format!(
+            "{:.2}% ({} of {} files)",
+            self.coverage.percentage(),
+            self.coverage.owned_files,
+            self.coverage.total_files
+        )
+    }
+
+    fn listed_unowned_paths(&self) -> (impl Iterator<Item = String> + '_, usize) {
+        let listed = self
+            .unowned_paths
+            .iter()
+            .take(MAX_LISTED_UNOWNED_PATHS)
+            .map(|path| path.to_string_lossy().to_string());
+
+        let omitted = self.unowned_paths.len().saturating_sub(MAX_LISTED_UNOWNED_PATHS);
+        (listed, omitted)
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut markdown = vec![
+            format!("# Ownership report for {}", self.project),
+            String::new(),
+            format!("Ownership coverage : **{}**", self.coverage_summary()),
+            String::new(),
+            "## Owners".to_string(),
+            String::new(),
+            "| Owner | Rules | Owned files |".to_string(),
+            "|-------|-------|-------------|".to_string(),
+        ];
+
+        markdown.extend(
+            self.owners
+                .iter()
+                .map(|summary| format!("| `{}` | {} | {} |", summary.owner, summary.rules, summary.owned_files)),
+        );
+
+        if !self.sections.is_empty() {
+            markdown.extend([
+                "".to_string(),
+                "## Sections".to_string(),
+                String::new(),
+                "| Section | Rules | Owned files |".to_string(),
+                "|---------|-------|-------------|".to_string(),
+            ]);
+
+            markdown.extend(
+                self.sections
+                    .iter()
+                    .map(|summary| format!("| {} | {} | {} |", summary.section, summary.rules, summary.owned_files)),
+            );
+        }
+
+        markdown.extend(["".to_string(), "## Unowned paths".to_string(), String::new()]);
+
+        let (listed, omitted) = self.listed_unowned_paths();
+        markdown.extend(listed.map(|path| format!("- `{path}`")));
+
+        if self.unowned_paths.is_empty() {
+            markdown.push("All project files have owners.".to_string());
+        }
+
+        if omitted > 0 {
+            markdown.push(format!("- ... and {omitted} more"));
+        }
+
+        markdown.extend(["".to_string(), "## Issues".to_string(), String::new()]);
+
+        markdown.extend(self.issues.iter().map(|issue| {
+            format!(
+                "- **{}** {} `{}` ({})",
+                Self::issue_location(issue),
+                issue.context,
+                issue.kind,
+                issue.kind.code()
+            )
+        }));
+
+        if self.issues.is_empty() {
+            markdown.push("No issues found.".to_string());
+        }
+
+        markdown.push(String::new());
+        markdown.join("\n")
+    }
+
+    fn to_html(&self) -> String {
+        let project = escape_html(&self.project);
+
+        let mut html = vec![
+            "<!DOCTYPE html>".to_string(),
+            "<html>".to_string(),
+            format!("<head><meta charset=\"utf-8\"><title>Ownership report for {project}</title></head>"),
+            "<body>".to_string(),
+            format!("<h1>Ownership report for {project}</h1>"),
+            format!(
+                "<p>Ownership coverage : <strong>{}</strong></p>",
+                self.coverage_summary()
+            ),
+            "<h2>Owners</h2>".to_string(),
+            "<table>".to_string(),
+            "<tr><th>Owner</th><th>Rules</th><th>Owned files</th></tr>".to_string(),
+        ];
+
+        html.extend(self.owners.iter().map(|summary| {
+            format!(
+                "<tr><td><code>{}</code></td><td>{}</td><td>{}</td></tr>",
+                escape_html(&summary.owner),
+                summary.rules,
+                summary.owned_files
+            )
+        }));
+
+        html.push("</table>".to_string());
+
+        if !self.sections.is_empty() {
+            html.extend([
+                "<h2>Sections</h2>".to_string(),
+                "<table>".to_string(),
+                "<tr><th>Section</th><th>Rules</th><th>Owned files</th></tr>".to_string(),
+            ]);
+
+            html.extend(self.sections.iter().map(|summary| {
+                format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    escape_html(&summary.section),
+                    summary.rules,
+                    summary.owned_files
+                )
+            }));
+
+            html.push("</table>".to_string());
+        }
+
+        html.push("<h2>Unowned paths</h2>".to_string());
+
+        if self.unowned_paths.is_empty() {
+            html.push("<p>All project files have owners.</p>".to_string());
+        } else {
+            let (listed, omitted) = self.listed_unowned_paths();
+
+            html.push("<ul>".to_string());
+            html.extend(listed.map(|path| format!("<li><code>{}</code></li>", escape_html(&path))));
+
+            if omitted > 0 {
+                html.push(format!("<li>... and {omitted} more</li>"));
+            }
+
+            html.push("</ul>".to_string());
+        }
+
+        html.push("<h2>Issues</h2>".to_string());
+
+        if self.issues.is_empty() {
+            html.push("<p>No issues found.</p>".to_string());
+        } else {
+            html.push("<ul>".to_string());
+            html.extend(self.issues.iter().map(|issue| {
+                format!(
+                    "<li><strong>{}</strong> {} <code>{}</code> ({})</li>",
+                    Self::issue_location(issue),
+                    escape_html(&issue.context),
+                    issue.kind,
+                    issue.kind.code()
+                )
+            }));
+            html.push("</ul>".to_string());
+        }
+
+        html.extend(["</body>".to_string(), "</html>".to_string(), String::new()]);
+        html.join("\n")
+    }
+}
+
+fn escape_html(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::reporting::{OwnerSummary, OwnershipReport, ReportFormat};
+    use crate::core::models::ValidationOutcome;
+    use crate::core::models::codeowners::CodeOwners;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use std::path::PathBuf;
+
+    fn create_report() -> OwnershipReport {
+        let codeowners_rules = indoc! {"
+            *.rs        @dotanuki-labs/rustaceans
+
+            # === Docs ===
+            docs/*.md   @ubiratansoares
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+
+        let project_files = ["src/main.rs", "src/lib.rs", "docs/using.md", "Cargo.toml"]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+
+        OwnershipReport::new("canopus", &codeowners, &project_files, ValidationOutcome::NoIssues)
+    }
+
+    #[test]
+    fn should_summarize_files_per_owner() {
+        let report = create_report();
+
+        let expected = vec![
+            OwnerSummary {
+                owner: "@dotanuki-labs/rustaceans".to_string(),
+                rules: 1,
+                owned_files: 2,
+            },
+            OwnerSummary {
+                owner: "@ubiratansoares".to_string(),
+                rules: 1,
+                owned_files: 1,
+            },
+        ];
+
+        assertor::assert_that!(report.owners).is_equal_to(expected);
+        assertor::assert_that!(report.unowned_paths).is_equal_to(vec![PathBuf::from("Cargo.toml")]);
+    }
+
+    #[test]
+    fn should_render_markdown_and_html_reports() {
+        let report = create_report();
+
+        let markdown = report.render(ReportFormat::Markdown);
+        let html = report.render(ReportFormat::Html);
+
+        assertor::assert_that!(markdown).contains("| `@dotanuki-labs/rustaceans` | 1 | 2 |");
+        assertor::assert_that!(markdown).contains("- `Cargo.toml`");
+        assertor::assert_that!(html).contains("<td><code>@ubiratansoares</code></td><td>1</td><td>1</td>");
+        assertor::assert_that!(html).contains("<p>No issues found.</p>");
+        assertor::assert_that!(markdown).contains("| Docs | 1 | 1 |");
+        assertor::assert_that!(html).contains("<tr><td>Docs</td><td>1</td><td>1</td></tr>");
+    }
+}
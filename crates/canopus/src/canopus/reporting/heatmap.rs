@@ -0,0 +1,290 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners::CodeOwners;
+use crate::core::ownership::OwnershipResolver;
+use itertools::Itertools;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+static MAX_LISTED_OWNERS: usize = 3;
+static ROOT_DIRECTORY: &str = ".";
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum HeatmapFormat {
+    Table,
+    #[value(name = "md")]
+    Markdown,
+}
+
+/// Effective ownership of the files living under a directory, at the depth of the heat map
+#[derive(Debug, PartialEq)]
+pub struct DirectoryOwnership {
+    pub directory: String,
+    pub files: usize,
+    pub unowned_files: usize,
+
+    /// Owners with how many files of this directory they own, most prominent first
+    pub owners: Vec<(String, usize)>,
+
+    /// Rules matching the same files of this directory with different owners
+    pub conflicting_rules: usize,
+}
+
+impl DirectoryOwnership {
+    fn listed_owners(&self) -> String {
+        if self.owners.is_empty() {
+            return "-".to_string();
+        }
+
+        let listed = self
+            .owners
+            .iter()
+            .take(MAX_LISTED_OWNERS)
+            .map(|(owner, files)| format!("{owner} ({files})"))
+            .join(", ");
+
+        let omitted = self.owners.len().saturating_sub(MAX_LISTED_OWNERS);
+
+        if omitted > 0 {
+            format!("{listed} and {omitted} more")
+        } else {
+            listed
+        }
+    }
+}
+
+#[derive(Default)]
+struct DirectoryTally {
+    files: usize,
+    unowned_files: usize,
+    files_per_owner: HashMap<String, usize>,
+    conflicting_lines: HashSet<usize>,
+}
+
+/// Effective ownership rolled up per directory, down to a given depth, as a one-page view over a project
+#[derive(Debug)]
+pub struct OwnershipHeatmap {
+    pub project: String,
+    pub depth: usize,
+    pub directories: Vec<DirectoryOwnership>,
+}
+
+impl OwnershipHeatmap {
+    pub fn compute(project: &str, code_owners: &CodeOwners, project_files: &[PathBuf], depth: usize) -> Self {
+        let resolver = OwnershipResolver::new(code_owners);
+        let mut tallies: BTreeMap<String, DirectoryTally> = BTreeMap::new();
+
+        for file in project_files {
+            let relative_file = file.strip_prefix(project).unwrap_or(file);
+            let tally = tallies.entry(directory_at_depth(relative_file, depth)).or_default();
+            tally.files += 1;
+
+            let matching = resolver.matching(file);
+
+            let Some(owning_rule) = matching.last() else {
+                tally.unowned_files += 1;
+                continue;
+            };
+
+            owning_rule.owners.iter().unique().for_each(|owner| {
+                *tally.files_per_owner.entry(owner.to_string()).or_default() += 1;
+            });
+
+            let overridden = matching
+                .iter()
+                .filter(|rule| rule.owners != owning_rule.owners)
+                .map(|rule| rule.line_number)
+                .collect_vec();
+
+            if !overridden.is_empty() {
+                tally.conflicting_lines.extend(overridden);
+                tally.conflicting_lines.insert(owning_rule.line_number);
+            }
+        }
+
+        let directories = tallies
+            .into_iter()
+            .map(|(directory, tally)| DirectoryOwnership {
+                directory,
+                files: tally.files,
+                unowned_files: tally.unowned_files,
+                owners: tally
+                    .files_per_owner
+                    .into_iter()
+                    .sorted_by(|(first, first_files), (second, second_files)| {
+                        second_files.cmp(first_files).then(first.cmp(second))
+                    })
+                    .collect_vec(),
+                conflicting_rules: tally.conflicting_lines.len(),
+            })
+            .collect_vec();
+
+        Self {
+            project: project.to_string(),
+            depth,
+            directories,
+        }
+    }
+
+    pub fn render(&self, format: HeatmapFormat) -> String {
+        match format {
+            HeatmapFormat::Table => self.to_table(),
+            HeatmapFormat::Markdown => self.to_markdown(),
+        }
+    }
+
+    fn to_table(&self) -> String {
+        let directory_width = self
+            .directories
+            .iter()
+            .map(|ownership| ownership.directory.len())
+            .chain(["Directory".len()])
+            .max()
+            .unwrap_or_default();
+
+        let mut table = vec![format!(
+            "{:<directory_width$}  {:>5}  {:>7}  {:>11}  Owners",
+            "Directory", "Files", "Unowned", "Conflicting"
+        )];
+
+        table.extend(self.directories.iter().map(|ownership| {
+            format!(
+                "{:<directory_width$}  {:>5}  {:>7}  {:>11}  {}",
+                ownership.directory,
+                ownership.files,
+                ownership.unowned_files,
+                ownership.conflicting_rules,
+                ownership.listed_owners()
+            )
+        }));
+
+        table.push(String::new());
+        table.join("\n")
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut markdown = vec![
+            format!("# Ownership heat map for {}", self.project),
+            String::new(),
+            format!("Directories rolled up at depth {}", self.depth),
+            String::new(),
+            "| Directory | Files | Unowned files | Conflicting rules | Owners |".to_string(),
+            "|-----------|-------|---------------|-------------------|--------|".to_string(),
+        ];
+
+        markdown.extend(self.directories.iter().map(|ownership| {
+            format!(
+                "| `{}` | {} | {} | {} | {} |",
+                ownership.directory,
+                ownership.files,
+                ownership.unowned_files,
+                ownership.conflicting_rules,
+                ownership.listed_owners()
+            )
+        }));
+
+        markdown.push(String::new());
+        markdown.join("\n")
+    }
+}
+
+// Files living at the project root are rolled up together, whatever the depth
+fn directory_at_depth(file: &Path, depth: usize) -> String {
+    let Some(parent) = file.parent() else {
+        return ROOT_DIRECTORY.to_string();
+    };
+
+    let directories = parent
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .take(depth)
+        .collect_vec();
+
+    if directories.is_empty() {
+        return ROOT_DIRECTORY.to_string();
+    }
+
+    format!("{}/", directories.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::reporting::heatmap::{DirectoryOwnership, HeatmapFormat, OwnershipHeatmap};
+    use crate::core::models::codeowners::CodeOwners;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use std::path::PathBuf;
+
+    fn create_heatmap(depth: usize) -> OwnershipHeatmap {
+        let codeowners_rules = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans
+            crates/cli/**   @dotanuki-labs/cli
+            docs/**         @dotanuki-labs/writers
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+
+        let project_files = [
+            "crates/cli/src/main.rs",
+            "crates/core/src/lib.rs",
+            "crates/core/Cargo.toml",
+            "docs/using.md",
+            "Cargo.toml",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect::<Vec<_>>();
+
+        OwnershipHeatmap::compute("canopus", &codeowners, &project_files, depth)
+    }
+
+    #[test]
+    fn should_roll_up_ownership_per_top_level_directory() {
+        let heatmap = create_heatmap(1);
+
+        let expected = vec![
+            DirectoryOwnership {
+                directory: ".".to_string(),
+                files: 1,
+                unowned_files: 1,
+                owners: vec![],
+                conflicting_rules: 0,
+            },
+            DirectoryOwnership {
+                directory: "crates/".to_string(),
+                files: 3,
+                unowned_files: 1,
+                owners: vec![
+                    ("@dotanuki-labs/cli".to_string(), 1),
+                    ("@dotanuki-labs/rustaceans".to_string(), 1),
+                ],
+                conflicting_rules: 2,
+            },
+            DirectoryOwnership {
+                directory: "docs/".to_string(),
+                files: 1,
+                unowned_files: 0,
+                owners: vec![("@dotanuki-labs/writers".to_string(), 1)],
+                conflicting_rules: 0,
+            },
+        ];
+
+        assertor::assert_that!(heatmap.directories).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_render_heatmap_at_configured_depth() {
+        let heatmap = create_heatmap(2);
+
+        let markdown = heatmap.render(HeatmapFormat::Markdown);
+        let table = heatmap.render(HeatmapFormat::Table);
+
+        assertor::assert_that!(markdown).contains("| `crates/cli/` | 1 | 0 | 2 | @dotanuki-labs/cli (1) |");
+        assertor::assert_that!(markdown).contains("| `crates/core/` | 2 | 1 | 0 | @dotanuki-labs/rustaceans (1) |");
+        assertor::assert_that!(table).contains("docs/             1        0            0  @dotanuki-labs/writers (1)");
+    }
+}
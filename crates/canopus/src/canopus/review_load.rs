@@ -0,0 +1,161 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::reviewers::RequestedReviewers;
+use crate::canopus::stats::StatsFormat;
+use crate::core::models::codeowners::CodeOwners;
+use itertools::Itertools;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Owners requested on more than this many times the average of requested owners are overloaded
+static OVERLOAD_FACTOR: f64 = 2.0;
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OwnerLoad {
+    pub owner: String,
+    #[serde(rename = "requested-reviews")]
+    pub requested_reviews: usize,
+    pub overloaded: bool,
+}
+
+/// How many recent pull requests each owner would have been requested to review
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ReviewLoad {
+    pub project: String,
+    #[serde(rename = "pull-requests")]
+    pub pull_requests: usize,
+    pub owners: Vec<OwnerLoad>,
+}
+
+impl ReviewLoad {
+    pub fn compute(project: &str, code_owners: &CodeOwners, changes_per_pull_request: &[Vec<PathBuf>]) -> Self {
+        let mut requests_per_owner: HashMap<String, usize> = HashMap::new();
+
+        changes_per_pull_request.iter().for_each(|changed_files| {
+            RequestedReviewers::compute(code_owners, changed_files)
+                .reviewers
+                .into_iter()
+                .for_each(|reviewer| *requests_per_owner.entry(reviewer.owner).or_default() += 1);
+        });
+
+        let average_requests = if requests_per_owner.is_empty() {
+            0.0
+        } else {
+            requests_per_owner.values().sum::<usize>() as f64 / requests_per_owner.len() as f64
+        };
+
+        let owners = requests_per_owner
+            .into_iter()
+            .map(|(owner, requested_reviews)| OwnerLoad {
+                owner,
+                requested_reviews,
+                overloaded: requested_reviews as f64 > average_requests * OVERLOAD_FACTOR,
+            })
+            .sorted_by(|first, second| {
+                second
+                    .requested_reviews
+                    .cmp(&first.requested_reviews)
+                    .then(first.owner.cmp(&second.owner))
+            })
+            .collect_vec();
+
+        Self {
+            project: project.to_string(),
+            pull_requests: changes_per_pull_request.len(),
+            owners,
+        }
+    }
+
+    pub fn render(&self, format: StatsFormat) -> String {
+        match format {
+            StatsFormat::Table => self.to_table(),
+            StatsFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    fn to_table(&self) -> String {
+        let owner_width = self
+            .owners
+            .iter()
+            .map(|load| load.owner.len())
+            .chain(["Owner".len()])
+            .max()
+            .unwrap_or_default();
+
+        let mut table = vec![
+            format!("Pull requests analyzed : {}", self.pull_requests),
+            String::new(),
+            format!("{:<owner_width$}  {:>17}", "Owner", "Requested reviews"),
+        ];
+
+        table.extend(self.owners.iter().map(|load| {
+            format!(
+                "{:<owner_width$}  {:>17}{}",
+                load.owner,
+                load.requested_reviews,
+                if load.overloaded { "  (overloaded)" } else { "" }
+            )
+        }));
+
+        table.push(String::new());
+        table.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::review_load::ReviewLoad;
+    use crate::canopus::stats::StatsFormat;
+    use crate::core::models::codeowners::CodeOwners;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use itertools::Itertools;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_highlight_overloaded_owners() {
+        let codeowners_rules = indoc! {"
+            *.rs        @dotanuki-labs/rustaceans
+            docs/*      @ubiratansoares
+            .github/*   @dotanuki-labs/infra
+            Cargo.toml  @dotanuki-labs/deps
+            deny.toml   @dotanuki-labs/security
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+
+        let pull_requests = [
+            vec!["src/main.rs"],
+            vec!["src/lib.rs", "docs/using.md"],
+            vec!["src/cli.rs"],
+            vec!["src/core.rs"],
+            vec!["src/infra.rs", "Cargo.toml"],
+            vec!["src/git.rs", ".github/CODEOWNERS", "deny.toml"],
+            vec!["src/remote.rs"],
+        ]
+        .iter()
+        .map(|files| files.iter().map(PathBuf::from).collect_vec())
+        .collect_vec();
+
+        let load = ReviewLoad::compute("canopus", &codeowners, &pull_requests);
+
+        let loads = load
+            .owners
+            .iter()
+            .map(|owner| (owner.owner.as_str(), owner.requested_reviews, owner.overloaded))
+            .collect_vec();
+
+        let expected = vec![
+            ("@dotanuki-labs/rustaceans", 7, true),
+            ("@dotanuki-labs/deps", 1, false),
+            ("@dotanuki-labs/infra", 1, false),
+            ("@dotanuki-labs/security", 1, false),
+            ("@ubiratansoares", 1, false),
+        ];
+
+        assertor::assert_that!(loads).is_equal_to(expected);
+        assertor::assert_that!(load.render(StatsFormat::Table)).contains("(overloaded)");
+    }
+}
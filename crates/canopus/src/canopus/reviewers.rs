@@ -0,0 +1,137 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners::CodeOwners;
+use crate::core::ownership::OwnershipResolver;
+use itertools::Itertools;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum ReviewersFormat {
+    Text,
+    Json,
+}
+
+/// An owner Github would request a review from, alongside the changed files it owns
+#[derive(Debug, PartialEq, Serialize)]
+pub struct RequestedReviewer {
+    pub owner: String,
+    #[serde(rename = "owned-files")]
+    pub owned_files: Vec<String>,
+}
+
+/// Reviewers requested by Github for a set of changed files
+#[derive(Debug, PartialEq, Serialize)]
+pub struct RequestedReviewers {
+    pub reviewers: Vec<RequestedReviewer>,
+    #[serde(rename = "unowned-files")]
+    pub unowned_files: Vec<String>,
+}
+
+impl RequestedReviewers {
+    pub fn compute(code_owners: &CodeOwners, changed_files: &[PathBuf]) -> Self {
+        let resolver = OwnershipResolver::new(code_owners);
+        let mut files_per_owner: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut unowned_files = vec![];
+
+        for file in changed_files.iter().unique() {
+            let path = file.to_string_lossy().to_string();
+
+            // Github only looks at the last matching rule, even when it has no owners
+            let owners = resolver.resolve(file).map(|rule| &rule.owners);
+
+            match owners {
+                Some(owners) if !owners.is_empty() => {
+                    owners.iter().map(|owner| owner.to_string()).unique().for_each(|owner| {
+                        files_per_owner.entry(owner).or_default().push(path.clone());
+                    })
+                },
+                _ => unowned_files.push(path),
+            }
+        }
+
+        let reviewers = files_per_owner
+            .into_iter()
+            .map(|(owner, owned_files)| RequestedReviewer { owner, owned_files })
+            .collect_vec();
+
+        Self {
+            reviewers,
+            unowned_files,
+        }
+    }
+
+    pub fn render(&self, format: ReviewersFormat) -> String {
+        match format {
+            ReviewersFormat::Text => self.to_text(),
+            ReviewersFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut lines = self
+            .reviewers
+            .iter()
+            .map(|reviewer| format!("{} ({} files)", reviewer.owner, reviewer.owned_files.len()))
+            .collect_vec();
+
+        if self.reviewers.is_empty() {
+            lines.push("No reviewers requested".to_string());
+        }
+
+        if !self.unowned_files.is_empty() {
+            lines.extend([String::new(), "Files without owners".to_string(), String::new()]);
+            lines.extend(self.unowned_files.iter().cloned());
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::reviewers::{RequestedReviewer, RequestedReviewers, ReviewersFormat};
+    use crate::core::models::codeowners::CodeOwners;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_compute_reviewers_from_last_matching_rules() {
+        let codeowners_rules = indoc! {"
+            *.rs        @dotanuki-labs/rustaceans @ubiratansoares
+            src/cli/*   @dotanuki-labs/cli
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+
+        let changed_files = ["src/cli/args.rs", "src/main.rs", "README.md", "src/main.rs"]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+
+        let requested = RequestedReviewers::compute(&codeowners, &changed_files);
+
+        let expected_reviewers = vec![
+            RequestedReviewer {
+                owner: "@dotanuki-labs/cli".to_string(),
+                owned_files: vec!["src/cli/args.rs".to_string()],
+            },
+            RequestedReviewer {
+                owner: "@dotanuki-labs/rustaceans".to_string(),
+                owned_files: vec!["src/main.rs".to_string()],
+            },
+            RequestedReviewer {
+                owner: "@ubiratansoares".to_string(),
+                owned_files: vec!["src/main.rs".to_string()],
+            },
+        ];
+
+        assertor::assert_that!(requested.reviewers).is_equal_to(expected_reviewers);
+        assertor::assert_that!(requested.unowned_files).is_equal_to(vec!["README.md".to_string()]);
+        assertor::assert_that!(requested.render(ReviewersFormat::Json)).contains(r#""unowned-files""#);
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use anyhow::bail;
+use std::path::{Path, PathBuf};
+
+static CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Extracts the Github organization from a remote URL, either over HTTPS or SSH
+pub fn github_organization(remote_url: &str) -> Option<String> {
+    let (_, repository_path) = remote_url
+        .split_once("github.com:")
+        .or_else(|| remote_url.split_once("github.com/"))?;
+
+    let (organization, _) = repository_path.split_once('/')?;
+
+    if organization.is_empty() {
+        return None;
+    }
+
+    Some(organization.to_string())
+}
+
+fn starter_config(organization: &str) -> String {
+    format!(
+        "\
+[general]
+github-organization = \"{organization}\"
+offline-checks-only = false
+
+[ownership]
+forbid-email-owners = true
+enforce-github-teams-owners = false
+enforce-one-owner-per-line = false
+"
+    )
+}
+
+fn starter_codeowners(organization: &str, team: Option<&str>) -> String {
+    let default_rule = match team {
+        Some(team) => format!("*    @{organization}/{}", team.trim_start_matches('@')),
+        None => format!("# *    @{organization}/<team>"),
+    };
+
+    format!(
+        "\
+# Ownership rules generated by canopus init
+# The last matching pattern takes precedence, see
+# https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/about-code-owners
+{default_rule}
+"
+    )
+}
+
+/// Writes a starter canopus.toml and, when the project has none, a starter CODEOWNERS
+pub fn scaffold(
+    project_root: &Path,
+    organization: &str,
+    team: Option<&str>,
+    overwrite: bool,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let github_dir = project_root.join(".github");
+    let config_path = github_dir.join("canopus.toml");
+
+    if config_path.exists() && !overwrite {
+        bail!("{} already exists, use --force to overwrite it", config_path.display());
+    }
+
+    std::fs::create_dir_all(&github_dir)?;
+    std::fs::write(&config_path, starter_config(organization))?;
+
+    let mut created = vec![config_path];
+
+    let has_codeowners = CODEOWNERS_LOCATIONS
+        .iter()
+        .any(|location| project_root.join(location).exists());
+
+    if !has_codeowners {
+        let codeowners_path = github_dir.join("CODEOWNERS");
+        std::fs::write(&codeowners_path, starter_codeowners(organization, team))?;
+        created.push(codeowners_path);
+    }
+
+    log::info!("Scaffolded canopus setup at : {}", project_root.to_string_lossy());
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::scaffolding;
+    use crate::core::models::config::CanopusConfig;
+    use assertor::{EqualityAssertion, OptionAssertion, ResultAssertion, StringAssertion};
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_detect_organization_from_remote_urls() {
+        let ssh = scaffolding::github_organization("git@github.com:dotanuki-labs/canopus.git");
+        let https = scaffolding::github_organization("https://github.com/dotanuki-labs/canopus");
+        let elsewhere = scaffolding::github_organization("https://gitlab.com/dotanuki-labs/canopus");
+
+        assertor::assert_that!(ssh).is_equal_to(Some("dotanuki-labs".to_string()));
+        assertor::assert_that!(https).is_equal_to(Some("dotanuki-labs".to_string()));
+        assertor::assert_that!(elsewhere).is_none();
+    }
+
+    #[test]
+    fn should_scaffold_config_and_codeowners() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        let created = scaffolding::scaffold(temp_dir.path(), "dotanuki-labs", Some("devs"), false).unwrap();
+
+        let codeowners = std::fs::read_to_string(temp_dir.path().join(".github/CODEOWNERS")).unwrap();
+        let config = CanopusConfig::try_from(temp_dir.path()).unwrap();
+
+        assertor::assert_that!(created.len()).is_equal_to(2);
+        assertor::assert_that!(codeowners).contains("*    @dotanuki-labs/devs");
+        assertor::assert_that!(config.general.github_organization).is_equal_to("dotanuki-labs".to_string());
+    }
+
+    #[test]
+    fn should_not_overwrite_existing_setup() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        std::fs::write(temp_dir.path().join("CODEOWNERS"), "* @ubiratansoares\n").unwrap();
+
+        let created = scaffolding::scaffold(temp_dir.path(), "dotanuki-labs", None, false).unwrap();
+        let scaffolded_again = scaffolding::scaffold(temp_dir.path(), "dotanuki-labs", None, false);
+
+        assertor::assert_that!(created).is_equal_to(vec![temp_dir.path().join(".github/canopus.toml")]);
+        assertor::assert_that!(scaffolded_again).is_err();
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+pub mod grpc;
+
+use crate::canopus::formats::{RunDetails, json};
+use crate::canopus::validation::CodeOwnersValidator;
+use crate::core::models::ValidationOutcome;
+use crate::core::models::codeowners::{CodeOwners, CodeOwnersContext};
+use crate::core::models::config::{CanopusConfig, ConfigLayer};
+use crate::core::models::deprecations::DeprecationPolicy;
+use crate::core::ownership::OwnershipResolver;
+use crate::infra::paths::{ListedPaths, PathWalker};
+use crate::infra::remote;
+use crate::infra::server::{ApiRequest, ApiResponse};
+use anyhow::{Context, bail};
+use hyper::{Method, StatusCode};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Where Github reads CODEOWNERS submitted to canopus serve from, since clients only send its contents
+pub static SUBMITTED_CODEOWNERS_LOCATION: &str = ".github/CODEOWNERS";
+
+/// Contents of a CODEOWNERS file alongside the project files it applies to, sent to POST /validate
+#[derive(Debug, Deserialize)]
+pub struct ValidationRequest {
+    pub codeowners: String,
+    pub files: Vec<PathBuf>,
+}
+
+/// Owners Github requests reviews from when a path changes, answered by GET /owners
+#[derive(Debug, PartialEq, Serialize)]
+pub struct PathOwners {
+    pub path: String,
+    pub owners: Vec<String>,
+    pub line: Option<usize>,
+}
+
+impl PathOwners {
+    pub fn resolve(code_owners: &CodeOwners, path: &str) -> Self {
+        // Clients may send paths anchored at the project root, as written in CODEOWNERS
+        let relative_path = path.trim_start_matches('/');
+        let rule = OwnershipResolver::new(code_owners).resolve(Path::new(relative_path));
+
+        Self {
+            path: relative_path.to_string(),
+            owners: rule
+                .map(|rule| rule.owners.iter().map(|owner| owner.to_string()).collect_vec())
+                .unwrap_or_default(),
+            line: rule.map(|rule| rule.line_number + 1),
+        }
+    }
+}
+
+/// Outcome of validating a submitted CODEOWNERS, alongside what it was validated with
+#[derive(Debug)]
+pub struct SubmittedValidation {
+    pub context: CodeOwnersContext,
+    pub outcome: anyhow::Result<ValidationOutcome>,
+    pub config_digest: String,
+}
+
+// Forgets files listed for a submission once dropped, including when clients disconnect mid-validation
+struct ListedSubmission<'a> {
+    listed_paths: &'a ListedPaths,
+    origin: &'a Path,
+}
+
+impl Drop for ListedSubmission<'_> {
+    fn drop(&mut self) {
+        self.listed_paths.forget(self.origin);
+    }
+}
+
+/// A project whose configuration answers validation and ownership queries, shared by every
+/// request canopus serve handles, whatever the protocol
+pub struct ServedProject {
+    project_root: PathBuf,
+    codeowners_validator: Arc<CodeOwnersValidator>,
+    github_client: octocrab::Octocrab,
+    deprecation_policy: DeprecationPolicy,
+    trust_extended_checks: bool,
+    submissions: AtomicUsize,
+}
+
+impl ServedProject {
+    pub fn new(
+        project_root: PathBuf,
+        codeowners_validator: Arc<CodeOwnersValidator>,
+        github_client: octocrab::Octocrab,
+        deprecation_policy: DeprecationPolicy,
+        trust_extended_checks: bool,
+    ) -> Self {
+        Self {
+            project_root,
+            codeowners_validator,
+            github_client,
+            deprecation_policy,
+            trust_extended_checks,
+            submissions: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
+    pub async fn config(&self) -> anyhow::Result<CanopusConfig> {
+        let config_layer = ConfigLayer::try_from(self.project_root.as_path())?;
+        let mut canopus_config =
+            remote::resolve_config(&self.github_client, config_layer, self.trust_extended_checks).await?;
+        self.deprecation_policy.enforce(&canopus_config.deprecated_keys)?;
+
+        // Submitted files come without any git history to compare coverage with
+        canopus_config.coverage.base_ref = None;
+        Ok(canopus_config)
+    }
+
+    /// Validates submitted CODEOWNERS contents against submitted files, with the configuration of the
+    /// served project. Owners verified against Github API stay cached for later requests
+    pub async fn validate(&self, request: ValidationRequest) -> anyhow::Result<SubmittedValidation> {
+        let canopus_config = self.config().await?;
+
+        let PathWalker::Listed(listed_paths) = self.codeowners_validator.path_walker() else {
+            bail!("cannot validate submitted files outside of canopus serve");
+        };
+
+        // Each submission gets its own pseudo project path, keeping concurrent requests apart
+        let submission = self.submissions.fetch_add(1, Ordering::Relaxed);
+        let origin = PathBuf::from(format!("submitted-{submission}"));
+        listed_paths.list(&origin, request.files);
+
+        let _listed = ListedSubmission {
+            listed_paths,
+            origin: &origin,
+        };
+
+        let context = CodeOwnersContext {
+            project_path: origin.clone(),
+            codeowners_path: PathBuf::from(SUBMITTED_CODEOWNERS_LOCATION),
+            contents: request.codeowners,
+        };
+
+        let outcome = self.codeowners_validator.validate(&context, &canopus_config).await;
+
+        Ok(SubmittedValidation {
+            context,
+            outcome,
+            config_digest: json::config_digest(&canopus_config),
+        })
+    }
+
+    // CODEOWNERS is read on every request, so that edits show up without restarting the server
+    pub fn owners_of(&self, path: &str) -> anyhow::Result<PathOwners> {
+        let codeowners_context = CodeOwnersContext::try_from(self.project_root.clone())?;
+        let code_owners = CodeOwners::try_from(codeowners_context.contents.as_str())?;
+        Ok(PathOwners::resolve(&code_owners, path))
+    }
+
+    /// Answers the HTTP API, rendering validations like `canopus validate --format json` does
+    pub async fn answer(&self, request: ApiRequest) -> ApiResponse {
+        let answered = match (&request.method, request.path.as_str()) {
+            (&Method::POST, "/validate") => self.answer_validation(&request.body).await,
+            (&Method::GET, "/owners") => match request.query.get("path") {
+                Some(path) => self
+                    .owners_of(path)
+                    .and_then(|path_owners| Ok(ApiResponse::ok(serde_json::to_string(&path_owners)?))),
+                None => return ApiResponse::error(StatusCode::BAD_REQUEST, "expecting a path query parameter"),
+            },
+            (method, path) => {
+                return ApiResponse::error(StatusCode::NOT_FOUND, format!("no route for {method} {path}"));
+            },
+        };
+
+        answered.unwrap_or_else(|error| ApiResponse::error(StatusCode::UNPROCESSABLE_ENTITY, format!("{error:#}")))
+    }
+
+    async fn answer_validation(&self, body: &[u8]) -> anyhow::Result<ApiResponse> {
+        let request: ValidationRequest = serde_json::from_slice(body).context("invalid validation request")?;
+        let validation = self.validate(request).await?;
+
+        let project_name = self.project_root.display().to_string();
+        let details = RunDetails {
+            config_digests: HashMap::from([(project_name.clone(), validation.config_digest)]),
+            ..Default::default()
+        };
+        let outcome = validation.outcome.map(|outcome| (validation.context, outcome));
+        let validations = vec![(project_name, outcome)];
+
+        Ok(ApiResponse::ok(json::render(&validations, &details)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::serving::PathOwners;
+    use crate::core::models::codeowners::CodeOwners;
+    use assertor::EqualityAssertion;
+    use indoc::indoc;
+
+    #[test]
+    fn should_resolve_owners_of_submitted_paths() {
+        let codeowners_rules = indoc! {"
+            *.rs        @dotanuki-labs/rustaceans
+            docs/*.md   @ubiratansoares @dotanuki-labs/writers
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+
+        let owned = PathOwners::resolve(&codeowners, "/docs/using.md");
+        let unowned = PathOwners::resolve(&codeowners, "Cargo.toml");
+
+        let expected_owned = PathOwners {
+            path: "docs/using.md".to_string(),
+            owners: vec!["@ubiratansoares".to_string(), "@dotanuki-labs/writers".to_string()],
+            line: Some(2),
+        };
+
+        let expected_unowned = PathOwners {
+            path: "Cargo.toml".to_string(),
+            owners: vec![],
+            line: None,
+        };
+
+        assertor::assert_that!(owned).is_equal_to(expected_owned);
+        assertor::assert_that!(unowned).is_equal_to(expected_unowned);
+    }
+}
@@ -0,0 +1,307 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::serving::{ServedProject, ValidationRequest};
+use crate::core::models::{ValidationIssue, ValidationOutcome};
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tonic::codegen::{Body, BoxFuture, Service, StdError, http};
+use tonic::server::{Grpc, NamedService, UnaryService};
+use tonic::{Request, Response, Status};
+use tonic_prost::ProstCodec;
+
+// Messages mirror proto/canopus/v1/ownership.proto by hand, since building canopus
+// should not require protoc. Field tags must stay in sync with the published definition
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ValidateRequest {
+    #[prost(string, tag = "1")]
+    pub codeowners: String,
+    #[prost(string, repeated, tag = "2")]
+    pub files: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Issue {
+    #[prost(string, tag = "1")]
+    pub code: String,
+    #[prost(string, tag = "2")]
+    pub check: String,
+    #[prost(string, tag = "3")]
+    pub severity: String,
+    #[prost(uint32, optional, tag = "4")]
+    pub line: Option<u32>,
+    #[prost(string, tag = "5")]
+    pub message: String,
+    #[prost(string, optional, tag = "6")]
+    pub fix: Option<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ValidateResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub issues: Vec<Issue>,
+    #[prost(string, tag = "2")]
+    pub config_digest: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OwnersRequest {
+    #[prost(string, tag = "1")]
+    pub path: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OwnersResponse {
+    #[prost(string, tag = "1")]
+    pub path: String,
+    #[prost(string, repeated, tag = "2")]
+    pub owners: Vec<String>,
+    #[prost(uint32, optional, tag = "3")]
+    pub line: Option<u32>,
+}
+
+impl From<&ValidationIssue> for Issue {
+    fn from(issue: &ValidationIssue) -> Self {
+        let definition = issue.kind.definition();
+
+        Self {
+            code: definition.code.to_string(),
+            check: definition.name.to_string(),
+            severity: issue.severity.to_string(),
+            line: (issue.line != usize::MAX).then(|| issue.line as u32 + 1),
+            message: console::strip_ansi_codes(&issue.context).to_string(),
+            fix: issue.fix.as_ref().map(|fix| fix.to_string()),
+        }
+    }
+}
+
+// Like the HTTP API answering 422, every failure comes from the served project or the submission
+fn failed_precondition(error: anyhow::Error) -> Status {
+    Status::failed_precondition(format!("{error:#}"))
+}
+
+/// Answers canopus.v1.Ownership, sharing the served project with the HTTP API
+#[derive(Clone)]
+pub struct OwnershipService {
+    served_project: Arc<ServedProject>,
+}
+
+impl OwnershipService {
+    pub fn new(served_project: Arc<ServedProject>) -> Self {
+        Self { served_project }
+    }
+
+    async fn validate(&self, request: ValidateRequest) -> Result<ValidateResponse, Status> {
+        let request = ValidationRequest {
+            codeowners: request.codeowners,
+            files: request.files.into_iter().map(PathBuf::from).collect(),
+        };
+
+        let validation = self
+            .served_project
+            .validate(request)
+            .await
+            .map_err(failed_precondition)?;
+
+        let issues = match validation.outcome.map_err(failed_precondition)? {
+            ValidationOutcome::NoIssues => vec![],
+            ValidationOutcome::IssuesDetected(issues) => issues.iter().map(Issue::from).collect(),
+        };
+
+        Ok(ValidateResponse {
+            issues,
+            config_digest: validation.config_digest,
+        })
+    }
+
+    async fn owners(&self, request: OwnersRequest) -> Result<OwnersResponse, Status> {
+        let path_owners = self
+            .served_project
+            .owners_of(&request.path)
+            .map_err(failed_precondition)?;
+
+        Ok(OwnersResponse {
+            path: path_owners.path,
+            owners: path_owners.owners,
+            line: path_owners.line.map(|line| line as u32),
+        })
+    }
+
+    fn unary<Message, Reply, Answered>(
+        &self,
+        request: http::Request<impl Body<Error: Into<StdError> + Send + 'static> + Send + 'static>,
+        answer: fn(Self, Message) -> Answered,
+    ) -> BoxFuture<http::Response<tonic::body::Body>, Infallible>
+    where
+        Message: prost::Message + Default + Send + 'static,
+        Reply: prost::Message + Send + 'static,
+        Answered: Future<Output = Result<Reply, Status>> + Send + 'static,
+    {
+        let rpc = Rpc {
+            service: self.clone(),
+            answer,
+        };
+
+        Box::pin(async move { Ok(Grpc::new(ProstCodec::default()).unary(rpc, request).await) })
+    }
+}
+
+/// One unary method of canopus.v1.Ownership, as tonic expects it
+struct Rpc<Message, Answered> {
+    service: OwnershipService,
+    answer: fn(OwnershipService, Message) -> Answered,
+}
+
+impl<Message, Reply, Answered> UnaryService<Message> for Rpc<Message, Answered>
+where
+    Answered: Future<Output = Result<Reply, Status>> + Send + 'static,
+{
+    type Response = Reply;
+    type Future = BoxFuture<Response<Reply>, Status>;
+
+    fn call(&mut self, request: Request<Message>) -> Self::Future {
+        let answered = (self.answer)(self.service.clone(), request.into_inner());
+        Box::pin(async move { answered.await.map(Response::new) })
+    }
+}
+
+impl<B> Service<http::Request<B>> for OwnershipService
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        match request.uri().path() {
+            "/canopus.v1.Ownership/Validate" => {
+                self.unary(
+                    request,
+                    |service, message| async move { service.validate(message).await },
+                )
+            },
+            "/canopus.v1.Ownership/Owners" => {
+                self.unary(request, |service, message| async move { service.owners(message).await })
+            },
+            _ => Box::pin(async move { Ok(Status::unimplemented("unknown method").into_http()) }),
+        }
+    }
+}
+
+impl NamedService for OwnershipService {
+    const NAME: &'static str = "canopus.v1.Ownership";
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::serving::ServedProject;
+    use crate::canopus::serving::grpc::{OwnersRequest, OwnersResponse, OwnershipService, ValidateRequest};
+    use crate::canopus::validation::CodeOwnersValidator;
+    use crate::core::models::deprecations::DeprecationPolicy;
+    use crate::infra::github::ConsistentGithubState;
+    use crate::infra::paths::{ListedPaths, PathWalker};
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use std::sync::Arc;
+    use temp_dir::TempDir;
+    use tonic::server::NamedService;
+
+    static OWNERSHIP_PROTO: &str = include_str!("../../../proto/canopus/v1/ownership.proto");
+
+    fn ownership_service(project_root: &TempDir) -> OwnershipService {
+        let github_dir = project_root.path().join(".github");
+        std::fs::create_dir_all(&github_dir).unwrap();
+
+        let canopus_config = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+            offline-checks-only = true
+
+            [ownership]
+            forbid-email-owners = true
+        "#};
+
+        let codeowners = indoc! {"
+            *.rs        @dotanuki-labs/rustaceans
+            docs/*.md   @ubiratansoares
+        "};
+
+        std::fs::write(github_dir.join("canopus.toml"), canopus_config).unwrap();
+        std::fs::write(github_dir.join("CODEOWNERS"), codeowners).unwrap();
+
+        let path_walker = PathWalker::Listed(ListedPaths::default());
+        let codeowners_validator = CodeOwnersValidator::new(Box::new(ConsistentGithubState), path_walker);
+
+        let served_project = ServedProject::new(
+            project_root.path().to_path_buf(),
+            Arc::new(codeowners_validator),
+            octocrab::Octocrab::default(),
+            DeprecationPolicy::Warn,
+            false,
+        );
+
+        OwnershipService::new(Arc::new(served_project))
+    }
+
+    #[tokio::test]
+    async fn should_validate_submitted_codeowners_over_grpc() {
+        let project_root = TempDir::new().unwrap();
+        let service = ownership_service(&project_root);
+
+        let request = ValidateRequest {
+            codeowners: "*.rs @dotanuki-labs/rustaceans\n*.py @dotanuki-labs/pythonistas\n".to_string(),
+            files: vec!["src/main.rs".to_string()],
+        };
+
+        let response = service.validate(request).await.unwrap();
+        let issues = response
+            .issues
+            .iter()
+            .map(|issue| (&issue.code, issue.line))
+            .collect::<Vec<_>>();
+
+        assertor::assert_that!(issues).is_equal_to(vec![(&"CNP002".to_string(), Some(2))]);
+        assertor::assert_that!(response.config_digest).starts_with("sha256:");
+    }
+
+    #[tokio::test]
+    async fn should_resolve_owners_over_grpc() {
+        let project_root = TempDir::new().unwrap();
+        let service = ownership_service(&project_root);
+
+        let request = OwnersRequest {
+            path: "/docs/using.md".to_string(),
+        };
+
+        let expected = OwnersResponse {
+            path: "docs/using.md".to_string(),
+            owners: vec!["@ubiratansoares".to_string()],
+            line: Some(2),
+        };
+
+        assertor::assert_that!(service.owners(request).await.unwrap()).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_answer_methods_declared_by_published_proto() {
+        let declared = [
+            format!("package {};", OwnershipService::NAME.trim_end_matches(".Ownership")),
+            "service Ownership {".to_string(),
+            "rpc Validate(ValidateRequest) returns (ValidateResponse);".to_string(),
+            "rpc Owners(OwnersRequest) returns (OwnersResponse);".to_string(),
+        ];
+
+        declared.iter().for_each(|declaration| {
+            assertor::assert_that!(OWNERSHIP_PROTO).contains(declaration.as_str());
+        });
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners::{CodeOwners, CodeOwnersContext};
+use crate::core::ownership::OwnershipResolver;
+use crate::infra::git;
+use anyhow::{Context, bail};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Layout version of snapshots, bumped whenever older snapshots can no longer be compared
+static SNAPSHOT_VERSION: u32 = 1;
+
+pub static DEFAULT_SNAPSHOT_FILE: &str = "canopus.lock";
+
+static SNAPSHOT_HEADER: &str = "# Ownership snapshot generated by `canopus snapshot`, do not edit by hand\n\n";
+
+static UNOWNED_FILES: &str = "(unowned)";
+
+/// Files resolved to the same owner, recorded by how many they are and a digest over their paths
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OwnedFiles {
+    pub files: usize,
+    pub digest: String,
+}
+
+/// Effective ownership of a project, normalized so that reordering rules without changing
+/// which files each owner resolves to produces the same snapshot
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct OwnershipSnapshot {
+    pub version: u32,
+    pub unowned: OwnedFiles,
+    pub owners: BTreeMap<String, OwnedFiles>,
+}
+
+/// An owner whose resolved files differ from the recorded ones, being absent on either side
+#[derive(Debug, PartialEq)]
+pub struct OwnershipDrift {
+    pub owner: String,
+    pub recorded_files: Option<usize>,
+    pub current_files: Option<usize>,
+}
+
+impl OwnershipDrift {
+    fn describe(&self) -> String {
+        match (self.recorded_files, self.current_files) {
+            (Some(recorded), Some(current)) => format!(
+                "{} : owned {} files when recorded, resolves to {} files with a different set now",
+                self.owner, recorded, current
+            ),
+            (Some(recorded), None) => format!("{} : owned {} files when recorded, none now", self.owner, recorded),
+            (None, Some(current)) => format!("{} : not recorded, owns {} files now", self.owner, current),
+            (None, None) => self.owner.clone(),
+        }
+    }
+}
+
+fn owned_files(paths: &[String]) -> OwnedFiles {
+    let mut hasher = Sha256::new();
+    paths.iter().for_each(|path| {
+        hasher.update(path.as_bytes());
+        hasher.update(b"\n");
+    });
+
+    OwnedFiles {
+        files: paths.len(),
+        digest: hex::encode(hasher.finalize()),
+    }
+}
+
+impl OwnershipSnapshot {
+    /// Resolves every file tracked by git against the current CODEOWNERS of a project
+    pub fn of_project(project_root: &Path) -> anyhow::Result<Self> {
+        let context = CodeOwnersContext::try_from(project_root.to_path_buf())?;
+        let code_owners = CodeOwners::try_from(context.contents.as_str())?;
+        let project_files = git::tracked_files(project_root)?.into_iter().collect_vec();
+        Ok(Self::compute(&code_owners, &project_files))
+    }
+
+    pub fn compute(code_owners: &CodeOwners, project_files: &[PathBuf]) -> Self {
+        let resolver = OwnershipResolver::new(code_owners);
+        let mut files_per_owner: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut unowned = vec![];
+
+        // Paths are normalized with forward slashes, hence snapshots compare across platforms
+        for file in project_files.iter().sorted() {
+            let path = file.to_string_lossy().replace('\\', "/");
+
+            let Some(rule) = resolver.resolve(file) else {
+                unowned.push(path);
+                continue;
+            };
+
+            rule.owners
+                .iter()
+                .map(|owner| owner.to_string())
+                .unique()
+                .for_each(|owner| files_per_owner.entry(owner).or_default().push(path.clone()));
+        }
+
+        Self {
+            version: SNAPSHOT_VERSION,
+            unowned: owned_files(&unowned),
+            owners: files_per_owner
+                .into_iter()
+                .map(|(owner, paths)| (owner, owned_files(&paths)))
+                .collect(),
+        }
+    }
+
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        let snapshot: Self = toml::from_str(contents).context("cannot parse ownership snapshot")?;
+
+        if snapshot.version != SNAPSHOT_VERSION {
+            bail!(
+                "ownership snapshot has version {}, while this canopus writes version {}; regenerate it with canopus snapshot",
+                snapshot.version,
+                SNAPSHOT_VERSION
+            );
+        }
+
+        Ok(snapshot)
+    }
+
+    pub fn render(&self) -> anyhow::Result<String> {
+        let contents = toml::to_string(self).context("cannot serialize ownership snapshot")?;
+        Ok(format!("{SNAPSHOT_HEADER}{contents}"))
+    }
+
+    fn entries(&self) -> BTreeMap<&str, &OwnedFiles> {
+        let mut entries = self
+            .owners
+            .iter()
+            .map(|(owner, owned)| (owner.as_str(), owned))
+            .collect::<BTreeMap<_, _>>();
+
+        entries.insert(UNOWNED_FILES, &self.unowned);
+        entries
+    }
+
+    /// Compares this snapshot against a recorded one, listing owners whose resolved files changed
+    pub fn drift_from(&self, recorded: &Self) -> Vec<OwnershipDrift> {
+        let current = self.entries();
+        let recorded = recorded.entries();
+
+        current
+            .keys()
+            .chain(recorded.keys())
+            .unique()
+            .sorted()
+            .filter_map(|owner| {
+                let current_files = current.get(owner);
+                let recorded_files = recorded.get(owner);
+
+                if current_files == recorded_files {
+                    return None;
+                }
+
+                Some(OwnershipDrift {
+                    owner: owner.to_string(),
+                    recorded_files: recorded_files.map(|owned| owned.files),
+                    current_files: current_files.map(|owned| owned.files),
+                })
+            })
+            .collect_vec()
+    }
+}
+
+pub fn render_drift(drift: &[OwnershipDrift]) -> String {
+    drift.iter().map(OwnershipDrift::describe).join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::snapshot::{OwnershipDrift, OwnershipSnapshot};
+    use crate::core::models::codeowners::CodeOwners;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use std::path::PathBuf;
+
+    fn project_files() -> Vec<PathBuf> {
+        ["src/main.rs", "src/cli/args.rs", "docs/using.md", "README.md"]
+            .iter()
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    #[test]
+    fn should_roundtrip_snapshot_regardless_of_rules_order() {
+        let code_owners = CodeOwners::try_from(indoc! {"
+            *.rs        @dotanuki-labs/rustaceans
+            docs/*      @ubiratansoares
+        "})
+        .unwrap();
+
+        let reordered = CodeOwners::try_from(indoc! {"
+            docs/*      @ubiratansoares
+            *.rs        @dotanuki-labs/rustaceans
+        "})
+        .unwrap();
+
+        let snapshot = OwnershipSnapshot::compute(&code_owners, &project_files());
+        let rendered = snapshot.render().unwrap();
+        let recorded = OwnershipSnapshot::parse(&rendered).unwrap();
+
+        assertor::assert_that!(rendered).contains("[owners.\"@dotanuki-labs/rustaceans\"]");
+        assertor::assert_that!(recorded.owners["@dotanuki-labs/rustaceans"].files).is_equal_to(2);
+        assertor::assert_that!(recorded.unowned.files).is_equal_to(1);
+        assertor::assert_that!(OwnershipSnapshot::compute(&reordered, &project_files()).drift_from(&recorded))
+            .is_equal_to(vec![]);
+    }
+
+    #[test]
+    fn should_detect_ownership_drift() {
+        let before = CodeOwners::try_from(indoc! {"
+            *.rs        @dotanuki-labs/rustaceans
+            docs/*      @ubiratansoares
+        "})
+        .unwrap();
+
+        let after = CodeOwners::try_from(indoc! {"
+            *.rs        @dotanuki-labs/rustaceans
+            src/cli/*   @dotanuki-labs/cli
+        "})
+        .unwrap();
+
+        let recorded = OwnershipSnapshot::compute(&before, &project_files());
+        let current = OwnershipSnapshot::compute(&after, &project_files());
+
+        let expected = vec![
+            OwnershipDrift {
+                owner: "(unowned)".to_string(),
+                recorded_files: Some(1),
+                current_files: Some(2),
+            },
+            OwnershipDrift {
+                owner: "@dotanuki-labs/cli".to_string(),
+                recorded_files: None,
+                current_files: Some(1),
+            },
+            OwnershipDrift {
+                owner: "@dotanuki-labs/rustaceans".to_string(),
+                recorded_files: Some(2),
+                current_files: Some(1),
+            },
+            OwnershipDrift {
+                owner: "@ubiratansoares".to_string(),
+                recorded_files: Some(1),
+                current_files: None,
+            },
+        ];
+
+        assertor::assert_that!(current.drift_from(&recorded)).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_reject_snapshots_from_other_versions() {
+        let contents = indoc! {r#"
+            version = 0
+
+            [unowned]
+            files = 0
+            digest = ""
+
+            [owners]
+        "#};
+
+        let parsed = OwnershipSnapshot::parse(contents);
+
+        assertor::assert_that!(parsed.unwrap_err().to_string()).contains("has version 0");
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::events::{
+    CheckSkippedEvent, ParseCompleteEvent, PhaseEndEvent, ValidationObserver, ValidationPhase, WalkCompleteEvent,
+};
+use crate::core::models::ValidationIssue;
+use crate::core::models::versions::Version;
+use anyhow::Context;
+use console::style;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Figures describing how much work validation performed, aggregated across projects
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationStatistics {
+    pub files_scanned: usize,
+    pub rules_parsed: usize,
+    pub unique_owners: usize,
+    pub issues_per_check: Vec<(&'static str, usize)>,
+    pub skipped_checks: Vec<(&'static str, &'static str)>,
+    pub elapsed_per_phase: Vec<(ValidationPhase, Duration)>,
+}
+
+impl ValidationStatistics {
+    pub fn print(&self) {
+        let total_issues = self.issues_per_check.iter().map(|(_, issues)| issues).sum::<usize>();
+
+        println!("{}", style("Summary").bold());
+        println!();
+        println!("  Files scanned : {}", self.files_scanned);
+        println!("  Rules parsed  : {}", self.rules_parsed);
+        println!("  Unique owners : {}", self.unique_owners);
+        println!("  Issues found  : {}", total_issues);
+
+        self.issues_per_check.iter().for_each(|(check, issues)| {
+            println!("    → {check} : {issues}");
+        });
+
+        if !self.skipped_checks.is_empty() {
+            println!("  Skipped checks : {}", self.skipped_checks.len());
+
+            self.skipped_checks.iter().for_each(|(check, reason)| {
+                println!("    → {check} : {}", style(reason).yellow());
+            });
+        }
+
+        println!("  Elapsed time per check");
+
+        self.elapsed_per_phase.iter().for_each(|(phase, elapsed)| {
+            println!("    → {phase} : {}ms", elapsed.as_millis());
+        });
+
+        println!();
+    }
+
+    pub fn metrics(&self, projects: usize, elapsed: Duration) -> UsageMetrics {
+        UsageMetrics {
+            canopus_version: Version::current().to_string(),
+            projects,
+            files_scanned: self.files_scanned,
+            rules_parsed: self.rules_parsed,
+            unique_owners: self.unique_owners,
+            total_issues: self.issues_per_check.iter().map(|(_, issues)| issues).sum(),
+            issues_per_check: self.issues_per_check.iter().copied().collect(),
+            skipped_checks: self.skipped_checks.iter().map(|(check, _)| *check).collect(),
+            elapsed_millis: elapsed.as_millis(),
+            elapsed_millis_per_phase: self
+                .elapsed_per_phase
+                .iter()
+                .map(|(phase, elapsed)| (phase.to_string(), elapsed.as_millis()))
+                .collect(),
+        }
+    }
+}
+
+/// Counts describing a single run, written to a local file only. CI jobs keep it as an
+/// artifact, letting platform teams aggregate hygiene trends across projects
+#[derive(Debug, Serialize)]
+pub struct UsageMetrics {
+    #[serde(rename = "canopus-version")]
+    pub canopus_version: String,
+    pub projects: usize,
+    #[serde(rename = "files-scanned")]
+    pub files_scanned: usize,
+    #[serde(rename = "rules-parsed")]
+    pub rules_parsed: usize,
+    #[serde(rename = "unique-owners")]
+    pub unique_owners: usize,
+    #[serde(rename = "total-issues")]
+    pub total_issues: usize,
+    #[serde(rename = "issues-per-check")]
+    pub issues_per_check: BTreeMap<&'static str, usize>,
+    #[serde(rename = "skipped-checks")]
+    pub skipped_checks: Vec<&'static str>,
+    #[serde(rename = "elapsed-millis")]
+    pub elapsed_millis: u128,
+    #[serde(rename = "elapsed-millis-per-phase")]
+    pub elapsed_millis_per_phase: BTreeMap<String, u128>,
+}
+
+impl UsageMetrics {
+    pub fn write(&self, metrics_file: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+
+        std::fs::write(metrics_file, contents + "\n")
+            .with_context(|| format!("cannot write metrics to {}", metrics_file.display()))
+    }
+}
+
+/// Collects statistics from validation lifecycle events
+#[derive(Clone, Default)]
+pub struct StatisticsCollector {
+    statistics: Arc<Mutex<ValidationStatistics>>,
+}
+
+impl StatisticsCollector {
+    pub fn collected(&self) -> ValidationStatistics {
+        self.statistics.lock().unwrap().clone()
+    }
+}
+
+impl ValidationObserver for StatisticsCollector {
+    fn on_parse_complete(&self, event: &ParseCompleteEvent) {
+        let mut statistics = self.statistics.lock().unwrap();
+        statistics.rules_parsed += event.total_rules;
+        statistics.unique_owners += event.unique_owners;
+    }
+
+    fn on_walk_complete(&self, event: &WalkCompleteEvent) {
+        self.statistics.lock().unwrap().files_scanned += event.total_paths;
+    }
+
+    fn on_issue(&self, _phase: ValidationPhase, issue: &ValidationIssue) {
+        let check = issue.kind.definition().name;
+        let mut statistics = self.statistics.lock().unwrap();
+
+        match statistics.issues_per_check.iter_mut().find(|(name, _)| *name == check) {
+            Some((_, issues)) => *issues += 1,
+            None => statistics.issues_per_check.push((check, 1)),
+        }
+    }
+
+    // Several projects validated anonymously skip the same checks, listed once
+    fn on_check_skipped(&self, event: &CheckSkippedEvent) {
+        let mut statistics = self.statistics.lock().unwrap();
+
+        if !statistics.skipped_checks.iter().any(|(check, _)| *check == event.check) {
+            statistics.skipped_checks.push((event.check, event.reason));
+        }
+    }
+
+    fn on_phase_end(&self, event: &PhaseEndEvent) {
+        let mut statistics = self.statistics.lock().unwrap();
+
+        match statistics
+            .elapsed_per_phase
+            .iter_mut()
+            .find(|(phase, _)| *phase == event.phase)
+        {
+            Some((_, elapsed)) => *elapsed += event.elapsed,
+            None => statistics.elapsed_per_phase.push((event.phase, event.elapsed)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::events::{
+        CheckSkippedEvent, ParseCompleteEvent, PhaseEndEvent, ValidationObserver, ValidationPhase, WalkCompleteEvent,
+    };
+    use crate::canopus::statistics::StatisticsCollector;
+    use crate::core::models::ValidationIssue;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn should_aggregate_statistics_across_validations() {
+        let collector = StatisticsCollector::default();
+
+        let issue = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+            .line_number(0)
+            .description("docs/*.md does not match any project path")
+            .build();
+
+        for _ in 0..2 {
+            collector.on_parse_complete(&ParseCompleteEvent {
+                total_lines: 3,
+                total_rules: 2,
+                unique_owners: 1,
+            });
+
+            collector.on_walk_complete(&WalkCompleteEvent {
+                origin: PathBuf::from("canopus"),
+                total_paths: 10,
+            });
+            collector.on_issue(ValidationPhase::DanglingGlobs, &issue);
+
+            collector.on_phase_end(&PhaseEndEvent {
+                phase: ValidationPhase::DanglingGlobs,
+                issues_found: 1,
+                elapsed: Duration::from_millis(5),
+            });
+        }
+
+        let statistics = collector.collected();
+
+        assertor::assert_that!(statistics.files_scanned).is_equal_to(20);
+        assertor::assert_that!(statistics.rules_parsed).is_equal_to(4);
+        assertor::assert_that!(statistics.issues_per_check).is_equal_to(vec![("dangling-glob", 2)]);
+        assertor::assert_that!(statistics.elapsed_per_phase)
+            .is_equal_to(vec![(ValidationPhase::DanglingGlobs, Duration::from_millis(10))]);
+    }
+
+    #[test]
+    fn should_export_usage_metrics() {
+        let collector = StatisticsCollector::default();
+
+        let issue = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+            .line_number(0)
+            .description("docs/*.md does not match any project path")
+            .build();
+
+        collector.on_parse_complete(&ParseCompleteEvent {
+            total_lines: 3,
+            total_rules: 2,
+            unique_owners: 1,
+        });
+        collector.on_issue(ValidationPhase::DanglingGlobs, &issue);
+
+        collector.on_phase_end(&PhaseEndEvent {
+            phase: ValidationPhase::DanglingGlobs,
+            issues_found: 1,
+            elapsed: Duration::from_millis(5),
+        });
+
+        for _ in 0..2 {
+            collector.on_check_skipped(&CheckSkippedEvent {
+                check: "outsider-user",
+                reason: "no token was provided through GITHUB_TOKEN",
+            });
+        }
+
+        let metrics = collector.collected().metrics(1, Duration::from_millis(42));
+        let exported = serde_json::to_string_pretty(&metrics).unwrap();
+
+        assertor::assert_that!(metrics.total_issues).is_equal_to(1);
+        assertor::assert_that!(exported).contains(r#""rules-parsed": 2"#);
+        assertor::assert_that!(exported).contains(r#""dangling-glob": 1"#);
+        assertor::assert_that!(exported).contains(r#""elapsed-millis": 42"#);
+        assertor::assert_that!(exported).contains(r#""dangling-globs": 5"#);
+        assertor::assert_that!(metrics.skipped_checks).is_equal_to(vec!["outsider-user"]);
+    }
+}
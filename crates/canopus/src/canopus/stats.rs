@@ -0,0 +1,328 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+pub mod export;
+
+use crate::core::models::codeowners::CodeOwners;
+use crate::core::ownership::OwnershipResolver;
+use itertools::Itertools;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum StatsFormat {
+    Table,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OwnerStats {
+    pub owner: String,
+    pub rules: usize,
+    #[serde(rename = "matched-files")]
+    pub matched_files: usize,
+    #[serde(rename = "owned-percentage")]
+    pub owned_percentage: f64,
+}
+
+/// Files owned at the same time by two owners, sharing the same ownership rule
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OwnersOverlap {
+    pub owners: (String, String),
+    #[serde(rename = "shared-files")]
+    pub shared_files: usize,
+}
+
+/// Files owned through the rules of a CODEOWNERS section, like `# === Backend ===`
+#[derive(Debug, PartialEq, Serialize)]
+pub struct SectionStats {
+    pub section: String,
+    pub rules: usize,
+    #[serde(rename = "matched-files")]
+    pub matched_files: usize,
+    #[serde(rename = "owned-percentage")]
+    pub owned_percentage: f64,
+}
+
+/// Per-owner metrics over the files of a project
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OwnershipStats {
+    pub project: String,
+    #[serde(rename = "total-files")]
+    pub total_files: usize,
+    #[serde(rename = "owned-files")]
+    pub owned_files: usize,
+    pub owners: Vec<OwnerStats>,
+    pub overlaps: Vec<OwnersOverlap>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<SectionStats>,
+}
+
+impl OwnershipStats {
+    pub fn compute(project: &str, code_owners: &CodeOwners, project_files: &[PathBuf]) -> Self {
+        let resolver = OwnershipResolver::new(code_owners);
+        let mut files_per_owner: HashMap<String, usize> = HashMap::new();
+        let mut files_per_pair: HashMap<(String, String), usize> = HashMap::new();
+        let mut files_per_section: HashMap<&str, usize> = HashMap::new();
+        let section_per_line = code_owners.section_per_line();
+        let mut owned_files = 0;
+
+        for file in project_files {
+            let Some(rule) = resolver.resolve(file) else {
+                continue;
+            };
+
+            owned_files += 1;
+
+            if let Some(section) = section_per_line.get(&rule.line_number) {
+                *files_per_section.entry(section).or_default() += 1;
+            }
+
+            let owners = rule
+                .owners
+                .iter()
+                .map(|owner| owner.to_string())
+                .unique()
+                .sorted()
+                .collect_vec();
+
+            owners.iter().for_each(|owner| {
+                *files_per_owner.entry(owner.clone()).or_default() += 1;
+            });
+
+            owners.iter().array_combinations().for_each(|[first, second]| {
+                *files_per_pair.entry((first.clone(), second.clone())).or_default() += 1;
+            });
+        }
+
+        let total_files = project_files.len();
+
+        let owners = code_owners
+            .unique_owners()
+            .into_iter()
+            .map(|owner| {
+                let matched_files = files_per_owner.get(&owner.to_string()).copied().unwrap_or_default();
+
+                OwnerStats {
+                    owner: owner.to_string(),
+                    rules: code_owners.occurrences(owner).len(),
+                    matched_files,
+                    owned_percentage: percentage(matched_files, total_files),
+                }
+            })
+            .sorted_by(|first, second| {
+                second
+                    .matched_files
+                    .cmp(&first.matched_files)
+                    .then(first.owner.cmp(&second.owner))
+            })
+            .collect_vec();
+
+        let overlaps = files_per_pair
+            .into_iter()
+            .map(|(owners, shared_files)| OwnersOverlap { owners, shared_files })
+            .sorted_by(|first, second| {
+                second
+                    .shared_files
+                    .cmp(&first.shared_files)
+                    .then(first.owners.cmp(&second.owners))
+            })
+            .collect_vec();
+
+        // Sections keep the order they follow in CODEOWNERS
+        let sections = code_owners
+            .sections()
+            .into_iter()
+            .filter_map(|section| {
+                let name = section.name?;
+                let matched_files = files_per_section.get(name).copied().unwrap_or_default();
+
+                Some(SectionStats {
+                    section: name.to_string(),
+                    rules: section.rules.len(),
+                    matched_files,
+                    owned_percentage: percentage(matched_files, total_files),
+                })
+            })
+            .collect_vec();
+
+        Self {
+            project: project.to_string(),
+            total_files,
+            owned_files,
+            owners,
+            overlaps,
+            sections,
+        }
+    }
+
+    pub fn render(&self, format: StatsFormat) -> String {
+        match format {
+            StatsFormat::Table => self.to_table(),
+            StatsFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    fn to_table(&self) -> String {
+        let owner_width = self
+            .owners
+            .iter()
+            .map(|stats| stats.owner.len())
+            .chain(["Owner".len()])
+            .max()
+            .unwrap_or_default();
+
+        let mut table = vec![
+            format!(
+                "Owned files : {} of {} ({:.2}%)",
+                self.owned_files,
+                self.total_files,
+                percentage(self.owned_files, self.total_files)
+            ),
+            String::new(),
+            format!(
+                "{:<owner_width$}  {:>5}  {:>13}  {:>7}",
+                "Owner", "Rules", "Matched files", "Owned"
+            ),
+        ];
+
+        table.extend(self.owners.iter().map(|stats| {
+            format!(
+                "{:<owner_width$}  {:>5}  {:>13}  {:>6.2}%",
+                stats.owner, stats.rules, stats.matched_files, stats.owned_percentage
+            )
+        }));
+
+        if !self.overlaps.is_empty() {
+            table.extend([String::new(), "Overlapping owners".to_string(), String::new()]);
+
+            table.extend(self.overlaps.iter().map(|overlap| {
+                let (first, second) = &overlap.owners;
+                format!("{first} + {second} : {} shared files", overlap.shared_files)
+            }));
+        }
+
+        if !self.sections.is_empty() {
+            let section_width = self
+                .sections
+                .iter()
+                .map(|stats| stats.section.len())
+                .chain(["Section".len()])
+                .max()
+                .unwrap_or_default();
+
+            table.extend([
+                String::new(),
+                format!(
+                    "{:<section_width$}  {:>5}  {:>13}  {:>7}",
+                    "Section", "Rules", "Matched files", "Owned"
+                ),
+            ]);
+
+            table.extend(self.sections.iter().map(|stats| {
+                format!(
+                    "{:<section_width$}  {:>5}  {:>13}  {:>6.2}%",
+                    stats.section, stats.rules, stats.matched_files, stats.owned_percentage
+                )
+            }));
+        }
+
+        table.push(String::new());
+        table.join("\n")
+    }
+}
+
+fn percentage(files: usize, total_files: usize) -> f64 {
+    if total_files == 0 {
+        return 0.0;
+    }
+
+    (files as f64 / total_files as f64) * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::stats::{OwnersOverlap, OwnershipStats, StatsFormat};
+    use crate::core::models::codeowners::CodeOwners;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_compute_owner_metrics_and_overlaps() {
+        let codeowners_rules = indoc! {"
+            *.rs        @dotanuki-labs/rustaceans @ubiratansoares
+            docs/*.md   @ubiratansoares
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+
+        let project_files = ["src/main.rs", "src/lib.rs", "docs/using.md", "Cargo.toml"]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+
+        let stats = OwnershipStats::compute("canopus", &codeowners, &project_files);
+
+        let owners = stats
+            .owners
+            .iter()
+            .map(|owner| {
+                (
+                    owner.owner.as_str(),
+                    owner.rules,
+                    owner.matched_files,
+                    owner.owned_percentage,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let expected_owners = vec![
+            ("@ubiratansoares", 2, 3, 75.0),
+            ("@dotanuki-labs/rustaceans", 1, 2, 50.0),
+        ];
+
+        let expected_overlaps = vec![OwnersOverlap {
+            owners: ("@dotanuki-labs/rustaceans".to_string(), "@ubiratansoares".to_string()),
+            shared_files: 2,
+        }];
+
+        assertor::assert_that!(stats.owned_files).is_equal_to(3);
+        assertor::assert_that!(owners).is_equal_to(expected_owners);
+        assertor::assert_that!(stats.overlaps).is_equal_to(expected_overlaps);
+        assertor::assert_that!(stats.render(StatsFormat::Json)).contains(r#""matched-files": 3"#);
+    }
+
+    #[test]
+    fn should_compute_section_metrics() {
+        let codeowners_rules = indoc! {"
+            *           @dotanuki-labs/maintainers
+
+            # === Sources ===
+            *.rs        @dotanuki-labs/rustaceans
+            build.rs    @dotanuki-labs/rustaceans
+
+            # === Docs ===
+            docs/*.md   @ubiratansoares
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+
+        let project_files = ["src/main.rs", "src/lib.rs", "docs/using.md", "Cargo.toml"]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+
+        let stats = OwnershipStats::compute("canopus", &codeowners, &project_files);
+
+        let sections = stats
+            .sections
+            .iter()
+            .map(|section| (section.section.as_str(), section.rules, section.matched_files))
+            .collect::<Vec<_>>();
+
+        assertor::assert_that!(sections).is_equal_to(vec![("Sources", 2, 2), ("Docs", 1, 1)]);
+        assertor::assert_that!(stats.render(StatsFormat::Table)).contains("Sources      2              2   50.00%");
+    }
+}
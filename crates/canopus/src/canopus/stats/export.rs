@@ -0,0 +1,212 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners::{CodeOwners, CodeOwnersEntry};
+use crate::core::ownership::OwnershipResolver;
+use itertools::Itertools;
+use parquet::data_type::{ByteArray, ByteArrayType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+use serde::Serialize;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+// Column names follow SQL conventions, since exports end up in data warehouses
+static PARQUET_SCHEMA: &str = "
+    message ownership {
+        REQUIRED INT64 line;
+        REQUIRED BYTE_ARRAY pattern (UTF8);
+        REQUIRED BYTE_ARRAY owner (UTF8);
+        OPTIONAL BYTE_ARRAY section (UTF8);
+        REQUIRED INT64 matched_files;
+    }
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+    Parquet,
+}
+
+impl ExportFormat {
+    /// Whether exported contents are binary, hence not meant to be printed on a terminal
+    pub fn is_binary(&self) -> bool {
+        matches!(self, ExportFormat::Parquet)
+    }
+}
+
+/// One owner of one rule, alongside how many project files that rule effectively owns
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OwnershipRow {
+    pub line: usize,
+    pub pattern: String,
+    pub owner: String,
+    pub section: Option<String>,
+    pub matched_files: usize,
+}
+
+/// Flattens ownership rules into rows, following the order of CODEOWNERS
+pub fn ownership_rows(code_owners: &CodeOwners, project_files: &[PathBuf]) -> Vec<OwnershipRow> {
+    let resolver = OwnershipResolver::new(code_owners);
+    let section_per_line = code_owners.section_per_line();
+
+    let files_per_rule = project_files
+        .iter()
+        .filter_map(|file| resolver.resolve(file))
+        .map(|rule| rule.line_number)
+        .counts();
+
+    code_owners
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            CodeOwnersEntry::Rule(rule) => Some(rule),
+            _ => None,
+        })
+        .flat_map(|rule| {
+            let matched_files = files_per_rule.get(&rule.line_number).copied().unwrap_or_default();
+            let section = section_per_line.get(&rule.line_number).map(|name| name.to_string());
+
+            rule.owners.iter().unique().map(move |owner| OwnershipRow {
+                line: rule.line_number + 1,
+                pattern: rule.glob.glob().to_string(),
+                owner: owner.to_string(),
+                section: section.clone(),
+                matched_files,
+            })
+        })
+        .collect_vec()
+}
+
+pub fn export(rows: &[OwnershipRow], format: ExportFormat, output: impl Write + Send) -> anyhow::Result<()> {
+    match format {
+        ExportFormat::Csv => export_csv(rows, output),
+        ExportFormat::Parquet => export_parquet(rows, output),
+    }
+}
+
+fn export_csv(rows: &[OwnershipRow], output: impl Write) -> anyhow::Result<()> {
+    let mut writer = csv::Writer::from_writer(output);
+
+    for row in rows {
+        writer.serialize(row)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+fn export_parquet(rows: &[OwnershipRow], output: impl Write + Send) -> anyhow::Result<()> {
+    let schema = Arc::new(parse_message_type(PARQUET_SCHEMA)?);
+    let properties = Arc::new(WriterProperties::builder().build());
+    let mut writer = SerializedFileWriter::new(output, schema, properties)?;
+
+    let text_column = |values: Vec<&str>| values.into_iter().map(ByteArray::from).collect_vec();
+    let lines = rows.iter().map(|row| row.line as i64).collect_vec();
+    let patterns = text_column(rows.iter().map(|row| row.pattern.as_str()).collect());
+    let owners = text_column(rows.iter().map(|row| row.owner.as_str()).collect());
+    let sections = text_column(rows.iter().filter_map(|row| row.section.as_deref()).collect());
+    let section_levels = rows.iter().map(|row| i16::from(row.section.is_some())).collect_vec();
+    let matched_files = rows.iter().map(|row| row.matched_files as i64).collect_vec();
+
+    let mut row_group = writer.next_row_group()?;
+    let mut column_index = 0;
+
+    while let Some(mut column) = row_group.next_column()? {
+        match column_index {
+            0 => column.typed::<Int64Type>().write_batch(&lines, None, None)?,
+            1 => column.typed::<ByteArrayType>().write_batch(&patterns, None, None)?,
+            2 => column.typed::<ByteArrayType>().write_batch(&owners, None, None)?,
+            3 => column
+                .typed::<ByteArrayType>()
+                .write_batch(&sections, Some(&section_levels), None)?,
+            _ => column.typed::<Int64Type>().write_batch(&matched_files, None, None)?,
+        };
+
+        column.close()?;
+        column_index += 1;
+    }
+
+    row_group.close()?;
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::stats::export;
+    use crate::canopus::stats::export::{ExportFormat, OwnershipRow};
+    use crate::core::models::codeowners::CodeOwners;
+    use assertor::EqualityAssertion;
+    use indoc::indoc;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use std::fs::File;
+    use std::path::PathBuf;
+    use temp_dir::TempDir;
+
+    fn create_rows() -> Vec<OwnershipRow> {
+        let codeowners_rules = indoc! {"
+            *.rs        @dotanuki-labs/rustaceans @ubiratansoares
+
+            # === Docs ===
+            docs/*.md   @ubiratansoares
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+
+        let project_files = ["src/main.rs", "src/lib.rs", "docs/using.md", "Cargo.toml"]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+
+        export::ownership_rows(&codeowners, &project_files)
+    }
+
+    #[test]
+    fn should_export_one_csv_row_per_rule_and_owner() {
+        let mut exported = Vec::new();
+        export::export(&create_rows(), ExportFormat::Csv, &mut exported).unwrap();
+
+        let expected = indoc! {"
+            line,pattern,owner,section,matched_files
+            1,*.rs,@dotanuki-labs/rustaceans,,2
+            1,*.rs,@ubiratansoares,,2
+            4,docs/*.md,@ubiratansoares,Docs,1
+        "};
+
+        assertor::assert_that!(String::from_utf8(exported).unwrap()).is_equal_to(expected.to_string());
+    }
+
+    #[test]
+    fn should_export_parquet_rows() {
+        let temp_dir = TempDir::new().unwrap();
+        let parquet_file = temp_dir.path().join("ownership.parquet");
+
+        export::export(
+            &create_rows(),
+            ExportFormat::Parquet,
+            File::create(&parquet_file).unwrap(),
+        )
+        .unwrap();
+
+        let reader = SerializedFileReader::new(File::open(&parquet_file).unwrap()).unwrap();
+        let metadata = reader.metadata();
+
+        let columns = metadata
+            .file_metadata()
+            .schema_descr()
+            .columns()
+            .iter()
+            .map(|column| column.name().to_string())
+            .collect::<Vec<_>>();
+
+        assertor::assert_that!(metadata.file_metadata().num_rows()).is_equal_to(3);
+        assertor::assert_that!(columns).is_equal_to(
+            ["line", "pattern", "owner", "section", "matched_files"]
+                .map(String::from)
+                .to_vec(),
+        );
+    }
+}
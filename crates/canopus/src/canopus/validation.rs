@@ -1,30 +1,176 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::codeowners::{CodeOwners, CodeOwnersContext, CodeOwnersEntry};
+use crate::canopus::events::{
+    CheckSkippedEvent, ConsistencyStartEvent, OwnerCheckEvent, ParseCompleteEvent, PhaseEndEvent, ValidationObserver,
+    ValidationPhase, WalkCompleteEvent, WalkStartEvent,
+};
+use crate::core::models::codeowners::{CODEOWNERS_LOCATIONS, CodeOwners, CodeOwnersContext, CodeOwnersEntry};
 use crate::core::models::config::{
-    CanopusConfig, DEFAULT_VALUE_ENFORCE_GITHUB_TEAMS_OWNERS, DEFAULT_VALUE_ENFORCE_ONE_OWNER_PER_LINE,
-    DEFAULT_VALUE_FORBID_EMAIL_ADDRESSES, DEFAULT_VALUE_OFFLINE_CHECKS_ONLY,
+    CanopusConfig, DEFAULT_VALUE_ALLOW_OUTSIDE_COLLABORATORS, DEFAULT_VALUE_GITHUB_TIMEOUT_SECONDS,
+    DEFAULT_VALUE_MAX_CONCURRENT_REQUESTS, DEFAULT_VALUE_OFFLINE_CHECKS_ONLY, DEFAULT_VALUE_PROVIDER,
+    DEFAULT_VALUE_REPORT_EVERY_OCCURRENCE, DEFAULT_VALUE_REQUIRE_REVIEW_ASSIGNMENT,
+    DEFAULT_VALUE_REQUIRE_TEAM_MEMBERSHIP, ExternalCheckConfig, OwnersProvider, OwnershipConfig, PathsConfig,
+};
+use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle, Owner};
+use crate::core::models::{
+    ConfigurationIssue, ConsistencyIssue, IssueKind, OwnerRole, StructuralIssue, ValidationIssue, ValidationOutcome,
 };
-use crate::core::models::handles::Owner;
-use crate::core::models::{ConfigurationIssue, IssueKind, StructuralIssue, ValidationIssue, ValidationOutcome};
-use crate::infra::github::{CheckGithubConsistency, GithubConsistencyChecker};
+use crate::core::offline;
+use crate::core::overrides::LineOverrides;
+use crate::core::ownership::OwnershipCoverage;
+use crate::core::paths::PathArena;
+use crate::core::policies::PolicyEngine;
+use crate::infra::directory::{DirectoryChecker, ScimDirectory};
+use crate::infra::external;
+use crate::infra::git;
+use crate::infra::github::{ApiCapability, CheckGithubConsistency};
+use crate::infra::paths;
 use crate::infra::paths::{DirWalking, PathWalker};
+use crate::infra::remote::RemoteRepository;
+use crate::infra::retries::RetryPolicy;
+use crate::infra::roster::{Roster, RosterChecker};
+use anyhow::bail;
+use futures::FutureExt;
+use futures::future::{BoxFuture, Shared};
 use itertools::Itertools;
-use std::collections::HashSet;
-use std::path::PathBuf;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+
+/// Tracks issues found while validating, hence remaining phases are skipped once reaching max issues
+struct IssueBudget {
+    max_issues: Option<usize>,
+    issues_found: Cell<usize>,
+}
+
+impl IssueBudget {
+    fn new(max_issues: Option<usize>) -> Self {
+        Self {
+            max_issues,
+            issues_found: Cell::new(0),
+        }
+    }
+
+    fn spend(&self, outcome: &ValidationOutcome) {
+        if let ValidationOutcome::IssuesDetected(issues) = outcome {
+            self.issues_found.set(self.issues_found.get() + issues.len());
+        }
+    }
+
+    fn exhausted(&self) -> bool {
+        self.max_issues
+            .is_some_and(|max_issues| self.issues_found.get() >= max_issues)
+    }
+}
+
+/// Users outside the organization accepted as owners anyway, as configured by canopus.toml
+struct OutsideCollaborators<'a> {
+    allowed: &'a [String],
+    repository: Option<&'a str>,
+}
+
+impl<'a> OutsideCollaborators<'a> {
+    fn from_config(ownership_config: &'a OwnershipConfig) -> anyhow::Result<Self> {
+        let allow_outside_collaborators = ownership_config
+            .allow_outside_collaborators
+            .unwrap_or(DEFAULT_VALUE_ALLOW_OUTSIDE_COLLABORATORS);
+
+        let repository = match (allow_outside_collaborators, &ownership_config.collaborators_repository) {
+            (false, _) => None,
+            (true, Some(repository)) => Some(repository.as_str()),
+            (true, None) => bail!("allow-outside-collaborators requires collaborators-repository, as owner/repo"),
+        };
+
+        Ok(Self {
+            allowed: &ownership_config.outside_collaborators,
+            repository,
+        })
+    }
+
+    async fn verify(
+        &self,
+        checker: &dyn CheckGithubConsistency,
+        outsider: GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let allowed = self
+            .allowed
+            .iter()
+            .any(|allowed| allowed.trim_start_matches('@').eq_ignore_ascii_case(outsider.inner()));
+
+        if allowed {
+            return Ok(());
+        }
+
+        match self.repository {
+            Some(repository) => checker.repository_collaborator(repository, &outsider, retries).await,
+            None => Err(ConsistencyIssue::OutsiderUser(outsider)),
+        }
+    }
+}
+
+// Everything needed to verify the role of an owner of a given rule
+struct RoleVerification {
+    organization: String,
+    listed_team: Option<GithubTeamHandle>,
+    users: Vec<GithubIdentityHandle>,
+    teams: Vec<GithubTeamHandle>,
+    unverifiable: ConsistencyIssue,
+    below_requirement: ConsistencyIssue,
+}
+
+// Evicts paths walked for a project however its validation ends, including when cancelled
+struct EvictedOnDrop<'a> {
+    path_walker: &'a PathWalker,
+    origin: &'a Path,
+}
+
+impl Drop for EvictedOnDrop<'_> {
+    fn drop(&mut self) {
+        self.path_walker.evict(self.origin);
+    }
+}
 
 /// The main driver for validating a parsed CodeOwners configuration
 pub struct CodeOwnersValidator {
-    github_consistency_checker: GithubConsistencyChecker,
+    consistency_checkers: HashMap<OwnersProvider, Box<dyn CheckGithubConsistency>>,
     path_walker: PathWalker,
+    observers: Vec<Box<dyn ValidationObserver>>,
+    interruption: Option<Shared<BoxFuture<'static, ()>>>,
 }
 
 impl CodeOwnersValidator {
-    pub fn new(github_consistency_checker: GithubConsistencyChecker, path_walker: PathWalker) -> Self {
+    pub fn new(github_consistency_checker: Box<dyn CheckGithubConsistency>, path_walker: PathWalker) -> Self {
         Self {
-            github_consistency_checker,
+            consistency_checkers: HashMap::from([(OwnersProvider::Github, github_consistency_checker)]),
             path_walker,
+            observers: Vec::new(),
+            interruption: None,
+        }
+    }
+
+    pub fn path_walker(&self) -> &PathWalker {
+        &self.path_walker
+    }
+
+    pub fn register_observer(&mut self, observer: Box<dyn ValidationObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Verifies owners of projects hosted by this provider with the given checker
+    pub fn register_consistency_checker(&mut self, provider: OwnersProvider, checker: Box<dyn CheckGithubConsistency>) {
+        self.consistency_checkers.insert(provider, checker);
+    }
+
+    fn consistency_checker(&self, canopus_config: &CanopusConfig) -> anyhow::Result<&dyn CheckGithubConsistency> {
+        let provider = canopus_config.general.provider.unwrap_or(DEFAULT_VALUE_PROVIDER);
+
+        match self.consistency_checkers.get(&provider) {
+            Some(checker) => Ok(checker.as_ref()),
+            None => bail!("cannot verify owners hosted by {provider}"),
         }
     }
 
@@ -34,140 +180,349 @@ impl CodeOwnersValidator {
         canopus_config: &CanopusConfig,
     ) -> anyhow::Result<ValidationOutcome> {
         let project_root = codeowners_context.project_path.as_path();
+
+        let _evicted = EvictedOnDrop {
+            path_walker: &self.path_walker,
+            origin: project_root,
+        };
+
         let codeowners = CodeOwners::try_from(codeowners_context.contents.as_str())?;
+        let overrides = LineOverrides::new(&codeowners, &canopus_config.overrides)?;
+
+        // Lines that fail to parse are flagged by the syntax phase, while remaining checks
+        // still run over whatever could be parsed
+        if let ValidationOutcome::IssuesDetected(issues) = &codeowners.syntax_validation {
+            log::info!("Syntax errors : {}", issues.len());
+        }
+
+        let parse_complete = ParseCompleteEvent {
+            total_lines: codeowners_context.contents.lines().count(),
+            total_rules: codeowners
+                .entries
+                .iter()
+                .filter(|entry| matches!(entry, CodeOwnersEntry::Rule(_)))
+                .count(),
+            unique_owners: codeowners.unique_owners().len(),
+        };
 
-        // All parsing issues must be flagged at this point
-        log::info!("Syntax errors : not found");
+        self.observers
+            .iter()
+            .for_each(|observer| observer.on_parse_complete(&parse_complete));
 
         // In the future, we could run all these validations in parallel
         // although check against Github API must drag most of the execution
         // time here
-        let validations = vec![
-            codeowners.syntax_validation.clone(), // We must include this
-            self.check_non_matching_glob_patterns(&codeowners, &self.path_walker.walk(project_root))?,
-            self.check_duplicated_owners(&codeowners)?,
-            self.check_multiple_ownership_per_entry(&codeowners, canopus_config)?,
-            self.check_allowed_owners(&codeowners, canopus_config)?,
-            self.check_github_consistency(&codeowners, canopus_config).await?,
-        ];
+        let budget = IssueBudget::new(canopus_config.general.max_issues);
+
+        // Checks relying on contents only run first, exactly as the library runs them
+        let mut validations = offline::check_contents(
+            &codeowners,
+            &codeowners_context.contents,
+            canopus_config,
+            |check, run| self.run_phase(&budget, &overrides, ValidationPhase::from(check), run),
+        )?;
+
+        validations.extend([
+            self.run_phase(&budget, &overrides, ValidationPhase::Location, || {
+                self.check_location(codeowners_context)
+            })?,
+            self.run_phase(&budget, &overrides, ValidationPhase::SelfOwnership, || {
+                // Github resolves CODEOWNERS rules relative to the repository root
+                let codeowners_location = codeowners_context
+                    .codeowners_path
+                    .strip_prefix(project_root)
+                    .unwrap_or(codeowners_context.codeowners_path.as_path());
+
+                offline::check_codeowners_ownership(&codeowners, codeowners_location, &canopus_config.ownership)
+            })?,
+            self.run_phase(&budget, &overrides, ValidationPhase::DanglingGlobs, || {
+                let walk_start = WalkStartEvent {
+                    origin: project_root.to_path_buf(),
+                };
+
+                self.observers
+                    .iter()
+                    .for_each(|observer| observer.on_walk_start(&walk_start));
+
+                // Paths are counted while streamed, the walk stopping once every glob matches
+                let mut total_paths = 0;
+
+                let project_paths = self
+                    .path_walker
+                    .walk(project_root, &canopus_config.paths)?
+                    .inspect(|_| total_paths += 1);
+
+                let outcome = self.check_non_matching_glob_patterns(
+                    &codeowners,
+                    project_paths,
+                    project_root,
+                    &canopus_config.paths,
+                );
+
+                let walk_complete = WalkCompleteEvent {
+                    origin: project_root.to_path_buf(),
+                    total_paths,
+                };
+
+                self.observers
+                    .iter()
+                    .for_each(|observer| observer.on_walk_complete(&walk_complete));
+
+                if total_paths == 0 {
+                    log::info!("No project paths found under {}", project_root.display());
+                }
 
-        // Short circuit in case there is no issues
-        if validations
-            .iter()
-            .all(|outcome| matches!(outcome, ValidationOutcome::NoIssues))
-        {
-            return Ok(ValidationOutcome::NoIssues);
+                outcome
+            })?,
+            self.run_phase(&budget, &overrides, ValidationPhase::OwnersPerPath, || {
+                self.check_owners_per_path(&codeowners, codeowners_context, canopus_config)
+            })?,
+            self.run_phase(&budget, &overrides, ValidationPhase::OwnershipCoverage, || {
+                self.check_ownership_coverage(&codeowners, codeowners_context, canopus_config)
+            })?,
+            self.run_phase(&budget, &overrides, ValidationPhase::ExternalChecks, || {
+                self.check_external_policies(&codeowners, codeowners_context, &canopus_config.checks.external)
+            })?,
+            // Most of the validation time is spent here, hence we skip it first when reaching max issues
+            if budget.exhausted() {
+                log::info!(
+                    "Skipping {} phase : reached max issues",
+                    ValidationPhase::GithubConsistency
+                );
+                ValidationOutcome::NoIssues
+            } else {
+                let started = Instant::now();
+                let outcome = self
+                    .check_github_consistency(&codeowners, project_root, canopus_config)
+                    .await;
+
+                // Owner checks end here however they went, interrupted ones included
+                for checker in self.consistency_checkers.values() {
+                    checker.flush().await;
+                }
+
+                let outcome = outcome?;
+                let outcome = overrides.apply_to_outcome(outcome);
+                self.publish_phase_end(ValidationPhase::GithubConsistency, started.elapsed(), &outcome);
+                outcome
+            },
+        ]);
+
+        Ok(offline::collect_issues(validations, canopus_config))
+    }
+
+    /// Stops verifying owners against Github API once the given future resolves, e.g. on Ctrl-C
+    pub fn interrupt_on(&mut self, interruption: impl Future<Output = ()> + Send + 'static) {
+        self.interruption = Some(interruption.boxed().shared());
+    }
+
+    // Never resolves when no interruption was registered
+    async fn interrupted(&self) {
+        match &self.interruption {
+            Some(interruption) => interruption.clone().await,
+            None => std::future::pending().await,
         }
+    }
 
-        // We collect all spotted issues by iterating and flat mapping
-        // each validation outcome
-        let all_issues = validations
-            .into_iter()
-            .filter_map(|outcome| match outcome {
-                ValidationOutcome::NoIssues => None,
-                ValidationOutcome::IssuesDetected(issues) => Some(issues),
-            })
-            .flatten()
-            .sorted_by_key(|issue| issue.line)
-            .collect_vec();
+    async fn verify_owner(
+        checker: &dyn CheckGithubConsistency,
+        gh_organizations: &[&str],
+        owner: &Owner,
+        outside_collaborators: &OutsideCollaborators<'_>,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        match owner {
+            Owner::GithubUser(identity) => {
+                match Self::verify_user(checker, gh_organizations, identity, retries).await {
+                    Err(ConsistencyIssue::OutsiderUser(outsider)) => {
+                        outside_collaborators.verify(checker, outsider, retries).await
+                    },
+                    checked => checked,
+                }
+            },
+            Owner::GithubTeam(team) => {
+                // Teams are checked against their own organization, as long as it is a listed one
+                let gh_organization = gh_organizations
+                    .iter()
+                    .find(|organization| organization.eq_ignore_ascii_case(team.organization.inner()))
+                    .or(gh_organizations.first())
+                    .copied()
+                    .unwrap_or_default();
+
+                checker.github_team(gh_organization, team, retries).await
+            },
+            Owner::EmailAddress(_) => Ok(()),
+        }
+    }
+
+    // Users belonging to any listed organization are accepted, while failing to verify
+    // some organization only matters when no other one accepts them
+    async fn verify_user(
+        checker: &dyn CheckGithubConsistency,
+        gh_organizations: &[&str],
+        identity: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let mut unverifiable = None;
+
+        for gh_organization in gh_organizations {
+            match checker.github_identity(gh_organization, identity, retries).await {
+                Ok(()) => return Ok(()),
+                Err(ConsistencyIssue::OutsiderUser(_)) => continue,
+                Err(issue) => {
+                    unverifiable.get_or_insert(issue);
+                },
+            }
+        }
+
+        Err(unverifiable.unwrap_or_else(|| ConsistencyIssue::OutsiderUser(identity.clone())))
+    }
 
-        Ok(ValidationOutcome::IssuesDetected(all_issues))
+    fn unverifiable(owner: &Owner) -> Result<(), ConsistencyIssue> {
+        match owner {
+            Owner::GithubUser(identity) => Err(ConsistencyIssue::CannotVerifyUser(identity.clone())),
+            Owner::GithubTeam(team) => Err(ConsistencyIssue::CannotVerifyTeam(team.clone())),
+            Owner::EmailAddress(_) => Ok(()),
+        }
     }
 
-    fn check_multiple_ownership_per_entry(
+    fn run_phase(
         &self,
-        code_owners: &CodeOwners,
-        canopus_config: &CanopusConfig,
+        budget: &IssueBudget,
+        overrides: &LineOverrides,
+        phase: ValidationPhase,
+        check: impl FnOnce() -> anyhow::Result<ValidationOutcome>,
     ) -> anyhow::Result<ValidationOutcome> {
-        // We short circuit if an opt-in disables this check
-        if !canopus_config
-            .ownership
-            .enforce_one_owner_per_line
-            .unwrap_or(DEFAULT_VALUE_ENFORCE_ONE_OWNER_PER_LINE)
-        {
+        if budget.exhausted() {
+            log::info!("Skipping {} phase : reached max issues", phase);
             return Ok(ValidationOutcome::NoIssues);
+        }
+
+        let started = Instant::now();
+        let outcome = overrides.apply_to_outcome(check()?);
+        budget.spend(&outcome);
+        self.publish_phase_end(phase, started.elapsed(), &outcome);
+        Ok(outcome)
+    }
+
+    fn publish_phase_end(&self, phase: ValidationPhase, elapsed: Duration, outcome: &ValidationOutcome) {
+        let issues = match outcome {
+            ValidationOutcome::NoIssues => vec![],
+            ValidationOutcome::IssuesDetected(issues) => issues.iter().collect_vec(),
         };
 
-        let entries_with_multiple_owners = code_owners
-            .entries
-            .iter()
-            .filter_map(|entry| match entry {
-                CodeOwnersEntry::Rule(ownership) => {
-                    if ownership.owners.len() != 1 {
-                        Some(ownership)
-                    } else {
-                        None
-                    }
-                },
-                _ => None,
-            })
-            .collect_vec();
+        let phase_end = PhaseEndEvent {
+            phase,
+            issues_found: issues.len(),
+            elapsed,
+        };
+
+        self.observers.iter().for_each(|observer| {
+            issues.iter().for_each(|issue| observer.on_issue(phase, issue));
+            observer.on_phase_end(&phase_end);
+        });
+    }
+
+    fn publish_skipped_checks(&self, checks: &[&'static str], reason: &'static str) {
+        checks.iter().for_each(|check| {
+            let check_skipped = CheckSkippedEvent { check, reason };
+
+            self.observers
+                .iter()
+                .for_each(|observer| observer.on_check_skipped(&check_skipped));
+        });
+    }
+
+    fn check_location(&self, codeowners_context: &CodeOwnersContext) -> anyhow::Result<ValidationOutcome> {
+        let project_root = codeowners_context.project_path.as_path();
+
+        // Remote projects are fetched from the repository root, where Github reads CODEOWNERS from
+        if !project_root.is_dir() {
+            return Ok(ValidationOutcome::NoIssues);
+        }
 
-        if entries_with_multiple_owners.is_empty() {
-            log::info!("All ownership entries have a single owner defined");
+        let Ok(repository_root) = git::repository_root(project_root).and_then(|root| Ok(root.canonicalize()?)) else {
+            log::info!("Project does not belong to a git repository, skipping CODEOWNERS location check");
             return Ok(ValidationOutcome::NoIssues);
         };
 
-        let issues = entries_with_multiple_owners
+        let codeowners_path = codeowners_context.codeowners_path.canonicalize()?;
+
+        if CODEOWNERS_LOCATIONS
             .iter()
-            .map(|rule| {
-                ValidationIssue::builder()
-                    .kind(IssueKind::Configuration(ConfigurationIssue::OnlyOneOwnerPerEntry))
-                    .line_number(rule.line_number)
-                    .description("Entry defines more than one owner for this glob")
-                    .build()
-            })
-            .collect_vec();
+            .any(|location| repository_root.join(location) == codeowners_path)
+        {
+            log::info!("CodeOwners is located where Github reads it");
+            return Ok(ValidationOutcome::NoIssues);
+        }
 
-        log::info!("Found some CodeOwners entries with multiple owners for the same glob");
-        Ok(ValidationOutcome::IssuesDetected(issues))
+        let relative_path = codeowners_path
+            .strip_prefix(&repository_root)
+            .unwrap_or(&codeowners_path);
+
+        let issue = ValidationIssue::builder()
+            .kind(IssueKind::Structural(StructuralIssue::UnhonoredLocation))
+            .line_number(usize::MAX)
+            .message(format!(
+                "Github ignores {}, only reading CODEOWNERS from {} at the root of {}",
+                relative_path.to_string_lossy(),
+                CODEOWNERS_LOCATIONS.join(", "),
+                repository_root.to_string_lossy()
+            ))
+            .build();
+
+        log::info!("Found CodeOwners outside locations read by Github");
+        Ok(ValidationOutcome::IssuesDetected(vec![issue]))
     }
 
-    fn check_duplicated_owners(&self, code_owners: &CodeOwners) -> anyhow::Result<ValidationOutcome> {
-        let ownerships = &code_owners
-            .entries
-            .iter()
-            .filter_map(|entry| match entry {
-                CodeOwnersEntry::Rule(ownership) => Some(ownership),
-                _ => None,
-            })
-            .sorted_by_key(|rule| rule.glob.glob())
-            .collect_vec();
+    fn check_external_policies(
+        &self,
+        code_owners: &CodeOwners,
+        codeowners_context: &CodeOwnersContext,
+        external_checks: &[ExternalCheckConfig],
+    ) -> anyhow::Result<ValidationOutcome> {
+        if external_checks.is_empty() {
+            return Ok(ValidationOutcome::NoIssues);
+        }
 
-        let mut grouped_per_glob = Vec::new();
+        // Remote projects are not cloned, hence commands run from the current directory
+        let project_root = codeowners_context.project_path.as_path();
+        let working_dir = if project_root.is_dir() {
+            project_root
+        } else {
+            Path::new(".")
+        };
 
-        for (glob, grouped) in &ownerships.iter().chunk_by(|rule| rule.glob.glob()) {
-            let lines = grouped.into_iter().map(|rule| rule.line_number).collect_vec();
+        let rules_json = external::rules_as_json(code_owners);
+        let mut issues = Vec::new();
 
-            if lines.len() > 1 {
-                grouped_per_glob.push((glob.to_string(), lines));
-            }
-        }
+        for check in external_checks {
+            let findings = external::run_external_check(check, working_dir, &rules_json)?;
 
-        if !grouped_per_glob.is_empty() {
-            let issues = grouped_per_glob
-                .iter()
-                .map(|(glob, lines)| {
-                    ValidationIssue::builder()
-                        .kind(IssueKind::Structural(StructuralIssue::DuplicateOwnership))
-                        .line_number(lines[0])
-                        .message(format!("{} defined multiple times : lines {:?}", glob, lines))
-                        .build()
-                })
-                .collect_vec();
+            issues.extend(findings.into_iter().map(|finding| {
+                ValidationIssue::builder()
+                    .kind(IssueKind::Configuration(ConfigurationIssue::ExternalCheckViolation))
+                    .line_number(finding.line.unwrap_or(usize::MAX))
+                    .message(format!("[{}] {}", check.name, finding.message))
+                    .build()
+            }));
+        }
 
-            log::info!("Found some duplicated ownership rules");
-            return Ok(ValidationOutcome::IssuesDetected(issues));
+        if issues.is_empty() {
+            log::info!("CodeOwners complies with external checks");
+            return Ok(ValidationOutcome::NoIssues);
         }
 
-        log::info!("Duplicated code owners : not found");
-        Ok(ValidationOutcome::NoIssues)
+        log::info!("Found CodeOwners entries violating external checks");
+        Ok(ValidationOutcome::IssuesDetected(issues))
     }
 
     fn check_non_matching_glob_patterns(
         &self,
         code_owners: &CodeOwners,
-        paths: &[PathBuf],
+        paths: impl Iterator<Item = impl AsRef<Path>>,
+        project_root: &Path,
+        paths_config: &PathsConfig,
     ) -> anyhow::Result<ValidationOutcome> {
         let lines_and_glob_matchers = code_owners
             .entries
@@ -178,26 +533,76 @@ impl CodeOwnersValidator {
             })
             .collect_vec();
 
-        let matching_globs = lines_and_glob_matchers
+        let mut pending_matchers = lines_and_glob_matchers
             .iter()
-            .filter_map(|(_, glob_matcher)| {
-                if paths.iter().any(|path| glob_matcher.is_match(path)) {
-                    Some(glob_matcher.glob().clone())
-                } else {
-                    None
+            .map(|(_, glob_matcher)| glob_matcher)
+            .unique_by(|glob_matcher| glob_matcher.glob())
+            .collect_vec();
+
+        // We stop walking as soon as every glob matches at least one path
+        for path in paths {
+            pending_matchers.retain(|glob_matcher| !glob_matcher.is_match(path.as_ref()));
+
+            if pending_matchers.is_empty() {
+                break;
+            }
+        }
+
+        // Patterns matching only ignored paths usually rely on build outputs,
+        // so we tell them apart from patterns matching nothing at all
+        let mut ignored_only_matchers = Vec::new();
+
+        if !pending_matchers.is_empty() {
+            for path in self.path_walker.walk_ignored(project_root, paths_config)? {
+                let (matching, not_matching) = pending_matchers
+                    .into_iter()
+                    .partition::<Vec<_>, _>(|glob_matcher| glob_matcher.is_match(&path));
+
+                ignored_only_matchers.extend(matching);
+                pending_matchers = not_matching;
+
+                if pending_matchers.is_empty() {
+                    break;
                 }
-            })
+            }
+        }
+
+        let dangling_globs = pending_matchers
+            .into_iter()
+            .map(|glob_matcher| glob_matcher.glob().clone())
+            .collect::<HashSet<_>>();
+
+        let ignored_only_globs = ignored_only_matchers
+            .into_iter()
+            .map(|glob_matcher| glob_matcher.glob().clone())
             .collect::<HashSet<_>>();
 
         let issues = lines_and_glob_matchers
             .iter()
-            .filter(|(_, glob_matcher)| !matching_globs.contains(glob_matcher.glob()))
-            .map(|(line, glob_matcher)| {
-                ValidationIssue::builder()
-                    .kind(IssueKind::Structural(StructuralIssue::DanglingGlobPattern))
+            .filter_map(|(line, glob_matcher)| {
+                let glob = glob_matcher.glob();
+
+                let (kind, message) = if dangling_globs.contains(glob) {
+                    (
+                        StructuralIssue::DanglingGlobPattern,
+                        format!("{glob} does not match any project path"),
+                    )
+                } else if ignored_only_globs.contains(glob) {
+                    (
+                        StructuralIssue::IgnoredPathsOnly,
+                        format!("{glob} only matches paths ignored by git"),
+                    )
+                } else {
+                    return None;
+                };
+
+                let issue = ValidationIssue::builder()
+                    .kind(IssueKind::Structural(kind))
                     .line_number(*line)
-                    .message(format!("{} does not match any project path", glob_matcher.glob()))
-                    .build()
+                    .message(message)
+                    .build();
+
+                Some(issue)
             })
             .collect_vec();
 
@@ -210,132 +615,764 @@ impl CodeOwnersValidator {
         Ok(ValidationOutcome::NoIssues)
     }
 
-    fn check_allowed_owners(
+    /// Computes how many project files are owned, honoring coverage exclusions
+    pub fn ownership_coverage(
         &self,
-        code_owners: &CodeOwners,
+        codeowners_context: &CodeOwnersContext,
         canopus_config: &CanopusConfig,
-    ) -> anyhow::Result<ValidationOutcome> {
-        // This option takes precedence over email owners
-        if canopus_config
-            .ownership
-            .enforce_github_teams_owners
-            .unwrap_or(DEFAULT_VALUE_ENFORCE_GITHUB_TEAMS_OWNERS)
-        {
-            return self.check_only_github_teams_owners(code_owners);
-        };
+    ) -> anyhow::Result<OwnershipCoverage> {
+        let codeowners = CodeOwners::try_from(codeowners_context.contents.as_str())?;
+        let project_files = self.coverage_files(codeowners_context, canopus_config)?;
+        Ok(OwnershipCoverage::compute(&codeowners, project_files.iter()))
+    }
 
-        if canopus_config
-            .ownership
-            .forbid_email_owners
-            .unwrap_or(DEFAULT_VALUE_FORBID_EMAIL_ADDRESSES)
-        {
-            return self.check_non_email_owners(code_owners);
-        };
+    pub fn coverage_files(
+        &self,
+        codeowners_context: &CodeOwnersContext,
+        canopus_config: &CanopusConfig,
+    ) -> anyhow::Result<PathArena> {
+        let project_root = codeowners_context.project_path.as_path();
 
-        Ok(ValidationOutcome::NoIssues)
+        // Coverage exclusions are independent from walking ones, since excluded
+        // paths still exist and may be targeted by ownership rules
+        let excluded = paths::compile_path_patterns(&canopus_config.coverage.exclude)?;
+
+        let project_files = self
+            .path_walker
+            .walk_files(project_root, &canopus_config.paths)?
+            .filter(|path| !excluded.is_match(path))
+            .collect::<PathArena>();
+
+        Ok(project_files)
     }
 
-    fn check_non_email_owners(&self, code_owners: &CodeOwners) -> anyhow::Result<ValidationOutcome> {
-        let email_owners = code_owners
-            .unique_owners()
-            .into_iter()
-            .filter(|owner| matches!(owner, Owner::EmailAddress(_)))
-            .collect_vec();
+    fn check_owners_per_path(
+        &self,
+        code_owners: &CodeOwners,
+        codeowners_context: &CodeOwnersContext,
+        canopus_config: &CanopusConfig,
+    ) -> anyhow::Result<ValidationOutcome> {
+        let policy_engine = PolicyEngine::new(&canopus_config.policies, &canopus_config.ownership)?;
 
-        if email_owners.is_empty() {
-            log::info!("Email owners : not found");
+        // We short circuit unless some policy requires a minimum of owners,
+        // since it requires walking the whole project
+        if !policy_engine.requires_project_files() {
             return Ok(ValidationOutcome::NoIssues);
-        };
+        }
 
-        let issues = email_owners
-            .into_iter()
-            .map(|owner| {
-                ValidationIssue::builder()
-                    .kind(IssueKind::Configuration(ConfigurationIssue::EmailOwnerForbidden))
-                    .line_number(code_owners.occurrences(owner)[0])
-                    .message("email owner is not allowed".to_string())
-                    .build()
-            })
-            .collect_vec();
+        let project_files = self
+            .path_walker
+            .walk_files(&codeowners_context.project_path, &canopus_config.paths)?
+            .collect::<PathArena>();
 
-        log::info!("Found owners defined by email");
-        Ok(ValidationOutcome::IssuesDetected(issues))
+        Ok(policy_engine.evaluate_per_path(code_owners, project_files.iter()))
     }
 
-    fn check_only_github_teams_owners(&self, code_owners: &CodeOwners) -> anyhow::Result<ValidationOutcome> {
-        let non_github_team_owners = code_owners
-            .unique_owners()
-            .into_iter()
-            .filter(|owner| !matches!(owner, Owner::GithubTeam(_)))
-            .collect_vec();
+    fn check_ownership_coverage(
+        &self,
+        code_owners: &CodeOwners,
+        codeowners_context: &CodeOwnersContext,
+        canopus_config: &CanopusConfig,
+    ) -> anyhow::Result<ValidationOutcome> {
+        let coverage_config = &canopus_config.coverage;
 
-        if non_github_team_owners.is_empty() {
-            log::info!("All owners are Github teams");
+        // We short circuit if coverage gating was not opted-in,
+        // since it requires walking the whole project
+        if coverage_config.minimum.is_none() && coverage_config.base_ref.is_none() {
             return Ok(ValidationOutcome::NoIssues);
-        };
+        }
 
-        let issues = non_github_team_owners
-            .into_iter()
-            .map(|owner| {
-                ValidationIssue::builder()
-                    .kind(IssueKind::Configuration(ConfigurationIssue::OnlyGithubTeamOwnerAllowed))
-                    .line_number(code_owners.occurrences(owner)[0])
-                    .message("only github team owner is allowed".to_string())
-                    .build()
-            })
-            .collect_vec();
+        let project_root = codeowners_context.project_path.as_path();
+        let project_files = self.coverage_files(codeowners_context, canopus_config)?;
+
+        let coverage = OwnershipCoverage::compute(code_owners, project_files.iter());
+        log::info!("Ownership coverage : {:.2}%", coverage.percentage());
+
+        let mut issues = Vec::new();
+
+        if let Some(minimum) = coverage_config.minimum
+            && coverage.percentage() < minimum
+        {
+            let below_minimum = ValidationIssue::builder()
+                .kind(IssueKind::Configuration(
+                    ConfigurationIssue::OwnershipCoverageBelowMinimum,
+                ))
+                .line_number(usize::MAX)
+                .message(format!(
+                    "ownership coverage is {:.2}% ({} of {} files), below the minimum of {}%",
+                    coverage.percentage(),
+                    coverage.owned_files,
+                    coverage.total_files,
+                    minimum
+                ))
+                .build();
+
+            issues.push(below_minimum);
+        }
+
+        if let Some(base_ref) = &coverage_config.base_ref {
+            let codeowners_location = codeowners_context
+                .codeowners_path
+                .strip_prefix(project_root)
+                .unwrap_or(codeowners_context.codeowners_path.as_path());
+
+            let base_contents = git::file_at_revision(project_root, base_ref, codeowners_location)?;
+            let base_codeowners = CodeOwners::try_from(base_contents.as_str())?;
+            let base_coverage = OwnershipCoverage::compute(&base_codeowners, project_files.iter());
+
+            if coverage.percentage() < base_coverage.percentage() {
+                let decreased = ValidationIssue::builder()
+                    .kind(IssueKind::Configuration(ConfigurationIssue::OwnershipCoverageDecreased))
+                    .line_number(usize::MAX)
+                    .message(format!(
+                        "ownership coverage decreased from {:.2}% at {} to {:.2}%",
+                        base_coverage.percentage(),
+                        base_ref,
+                        coverage.percentage()
+                    ))
+                    .build();
+
+                issues.push(decreased);
+            }
+        }
+
+        if issues.is_empty() {
+            return Ok(ValidationOutcome::NoIssues);
+        }
 
-        log::info!("Found owners defined by email or github users");
+        log::info!("Ownership coverage does not satisfy configured thresholds");
         Ok(ValidationOutcome::IssuesDetected(issues))
     }
 
     async fn check_github_consistency(
         &self,
         code_owners: &CodeOwners,
+        project_root: &Path,
         canopus_config: &CanopusConfig,
     ) -> anyhow::Result<ValidationOutcome> {
-        // We short circuit if an opt-in disables this check
+        // We short circuit if an opt-in disables this check, unless a committed roster answers it
         let offline_checks_only = canopus_config
             .general
             .offline_checks_only
             .unwrap_or(DEFAULT_VALUE_OFFLINE_CHECKS_ONLY);
 
-        if offline_checks_only {
+        if offline_checks_only && canopus_config.general.roster_file.is_none() {
             return Ok(ValidationOutcome::NoIssues);
         }
 
-        let gh_organization = canopus_config.general.github_organization.as_str();
-        let unique_ownerships = code_owners.unique_owners();
+        let gh_organizations = canopus_config.general.organizations();
+        let checker = self.consistency_checker(canopus_config)?;
+        let retries = RetryPolicy::from(&canopus_config.retries);
 
-        let consistency_checks = unique_ownerships
-            .into_iter()
-            .map(|owner| async move {
-                match owner {
-                    Owner::GithubUser(identity) => {
-                        self.github_consistency_checker
-                            .github_identity(gh_organization, identity)
-                            .await
-                    },
-                    Owner::GithubTeam(team) => self.github_consistency_checker.github_team(gh_organization, team).await,
-                    Owner::EmailAddress(_) => Ok(()),
-                }
-            })
-            .collect_vec();
+        // Misconfigured credentials would otherwise end up as plenty of owners we cannot verify
+        if !offline_checks_only {
+            checker.preflight(canopus_config, &retries).await?;
+        }
 
-        let consistency_results = futures::future::join_all(consistency_checks).await;
+        // Without credentials, Github only tells whether users exist, unless a committed roster
+        // answers membership instead. Checks left unanswered are skipped rather than reported per owner
+        let anonymous = checker.capability() == ApiCapability::Anonymous;
+        let anonymous_membership = anonymous && canopus_config.general.roster_file.is_none();
+        let anonymous_reason = "no token was provided through GITHUB_TOKEN";
 
-        if consistency_results.iter().all(|check| check.is_ok()) {
-            return Ok(ValidationOutcome::NoIssues);
+        if anonymous && canopus_config.strict {
+            bail!("strict mode requires verifying owners with a token defined by GITHUB_TOKEN");
+        }
+
+        if anonymous_membership {
+            self.publish_skipped_checks(&["outsider-user", "team-does-not-exist"], anonymous_reason);
+        }
+
+        // Users and teams are verified against the roster instead, once one is configured
+        let roster_checker;
+        let checker = match &canopus_config.general.roster_file {
+            Some(roster_file) => {
+                let roster = Roster::load(&project_root.join(roster_file))?;
+                roster_checker = RosterChecker::new(roster, checker);
+                &roster_checker as &dyn CheckGithubConsistency
+            },
+            None => checker,
         };
 
-        let issues = consistency_results
-            .into_iter()
-            .filter_map(|check| check.err())
-            .map(|issue| issue.to_validation_issue(code_owners))
-            .collect_vec();
+        // User owners are verified against a directory instead, once one is configured
+        let directory_checker;
+        let checker = match ScimDirectory::from_config(&canopus_config.directory)? {
+            Some(directory) => {
+                directory_checker = DirectoryChecker::new(directory, checker);
+                &directory_checker as &dyn CheckGithubConsistency
+            },
+            None => checker,
+        };
+
+        // Email addresses cannot be verified against Github, neither teams without credentials
+        let unique_ownerships = code_owners
+            .unique_owners()
+            .into_iter()
+            .filter(|owner| match owner {
+                Owner::EmailAddress(_) => false,
+                Owner::GithubTeam(_) => !anonymous_membership,
+                Owner::GithubUser(_) => true,
+            })
+            .collect_vec();
+
+        let consistency_start = ConsistencyStartEvent {
+            total_owners: unique_ownerships.len(),
+        };
+
+        self.observers
+            .iter()
+            .for_each(|observer| observer.on_consistency_start(&consistency_start));
+
+        // Bounding concurrency avoids hammering Github API with large CODEOWNERS files
+        let max_concurrent_requests = canopus_config
+            .general
+            .max_concurrent_requests
+            .unwrap_or(DEFAULT_VALUE_MAX_CONCURRENT_REQUESTS)
+            .max(1);
+
+        let request_permits = Semaphore::new(max_concurrent_requests);
+
+        let github_timeout = Duration::from_secs(
+            canopus_config
+                .general
+                .github_timeout_seconds
+                .unwrap_or(DEFAULT_VALUE_GITHUB_TIMEOUT_SECONDS),
+        );
+
+        let outside_collaborators = OutsideCollaborators::from_config(&canopus_config.ownership)?;
+
+        let permits = &request_permits;
+        let retry_policy = &retries;
+        let collaborators = &outside_collaborators;
+        let organizations = gh_organizations.as_slice();
+
+        let consistency_checks = unique_ownerships
+            .into_iter()
+            .map(|owner| async move {
+                let verification = async {
+                    let _permit = permits
+                        .acquire()
+                        .await
+                        .expect("FATAL: request permits are never closed");
+
+                    let owner_check = OwnerCheckEvent {
+                        owner: owner.to_string(),
+                    };
+
+                    self.observers
+                        .iter()
+                        .for_each(|observer| observer.on_owner_check_start(&owner_check));
+
+                    let verified = tokio::time::timeout(
+                        github_timeout,
+                        Self::verify_owner(checker, organizations, owner, collaborators, retry_policy),
+                    )
+                    .await;
+
+                    let checked = match verified {
+                        Ok(checked) => checked,
+                        Err(_) => {
+                            log::info!("Verifying {} timed out after {}s", owner, github_timeout.as_secs());
+                            Self::unverifiable(owner)
+                        },
+                    };
+
+                    self.observers
+                        .iter()
+                        .for_each(|observer| observer.on_owner_check_end(&owner_check));
+
+                    checked
+                };
+
+                // Owners not verified yet when interrupted are reported as such, keeping results found so far
+                tokio::select! {
+                    checked = verification => checked,
+                    _ = self.interrupted() => Self::unverifiable(owner),
+                }
+            })
+            .collect_vec();
+
+        let consistency_results = futures::future::join_all(consistency_checks).await;
+
+        let mut consistency_issues = consistency_results
+            .into_iter()
+            .filter_map(|check| check.err())
+            .collect_vec();
+
+        let require_team_membership = canopus_config
+            .ownership
+            .require_team_membership
+            .unwrap_or(DEFAULT_VALUE_REQUIRE_TEAM_MEMBERSHIP);
+
+        if require_team_membership && anonymous_membership {
+            self.publish_skipped_checks(&["owner-not-in-owning-team"], anonymous_reason);
+        } else if require_team_membership {
+            let membership_issues = self
+                .check_team_membership(
+                    checker,
+                    code_owners,
+                    canopus_config,
+                    &request_permits,
+                    &retries,
+                    github_timeout,
+                )
+                .await?;
+
+            consistency_issues.extend(membership_issues);
+        }
+
+        let require_review_assignment = canopus_config
+            .ownership
+            .require_review_assignment
+            .unwrap_or(DEFAULT_VALUE_REQUIRE_REVIEW_ASSIGNMENT);
+
+        if require_review_assignment && anonymous {
+            self.publish_skipped_checks(&["team-review-assignment-disabled"], anonymous_reason);
+        } else if require_review_assignment {
+            let assignment_issues = self
+                .check_review_assignment(checker, code_owners, &request_permits, &retries, github_timeout)
+                .await;
+
+            consistency_issues.extend(assignment_issues);
+        }
+
+        let require_roles = canopus_config
+            .policies
+            .iter()
+            .any(|policy| policy.required_role.is_some());
+
+        if require_roles && anonymous {
+            self.publish_skipped_checks(
+                &["user-role-below-requirement", "team-role-below-requirement"],
+                anonymous_reason,
+            );
+        } else if require_roles {
+            let role_issues = self
+                .check_owner_roles(
+                    checker,
+                    code_owners,
+                    canopus_config,
+                    &request_permits,
+                    &retries,
+                    github_timeout,
+                )
+                .await?;
+
+            consistency_issues.extend(role_issues);
+        }
+
+        if canopus_config.protection.branch.is_some() && anonymous {
+            self.publish_skipped_checks(
+                &["codeowners-review-not-required", "required-approvals-below-policies"],
+                anonymous_reason,
+            );
+        } else if let Some(branch) = &canopus_config.protection.branch {
+            let protection_issues =
+                Self::check_branch_protection(checker, project_root, canopus_config, branch, &retries, github_timeout)
+                    .await;
+
+            consistency_issues.extend(protection_issues);
+        }
+
+        if consistency_issues.is_empty() {
+            return Ok(ValidationOutcome::NoIssues);
+        };
+
+        let report_every_occurrence = canopus_config
+            .ownership
+            .report_every_occurrence
+            .unwrap_or(DEFAULT_VALUE_REPORT_EVERY_OCCURRENCE);
+
+        let issues = consistency_issues
+            .into_iter()
+            .flat_map(|issue| {
+                if report_every_occurrence {
+                    issue.to_validation_issues_per_occurrence(code_owners)
+                } else {
+                    vec![issue.to_validation_issue(code_owners)]
+                }
+            })
+            .collect_vec();
 
         Ok(ValidationOutcome::IssuesDetected(issues))
     }
+
+    // Teams notifying everyone on review requests end up with nobody in particular reviewing
+    async fn check_review_assignment(
+        &self,
+        checker: &dyn CheckGithubConsistency,
+        code_owners: &CodeOwners,
+        request_permits: &Semaphore,
+        retries: &RetryPolicy,
+        github_timeout: Duration,
+    ) -> Vec<ConsistencyIssue> {
+        let team_owners = code_owners
+            .unique_owners()
+            .into_iter()
+            .filter_map(|owner| match owner {
+                Owner::GithubTeam(team) => Some(team.clone()),
+                _ => None,
+            })
+            .collect_vec();
+
+        let assignments = team_owners
+            .iter()
+            .map(|team| async {
+                let fetching = async {
+                    let _permit = request_permits
+                        .acquire()
+                        .await
+                        .expect("FATAL: request permits are never closed");
+
+                    tokio::time::timeout(github_timeout, checker.team_review_assignment(team, retries))
+                        .await
+                        .unwrap_or_else(|_| Err(ConsistencyIssue::CannotVerifyTeam(team.clone())))
+                };
+
+                let fetched = tokio::select! {
+                    assignment = fetching => assignment,
+                    _ = self.interrupted() => Err(ConsistencyIssue::CannotVerifyTeam(team.clone())),
+                };
+
+                match fetched {
+                    Ok(assignment) if assignment.enabled => None,
+                    Ok(_) => Some(ConsistencyIssue::TeamReviewAssignmentDisabled(team.clone())),
+                    Err(issue @ ConsistencyIssue::CannotVerifyTeam(_)) => Some(issue),
+                    // Missing teams are reported by owner checks already
+                    Err(_) => None,
+                }
+            })
+            .collect_vec();
+
+        futures::future::join_all(assignments)
+            .await
+            .into_iter()
+            .flatten()
+            .collect_vec()
+    }
+
+    // Owners of critical paths may be required to maintain an owning team, or even administer the organization
+    async fn check_owner_roles(
+        &self,
+        checker: &dyn CheckGithubConsistency,
+        code_owners: &CodeOwners,
+        canopus_config: &CanopusConfig,
+        request_permits: &Semaphore,
+        retries: &RetryPolicy,
+        github_timeout: Duration,
+    ) -> anyhow::Result<Vec<ConsistencyIssue>> {
+        let policy_engine = PolicyEngine::new(&canopus_config.policies, &canopus_config.ownership)?;
+        let organizations = canopus_config.general.organizations();
+        let organizations = organizations.as_slice();
+
+        let verifications = policy_engine
+            .required_roles(code_owners)
+            .into_iter()
+            .flat_map(|(rule, required)| {
+                let owning_teams = rule
+                    .owners
+                    .iter()
+                    .filter_map(|owner| match owner {
+                        Owner::GithubTeam(team) => Some(team.clone()),
+                        _ => None,
+                    })
+                    .unique()
+                    .collect_vec();
+
+                let line = rule.line_number;
+
+                // Team owners are held to the role through each one of their members
+                rule.owners
+                    .iter()
+                    .unique()
+                    .filter_map(move |owner| match owner {
+                        Owner::GithubUser(user) => Some(RoleVerification {
+                            organization: organizations.first()?.to_string(),
+                            listed_team: None,
+                            users: vec![user.clone()],
+                            teams: owning_teams.clone(),
+                            unverifiable: ConsistencyIssue::CannotVerifyUser(user.clone()),
+                            below_requirement: ConsistencyIssue::UserRoleBelowRequirement {
+                                user: user.clone(),
+                                required,
+                                line,
+                            },
+                        }),
+                        Owner::GithubTeam(team) => Some(RoleVerification {
+                            organization: team.organization.inner().to_string(),
+                            listed_team: Some(team.clone()),
+                            users: vec![],
+                            teams: vec![team.clone()],
+                            unverifiable: ConsistencyIssue::CannotVerifyTeam(team.clone()),
+                            below_requirement: ConsistencyIssue::TeamRoleBelowRequirement {
+                                team: team.clone(),
+                                required,
+                                line,
+                            },
+                        }),
+                        Owner::EmailAddress(_) => None,
+                    })
+                    .map(move |verification| async move {
+                        let verifying = async {
+                            let _permit = request_permits
+                                .acquire()
+                                .await
+                                .expect("FATAL: request permits are never closed");
+
+                            let checking = async {
+                                let users = match &verification.listed_team {
+                                    Some(team) => checker.team_members(team, retries).await?.into_iter().collect_vec(),
+                                    None => verification.users.clone(),
+                                };
+
+                                for user in &users {
+                                    let qualified = Self::holds_role(
+                                        checker,
+                                        &verification.organization,
+                                        user,
+                                        &verification.teams,
+                                        required,
+                                        retries,
+                                    )
+                                    .await?;
+
+                                    if !qualified {
+                                        return Ok(false);
+                                    }
+                                }
+
+                                Ok(true)
+                            };
+
+                            tokio::time::timeout(github_timeout, checking)
+                                .await
+                                .unwrap_or_else(|_| Err(verification.unverifiable.clone()))
+                        };
+
+                        let verified = tokio::select! {
+                            verified = verifying => verified,
+                            _ = self.interrupted() => Err(verification.unverifiable.clone()),
+                        };
+
+                        match verified {
+                            Ok(true) => None,
+                            Ok(false) => Some(verification.below_requirement),
+                            Err(
+                                issue @ (ConsistencyIssue::CannotVerifyUser(_) | ConsistencyIssue::CannotVerifyTeam(_)),
+                            ) => Some(issue),
+                            // Missing users and teams are reported by owner checks already
+                            Err(_) => None,
+                        }
+                    })
+            })
+            .collect_vec();
+
+        // Owners of several rules may fail verification once per rule
+        let issues = futures::future::join_all(verifications)
+            .await
+            .into_iter()
+            .flatten()
+            .fold(vec![], |mut issues, issue| {
+                if !issues.contains(&issue) {
+                    issues.push(issue);
+                }
+                issues
+            });
+
+        Ok(issues)
+    }
+
+    // Organization admins hold every role, while maintainers of any given team hold the maintainer role
+    async fn holds_role(
+        checker: &dyn CheckGithubConsistency,
+        organization: &str,
+        user: &GithubIdentityHandle,
+        teams: &[GithubTeamHandle],
+        required: OwnerRole,
+        retries: &RetryPolicy,
+    ) -> Result<bool, ConsistencyIssue> {
+        let organization_role = checker.organization_role(organization, user, retries).await?;
+
+        if organization_role.is_some_and(|role| role >= required) {
+            return Ok(true);
+        }
+
+        if required > OwnerRole::Maintainer {
+            return Ok(false);
+        }
+
+        for team in teams {
+            let team_role = checker.team_role(team, user, retries).await?;
+
+            if team_role.is_some_and(|role| role >= required) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    // A flawless CODEOWNERS is useless unless Github requires reviews from code owners before merging
+    async fn check_branch_protection(
+        checker: &dyn CheckGithubConsistency,
+        project_root: &Path,
+        canopus_config: &CanopusConfig,
+        branch: &str,
+        retries: &RetryPolicy,
+        github_timeout: Duration,
+    ) -> Vec<ConsistencyIssue> {
+        let unverifiable = ConsistencyIssue::CannotVerifyBranchProtection(branch.to_string());
+
+        let detected_repository = || {
+            git::origin_url(project_root)
+                .ok()
+                .and_then(|remote_url| RemoteRepository::from_remote_url(&remote_url))
+                .map(|repository| format!("{}/{}", repository.owner, repository.name))
+        };
+
+        let Some(repository) = canopus_config
+            .protection
+            .repository
+            .clone()
+            .or_else(detected_repository)
+        else {
+            log::info!("Cannot detect the Github repository holding {} branch", branch);
+            return vec![unverifiable];
+        };
+
+        let fetched =
+            tokio::time::timeout(github_timeout, checker.branch_protection(&repository, branch, retries)).await;
+
+        let protection = match fetched {
+            Ok(Ok(protection)) => protection,
+            Ok(Err(issue)) => return vec![issue],
+            Err(_) => {
+                log::info!("Fetching protection of {} branch timed out", branch);
+                return vec![unverifiable];
+            },
+        };
+
+        let mut issues = Vec::new();
+
+        if !protection.require_code_owner_reviews {
+            issues.push(ConsistencyIssue::CodeOwnersReviewNotRequired(branch.to_string()));
+        }
+
+        // Critical paths requiring several owners expect as many approvals before merging
+        let min_owners = canopus_config
+            .policies
+            .iter()
+            .filter_map(|policy| policy.min_owners)
+            .max();
+
+        if let Some(min_owners) = min_owners
+            && (protection.required_approvals as usize) < min_owners
+        {
+            issues.push(ConsistencyIssue::RequiredApprovalsBelowPolicies {
+                branch: branch.to_string(),
+                required_approvals: protection.required_approvals,
+                min_owners,
+            });
+        }
+
+        issues
+    }
+
+    // User owners not backed by any owning team are a bus-factor-of-one risk
+    async fn check_team_membership(
+        &self,
+        checker: &dyn CheckGithubConsistency,
+        code_owners: &CodeOwners,
+        canopus_config: &CanopusConfig,
+        request_permits: &Semaphore,
+        retries: &RetryPolicy,
+        github_timeout: Duration,
+    ) -> anyhow::Result<Vec<ConsistencyIssue>> {
+        let user_owners = code_owners
+            .unique_owners()
+            .into_iter()
+            .filter_map(|owner| match owner {
+                Owner::GithubUser(identity) => Some(identity.clone()),
+                _ => None,
+            })
+            .collect_vec();
+
+        if user_owners.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let owning_teams = match canopus_config.ownership.membership_teams.as_slice() {
+            [] => code_owners
+                .unique_owners()
+                .into_iter()
+                .filter_map(|owner| match owner {
+                    Owner::GithubTeam(team) => Some(team.clone()),
+                    _ => None,
+                })
+                .collect_vec(),
+            configured => configured
+                .iter()
+                .map(|raw_team| Self::parse_membership_team(raw_team))
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        };
+
+        let team_listings = owning_teams
+            .iter()
+            .map(|team| async {
+                let listing = async {
+                    let _permit = request_permits
+                        .acquire()
+                        .await
+                        .expect("FATAL: request permits are never closed");
+
+                    tokio::time::timeout(github_timeout, checker.team_members(team, retries))
+                        .await
+                        .unwrap_or_else(|_| Err(ConsistencyIssue::CannotVerifyTeam(team.clone())))
+                };
+
+                tokio::select! {
+                    members = listing => members,
+                    _ = self.interrupted() => Err(ConsistencyIssue::CannotVerifyTeam(team.clone())),
+                }
+            })
+            .collect_vec();
+
+        let (team_members, listing_issues): (Vec<_>, Vec<_>) = futures::future::join_all(team_listings)
+            .await
+            .into_iter()
+            .partition_result();
+
+        // We cannot tell whether someone belongs to an owning team without all listings
+        if !listing_issues.is_empty() {
+            return Ok(listing_issues);
+        }
+
+        let members_of_owning_teams = team_members
+            .into_iter()
+            .flatten()
+            .collect::<HashSet<GithubIdentityHandle>>();
+
+        let issues = user_owners
+            .into_iter()
+            .filter(|user| !members_of_owning_teams.contains(user))
+            .map(ConsistencyIssue::UserNotInOwningTeam)
+            .collect_vec();
+
+        Ok(issues)
+    }
+
+    fn parse_membership_team(raw_team: &str) -> anyhow::Result<GithubTeamHandle> {
+        let Some((organization, team)) = raw_team.trim_start_matches('@').split_once('/') else {
+            anyhow::bail!("invalid team '{raw_team}' in ownership.membership-teams (expected @org/team)");
+        };
+
+        if organization.is_empty() || team.is_empty() || team.contains('/') {
+            anyhow::bail!("invalid team '{raw_team}' in ownership.membership-teams (expected @org/team)");
+        }
+
+        let organization = GithubIdentityHandle::new(organization.to_string());
+        Ok(GithubTeamHandle::new(organization, team.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -344,7 +1381,7 @@ mod test_builders {
     use crate::core::models::codeowners::CodeOwnersContext;
     use crate::core::models::config;
     use crate::core::models::config::CanopusConfig;
-    use crate::infra::github::{FakeGithubState, GithubConsistencyChecker};
+    use crate::infra::github::{ConsistentGithubState, FakeGithubState, UnresponsiveGithub};
     use crate::infra::paths::PathWalker;
     use std::path::PathBuf;
 
@@ -368,7 +1405,7 @@ mod test_builders {
 
     pub fn structural_only_codeowners_validator(project_paths: Vec<&str>) -> CodeOwnersValidator {
         let path_walker = PathWalker::with_paths(project_paths);
-        let consistency_checker = GithubConsistencyChecker::ConsistentState;
+        let consistency_checker = Box::new(ConsistentGithubState);
         CodeOwnersValidator::new(consistency_checker, path_walker)
     }
 
@@ -377,18 +1414,28 @@ mod test_builders {
         state: FakeGithubState,
     ) -> CodeOwnersValidator {
         let path_walker = PathWalker::with_paths(project_paths);
-        let consistency_checker = GithubConsistencyChecker::FakeChecks(state);
+        let consistency_checker = Box::new(state);
+        CodeOwnersValidator::new(consistency_checker, path_walker)
+    }
+
+    pub fn unresponsive_codeowners_validator(project_paths: Vec<&str>) -> CodeOwnersValidator {
+        let path_walker = PathWalker::with_paths(project_paths);
+        let consistency_checker = Box::new(UnresponsiveGithub);
         CodeOwnersValidator::new(consistency_checker, path_walker)
     }
 }
 
 #[cfg(test)]
 mod structural_validation_tests {
-    use crate::canopus::validation::test_builders;
+    use crate::canopus::events::test_helpers::RecordingObserver;
+    use crate::canopus::validation::{CodeOwnersValidator, test_builders};
     use crate::core::models::test_helpers::ValidationIssueKindFactory;
-    use crate::core::models::{ValidationIssue, ValidationOutcome};
-    use assertor::{EqualityAssertion, ResultAssertion};
+    use crate::core::models::{IssueFix, ValidationIssue, ValidationOutcome};
+    use crate::infra::github::ConsistentGithubState;
+    use crate::infra::paths::PathWalker;
+    use assertor::{EqualityAssertion, ResultAssertion, VecAssertion};
     use indoc::indoc;
+    use itertools::Itertools;
 
     #[tokio::test]
     async fn should_find_no_syntax_issues() {
@@ -503,324 +1550,1745 @@ mod structural_validation_tests {
 
         let validation = validator.validate(&context, &config).await.unwrap();
 
-        let issue = ValidationIssue::builder()
-            .kind(ValidationIssueKindFactory::dangling_glob_pattern())
-            .line_number(1)
-            .description(".automation/** does not match any project path")
-            .build();
-
-        let expected = ValidationOutcome::IssuesDetected(vec![issue]);
+        let issue = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+            .line_number(1)
+            .description(".automation/** does not match any project path")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![issue]);
+
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_detect_globs_matching_only_ignored_paths() {
+        let contents = indoc! {"
+            *.rs                @dotanuki-labs/rustaceans
+            dist/**             @dotanuki-labs/infra
+            .automation/**      @dotanuki-labs/infra
+        "};
+
+        let path_walker = PathWalker::with_ignored_paths(vec!["validation.rs"], vec!["dist/canopus.tar.gz"]);
+        let validator = CodeOwnersValidator::new(Box::new(ConsistentGithubState), path_walker);
+
+        let context = test_builders::codeowners_attributes(contents);
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let ignored_paths_only = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::ignored_paths_only())
+            .line_number(1)
+            .description("dist/** only matches paths ignored by git")
+            .build();
+
+        let dangling_glob = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+            .line_number(2)
+            .description(".automation/** does not match any project path")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![ignored_paths_only, dangling_glob]);
+
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_detect_strictly_duplicated_ownership_rules() {
+        let contents = indoc! {"
+            *.rs            @org/rustaceans
+            docs/**/*.md    @org/devs
+            *.rs            @org/crabbers @ubiratansoares
+        "};
+
+        let project_paths = vec!["validation.rs", "docs/README.md", "docs/README.md"];
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let duplicated_ownership = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::duplicate_ownership())
+            .line_number(0)
+            .description("*.rs defined multiple times : lines [0, 2]")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![duplicated_ownership]);
+
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_detect_semantically_duplicated_ownership_rules() {
+        let contents = indoc! {"
+            docs/**     @org/devs
+            docs/       @org/writers
+            /docs/      @org/devs @ubiratansoares
+        "};
+
+        let project_paths = vec!["docs", "docs/README.md"];
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let duplicated_ownership = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::duplicate_ownership())
+            .line_number(0)
+            .description("docs/**, /docs/ defined multiple times : lines [0, 2]")
+            .build();
+
+        let near_duplicated_ownership = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::near_duplicate_ownership())
+            .line_number(0)
+            .description("docs/**, docs/, /docs/ likely target the same paths : lines [0, 1, 2]")
+            .build();
+
+        // Directory patterns are reported as dangling as well, which is not what we are after here
+        let ValidationOutcome::IssuesDetected(issues) = validation else {
+            panic!("expecting duplicated ownership rules");
+        };
+
+        let duplication_issues = issues
+            .into_iter()
+            .filter(|issue| issue.kind != ValidationIssueKindFactory::dangling_glob_pattern())
+            .collect_vec();
+
+        assertor::assert_that!(duplication_issues).is_equal_to(vec![duplicated_ownership, near_duplicated_ownership]);
+    }
+
+    #[tokio::test]
+    async fn should_suggest_merging_duplicated_rules_with_same_owners() {
+        let contents = indoc! {"
+            *.rs            @org/rustaceans @ubiratansoares
+            docs/**/*.md    @org/devs
+            *.rs            @ubiratansoares @org/rustaceans
+        "};
+
+        let project_paths = vec!["validation.rs", "docs/README.md"];
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let duplicated_ownership = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::duplicate_ownership())
+            .line_number(0)
+            .description("*.rs defined multiple times with the same owners : lines [0, 2]")
+            .fix(IssueFix::MergeDuplicates(vec![2]))
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![duplicated_ownership]);
+
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_detect_owner_repeated_within_rule() {
+        let contents = indoc! {"
+            *.rs    @org/rustaceans @ubiratansoares @Org/Rustaceans # Rust
+        "};
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::structural_only_codeowners_validator(vec!["main.rs"]);
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let repeated_owner = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::repeated_owner())
+            .line_number(0)
+            .description("@Org/Rustaceans listed more than once for *.rs")
+            .fix(IssueFix::RewriteLine(
+                0,
+                "*.rs    @org/rustaceans @ubiratansoares # Rust".to_string(),
+            ))
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![repeated_owner]);
+
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_detect_multiple_non_syntax_issues() {
+        let contents = indoc! {"
+            *.rs            @org/rustaceans
+            docs/**/*.md    @org/devs
+            *.rs            @org/crabbers @ubiratansoares
+        "};
+
+        let project_paths = vec!["validation.rs", ".github", ".github/CODEOWNERS"];
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let dangling_glob = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+            .line_number(1)
+            .description("docs/**/*.md does not match any project path")
+            .build();
+
+        let duplicated_ownership = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::duplicate_ownership())
+            .line_number(0)
+            .description("*.rs defined multiple times : lines [0, 2]")
+            .build();
+
+        let issues = vec![duplicated_ownership, dangling_glob];
+        let expected = ValidationOutcome::IssuesDetected(issues);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_keep_validating_lines_around_syntax_issues() {
+        let contents = indoc! {"
+            *.rs          @org/rustaceans not-an-owner
+            docs/**       @org/writers
+            [z-a]*.js     @org/frontend
+        "};
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::structural_only_codeowners_validator(vec!["main.rs"]);
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let invalid_owner = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::invalid_syntax())
+            .line_number(0)
+            .description("cannot parse owner")
+            .build();
+
+        let dangling_glob = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::dangling_glob_pattern())
+            .line_number(1)
+            .description("docs/** does not match any project path")
+            .build();
+
+        let invalid_glob = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::invalid_syntax())
+            .line_number(2)
+            .description("invalid glob pattern")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![invalid_owner, dangling_glob, invalid_glob]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_notify_observers_about_validation_lifecycle() {
+        let contents = indoc! {"
+            *.rs            @org/rustaceans
+            docs/**/*.md    @org/devs
+        "};
+
+        let observer = RecordingObserver::default();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let mut validator = test_builders::structural_only_codeowners_validator(vec!["main.rs"]);
+        validator.register_observer(Box::new(observer.clone()));
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        validator.validate(&context, &config).await.unwrap();
+
+        let expected = vec![
+            "parsed:2:2",
+            "phase:syntax:0",
+            "phase:limits:0",
+            "phase:duplicate-ownership:0",
+            "phase:repeated-owners:0",
+            "phase:owners-per-entry:0",
+            "phase:allowed-owners:0",
+            "phase:ordering:0",
+            "phase:whitespace:0",
+            "phase:location:0",
+            "phase:self-ownership:0",
+            "walked:1",
+            "issue:dangling-globs:1",
+            "phase:dangling-globs:1",
+            "phase:owners-per-path:0",
+            "phase:ownership-coverage:0",
+            "phase:external-checks:0",
+            "phase:github-consistency:0",
+        ];
+
+        assertor::assert_that!(observer.recorded()).is_equal_to(expected.into_iter().map(String::from).collect_vec());
+    }
+
+    #[tokio::test]
+    async fn should_stop_walking_once_every_glob_matches() {
+        let contents = indoc! {"
+            *.rs    @org/rustaceans
+        "};
+
+        let observer = RecordingObserver::default();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let mut validator =
+            test_builders::structural_only_codeowners_validator(vec!["main.rs", "lib.rs", "docs/using.md"]);
+        validator.register_observer(Box::new(observer.clone()));
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        assertor::assert_that!(validation).is_equal_to(ValidationOutcome::NoIssues);
+        assertor::assert_that!(observer.recorded()).contains(String::from("walked:1"));
+    }
+}
+
+#[cfg(test)]
+mod consistency_validation_tests {
+    use crate::canopus::events::test_helpers::RecordingObserver;
+    use crate::canopus::validation::test_builders;
+    use crate::core::models::codeowners::CodeOwnersContext;
+    use crate::core::models::config::{CanopusConfig, OwnershipConfig, PolicyConfig, ProtectionConfig};
+    use crate::core::models::handles::GithubIdentityHandle;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{ConsistencyIssue, IssueKind, OwnerRole, ValidationIssue, ValidationOutcome, config};
+    use crate::infra::github;
+    use assertor::{EqualityAssertion, ResultAssertion};
+    use console::style;
+    use indoc::indoc;
+    use itertools::Itertools;
+    use temp_dir::TempDir;
+
+    #[tokio::test]
+    async fn should_find_no_consistency_issues() {
+        let contents = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans
+            .github/**/*    @ubiratansoares
+        "};
+
+        let project_paths = vec![".github/CODEOWNERS", "main.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_user("@ubiratansoares")
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await;
+
+        assertor::assert_that!(validation).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_detect_non_existing_github_user() {
+        let contents = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans
+            .github/**/*    @ufs
+        "};
+
+        let project_paths = vec![".github/CODEOWNERS", "main.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let formatted_feedback = format!("user {} does not belong to this organization", style("ufs").cyan());
+
+        let user_not_found = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::user_does_not_belong_to_organization("ufs"))
+            .line_number(1)
+            .message(formatted_feedback)
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![user_not_found]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_detect_non_existing_github_team() {
+        let contents = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans
+            *.md            @dotanuki-labs/writers
+            .github/*.json  @dotanuki-labs/devops
+        "};
+
+        let project_paths = vec![".github/renovate.json", "README.md", "main.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .add_known_team("@dotanuki-labs/writers")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let formatted_feedback = format!(
+            "{} team not found for {} organization",
+            style("devops").cyan(),
+            style("dotanuki-labs").cyan()
+        );
+
+        let user_not_found = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::team_does_not_exist(
+                "dotanuki-labs",
+                "devops",
+            ))
+            .line_number(2)
+            .message(formatted_feedback)
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![user_not_found]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_detect_user_owners_outside_owning_teams() {
+        let contents = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans
+            docs/*.md       @ubiratansoares
+            .github/**/*    @dotanuki-bot
+        "};
+
+        let project_paths = vec![".github/CODEOWNERS", "docs/using.md", "main.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_user("@ubiratansoares")
+            .add_known_user("@dotanuki-bot")
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .add_team_member("@dotanuki-labs/rustaceans", "@ubiratansoares")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            ownership: OwnershipConfig {
+                require_team_membership: Some(true),
+                ..Default::default()
+            },
+            ..test_builders::simple_canopus_config("dotanuki-labs")
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let outside_owning_teams = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::user_not_in_owning_team("dotanuki-bot"))
+            .line_number(2)
+            .message(format!(
+                "owner {} is not in any owning team",
+                style("dotanuki-bot").cyan()
+            ))
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![outside_owning_teams]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_detect_teams_never_assigning_reviews() {
+        let contents = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans
+            docs/*.md       @dotanuki-labs/writers
+        "};
+
+        let project_paths = vec!["docs/using.md", "main.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .add_known_team("@dotanuki-labs/writers")
+            .disable_review_assignment("@dotanuki-labs/writers")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            ownership: OwnershipConfig {
+                require_review_assignment: Some(true),
+                ..Default::default()
+            },
+            ..test_builders::simple_canopus_config("dotanuki-labs")
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let never_assigning = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::team_review_assignment_disabled(
+                "dotanuki-labs",
+                "writers",
+            ))
+            .line_number(1)
+            .message(format!(
+                "team {}{}{} never assigns review requests to its members",
+                style("dotanuki-labs").cyan(),
+                style("/").cyan(),
+                style("writers").cyan(),
+            ))
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![never_assigning]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_skip_checks_requiring_credentials_when_anonymous() {
+        let contents = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans @ubiratansoares
+            docs/*.md       @itto-ogami
+        "};
+
+        let project_paths = vec!["docs/using.md", "main.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_user("@ubiratansoares")
+            .anonymous()
+            .build();
+
+        let observer = RecordingObserver::default();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let mut validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+        validator.register_observer(Box::new(observer.clone()));
+
+        let config = CanopusConfig {
+            ownership: OwnershipConfig {
+                require_team_membership: Some(true),
+                require_review_assignment: Some(true),
+                ..Default::default()
+            },
+            ..test_builders::simple_canopus_config("dotanuki-labs")
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let user_not_found = ValidationIssue::builder()
+            .kind(IssueKind::Consistency(ConsistencyIssue::UserDoesNotExist(
+                GithubIdentityHandle::new("itto-ogami".to_string()),
+            )))
+            .line_number(1)
+            .message(format!("{} user does not exist", style("itto-ogami").cyan()))
+            .build();
+
+        let skipped = observer
+            .recorded()
+            .into_iter()
+            .filter(|event| event.starts_with("skipped:"))
+            .collect_vec();
+
+        let expected_skipped = vec![
+            "skipped:outsider-user",
+            "skipped:team-does-not-exist",
+            "skipped:owner-not-in-owning-team",
+            "skipped:team-review-assignment-disabled",
+        ];
+
+        let expected = ValidationOutcome::IssuesDetected(vec![user_not_found]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+        assertor::assert_that!(skipped).is_equal_to(expected_skipped.into_iter().map(String::from).collect_vec());
+    }
+
+    #[tokio::test]
+    async fn should_detect_owners_below_required_role() {
+        let contents = indoc! {"
+            payments/**         @dotanuki-labs/payments
+            payments/*.sql      @octocat
+            payments/ledger/**  @dotanuki-labs/finance @ubiratansoares
+            payments/keys/**    @itto-ogami
+        "};
+
+        let project_paths = vec![
+            "payments/charges.rs",
+            "payments/schema.sql",
+            "payments/ledger/entries.rs",
+            "payments/keys/signing.pem",
+        ];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_user("@octocat")
+            .add_known_user("@ubiratansoares")
+            .add_known_team("@dotanuki-labs/payments")
+            .add_known_team("@dotanuki-labs/finance")
+            .add_team_maintainer("@dotanuki-labs/payments", "@itto-ogami")
+            .add_team_member("@dotanuki-labs/payments", "@dotanuki-bot")
+            .add_team_maintainer("@dotanuki-labs/finance", "@ubiratansoares")
+            .add_known_user("@itto-ogami")
+            .add_organization_admin("@itto-ogami")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            policies: vec![PolicyConfig {
+                patterns: vec!["payments/**".to_string()],
+                require_team_owners: false,
+                forbid_email_owners: false,
+                min_owners: None,
+                distinct_teams: false,
+                required_role: Some(OwnerRole::Maintainer),
+            }],
+            ..test_builders::simple_canopus_config("dotanuki-labs")
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let team_below_requirement = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::team_role_below_requirement(
+                "dotanuki-labs",
+                "payments",
+                OwnerRole::Maintainer,
+                0,
+            ))
+            .line_number(0)
+            .message(format!(
+                "some members of team {}{}{} neither maintain it nor administer the organization, while policies require a maintainer for this rule",
+                style("dotanuki-labs").cyan(),
+                style("/").cyan(),
+                style("payments").cyan(),
+            ))
+            .build();
+
+        let user_below_requirement = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::user_role_below_requirement(
+                "octocat",
+                OwnerRole::Maintainer,
+                1,
+            ))
+            .line_number(1)
+            .message(format!(
+                "user {} neither maintains an owning team nor administers the organization, while policies require a maintainer for this rule",
+                style("octocat").cyan(),
+            ))
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![team_below_requirement, user_below_requirement]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_cross_check_branch_protection() {
+        let contents = indoc! {"
+            payments/**     @dotanuki-labs/payments @dotanuki-labs/finance
+        "};
+
+        let project_paths = vec!["payments/charges.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_team("@dotanuki-labs/payments")
+            .add_known_team("@dotanuki-labs/finance")
+            .protect_branch(false, 1)
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            protection: ProtectionConfig {
+                branch: Some("main".to_string()),
+                repository: Some("dotanuki-labs/canopus".to_string()),
+            },
+            policies: vec![PolicyConfig {
+                patterns: vec!["payments/**".to_string()],
+                require_team_owners: false,
+                forbid_email_owners: false,
+                min_owners: Some(2),
+                distinct_teams: false,
+                required_role: None,
+            }],
+            ..test_builders::simple_canopus_config("dotanuki-labs")
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let review_not_required = ConsistencyIssue::CodeOwnersReviewNotRequired("main".to_string());
+
+        let approvals_below_policies = ConsistencyIssue::RequiredApprovalsBelowPolicies {
+            branch: "main".to_string(),
+            required_approvals: 1,
+            min_owners: 2,
+        };
+
+        let issues = match validation {
+            ValidationOutcome::IssuesDetected(issues) => issues.into_iter().map(|issue| issue.kind).collect_vec(),
+            ValidationOutcome::NoIssues => vec![],
+        };
+
+        let expected = vec![
+            IssueKind::Consistency(review_not_required),
+            IssueKind::Consistency(approvals_below_policies),
+        ];
+
+        assertor::assert_that!(issues).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_verify_owners_against_roster_when_offline() {
+        let contents = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans @ubiratansoares
+            docs/*.md       @dotanuki-labs/writers
+            *.yml           @octocat
+        "};
+
+        let roster = indoc! {"
+            roster:
+              version: 1
+              organization: dotanuki-labs
+              members: [ubiratansoares]
+            teams:
+              rustaceans: [ubiratansoares]
+        "};
+
+        let project_dir = TempDir::new().unwrap();
+        std::fs::write(project_dir.path().join("owners-roster.yaml"), roster).unwrap();
+
+        let context = CodeOwnersContext {
+            project_path: project_dir.path().to_path_buf(),
+            codeowners_path: project_dir.path().join(".github/CODEOWNERS"),
+            contents: contents.to_string(),
+        };
+
+        let project_paths = vec!["docs/using.md", "main.rs", "release.yml"];
+        let github_state = github::FakeGithubState::builder().build();
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                roster_file: Some("owners-roster.yaml".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let issues = match validation {
+            ValidationOutcome::IssuesDetected(issues) => issues.into_iter().map(|issue| issue.kind).collect_vec(),
+            ValidationOutcome::NoIssues => vec![],
+        };
+
+        let expected = vec![
+            ValidationIssueKindFactory::team_does_not_exist("dotanuki-labs", "writers"),
+            ValidationIssueKindFactory::user_does_not_belong_to_organization("octocat"),
+        ];
+
+        assertor::assert_that!(issues).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_check_team_membership_against_configured_teams() {
+        let contents = indoc! {"
+            *.rs    @dotanuki-labs/rustaceans @ubiratansoares
+        "};
+
+        let project_paths = vec!["main.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_user("@ubiratansoares")
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .add_team_member("@dotanuki-labs/maintainers", "@ubiratansoares")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            ownership: OwnershipConfig {
+                require_team_membership: Some(true),
+                membership_teams: vec!["@dotanuki-labs/maintainers".to_string()],
+                ..Default::default()
+            },
+            ..test_builders::simple_canopus_config("dotanuki-labs")
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        assertor::assert_that!(validation).is_equal_to(ValidationOutcome::NoIssues);
+    }
+
+    #[tokio::test]
+    async fn should_accept_outside_collaborators_when_configured() {
+        let contents = indoc! {"
+            *.rs        @dotanuki-labs/rustaceans @itto-ogami
+            docs/*      @daigoro
+            .github/*   @ufs
+        "};
+
+        let project_paths = vec![".github/CODEOWNERS", "main.rs", "docs/using.md"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .add_repository_collaborator("@itto-ogami")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            ownership: OwnershipConfig {
+                allow_outside_collaborators: Some(true),
+                collaborators_repository: Some("dotanuki-labs/canopus".to_string()),
+                outside_collaborators: vec!["@daigoro".to_string()],
+                ..Default::default()
+            },
+            ..test_builders::simple_canopus_config("dotanuki-labs")
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let outsider = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::user_does_not_belong_to_organization("ufs"))
+            .line_number(2)
+            .message(format!(
+                "user {} does not belong to this organization",
+                style("ufs").cyan()
+            ))
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![outsider]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_accept_teams_from_sibling_organizations() {
+        let contents = indoc! {"
+            *.rs        @dotanuki-labs/rustaceans
+            docs/*      @dotanuki-tools/writers
+            .github/*   @itto-ogami/infra
+        "};
+
+        let project_paths = vec![".github/CODEOWNERS", "main.rs", "docs/using.md"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .add_known_team("@dotanuki-tools/writers")
+            .add_known_team("@itto-ogami/infra")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                github_organizations: vec!["dotanuki-tools".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let outsider_team = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::team_does_not_match_organization(
+                "itto-ogami",
+                "infra",
+            ))
+            .line_number(2)
+            .message(format!(
+                "team {}{}{} does not belong to this organization",
+                style("itto-ogami").cyan(),
+                style("/").cyan(),
+                style("infra").cyan()
+            ))
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![outsider_team]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_skip_github_checks_once_reaching_max_issues() {
+        let contents = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans
+            .github/**/*    @ufs
+            docs/*.md       dotanuki-labs/writers
+        "};
+
+        let project_paths = vec![".github/CODEOWNERS", "main.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                max_issues: Some(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let invalid_owner = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::invalid_syntax())
+            .line_number(2)
+            .description("cannot parse owner")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![invalid_owner]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_report_owners_as_unverifiable_when_github_times_out() {
+        let contents = indoc! {"
+            *.rs    @dotanuki-labs/rustaceans
+            *.md    @ubiratansoares
+        "};
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::unresponsive_codeowners_validator(vec!["main.rs", "README.md"]);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                github_timeout_seconds: Some(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let team_timed_out = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::cannot_verify_team(
+                "dotanuki-labs",
+                "rustaceans",
+            ))
+            .line_number(0)
+            .message(format!(
+                "cannot confirm whether {}{}{} team exists",
+                style("dotanuki-labs").cyan(),
+                style("/").cyan(),
+                style("rustaceans").cyan()
+            ))
+            .build();
+
+        let user_timed_out = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::cannot_verify_user("ubiratansoares"))
+            .line_number(1)
+            .message(format!(
+                "cannot confirm if user {} exists",
+                style("ubiratansoares").cyan()
+            ))
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![team_timed_out, user_timed_out]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_keep_issues_found_so_far_when_interrupted() {
+        let contents = indoc! {"
+            *.rs        @ubiratansoares
+            docs/**     @dotanuki-labs/writers
+        "};
+
+        let context = test_builders::codeowners_attributes(contents);
+        let mut validator = test_builders::unresponsive_codeowners_validator(vec!["main.rs"]);
+        validator.interrupt_on(async {});
+
+        let config = test_builders::simple_canopus_config("dotanuki-labs");
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let kinds = match validation {
+            ValidationOutcome::IssuesDetected(issues) => issues.into_iter().map(|issue| issue.kind).collect_vec(),
+            ValidationOutcome::NoIssues => vec![],
+        };
+
+        let expected = vec![
+            ValidationIssueKindFactory::cannot_verify_user("ubiratansoares"),
+            ValidationIssueKindFactory::dangling_glob_pattern(),
+            ValidationIssueKindFactory::cannot_verify_team("dotanuki-labs", "writers"),
+        ];
+
+        assertor::assert_that!(kinds).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_report_every_occurrence_of_an_owner_when_configured() {
+        let contents = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans
+            .github/**/*    @ufs
+            docs/*.md       @dotanuki-labs/rustaceans @ufs
+        "};
+
+        let project_paths = vec![".github/CODEOWNERS", "docs/using.md", "main.rs"];
+
+        let github_state = github::FakeGithubState::builder()
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .build();
+
+        let context = test_builders::codeowners_attributes(contents);
+        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+
+        let config = CanopusConfig {
+            ownership: OwnershipConfig {
+                report_every_occurrence: Some(true),
+                ..Default::default()
+            },
+            ..test_builders::simple_canopus_config("dotanuki-labs")
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let issues = [1, 2]
+            .into_iter()
+            .map(|line| {
+                ValidationIssue::builder()
+                    .kind(ValidationIssueKindFactory::user_does_not_belong_to_organization("ufs"))
+                    .line_number(line)
+                    .message(format!(
+                        "user {} does not belong to this organization",
+                        style("ufs").cyan()
+                    ))
+                    .build()
+            })
+            .collect_vec();
+
+        let expected = ValidationOutcome::IssuesDetected(issues);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+}
+
+#[cfg(test)]
+mod configuration_aware_tests {
+    use crate::canopus::validation::CodeOwnersValidator;
+    use crate::canopus::validation::test_builders;
+    use crate::core::models::checks::IssueSeverity;
+    use crate::core::models::codeowners::{CodeOwnersContext, GITHUB_CODEOWNERS_SIZE_LIMIT};
+    use crate::core::models::config::{
+        CanopusConfig, ChecksConfig, CoverageConfig, ExternalCheckConfig, LimitsConfig, OwnershipConfig, StyleConfig,
+    };
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{IssueFix, ValidationIssue, ValidationOutcome, config};
+    use crate::infra::git;
+    use crate::infra::github::{ConsistentGithubState, FakeGithubState};
+    use crate::infra::paths::PathWalker;
+    use assertor::{EqualityAssertion, ResultAssertion};
+    use indoc::indoc;
+    use temp_dir::TempDir;
+
+    #[tokio::test]
+    async fn should_honor_offline_checks_only() {
+        let contents = indoc! {"
+            *.rs    @org/rustaceans
+        "};
+
+        let project_paths = vec!["main.rs"];
+
+        let context = test_builders::codeowners_attributes(contents);
+
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await;
+
+        assertor::assert_that!(validation).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_deny_email_owners() {
+        let contents = indoc! {"
+            *.rs    me@hakagi.dev
+        "};
+
+        let project_paths = vec!["main.rs"];
+
+        let context = test_builders::codeowners_attributes(contents);
+
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            ownership: OwnershipConfig {
+                forbid_email_owners: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let email_owner_not_allowed = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::github_owners_only())
+            .line_number(0)
+            .description("email owner is not allowed")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![email_owner_not_allowed]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_enforce_github_teams_owners() {
+        let contents = indoc! {"
+            *.rs    @ubiratansoares
+        "};
+
+        let project_paths = vec!["main.rs"];
+
+        let context = test_builders::codeowners_attributes(contents);
+
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            ownership: OwnershipConfig {
+                enforce_github_teams_owners: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let only_team_owner_allowed = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::github_team_owners_only())
+            .line_number(0)
+            .description("only github team owner is allowed")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![only_team_owner_allowed]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_enforce_single_owner_per_entry() {
+        let contents = indoc! {"
+            *.rs    @ubiratansoares @dotanukibot
+        "};
+
+        let project_paths = vec!["main.rs"];
+
+        let context = test_builders::codeowners_attributes(contents);
+
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            ownership: OwnershipConfig {
+                enforce_one_owner_per_line: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let only_one_owner_allowed = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::single_owner_only())
+            .line_number(0)
+            .description("Entry defines more than one owner for this glob")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![only_one_owner_allowed]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_enforce_owners_and_rules_ordering() {
+        let contents = indoc! {"
+            src/**      @ubiratansoares @dotanuki-labs/rustaceans
+            Cargo.toml  @dotanuki-labs/rustaceans
+
+            docs/**     @dotanuki-labs/writers
+        "};
+
+        let project_paths = vec!["src/main.rs", "Cargo.toml", "docs/README.md"];
+
+        let context = test_builders::codeowners_attributes(contents);
+
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            style: StyleConfig {
+                sort_owners_alphabetically: Some(true),
+                sort_rules_by_path: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let owners_not_sorted = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::owners_not_sorted())
+            .line_number(0)
+            .description("Owners are not sorted alphabetically")
+            .fix(IssueFix::RewriteLine(
+                0,
+                "src/**      @dotanuki-labs/rustaceans @ubiratansoares".to_string(),
+            ))
+            .build();
+
+        let rules_not_sorted = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::rules_not_sorted())
+            .line_number(0)
+            .description("Rules are not sorted by path : lines 1 to 2")
+            .fix(IssueFix::ReorderLines(vec![1, 0]))
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![owners_not_sorted, rules_not_sorted]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_enforce_rules_within_sections() {
+        let contents = indoc! {"
+            Cargo.toml  @dotanuki-labs/rustaceans
+
+            # === Sources ===
+            src/**      @dotanuki-labs/rustaceans
+        "};
+
+        let project_paths = vec!["src/main.rs", "Cargo.toml"];
+
+        let context = test_builders::codeowners_attributes(contents);
+
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            style: StyleConfig {
+                require_sections: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let rule_outside_section = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::rule_outside_section())
+            .line_number(0)
+            .description("Rule does not belong to any section, like # === Backend ===")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![rule_outside_section]);
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_enforce_size_limits() {
+        let oversized_comment = format!("# {}", "=".repeat(GITHUB_CODEOWNERS_SIZE_LIMIT));
+        let contents =
+            format!("*.rs    @dotanuki-labs/rustaceans\n*.md    @dotanuki-labs/writers\n{oversized_comment}\n");
+
+        let project_paths = vec!["main.rs", "README.md"];
+
+        let context = test_builders::codeowners_attributes(&contents);
+
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            limits: LimitsConfig {
+                max_rules: Some(1),
+                max_line_length: Some(40),
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::line_too_long())
+                .line_number(2)
+                .message(format!(
+                    "Line has {} characters, exceeding the maximum of 40",
+                    oversized_comment.len()
+                ))
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::file_size_limit_exceeded())
+                .line_number(usize::MAX)
+                .message(format!(
+                    "CODEOWNERS has {} bytes, exceeding the {} bytes accepted by Github",
+                    contents.len(),
+                    GITHUB_CODEOWNERS_SIZE_LIMIT
+                ))
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::too_many_rules())
+                .line_number(usize::MAX)
+                .description("CODEOWNERS defines 2 rules, exceeding the maximum of 1")
+                .build(),
+        ]);
+
+        assertor::assert_that!(validation).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_require_codeowners_owned_by_administrators() {
+        let contents = indoc! {"
+            *               @dotanuki-labs/devs
+            .github/*.toml  @dotanuki-labs/admins
+        "};
+
+        let project_paths = vec![".github/CODEOWNERS", ".github/canopus.toml"];
+
+        let context = test_builders::codeowners_attributes(contents);
+
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            ownership: OwnershipConfig {
+                codeowners_admins: vec!["@dotanuki-labs/admins".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::unowned_codeowners())
+                .line_number(0)
+                .message(
+                    ".github/CODEOWNERS is not owned by any of the CODEOWNERS administrators (@dotanuki-labs/admins)"
+                        .to_string(),
+                )
+                .build(),
+        ]);
 
         assertor::assert_that!(validation).is_equal_to(expected);
     }
 
     #[tokio::test]
-    async fn should_detect_strictly_duplicated_ownership_rules() {
+    async fn should_report_findings_from_external_checks() {
         let contents = indoc! {"
-            *.rs            @org/rustaceans
-            docs/**/*.md    @org/devs
-            *.rs            @org/crabbers @ubiratansoares
+            *.rs    @dotanuki-labs/rustaceans
+            *.md    @ubiratansoares
         "};
 
-        let project_paths = vec!["validation.rs", "docs/README.md", "docs/README.md"];
-
         let context = test_builders::codeowners_attributes(contents);
-        let validator = test_builders::structural_only_codeowners_validator(project_paths);
 
-        let config = test_builders::simple_canopus_config("dotanuki-labs");
+        let validator = test_builders::structural_only_codeowners_validator(vec!["main.rs", "README.md"]);
+
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            checks: ChecksConfig {
+                external: vec![ExternalCheckConfig {
+                    name: "teams-only".to_string(),
+                    command: vec![
+                        "sh".to_string(),
+                        "-c".to_string(),
+                        r#"grep -q '"owners":\["@ubiratansoares"\]' && echo '2: owners must be teams'"#.to_string(),
+                    ],
+                }],
+            },
+            ..Default::default()
+        };
 
         let validation = validator.validate(&context, &config).await.unwrap();
 
-        let duplicated_ownership = ValidationIssue::builder()
-            .kind(ValidationIssueKindFactory::duplicate_ownership())
-            .line_number(0)
-            .description("*.rs defined multiple times : lines [0, 2]")
+        let issue = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::external_check_violation())
+            .line_number(1)
+            .description("[teams-only] owners must be teams")
             .build();
 
-        let expected = ValidationOutcome::IssuesDetected(vec![duplicated_ownership]);
-
-        assertor::assert_that!(validation).is_equal_to(expected);
+        assertor::assert_that!(validation).is_equal_to(ValidationOutcome::IssuesDetected(vec![issue]));
     }
 
     #[tokio::test]
-    async fn should_detect_multiple_non_syntax_issues() {
-        let contents = indoc! {"
-            *.rs            @org/rustaceans
-            docs/**/*.md    @org/devs
-            *.rs            @org/crabbers @ubiratansoares
-        "};
+    async fn should_enforce_whitespace_style() {
+        let contents = "*.rs\t@dotanuki-labs/rustaceans\ndocs/** @dotanuki-labs/writers  \n# Misc\nCargo.toml   @dotanuki-labs/rustaceans";
 
-        let project_paths = vec!["validation.rs", ".github", ".github/CODEOWNERS"];
+        let project_paths = vec!["main.rs", "docs/README.md", "Cargo.toml"];
 
         let context = test_builders::codeowners_attributes(contents);
+
         let validator = test_builders::structural_only_codeowners_validator(project_paths);
 
-        let config = test_builders::simple_canopus_config("dotanuki-labs");
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            style: StyleConfig {
+                forbid_tabs: Some(true),
+                forbid_trailing_whitespace: Some(true),
+                require_final_newline: Some(true),
+                owners_column: Some(13),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
 
         let validation = validator.validate(&context, &config).await.unwrap();
 
-        let dangling_glob = ValidationIssue::builder()
-            .kind(ValidationIssueKindFactory::dangling_glob_pattern())
-            .line_number(1)
-            .description("docs/**/*.md does not match any project path")
-            .build();
-
-        let duplicated_ownership = ValidationIssue::builder()
-            .kind(ValidationIssueKindFactory::duplicate_ownership())
-            .line_number(0)
-            .description("*.rs defined multiple times : lines [0, 2]")
-            .build();
+        let first_rule_fix = IssueFix::RewriteLine(0, "*.rs        @dotanuki-labs/rustaceans".to_string());
+        let second_rule_fix = IssueFix::RewriteLine(1, "docs/**     @dotanuki-labs/writers".to_string());
+
+        let expected = ValidationOutcome::IssuesDetected(vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::tab_characters())
+                .line_number(0)
+                .description("Line contains tab characters")
+                .fix(first_rule_fix.clone())
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::misaligned_owners())
+                .line_number(0)
+                .description("Owners start before column 13")
+                .fix(first_rule_fix)
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::trailing_whitespace())
+                .line_number(1)
+                .description("Line ends with whitespace")
+                .fix(second_rule_fix.clone())
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::misaligned_owners())
+                .line_number(1)
+                .description("Owners start before column 13")
+                .fix(second_rule_fix)
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::missing_final_newline())
+                .line_number(3)
+                .description("File does not end with a newline")
+                .fix(IssueFix::InsertFinalNewline)
+                .build(),
+        ]);
 
-        let issues = vec![duplicated_ownership, dangling_glob];
-        let expected = ValidationOutcome::IssuesDetected(issues);
         assertor::assert_that!(validation).is_equal_to(expected);
     }
-}
-
-#[cfg(test)]
-mod consistency_validation_tests {
-    use crate::canopus::validation::test_builders;
-    use crate::core::models::test_helpers::ValidationIssueKindFactory;
-    use crate::core::models::{ValidationIssue, ValidationOutcome};
-    use crate::infra::github;
-    use assertor::{EqualityAssertion, ResultAssertion};
-    use console::style;
-    use indoc::indoc;
 
     #[tokio::test]
-    async fn should_find_no_consistency_issues() {
+    async fn should_enforce_minimum_ownership_coverage() {
         let contents = indoc! {"
-            *.rs            @dotanuki-labs/rustaceans
-            .github/**/*    @ubiratansoares
+            *.rs    @dotanuki-labs/rustaceans
         "};
 
-        let project_paths = vec![".github/CODEOWNERS", "main.rs"];
-
-        let github_state = github::FakeGithubState::builder()
-            .add_known_user("@ubiratansoares")
-            .add_known_team("@dotanuki-labs/rustaceans")
-            .build();
+        let project_paths = vec!["main.rs", "lib.rs", "README.md", "Cargo.toml"];
 
         let context = test_builders::codeowners_attributes(contents);
-        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
 
-        let config = test_builders::simple_canopus_config("dotanuki-labs");
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
 
-        let validation = validator.validate(&context, &config).await;
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            coverage: CoverageConfig {
+                minimum: Some(90.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
 
-        assertor::assert_that!(validation).is_ok();
+        let validation = validator.validate(&context, &config).await.unwrap();
+
+        let below_minimum = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::coverage_below_minimum())
+            .line_number(usize::MAX)
+            .description("ownership coverage is 50.00% (2 of 4 files), below the minimum of 90%")
+            .build();
+
+        let expected = ValidationOutcome::IssuesDetected(vec![below_minimum]);
+        assertor::assert_that!(validation).is_equal_to(expected);
     }
 
     #[tokio::test]
-    async fn should_detect_non_existing_github_user() {
+    async fn should_exclude_paths_from_ownership_coverage() {
         let contents = indoc! {"
-            *.rs            @dotanuki-labs/rustaceans
-            .github/**/*    @ufs
+            *.rs    @dotanuki-labs/rustaceans
         "};
 
-        let project_paths = vec![".github/CODEOWNERS", "main.rs"];
-
-        let github_state = github::FakeGithubState::builder()
-            .add_known_team("@dotanuki-labs/rustaceans")
-            .build();
+        let project_paths = vec!["main.rs", "lib.rs", "legacy/README.md", "third_party/acme.c"];
 
         let context = test_builders::codeowners_attributes(contents);
-        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
-
-        let config = test_builders::simple_canopus_config("dotanuki-labs");
 
-        let validation = validator.validate(&context, &config).await.unwrap();
+        let validator = test_builders::structural_only_codeowners_validator(project_paths);
 
-        let formatted_feedback = format!("user {} does not belong to this organization", style("ufs").cyan());
+        let config = CanopusConfig {
+            general: config::GeneralConfig {
+                github_organization: "dotanuki-labs".to_string(),
+                offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            coverage: CoverageConfig {
+                minimum: Some(100.0),
+                exclude: vec!["legacy/**".to_string(), "third_party/**".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
 
-        let user_not_found = ValidationIssue::builder()
-            .kind(ValidationIssueKindFactory::user_does_not_belong_to_organization("ufs"))
-            .line_number(1)
-            .message(formatted_feedback)
-            .build();
+        let validation = validator.validate(&context, &config).await.unwrap();
 
-        let expected = ValidationOutcome::IssuesDetected(vec![user_not_found]);
-        assertor::assert_that!(validation).is_equal_to(expected);
+        assertor::assert_that!(validation).is_equal_to(ValidationOutcome::NoIssues);
     }
 
     #[tokio::test]
-    async fn should_detect_non_existing_github_team() {
+    async fn should_detect_codeowners_not_read_by_github() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let repository_root = temp_dir.path().canonicalize().unwrap();
+        let project_root = repository_root.join("app");
+        std::fs::create_dir_all(project_root.join(".github")).unwrap();
+
         let contents = indoc! {"
-            *.rs            @dotanuki-labs/rustaceans
-            *.md            @dotanuki-labs/writers
-            .github/*.json  @dotanuki-labs/devops
+            *.rs    @dotanuki-labs/rustaceans
         "};
 
-        let project_paths = vec![".github/renovate.json", "README.md", "main.rs"];
-
-        let github_state = github::FakeGithubState::builder()
-            .add_known_team("@dotanuki-labs/rustaceans")
-            .add_known_team("@dotanuki-labs/writers")
-            .build();
+        std::fs::write(project_root.join(".github/CODEOWNERS"), contents).unwrap();
+        git::test_helpers::git(&repository_root, &["init", "-q"]);
 
-        let context = test_builders::codeowners_attributes(contents);
-        let validator = test_builders::consistency_aware_codeowners_validator(project_paths, github_state);
+        let context = CodeOwnersContext::try_from(project_root.clone()).unwrap();
 
+        let validator = test_builders::structural_only_codeowners_validator(vec!["main.rs"]);
         let config = test_builders::simple_canopus_config("dotanuki-labs");
 
         let validation = validator.validate(&context, &config).await.unwrap();
 
-        let formatted_feedback = format!(
-            "{} team not found for {} organization",
-            style("devops").cyan(),
-            style("dotanuki-labs").cyan()
-        );
-
-        let user_not_found = ValidationIssue::builder()
-            .kind(ValidationIssueKindFactory::team_does_not_exist(
-                "dotanuki-labs",
-                "devops",
+        let unhonored_location = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::unhonored_location())
+            .line_number(usize::MAX)
+            .message(format!(
+                "Github ignores app/.github/CODEOWNERS, only reading CODEOWNERS from .github/CODEOWNERS, CODEOWNERS, docs/CODEOWNERS at the root of {}",
+                repository_root.to_string_lossy()
             ))
-            .line_number(2)
-            .message(formatted_feedback)
             .build();
 
-        let expected = ValidationOutcome::IssuesDetected(vec![user_not_found]);
+        let expected = ValidationOutcome::IssuesDetected(vec![unhonored_location]);
         assertor::assert_that!(validation).is_equal_to(expected);
     }
-}
-
-#[cfg(test)]
-mod configuration_aware_tests {
-    use crate::canopus::validation::test_builders;
-    use crate::core::models::config::{CanopusConfig, OwnershipConfig};
-    use crate::core::models::test_helpers::ValidationIssueKindFactory;
-    use crate::core::models::{ValidationIssue, ValidationOutcome, config};
-    use assertor::{EqualityAssertion, ResultAssertion};
-    use indoc::indoc;
 
     #[tokio::test]
-    async fn should_honor_offline_checks_only() {
+    async fn should_match_anchored_patterns_when_validating_from_outside_project() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let project_root = temp_dir.path();
+        std::fs::create_dir_all(project_root.join(".github")).unwrap();
+        std::fs::create_dir_all(project_root.join("src")).unwrap();
+        std::fs::write(project_root.join("src/main.rs"), "fn main() {}").unwrap();
+        std::fs::write(project_root.join("README.md"), "# Hello").unwrap();
+        git::test_helpers::git(project_root, &["init", "-q"]);
+
         let contents = indoc! {"
-            *.rs    @org/rustaceans
+            src/**          @dotanuki-labs/rustaceans
+            .github/**      @dotanuki-labs/admins
+            README.md       @dotanuki-labs/writers
         "};
 
-        let project_paths = vec!["main.rs"];
+        std::fs::write(project_root.join(".github/CODEOWNERS"), contents).unwrap();
 
-        let context = test_builders::codeowners_attributes(contents);
+        let context = CodeOwnersContext {
+            project_path: project_root.to_path_buf(),
+            codeowners_path: project_root.join(".github/CODEOWNERS"),
+            contents: contents.to_string(),
+        };
 
-        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+        let validator = CodeOwnersValidator::new(Box::new(ConsistentGithubState), PathWalker::GitAware);
 
         let config = CanopusConfig {
             general: config::GeneralConfig {
                 github_organization: "dotanuki-labs".to_string(),
                 offline_checks_only: Some(true),
+                ..Default::default()
+            },
+            coverage: CoverageConfig {
+                minimum: Some(100.0),
+                ..Default::default()
             },
             ..Default::default()
         };
 
-        let validation = validator.validate(&context, &config).await;
+        // Tests run from the crate directory, hence far from the project being validated
+        let validation = validator.validate(&context, &config).await.unwrap();
 
-        assertor::assert_that!(validation).is_ok();
+        assertor::assert_that!(validation).is_equal_to(ValidationOutcome::NoIssues);
     }
 
     #[tokio::test]
-    async fn should_deny_email_owners() {
-        let contents = indoc! {"
-            *.rs    me@hakagi.dev
+    async fn should_detect_decreased_ownership_coverage() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let project_root = temp_dir.path();
+        std::fs::create_dir_all(project_root.join(".github")).unwrap();
+
+        let base_contents = indoc! {"
+            *.rs    @dotanuki-labs/rustaceans
+            *.md    @dotanuki-labs/writers
         "};
 
-        let project_paths = vec!["main.rs"];
+        std::fs::write(project_root.join(".github/CODEOWNERS"), base_contents).unwrap();
+        git::test_helpers::git(project_root, &["init", "-q"]);
+        git::test_helpers::git(project_root, &["add", "-A"]);
+        git::test_helpers::git(project_root, &["commit", "-q", "-m", "baseline"]);
 
-        let context = test_builders::codeowners_attributes(contents);
+        let contents = indoc! {"
+            *.rs    @dotanuki-labs/rustaceans
+        "};
 
-        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+        let context = CodeOwnersContext {
+            project_path: project_root.to_path_buf(),
+            codeowners_path: project_root.join(".github/CODEOWNERS"),
+            contents: contents.to_string(),
+        };
+
+        let validator = test_builders::structural_only_codeowners_validator(vec!["main.rs", "README.md"]);
 
         let config = CanopusConfig {
             general: config::GeneralConfig {
                 github_organization: "dotanuki-labs".to_string(),
                 offline_checks_only: Some(true),
+                ..Default::default()
             },
-            ownership: OwnershipConfig {
-                forbid_email_owners: Some(true),
+            coverage: CoverageConfig {
+                base_ref: Some("HEAD".to_string()),
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         let validation = validator.validate(&context, &config).await.unwrap();
 
-        let email_owner_not_allowed = ValidationIssue::builder()
-            .kind(ValidationIssueKindFactory::github_owners_only())
-            .line_number(0)
-            .description("email owner is not allowed")
+        let decreased = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::coverage_decreased())
+            .line_number(usize::MAX)
+            .description("ownership coverage decreased from 100.00% at HEAD to 50.00%")
             .build();
 
-        let expected = ValidationOutcome::IssuesDetected(vec![email_owner_not_allowed]);
+        let expected = ValidationOutcome::IssuesDetected(vec![decreased]);
         assertor::assert_that!(validation).is_equal_to(expected);
     }
 
     #[tokio::test]
-    async fn should_enforce_github_teams_owners() {
+    async fn should_report_warnings_as_errors_in_strict_mode() {
         let contents = indoc! {"
-            *.rs    @ubiratansoares
+            docs/       @dotanuki-labs/writers
+            /docs/      @dotanuki-labs/writers
         "};
 
-        let project_paths = vec!["main.rs"];
+        let project_paths = vec!["docs/using.md"];
 
         let context = test_builders::codeowners_attributes(contents);
-
         let validator = test_builders::structural_only_codeowners_validator(project_paths);
 
         let config = CanopusConfig {
-            general: config::GeneralConfig {
-                github_organization: "dotanuki-labs".to_string(),
-                offline_checks_only: Some(true),
-            },
-            ownership: OwnershipConfig {
-                enforce_github_teams_owners: Some(true),
-                ..Default::default()
-            },
+            strict: true,
+            ..test_builders::simple_canopus_config("dotanuki-labs")
         };
 
-        let validation = validator.validate(&context, &config).await.unwrap();
+        let ValidationOutcome::IssuesDetected(issues) = validator.validate(&context, &config).await.unwrap() else {
+            panic!("expecting near-duplicate rules to be reported");
+        };
 
-        let only_team_owner_allowed = ValidationIssue::builder()
-            .kind(ValidationIssueKindFactory::github_team_owners_only())
-            .line_number(0)
-            .description("only github team owner is allowed")
-            .build();
+        let near_duplicate = issues
+            .iter()
+            .find(|issue| issue.kind.definition().name == "near-duplicate-ownership")
+            .map(|issue| issue.severity);
 
-        let expected = ValidationOutcome::IssuesDetected(vec![only_team_owner_allowed]);
-        assertor::assert_that!(validation).is_equal_to(expected);
+        assertor::assert_that!(near_duplicate).is_equal_to(Some(IssueSeverity::Error));
     }
 
     #[tokio::test]
-    async fn should_enforce_single_owner_per_entry() {
+    async fn should_require_credentials_in_strict_mode() {
         let contents = indoc! {"
-            *.rs    @ubiratansoares @dotanukibot
+            *.rs    @ubiratansoares
         "};
 
-        let project_paths = vec!["main.rs"];
+        let github_state = FakeGithubState::builder()
+            .add_known_user("@ubiratansoares")
+            .anonymous()
+            .build();
 
         let context = test_builders::codeowners_attributes(contents);
-
-        let validator = test_builders::structural_only_codeowners_validator(project_paths);
+        let validator = test_builders::consistency_aware_codeowners_validator(vec!["main.rs"], github_state);
 
         let config = CanopusConfig {
-            general: config::GeneralConfig {
-                github_organization: "dotanuki-labs".to_string(),
-                offline_checks_only: Some(true),
-            },
-            ownership: OwnershipConfig {
-                enforce_one_owner_per_line: Some(true),
-                ..Default::default()
-            },
+            strict: true,
+            ..test_builders::simple_canopus_config("dotanuki-labs")
         };
 
-        let validation = validator.validate(&context, &config).await.unwrap();
-
-        let only_one_owner_allowed = ValidationIssue::builder()
-            .kind(ValidationIssueKindFactory::single_owner_only())
-            .line_number(0)
-            .description("Entry defines more than one owner for this glob")
-            .build();
+        let validation = validator.validate(&context, &config).await;
 
-        let expected = ValidationOutcome::IssuesDetected(vec![only_one_owner_allowed]);
-        assertor::assert_that!(validation).is_equal_to(expected);
+        assertor::assert_that!(validation.unwrap_err().to_string())
+            .is_equal_to("strict mode requires verifying owners with a token defined by GITHUB_TOKEN".to_string());
     }
 }
@@ -2,6 +2,11 @@
 // SPDX-License-Identifier: MIT
 
 pub mod models;
+pub mod offline;
+pub mod overrides;
+pub mod ownership;
+pub mod paths;
+pub mod policies;
 
 #[cfg(test)]
 mod tests {
@@ -147,6 +152,60 @@ mod tests {
         assertor::assert_that!(codeowners.syntax_validation).is_equal_to(expected);
     }
 
+    #[test]
+    fn should_keep_parsable_owners_of_partially_invalid_rule() -> anyhow::Result<()> {
+        let codeowners_rules = indoc! {"
+            *.rs    @org/rustaceans   ufs.dotanuki
+            \t
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules)?;
+
+        let rule = CodeOwnersEntry::ownership(0, "*.rs", "@org/rustaceans");
+
+        let ownerships = HashMap::from([(
+            Owner::from("@org/rustaceans"),
+            vec![OwnershipRecord::new(0, Glob::new("*.rs")?)],
+        )]);
+
+        let syntax_issues = vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::invalid_syntax())
+                .line_number(0)
+                .description("cannot parse owner")
+                .build(),
+        ];
+
+        let expected = CodeOwners::new(
+            vec![rule, CodeOwnersEntry::BlankLine],
+            ValidationOutcome::IssuesDetected(syntax_issues),
+            ownerships,
+        );
+
+        assertor::assert_that!(codeowners).is_equal_to(expected);
+        Ok(())
+    }
+
+    #[test]
+    fn should_normalize_owners_regardless_of_case() {
+        let codeowners_rules = indoc! {"
+            *.rs        @Dotanuki-Labs/Rustaceans   @UbiratanSoares
+            crates/**   @dotanuki-labs/rustaceans   @ubiratansoares
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+
+        let team = Owner::from("@dotanuki-labs/RUSTACEANS");
+        let first_owner = match &codeowners.entries[0] {
+            CodeOwnersEntry::Rule(rule) => rule.owners[0].to_string(),
+            _ => panic!("expecting a rule"),
+        };
+
+        assertor::assert_that!(codeowners.unique_owners().len()).is_equal_to(2);
+        assertor::assert_that!(codeowners.occurrences(&team)).is_equal_to(vec![0, 1]);
+        assertor::assert_that!(first_owner).is_equal_to("@Dotanuki-Labs/Rustaceans".to_string());
+    }
+
     #[test]
     fn should_fail_with_invalid_github_handle() {
         let codeowners_rules = indoc! {"
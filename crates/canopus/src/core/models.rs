@@ -1,14 +1,21 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+use crate::core::models::checks::IssueSeverity;
 use crate::core::models::codeowners::CodeOwners;
 use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle, Owner};
 use console::style;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
+pub mod aggregation;
+pub mod checks;
 pub mod codeowners;
 pub mod config;
+pub mod deprecations;
 pub mod handles;
+pub mod versions;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ValidationOutcome {
@@ -21,9 +28,43 @@ pub enum StructuralIssue {
     InvalidSyntax,
     DanglingGlobPattern,
     DuplicateOwnership,
+    IgnoredPathsOnly,
+    NearDuplicateOwnership,
+    FileSizeLimitExceeded,
+    UnhonoredLocation,
+    RepeatedOwner,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// Roles a user holds within an organization or a team, from the least to the most privileged one
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OwnerRole {
+    Member,
+    Maintainer,
+    Admin,
+}
+
+impl OwnerRole {
+    pub fn with_article(&self) -> String {
+        match self {
+            OwnerRole::Admin => format!("an {self}"),
+            _ => format!("a {self}"),
+        }
+    }
+}
+
+impl Display for OwnerRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OwnerRole::Member => write!(f, "member"),
+            OwnerRole::Maintainer => write!(f, "maintainer"),
+            OwnerRole::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ConsistencyIssue {
     CannotListMembersInTheOrganization(String),
     CannotVerifyUser(GithubIdentityHandle),
@@ -33,11 +74,74 @@ pub enum ConsistencyIssue {
     TeamDoesNotMatchOrganization(GithubTeamHandle),
     TeamDoesNotExist(GithubTeamHandle),
     UserDoesNotExist(GithubIdentityHandle),
+    UserNotInOwningTeam(GithubIdentityHandle),
+    TeamReviewAssignmentDisabled(GithubTeamHandle),
+    CannotVerifyBranchProtection(String),
+    CodeOwnersReviewNotRequired(String),
+    RequiredApprovalsBelowPolicies {
+        branch: String,
+        required_approvals: u32,
+        min_owners: usize,
+    },
+    UserRoleBelowRequirement {
+        user: GithubIdentityHandle,
+        required: OwnerRole,
+        line: usize,
+    },
+    TeamRoleBelowRequirement {
+        team: GithubTeamHandle,
+        required: OwnerRole,
+        line: usize,
+    },
 }
 
 impl ConsistencyIssue {
     // Pragmatic way to convert a consistency issue to a validation one,
     // which requires aggregate contextual information from CodeOwners
+    /// The owner this issue is about, if any
+    fn owner(&self) -> Option<Owner> {
+        match self {
+            ConsistencyIssue::UserDoesNotExist(handle)
+            | ConsistencyIssue::OrganizationDoesNotExist(handle)
+            | ConsistencyIssue::OutsiderUser(handle)
+            | ConsistencyIssue::CannotVerifyUser(handle)
+            | ConsistencyIssue::UserNotInOwningTeam(handle) => Some(Owner::GithubUser(handle.clone())),
+            ConsistencyIssue::TeamDoesNotExist(handle)
+            | ConsistencyIssue::CannotVerifyTeam(handle)
+            | ConsistencyIssue::TeamReviewAssignmentDisabled(handle)
+            | ConsistencyIssue::TeamDoesNotMatchOrganization(handle) => Some(Owner::GithubTeam(handle.clone())),
+            ConsistencyIssue::CannotListMembersInTheOrganization(_)
+            | ConsistencyIssue::CannotVerifyBranchProtection(_)
+            | ConsistencyIssue::CodeOwnersReviewNotRequired(_)
+            | ConsistencyIssue::RequiredApprovalsBelowPolicies { .. } => None,
+            // Roles are required by policies for some rules only, hence other occurrences are fine
+            ConsistencyIssue::UserRoleBelowRequirement { .. } | ConsistencyIssue::TeamRoleBelowRequirement { .. } => {
+                None
+            },
+        }
+    }
+
+    /// Reports this issue once for every line listing the concerned owner,
+    /// hence annotations mark all affected lines instead of the first one
+    pub fn to_validation_issues_per_occurrence(&self, code_owners: &CodeOwners) -> Vec<ValidationIssue> {
+        let issue = self.to_validation_issue(code_owners);
+
+        let occurrences = self
+            .owner()
+            .map(|owner| code_owners.occurrences(&owner))
+            .unwrap_or_default();
+
+        if occurrences.is_empty() {
+            return vec![issue];
+        }
+
+        occurrences
+            .into_iter()
+            .dedup()
+            .map(|line| ValidationIssue { line, ..issue.clone() })
+            .collect()
+    }
+
     pub fn to_validation_issue(&self, code_owners: &CodeOwners) -> ValidationIssue {
         // We will build a triple for each variant of ConsistencyIssue
         let (issue, occurrence, reason) = match self {
@@ -94,8 +198,9 @@ impl ConsistencyIssue {
                 )
             },
             ConsistencyIssue::CannotVerifyTeam(handle) => {
+                // Teams configured for membership checks might not own anything in CODEOWNERS
                 let owner = Owner::GithubTeam(handle.clone());
-                let first_occurrence = code_owners.occurrences(&owner)[0];
+                let first_occurrence = code_owners.occurrences(&owner).first().copied().unwrap_or(usize::MAX);
                 (
                     self,
                     first_occurrence,
@@ -107,11 +212,98 @@ impl ConsistencyIssue {
                     ),
                 )
             },
+            ConsistencyIssue::UserNotInOwningTeam(handle) => {
+                let owner = Owner::GithubUser(handle.clone());
+                let first_occurrence = code_owners.occurrences(&owner)[0];
+                (
+                    self,
+                    first_occurrence,
+                    format!("owner {} is not in any owning team", style(&handle.inner()).cyan()),
+                )
+            },
+            ConsistencyIssue::TeamReviewAssignmentDisabled(handle) => {
+                let owner = Owner::GithubTeam(handle.clone());
+                let first_occurrence = code_owners.occurrences(&owner)[0];
+                (
+                    self,
+                    first_occurrence,
+                    format!(
+                        "team {}{}{} never assigns review requests to its members",
+                        style(&handle.organization.inner()).cyan(),
+                        style("/").cyan(),
+                        style(&handle.name).cyan(),
+                    ),
+                )
+            },
             ConsistencyIssue::CannotListMembersInTheOrganization(organization) => (
                 self,
                 usize::MAX, // Super hacky solution since we can't assign a line in this case
                 format!("failed to list members that belong to '{}' organization", organization),
             ),
+            // Branch settings are about the whole file, hence they don't belong to any line either
+            ConsistencyIssue::CannotVerifyBranchProtection(branch) => (
+                self,
+                usize::MAX,
+                format!("cannot confirm how {} branch is protected", style(branch).cyan()),
+            ),
+            ConsistencyIssue::CodeOwnersReviewNotRequired(branch) => (
+                self,
+                usize::MAX,
+                format!(
+                    "{} branch does not require reviews from code owners, hence CODEOWNERS is not enforced",
+                    style(branch).cyan()
+                ),
+            ),
+            ConsistencyIssue::RequiredApprovalsBelowPolicies {
+                branch,
+                required_approvals,
+                min_owners,
+            } => (
+                self,
+                usize::MAX,
+                format!(
+                    "{} branch requires {} approvals, while policies require at least {} owners for critical paths",
+                    style(branch).cyan(),
+                    required_approvals,
+                    min_owners
+                ),
+            ),
+            ConsistencyIssue::UserRoleBelowRequirement { user, required, line } => {
+                let held = match required {
+                    OwnerRole::Admin => "does not administer the organization",
+                    _ => "neither maintains an owning team nor administers the organization",
+                };
+
+                (
+                    self,
+                    *line,
+                    format!(
+                        "user {} {}, while policies require {} for this rule",
+                        style(user.inner()).cyan(),
+                        held,
+                        required.with_article()
+                    ),
+                )
+            },
+            ConsistencyIssue::TeamRoleBelowRequirement { team, required, line } => {
+                let held = match required {
+                    OwnerRole::Admin => "do not administer the organization",
+                    _ => "neither maintain it nor administer the organization",
+                };
+
+                (
+                    self,
+                    *line,
+                    format!(
+                        "some members of team {}{}{} {}, while policies require {} for this rule",
+                        style(&team.organization.inner()).cyan(),
+                        style("/").cyan(),
+                        style(&team.name).cyan(),
+                        held,
+                        required.with_article()
+                    ),
+                )
+            },
             ConsistencyIssue::TeamDoesNotMatchOrganization(handle) => {
                 let owner = Owner::GithubTeam(handle.clone());
                 let first_occurrence = code_owners.occurrences(&owner)[0];
@@ -142,6 +334,24 @@ pub enum ConfigurationIssue {
     EmailOwnerForbidden,
     OnlyGithubTeamOwnerAllowed,
     OnlyOneOwnerPerEntry,
+    OwnershipCoverageBelowMinimum,
+    OwnershipCoverageDecreased,
+    OwnersNotSorted,
+    RulesNotSorted,
+    RuleOutsideSection,
+    NotEnoughOwners,
+    TooManyRules,
+    LineTooLong,
+    ExternalCheckViolation,
+    UnownedCodeOwners,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StyleIssue {
+    TabCharacters,
+    TrailingWhitespace,
+    MisalignedOwners,
+    MissingFinalNewline,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -149,6 +359,7 @@ pub enum IssueKind {
     Structural(StructuralIssue),
     Consistency(ConsistencyIssue),
     Configuration(ConfigurationIssue),
+    Style(StyleIssue),
 }
 
 impl Display for IssueKind {
@@ -157,6 +368,40 @@ impl Display for IssueKind {
             IssueKind::Structural(_) => write!(f, "[structure]"),
             IssueKind::Consistency(_) => write!(f, "[consistency]"),
             IssueKind::Configuration(_) => write!(f, "[configuration]"),
+            IssueKind::Style(_) => write!(f, "[style]"),
+        }
+    }
+}
+
+/// An automatic fix for an issue, applied when repairing CODEOWNERS instead of patching the flagged line
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum IssueFix {
+    /// Keeps the flagged line, dropping the given lines duplicating it
+    MergeDuplicates(Vec<usize>),
+
+    /// Replaces the contents of a line
+    RewriteLine(usize, String),
+
+    /// Moves lines around, listing the lines of a block in their expected order
+    ReorderLines(Vec<usize>),
+
+    /// Terminates the file with a newline
+    InsertFinalNewline,
+}
+
+impl Display for IssueFix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueFix::MergeDuplicates(lines) => {
+                let lines = lines.iter().map(|line| format!("L{}", line + 1)).collect::<Vec<_>>();
+                write!(f, "merging {} into it", lines.join(", "))
+            },
+            IssueFix::RewriteLine(_, contents) => write!(f, "rewriting it as '{contents}'"),
+            IssueFix::ReorderLines(lines) => {
+                let lines = lines.iter().map(|line| format!("L{}", line + 1)).collect::<Vec<_>>();
+                write!(f, "reordering lines as {}", lines.join(", "))
+            },
+            IssueFix::InsertFinalNewline => write!(f, "adding a final newline"),
         }
     }
 }
@@ -166,6 +411,9 @@ pub struct ValidationIssue {
     pub line: usize,
     pub context: String,
     pub kind: IssueKind,
+    pub fix: Option<IssueFix>,
+    /// Defaults to the severity of the check, unless overridden for the offending line
+    pub severity: IssueSeverity,
 }
 
 #[derive(Default)]
@@ -173,6 +421,7 @@ pub struct ValidationIssueBuilder {
     kind: Option<IssueKind>,
     line: Option<usize>,
     context: Option<String>,
+    fix: Option<IssueFix>,
 }
 
 impl ValidationIssueBuilder {
@@ -196,11 +445,20 @@ impl ValidationIssueBuilder {
         self
     }
 
+    pub fn fix(mut self, fix: IssueFix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
     pub fn build(self) -> ValidationIssue {
+        let kind = self.kind.expect("missing diagnostic kind");
+
         ValidationIssue {
-            kind: self.kind.expect("missing diagnostic kind"),
+            severity: kind.default_severity(),
+            kind,
             line: self.line.expect("missing related line in codeowners file"),
             context: self.context.expect("missing context for this diagnostic"),
+            fix: self.fix,
         }
     }
 }
@@ -250,7 +508,9 @@ pub struct CodeownersParsingOutcome(pub Vec<ValidationIssue>);
 #[cfg(test)]
 pub mod test_helpers {
     use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
-    use crate::core::models::{ConfigurationIssue, ConsistencyIssue, IssueKind, StructuralIssue};
+    use crate::core::models::{
+        ConfigurationIssue, ConsistencyIssue, IssueKind, OwnerRole, StructuralIssue, StyleIssue,
+    };
 
     pub struct ValidationIssueKindFactory;
 
@@ -267,16 +527,128 @@ pub mod test_helpers {
             IssueKind::Structural(StructuralIssue::DuplicateOwnership)
         }
 
+        pub fn near_duplicate_ownership() -> IssueKind {
+            IssueKind::Structural(StructuralIssue::NearDuplicateOwnership)
+        }
+
+        pub fn ignored_paths_only() -> IssueKind {
+            IssueKind::Structural(StructuralIssue::IgnoredPathsOnly)
+        }
+
         pub fn team_does_not_exist(organization: &str, team: &str) -> IssueKind {
             let handle = GithubTeamHandle::new(GithubIdentityHandle::new(organization.to_string()), team.to_string());
             IssueKind::Consistency(ConsistencyIssue::TeamDoesNotExist(handle))
         }
 
+        pub fn team_does_not_match_organization(organization: &str, team: &str) -> IssueKind {
+            let handle = GithubTeamHandle::new(GithubIdentityHandle::new(organization.to_string()), team.to_string());
+            IssueKind::Consistency(ConsistencyIssue::TeamDoesNotMatchOrganization(handle))
+        }
+
         pub fn user_does_not_belong_to_organization(name: &str) -> IssueKind {
             let handle = GithubIdentityHandle::new(name.to_string());
             IssueKind::Consistency(ConsistencyIssue::OutsiderUser(handle))
         }
 
+        pub fn cannot_verify_user(name: &str) -> IssueKind {
+            let handle = GithubIdentityHandle::new(name.to_string());
+            IssueKind::Consistency(ConsistencyIssue::CannotVerifyUser(handle))
+        }
+
+        pub fn cannot_verify_team(organization: &str, team: &str) -> IssueKind {
+            let handle = GithubTeamHandle::new(GithubIdentityHandle::new(organization.to_string()), team.to_string());
+            IssueKind::Consistency(ConsistencyIssue::CannotVerifyTeam(handle))
+        }
+
+        pub fn team_review_assignment_disabled(organization: &str, team: &str) -> IssueKind {
+            let handle = GithubTeamHandle::new(GithubIdentityHandle::new(organization.to_string()), team.to_string());
+            IssueKind::Consistency(ConsistencyIssue::TeamReviewAssignmentDisabled(handle))
+        }
+
+        pub fn user_role_below_requirement(name: &str, required: OwnerRole, line: usize) -> IssueKind {
+            let user = GithubIdentityHandle::new(name.to_string());
+            IssueKind::Consistency(ConsistencyIssue::UserRoleBelowRequirement { user, required, line })
+        }
+
+        pub fn team_role_below_requirement(
+            organization: &str,
+            team: &str,
+            required: OwnerRole,
+            line: usize,
+        ) -> IssueKind {
+            let team = GithubTeamHandle::new(GithubIdentityHandle::new(organization.to_string()), team.to_string());
+            IssueKind::Consistency(ConsistencyIssue::TeamRoleBelowRequirement { team, required, line })
+        }
+
+        pub fn user_not_in_owning_team(name: &str) -> IssueKind {
+            let handle = GithubIdentityHandle::new(name.to_string());
+            IssueKind::Consistency(ConsistencyIssue::UserNotInOwningTeam(handle))
+        }
+
+        pub fn owners_not_sorted() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::OwnersNotSorted)
+        }
+
+        pub fn rules_not_sorted() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::RulesNotSorted)
+        }
+
+        pub fn only_github_team_owner_allowed() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::OnlyGithubTeamOwnerAllowed)
+        }
+
+        pub fn not_enough_owners() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::NotEnoughOwners)
+        }
+
+        pub fn rule_outside_section() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::RuleOutsideSection)
+        }
+
+        pub fn tab_characters() -> IssueKind {
+            IssueKind::Style(StyleIssue::TabCharacters)
+        }
+
+        pub fn file_size_limit_exceeded() -> IssueKind {
+            IssueKind::Structural(StructuralIssue::FileSizeLimitExceeded)
+        }
+
+        pub fn unhonored_location() -> IssueKind {
+            IssueKind::Structural(StructuralIssue::UnhonoredLocation)
+        }
+
+        pub fn repeated_owner() -> IssueKind {
+            IssueKind::Structural(StructuralIssue::RepeatedOwner)
+        }
+
+        pub fn too_many_rules() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::TooManyRules)
+        }
+
+        pub fn line_too_long() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::LineTooLong)
+        }
+
+        pub fn unowned_codeowners() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::UnownedCodeOwners)
+        }
+
+        pub fn external_check_violation() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::ExternalCheckViolation)
+        }
+
+        pub fn trailing_whitespace() -> IssueKind {
+            IssueKind::Style(StyleIssue::TrailingWhitespace)
+        }
+
+        pub fn misaligned_owners() -> IssueKind {
+            IssueKind::Style(StyleIssue::MisalignedOwners)
+        }
+
+        pub fn missing_final_newline() -> IssueKind {
+            IssueKind::Style(StyleIssue::MissingFinalNewline)
+        }
+
         pub fn github_owners_only() -> IssueKind {
             IssueKind::Configuration(ConfigurationIssue::EmailOwnerForbidden)
         }
@@ -288,5 +660,13 @@ pub mod test_helpers {
         pub fn single_owner_only() -> IssueKind {
             IssueKind::Configuration(ConfigurationIssue::OnlyOneOwnerPerEntry)
         }
+
+        pub fn coverage_below_minimum() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::OwnershipCoverageBelowMinimum)
+        }
+
+        pub fn coverage_decreased() -> IssueKind {
+            IssueKind::Configuration(ConfigurationIssue::OwnershipCoverageDecreased)
+        }
     }
 }
@@ -0,0 +1,108 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::{IssueFix, IssueKind, ValidationIssue};
+use itertools::Itertools;
+
+/// Every issue flagged on the same CODEOWNERS line, so that a line reported by several checks
+/// keeps all of its findings while being reported and repaired only once
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineIssues {
+    pub line: usize,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl LineIssues {
+    pub fn kinds(&self) -> Vec<&IssueKind> {
+        self.issues
+            .iter()
+            .map(|issue| &issue.kind)
+            .unique_by(|kind| kind.code())
+            .collect_vec()
+    }
+
+    pub fn fixes(&self) -> Vec<IssueFix> {
+        self.issues
+            .iter()
+            .filter_map(|issue| issue.fix.clone())
+            .unique()
+            .collect_vec()
+    }
+
+    /// Whether some issue on this line has no automatic fix, hence the whole line must be repaired
+    pub fn is_flagged(&self) -> bool {
+        self.issues.iter().any(|issue| issue.fix.is_none())
+    }
+
+    /// Names of the checks reporting this line, in the order they were reported
+    pub fn check_names(&self) -> Vec<&'static str> {
+        self.kinds().iter().map(|kind| kind.definition().name).collect_vec()
+    }
+}
+
+/// Groups issues per line, dropping the same finding reported twice by different phases.
+/// Lines come in ascending order, followed by project-wide issues
+pub fn aggregate_per_line(issues: Vec<ValidationIssue>) -> Vec<LineIssues> {
+    issues
+        .into_iter()
+        .unique_by(|issue| (issue.line, issue.kind.code(), issue.context.clone()))
+        .into_group_map_by(|issue| issue.line)
+        .into_iter()
+        .sorted_by_key(|(line, _)| *line)
+        .map(|(line, issues)| LineIssues { line, issues })
+        .collect_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::aggregation::aggregate_per_line;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{IssueFix, ValidationIssue};
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use itertools::Itertools;
+
+    #[test]
+    fn should_aggregate_issues_flagged_on_same_line() {
+        let unknown_team = ValidationIssue::builder()
+            .kind(ValidationIssueKindFactory::team_does_not_exist(
+                "dotanuki-labs",
+                "writers",
+            ))
+            .line_number(0)
+            .description("writers team not found for dotanuki-labs organization")
+            .build();
+
+        let issues = vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::duplicate_ownership())
+                .line_number(0)
+                .description("docs/* is also defined at L3")
+                .fix(IssueFix::MergeDuplicates(vec![2]))
+                .build(),
+            unknown_team.clone(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::coverage_below_minimum())
+                .line_number(usize::MAX)
+                .description("ownership coverage is 50.00%, below minimum of 90.00%")
+                .build(),
+            unknown_team.clone(),
+            ValidationIssue {
+                line: 2,
+                ..unknown_team
+            },
+        ];
+
+        let aggregated = aggregate_per_line(issues);
+        let first_line = &aggregated[0];
+
+        assertor::assert_that!(aggregated.iter().map(|line| line.line).collect_vec()).is_equal_to(vec![
+            0,
+            2,
+            usize::MAX,
+        ]);
+        assertor::assert_that!(first_line.check_names())
+            .is_equal_to(vec!["duplicate-ownership", "team-does-not-exist"]);
+        assertor::assert_that!(first_line.fixes()).is_equal_to(vec![IssueFix::MergeDuplicates(vec![2])]);
+        assertor::assert_that!(first_line.is_flagged()).is_true();
+    }
+}
@@ -0,0 +1,638 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::{ConfigurationIssue, ConsistencyIssue, IssueKind, StructuralIssue, StyleIssue};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueSeverity {
+    Error,
+    Warning,
+}
+
+impl Display for IssueSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueSeverity::Error => write!(f, "error"),
+            IssueSeverity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// Static metadata describing one kind of issue canopus is able to report
+#[derive(Debug, PartialEq, Serialize)]
+pub struct CheckDefinition {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub alias: &'static str,
+    pub category: &'static str,
+    #[serde(rename = "default-severity")]
+    pub default_severity: IssueSeverity,
+    pub offline: bool,
+    pub description: &'static str,
+    /// Configuration keys enabling or tuning this check
+    #[serde(rename = "config-keys")]
+    pub config_keys: &'static [&'static str],
+}
+
+/// The registry of all checks supported by this version of canopus
+pub static CHECKS: [CheckDefinition; 40] = [
+    CheckDefinition {
+        code: "CNP001",
+        name: "invalid-syntax",
+        alias: "InvalidSyntax",
+        category: "structure",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Line cannot be parsed as a comment or an ownership rule",
+        config_keys: &[],
+    },
+    CheckDefinition {
+        code: "CNP002",
+        name: "dangling-glob",
+        alias: "DanglingGlobPattern",
+        category: "structure",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Glob pattern does not match any project path",
+        config_keys: &["paths.include", "paths.exclude"],
+    },
+    CheckDefinition {
+        code: "CNP003",
+        name: "duplicate-ownership",
+        alias: "DuplicateOwnership",
+        category: "structure",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Same glob pattern is defined by more than one rule",
+        config_keys: &[],
+    },
+    CheckDefinition {
+        code: "CNP004",
+        name: "ignored-paths-only",
+        alias: "IgnoredPathsOnly",
+        category: "structure",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Glob pattern only matches paths ignored by git, usually build outputs",
+        config_keys: &["paths.include", "paths.exclude"],
+    },
+    CheckDefinition {
+        code: "CNP005",
+        name: "near-duplicate-ownership",
+        alias: "NearDuplicateOwnership",
+        category: "structure",
+        default_severity: IssueSeverity::Warning,
+        offline: true,
+        description: "Glob patterns differ only by anchoring or directory semantics, likely targeting the same paths",
+        config_keys: &[],
+    },
+    CheckDefinition {
+        code: "CNP006",
+        name: "file-size-limit-exceeded",
+        alias: "FileSizeLimitExceeded",
+        category: "structure",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "CODEOWNERS exceeds the size Github accepts, hence Github ignores it entirely",
+        config_keys: &[],
+    },
+    CheckDefinition {
+        code: "CNP007",
+        name: "unhonored-location",
+        alias: "UnhonoredLocation",
+        category: "structure",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "CODEOWNERS is not located where Github reads it, relative to the repository root",
+        config_keys: &[],
+    },
+    CheckDefinition {
+        code: "CNP008",
+        name: "repeated-owner",
+        alias: "RepeatedOwner",
+        category: "structure",
+        default_severity: IssueSeverity::Warning,
+        offline: true,
+        description: "Same owner is listed more than once by a single rule",
+        config_keys: &[],
+    },
+    CheckDefinition {
+        code: "CNP101",
+        name: "cannot-list-organization-members",
+        alias: "CannotListMembersInTheOrganization",
+        category: "consistency",
+        default_severity: IssueSeverity::Warning,
+        offline: false,
+        description: "Members of the configured organization cannot be listed",
+        config_keys: &["general.github-organization", "general.github-organizations"],
+    },
+    CheckDefinition {
+        code: "CNP102",
+        name: "cannot-verify-user",
+        alias: "CannotVerifyUser",
+        category: "consistency",
+        default_severity: IssueSeverity::Warning,
+        offline: false,
+        description: "Github user owner cannot be verified",
+        config_keys: &["general.github-timeout-seconds"],
+    },
+    CheckDefinition {
+        code: "CNP103",
+        name: "cannot-verify-team",
+        alias: "CannotVerifyTeam",
+        category: "consistency",
+        default_severity: IssueSeverity::Warning,
+        offline: false,
+        description: "Github team owner cannot be verified",
+        config_keys: &["general.github-timeout-seconds"],
+    },
+    CheckDefinition {
+        code: "CNP104",
+        name: "organization-does-not-exist",
+        alias: "OrganizationDoesNotExist",
+        category: "consistency",
+        default_severity: IssueSeverity::Error,
+        offline: false,
+        description: "Github organization does not exist",
+        config_keys: &["general.github-organization", "general.github-organizations"],
+    },
+    CheckDefinition {
+        code: "CNP105",
+        name: "outsider-user",
+        alias: "OutsiderUser",
+        category: "consistency",
+        default_severity: IssueSeverity::Error,
+        offline: false,
+        description: "Github user owner does not belong to the organization",
+        config_keys: &[
+            "ownership.allow-outside-collaborators",
+            "ownership.collaborators-repository",
+            "ownership.outside-collaborators",
+            "directory.scim-endpoint",
+        ],
+    },
+    CheckDefinition {
+        code: "CNP106",
+        name: "team-does-not-match-organization",
+        alias: "TeamDoesNotMatchOrganization",
+        category: "consistency",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Github team owner belongs to another organization",
+        config_keys: &["general.github-organization", "general.github-organizations"],
+    },
+    CheckDefinition {
+        code: "CNP107",
+        name: "team-does-not-exist",
+        alias: "TeamDoesNotExist",
+        category: "consistency",
+        default_severity: IssueSeverity::Error,
+        offline: false,
+        description: "Github team owner does not exist",
+        config_keys: &["ownership.report-every-occurrence"],
+    },
+    CheckDefinition {
+        code: "CNP108",
+        name: "user-does-not-exist",
+        alias: "UserDoesNotExist",
+        category: "consistency",
+        default_severity: IssueSeverity::Error,
+        offline: false,
+        description: "Github user owner does not exist",
+        config_keys: &["ownership.report-every-occurrence"],
+    },
+    CheckDefinition {
+        code: "CNP109",
+        name: "owner-not-in-owning-team",
+        alias: "UserNotInOwningTeam",
+        category: "consistency",
+        default_severity: IssueSeverity::Warning,
+        offline: false,
+        description: "Github user owner is not a member of any owning team",
+        config_keys: &["ownership.require-team-membership", "ownership.membership-teams"],
+    },
+    CheckDefinition {
+        code: "CNP110",
+        name: "cannot-verify-branch-protection",
+        alias: "CannotVerifyBranchProtection",
+        category: "consistency",
+        default_severity: IssueSeverity::Warning,
+        offline: false,
+        description: "Protection of the configured branch cannot be fetched from Github",
+        config_keys: &["protection.branch", "protection.repository"],
+    },
+    CheckDefinition {
+        code: "CNP111",
+        name: "codeowners-review-not-required",
+        alias: "CodeOwnersReviewNotRequired",
+        category: "consistency",
+        default_severity: IssueSeverity::Warning,
+        offline: false,
+        description: "Configured branch does not require reviews from code owners, neither by protection nor rulesets",
+        config_keys: &["protection.branch"],
+    },
+    CheckDefinition {
+        code: "CNP112",
+        name: "required-approvals-below-policies",
+        alias: "RequiredApprovalsBelowPolicies",
+        category: "consistency",
+        default_severity: IssueSeverity::Warning,
+        offline: false,
+        description: "Configured branch requires fewer approvals than the owners policies require for critical paths",
+        config_keys: &["protection.branch", "policies"],
+    },
+    CheckDefinition {
+        code: "CNP113",
+        name: "team-review-assignment-disabled",
+        alias: "TeamReviewAssignmentDisabled",
+        category: "consistency",
+        default_severity: IssueSeverity::Warning,
+        offline: false,
+        description: "Github team owner never assigns review requests to its members",
+        config_keys: &["ownership.require-review-assignment"],
+    },
+    CheckDefinition {
+        code: "CNP114",
+        name: "user-role-below-requirement",
+        alias: "UserRoleBelowRequirement",
+        category: "consistency",
+        default_severity: IssueSeverity::Error,
+        offline: false,
+        description: "Github user owner does not hold the organization or team role policies require for a rule",
+        config_keys: &["policies"],
+    },
+    CheckDefinition {
+        code: "CNP115",
+        name: "team-role-below-requirement",
+        alias: "TeamRoleBelowRequirement",
+        category: "consistency",
+        default_severity: IssueSeverity::Error,
+        offline: false,
+        description: "Github team owner has members not holding the role policies require for a rule",
+        config_keys: &["policies"],
+    },
+    CheckDefinition {
+        code: "CNP201",
+        name: "email-owner-forbidden",
+        alias: "EmailOwnerForbidden",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Email owners are forbidden by configuration",
+        config_keys: &["ownership.forbid-email-owners"],
+    },
+    CheckDefinition {
+        code: "CNP202",
+        name: "only-github-team-owner-allowed",
+        alias: "OnlyGithubTeamOwnerAllowed",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Only Github teams are allowed as owners by configuration",
+        config_keys: &["ownership.enforce-github-teams-owners"],
+    },
+    CheckDefinition {
+        code: "CNP203",
+        name: "only-one-owner-per-entry",
+        alias: "OnlyOneOwnerPerEntry",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Only one owner per rule is allowed by configuration",
+        config_keys: &["ownership.enforce-one-owner-per-line"],
+    },
+    CheckDefinition {
+        code: "CNP204",
+        name: "coverage-below-minimum",
+        alias: "OwnershipCoverageBelowMinimum",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Percentage of owned project files is below the configured minimum",
+        config_keys: &["coverage.minimum", "coverage.exclude"],
+    },
+    CheckDefinition {
+        code: "CNP205",
+        name: "coverage-decreased",
+        alias: "OwnershipCoverageDecreased",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Percentage of owned project files decreased against the configured base revision",
+        config_keys: &["coverage.base-ref", "coverage.exclude"],
+    },
+    CheckDefinition {
+        code: "CNP206",
+        name: "owners-not-sorted",
+        alias: "OwnersNotSorted",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Owners of a rule are not sorted alphabetically, as required by configuration",
+        config_keys: &["style.sort-owners-alphabetically"],
+    },
+    CheckDefinition {
+        code: "CNP207",
+        name: "rules-not-sorted",
+        alias: "RulesNotSorted",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Consecutive rules are not sorted by glob pattern, as required by configuration",
+        config_keys: &["style.sort-rules-by-path"],
+    },
+    CheckDefinition {
+        code: "CNP208",
+        name: "too-many-rules",
+        alias: "TooManyRules",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "CODEOWNERS defines more rules than allowed by configuration",
+        config_keys: &["limits.max-rules"],
+    },
+    CheckDefinition {
+        code: "CNP209",
+        name: "line-too-long",
+        alias: "LineTooLong",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Line is longer than allowed by configuration",
+        config_keys: &["limits.max-line-length"],
+    },
+    CheckDefinition {
+        code: "CNP210",
+        name: "external-check",
+        alias: "ExternalCheckViolation",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "External command configured as a check reported a finding",
+        config_keys: &["checks.external"],
+    },
+    CheckDefinition {
+        code: "CNP211",
+        name: "unowned-codeowners",
+        alias: "UnownedCodeOwners",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "CODEOWNERS itself is not owned, or not owned by the configured administrators",
+        config_keys: &["ownership.require-codeowners-owner", "ownership.codeowners-admins"],
+    },
+    CheckDefinition {
+        code: "CNP212",
+        name: "rule-outside-section",
+        alias: "RuleOutsideSection",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Rule does not follow any section comment, as required by configuration",
+        config_keys: &["style.require-sections"],
+    },
+    CheckDefinition {
+        code: "CNP213",
+        name: "not-enough-owners",
+        alias: "NotEnoughOwners",
+        category: "configuration",
+        default_severity: IssueSeverity::Error,
+        offline: true,
+        description: "Files under critical paths are owned by fewer owners than required by policies",
+        config_keys: &["policies"],
+    },
+    CheckDefinition {
+        code: "CNP301",
+        name: "tab-characters",
+        alias: "TabCharacters",
+        category: "style",
+        default_severity: IssueSeverity::Warning,
+        offline: true,
+        description: "Line is indented or aligned with tabs instead of spaces",
+        config_keys: &["style.forbid-tabs"],
+    },
+    CheckDefinition {
+        code: "CNP302",
+        name: "trailing-whitespace",
+        alias: "TrailingWhitespace",
+        category: "style",
+        default_severity: IssueSeverity::Warning,
+        offline: true,
+        description: "Line ends with whitespace",
+        config_keys: &["style.forbid-trailing-whitespace"],
+    },
+    CheckDefinition {
+        code: "CNP303",
+        name: "misaligned-owners",
+        alias: "MisalignedOwners",
+        category: "style",
+        default_severity: IssueSeverity::Warning,
+        offline: true,
+        description: "Owners of a rule start before the configured column",
+        config_keys: &["style.owners-column"],
+    },
+    CheckDefinition {
+        code: "CNP304",
+        name: "missing-final-newline",
+        alias: "MissingFinalNewline",
+        category: "style",
+        default_severity: IssueSeverity::Warning,
+        offline: true,
+        description: "CODEOWNERS does not end with a newline",
+        config_keys: &["style.require-final-newline"],
+    },
+];
+
+impl IssueKind {
+    pub fn alias(&self) -> &'static str {
+        match self {
+            IssueKind::Structural(issue) => match issue {
+                StructuralIssue::InvalidSyntax => "InvalidSyntax",
+                StructuralIssue::DanglingGlobPattern => "DanglingGlobPattern",
+                StructuralIssue::DuplicateOwnership => "DuplicateOwnership",
+                StructuralIssue::IgnoredPathsOnly => "IgnoredPathsOnly",
+                StructuralIssue::NearDuplicateOwnership => "NearDuplicateOwnership",
+                StructuralIssue::FileSizeLimitExceeded => "FileSizeLimitExceeded",
+                StructuralIssue::UnhonoredLocation => "UnhonoredLocation",
+                StructuralIssue::RepeatedOwner => "RepeatedOwner",
+            },
+            IssueKind::Consistency(issue) => match issue {
+                ConsistencyIssue::CannotListMembersInTheOrganization(_) => "CannotListMembersInTheOrganization",
+                ConsistencyIssue::CannotVerifyUser(_) => "CannotVerifyUser",
+                ConsistencyIssue::CannotVerifyTeam(_) => "CannotVerifyTeam",
+                ConsistencyIssue::OrganizationDoesNotExist(_) => "OrganizationDoesNotExist",
+                ConsistencyIssue::OutsiderUser(_) => "OutsiderUser",
+                ConsistencyIssue::TeamDoesNotMatchOrganization(_) => "TeamDoesNotMatchOrganization",
+                ConsistencyIssue::TeamDoesNotExist(_) => "TeamDoesNotExist",
+                ConsistencyIssue::UserDoesNotExist(_) => "UserDoesNotExist",
+                ConsistencyIssue::UserNotInOwningTeam(_) => "UserNotInOwningTeam",
+                ConsistencyIssue::TeamReviewAssignmentDisabled(_) => "TeamReviewAssignmentDisabled",
+                ConsistencyIssue::CannotVerifyBranchProtection(_) => "CannotVerifyBranchProtection",
+                ConsistencyIssue::CodeOwnersReviewNotRequired(_) => "CodeOwnersReviewNotRequired",
+                ConsistencyIssue::RequiredApprovalsBelowPolicies { .. } => "RequiredApprovalsBelowPolicies",
+                ConsistencyIssue::UserRoleBelowRequirement { .. } => "UserRoleBelowRequirement",
+                ConsistencyIssue::TeamRoleBelowRequirement { .. } => "TeamRoleBelowRequirement",
+            },
+            IssueKind::Configuration(issue) => match issue {
+                ConfigurationIssue::EmailOwnerForbidden => "EmailOwnerForbidden",
+                ConfigurationIssue::OnlyGithubTeamOwnerAllowed => "OnlyGithubTeamOwnerAllowed",
+                ConfigurationIssue::OnlyOneOwnerPerEntry => "OnlyOneOwnerPerEntry",
+                ConfigurationIssue::OwnershipCoverageBelowMinimum => "OwnershipCoverageBelowMinimum",
+                ConfigurationIssue::OwnershipCoverageDecreased => "OwnershipCoverageDecreased",
+                ConfigurationIssue::OwnersNotSorted => "OwnersNotSorted",
+                ConfigurationIssue::RulesNotSorted => "RulesNotSorted",
+                ConfigurationIssue::RuleOutsideSection => "RuleOutsideSection",
+                ConfigurationIssue::NotEnoughOwners => "NotEnoughOwners",
+                ConfigurationIssue::TooManyRules => "TooManyRules",
+                ConfigurationIssue::LineTooLong => "LineTooLong",
+                ConfigurationIssue::ExternalCheckViolation => "ExternalCheckViolation",
+                ConfigurationIssue::UnownedCodeOwners => "UnownedCodeOwners",
+            },
+            IssueKind::Style(issue) => match issue {
+                StyleIssue::TabCharacters => "TabCharacters",
+                StyleIssue::TrailingWhitespace => "TrailingWhitespace",
+                StyleIssue::MisalignedOwners => "MisalignedOwners",
+                StyleIssue::MissingFinalNewline => "MissingFinalNewline",
+            },
+        }
+    }
+
+    pub fn definition(&self) -> &'static CheckDefinition {
+        let alias = self.alias();
+
+        CHECKS
+            .iter()
+            .find(|check| check.alias == alias)
+            .unwrap_or_else(|| panic!("FATAL: {alias} is not registered as a check"))
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.definition().code
+    }
+
+    pub fn default_severity(&self) -> IssueSeverity {
+        self.definition().default_severity
+    }
+
+    /// Whether this issue comes from failing to reach Github API, rather than from CODEOWNERS contents
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            IssueKind::Consistency(
+                ConsistencyIssue::CannotListMembersInTheOrganization(_)
+                    | ConsistencyIssue::CannotVerifyUser(_)
+                    | ConsistencyIssue::CannotVerifyTeam(_)
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::checks::{CHECKS, IssueSeverity};
+    use crate::core::models::config::SUPPORTED_CONFIG_KEYS;
+    use crate::core::models::handles::GithubIdentityHandle;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{ConsistencyIssue, IssueKind, OwnerRole};
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use itertools::Itertools;
+
+    #[test]
+    fn should_register_checks_with_unique_codes_and_names() {
+        let unique_codes = CHECKS.iter().map(|check| check.code).unique().count();
+        let unique_names = CHECKS.iter().map(|check| check.name).unique().count();
+
+        assertor::assert_that!(unique_codes).is_equal_to(CHECKS.len());
+        assertor::assert_that!(unique_names).is_equal_to(CHECKS.len());
+    }
+
+    #[test]
+    fn should_refer_only_to_supported_config_keys() {
+        let unknown_keys = CHECKS
+            .iter()
+            .flat_map(|check| check.config_keys)
+            .filter(|key| !SUPPORTED_CONFIG_KEYS.contains(key))
+            .collect_vec();
+
+        assertor::assert_that!(unknown_keys).is_equal_to(Vec::<&&str>::new());
+    }
+
+    #[test]
+    fn should_resolve_definition_from_issue_kind() {
+        let dangling_glob = ValidationIssueKindFactory::dangling_glob_pattern();
+
+        assertor::assert_that!(dangling_glob.code()).is_equal_to("CNP002");
+        assertor::assert_that!(dangling_glob.definition().offline).is_true();
+    }
+
+    #[test]
+    fn should_resolve_definition_for_every_issue_kind() {
+        let organization = GithubIdentityHandle::new("dotanuki-labs".to_string());
+        let branch = "main".to_string();
+
+        let issue_kinds = vec![
+            ValidationIssueKindFactory::invalid_syntax(),
+            ValidationIssueKindFactory::dangling_glob_pattern(),
+            ValidationIssueKindFactory::duplicate_ownership(),
+            ValidationIssueKindFactory::ignored_paths_only(),
+            ValidationIssueKindFactory::near_duplicate_ownership(),
+            ValidationIssueKindFactory::file_size_limit_exceeded(),
+            ValidationIssueKindFactory::unhonored_location(),
+            ValidationIssueKindFactory::repeated_owner(),
+            IssueKind::Consistency(ConsistencyIssue::CannotListMembersInTheOrganization(
+                "dotanuki-labs".to_string(),
+            )),
+            ValidationIssueKindFactory::cannot_verify_user("ubiratansoares"),
+            ValidationIssueKindFactory::cannot_verify_team("dotanuki-labs", "rustaceans"),
+            IssueKind::Consistency(ConsistencyIssue::OrganizationDoesNotExist(organization.clone())),
+            ValidationIssueKindFactory::user_does_not_belong_to_organization("daigoro"),
+            ValidationIssueKindFactory::team_does_not_match_organization("dotanuki-labs", "rustaceans"),
+            ValidationIssueKindFactory::team_does_not_exist("dotanuki-labs", "rustaceans"),
+            IssueKind::Consistency(ConsistencyIssue::UserDoesNotExist(organization)),
+            ValidationIssueKindFactory::user_not_in_owning_team("ubiratansoares"),
+            ValidationIssueKindFactory::team_review_assignment_disabled("dotanuki-labs", "rustaceans"),
+            IssueKind::Consistency(ConsistencyIssue::CannotVerifyBranchProtection(branch.clone())),
+            IssueKind::Consistency(ConsistencyIssue::CodeOwnersReviewNotRequired(branch.clone())),
+            IssueKind::Consistency(ConsistencyIssue::RequiredApprovalsBelowPolicies {
+                branch,
+                required_approvals: 1,
+                min_owners: 2,
+            }),
+            ValidationIssueKindFactory::user_role_below_requirement("ubiratansoares", OwnerRole::Admin, 0),
+            ValidationIssueKindFactory::team_role_below_requirement("dotanuki-labs", "rustaceans", OwnerRole::Admin, 0),
+            ValidationIssueKindFactory::github_owners_only(),
+            ValidationIssueKindFactory::only_github_team_owner_allowed(),
+            ValidationIssueKindFactory::single_owner_only(),
+            ValidationIssueKindFactory::coverage_below_minimum(),
+            ValidationIssueKindFactory::coverage_decreased(),
+            ValidationIssueKindFactory::owners_not_sorted(),
+            ValidationIssueKindFactory::rules_not_sorted(),
+            ValidationIssueKindFactory::rule_outside_section(),
+            ValidationIssueKindFactory::not_enough_owners(),
+            ValidationIssueKindFactory::too_many_rules(),
+            ValidationIssueKindFactory::line_too_long(),
+            ValidationIssueKindFactory::external_check_violation(),
+            ValidationIssueKindFactory::unowned_codeowners(),
+            ValidationIssueKindFactory::tab_characters(),
+            ValidationIssueKindFactory::trailing_whitespace(),
+            ValidationIssueKindFactory::misaligned_owners(),
+            ValidationIssueKindFactory::missing_final_newline(),
+        ];
+
+        // Resolving panics for unregistered kinds, while distinct codes prove every check is reachable
+        let resolved_codes = issue_kinds.iter().map(IssueKind::code).unique().count();
+
+        assertor::assert_that!(resolved_codes).is_equal_to(CHECKS.len());
+    }
+
+    #[test]
+    fn should_consider_transient_failures_as_warnings() {
+        let handle = GithubIdentityHandle::new("ubiratansoares".to_string());
+        let cannot_verify = IssueKind::Consistency(ConsistencyIssue::CannotVerifyUser(handle));
+
+        assertor::assert_that!(cannot_verify.default_severity()).is_equal_to(IssueSeverity::Warning);
+    }
+}
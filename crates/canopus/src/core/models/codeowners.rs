@@ -3,12 +3,19 @@
 
 use crate::core::models::handles::Owner;
 use crate::core::models::{CodeownersParsingOutcome, IssueKind, StructuralIssue, ValidationIssue, ValidationOutcome};
-use anyhow::bail;
-use globset::Glob;
+use anyhow::{Context, bail};
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
 use itertools::Itertools;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
+/// Locations Github reads CODEOWNERS from, relative to the repository root
+pub static CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Github ignores CODEOWNERS files larger than 3 MB
+pub static GITHUB_CODEOWNERS_SIZE_LIMIT: usize = 3 * 1024 * 1024;
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct OwnershipRule {
     pub line_number: usize,
@@ -17,6 +24,241 @@ pub struct OwnershipRule {
     pub inline_comment: Option<String>,
 }
 
+impl OwnershipRule {
+    /// The glob pattern of this rule rewritten in a canonical form, see [canonical_glob]
+    pub fn canonical_glob(&self) -> String {
+        canonical_glob(self.glob.glob())
+    }
+}
+
+/// Rewrites a glob pattern so patterns matching the same paths compare equal,
+/// for instance `docs/**`, `/docs/` and `/docs/**` all become `/docs/`
+pub fn canonical_glob(glob: &str) -> String {
+    let (anchored, stem, directory) = glob_parts(glob);
+
+    if stem.is_empty() {
+        return glob.to_string();
+    }
+
+    format!(
+        "{}{}{}",
+        if anchored { "/" } else { "" },
+        stem,
+        if directory { "/" } else { "" }
+    )
+}
+
+/// The paths targeted by a glob pattern, regardless of anchoring and directory semantics
+pub fn glob_stem(glob: &str) -> &str {
+    let (_, stem, _) = glob_parts(glob);
+    if stem.is_empty() { glob } else { stem }
+}
+
+/// Whether a glob pattern is anchored to the repository root, then whether it targets a directory
+pub fn glob_anchoring(glob: &str) -> (bool, bool) {
+    let (anchored, _, directory) = glob_parts(glob);
+    (anchored, directory)
+}
+
+/// The name of a section opened by a structured comment like `# === Backend ===`, given the comment
+/// without its leading `#`
+pub fn section_name(comment: &str) -> Option<&str> {
+    let name = comment
+        .trim()
+        .strip_prefix("===")?
+        .strip_suffix("===")?
+        .trim_matches('=')
+        .trim();
+
+    (!name.is_empty()).then_some(name)
+}
+
+/// Selects CODEOWNERS rules whose patterns, written without leading slash, match any of the given globs
+#[derive(Debug)]
+pub struct RuleSelector {
+    globs: GlobSet,
+}
+
+impl RuleSelector {
+    pub fn new(patterns: &[String]) -> anyhow::Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+
+        for pattern in patterns {
+            let glob = Glob::new(pattern).with_context(|| format!("invalid rule selector : {pattern}"))?;
+            builder.add(glob);
+        }
+
+        Ok(Self {
+            globs: builder.build()?,
+        })
+    }
+
+    pub fn selects(&self, rule: &OwnershipRule) -> bool {
+        let pattern = rule.glob.glob();
+        let candidates = [pattern.trim_start_matches('/'), glob_stem(pattern)];
+        candidates.iter().any(|candidate| self.globs.is_match(candidate))
+    }
+
+    /// Whether a project path, relative to the project root, matches any of the globs
+    pub fn selects_path(&self, path: &Path) -> bool {
+        self.globs.is_match(path)
+    }
+}
+
+/// Builds the glob of a rule pattern, where escapes must be honored regardless of the platform canopus runs on
+pub fn parse_glob(raw_pattern: &str) -> Result<Glob, globset::Error> {
+    GlobBuilder::new(raw_pattern).backslash_escape(true).build()
+}
+
+/// Tokens of a rule line, as byte ranges, followed by where its inline comment starts if any
+struct RuleTokens {
+    tokens: Vec<Range<usize>>,
+    comment_start: Option<usize>,
+}
+
+// Following Github, a backslash escapes the next character, hence `\ ` keeps a space within a
+// pattern and `\#` a literal hash. An unescaped `#` starting a token begins the inline comment,
+// unless it belongs to a bracket expression like `[#]`
+fn tokenize(raw_line: &str) -> RuleTokens {
+    let mut tokens: Vec<Range<usize>> = vec![];
+    let mut token_start: Option<usize> = None;
+    let mut within_brackets = false;
+    let mut escaped = false;
+
+    for (position, character) in raw_line.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match character {
+            '\\' => {
+                escaped = true;
+                token_start.get_or_insert(position);
+            },
+            '#' if token_start.is_none() => {
+                return RuleTokens {
+                    tokens,
+                    comment_start: Some(position),
+                };
+            },
+            character if character.is_whitespace() && !within_brackets => {
+                if let Some(start) = token_start.take() {
+                    tokens.push(start..position);
+                }
+            },
+            character => {
+                token_start.get_or_insert(position);
+
+                match character {
+                    '[' => within_brackets = true,
+                    ']' => within_brackets = false,
+                    _ => {},
+                }
+            },
+        }
+    }
+
+    if let Some(start) = token_start {
+        tokens.push(start..raw_line.len());
+    }
+
+    RuleTokens {
+        tokens,
+        comment_start: None,
+    }
+}
+
+// Byte offsets where the glob pattern ends, and where owners of a rule line start and end
+fn owners_span(raw_line: &str) -> (usize, usize, usize) {
+    let RuleTokens { tokens, .. } = tokenize(raw_line);
+
+    let glob_end = tokens.first().map_or(raw_line.len(), |glob| glob.end);
+
+    match (tokens.get(1), tokens.last()) {
+        (Some(first_owner), Some(last_owner)) => (glob_end, first_owner.start, last_owner.end),
+        _ => (glob_end, glob_end, glob_end),
+    }
+}
+
+/// Column owners of a rule line start at, counting characters from 1, if the rule has owners
+pub fn owners_column(raw_line: &str) -> Option<usize> {
+    let (_, owners_start, owners_end) = owners_span(raw_line);
+    (owners_start < owners_end).then(|| raw_line[..owners_start].chars().count() + 1)
+}
+
+/// Rewrites a rule line padding its glob pattern, hence owners start at least at the given column
+pub fn with_owners_at_column(raw_line: &str, column: usize) -> String {
+    let (glob_end, owners_start, owners_end) = owners_span(raw_line);
+
+    if owners_start == owners_end || owners_column(raw_line).is_some_and(|current| current >= column) {
+        return raw_line.to_string();
+    }
+
+    let glob_width = raw_line[..glob_end].chars().count();
+    let padding = column.saturating_sub(glob_width + 1).max(1);
+
+    format!(
+        "{}{}{}",
+        &raw_line[..glob_end],
+        " ".repeat(padding),
+        &raw_line[owners_start..]
+    )
+}
+
+/// Rewrites a rule line keeping only the first occurrence of each owner, preserving spacing and inline comments
+pub fn with_deduplicated_owners(raw_line: &str) -> String {
+    let (_, owners_start, owners_end) = owners_span(raw_line);
+
+    // Owners compare like parsed ones, hence @Org/Team and @org/team are the same owner
+    let unique_owners = raw_line[owners_start..owners_end]
+        .split_whitespace()
+        .unique_by(|owner| Owner::try_from((0, owner.to_string())).map_err(|_| owner.to_string()))
+        .join(" ");
+
+    format!(
+        "{}{}{}",
+        &raw_line[..owners_start],
+        unique_owners,
+        &raw_line[owners_end..]
+    )
+}
+
+/// Rewrites a rule line with its owners sorted alphabetically, preserving spacing and inline comments
+pub fn with_sorted_owners(raw_line: &str) -> String {
+    let (_, owners_start, owners_end) = owners_span(raw_line);
+
+    let sorted_owners = raw_line[owners_start..owners_end]
+        .split_whitespace()
+        .sorted_by_key(|owner| owner.to_lowercase())
+        .join(" ");
+
+    format!(
+        "{}{}{}",
+        &raw_line[..owners_start],
+        sorted_owners,
+        &raw_line[owners_end..]
+    )
+}
+
+// Following gitignore rules, patterns starting with or containing a slash are relative to
+// the project root, while trailing slashes and trailing /** restrict matches to directory contents
+fn glob_parts(glob: &str) -> (bool, &str, bool) {
+    let matches_anywhere = glob.starts_with("**/");
+    let unprefixed = glob.strip_prefix("**/").unwrap_or(glob);
+
+    let (pattern, directory) = match unprefixed.strip_suffix("/**") {
+        Some(pattern) => (pattern, true),
+        None => match unprefixed.strip_suffix('/') {
+            Some(pattern) => (pattern, true),
+            None => (unprefixed, false),
+        },
+    };
+
+    let anchored = !matches_anywhere && (unprefixed.starts_with('/') || unprefixed.trim_end_matches('/').contains('/'));
+    (anchored, pattern.trim_start_matches('/'), directory)
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum CodeOwnersEntry {
     BlankLine,
@@ -25,14 +267,14 @@ pub enum CodeOwnersEntry {
 }
 
 impl CodeOwnersEntry {
-    fn try_new_comment(line_number: usize, comment: &str) -> Result<Self, ValidationIssue> {
+    fn try_new_comment(line_number: usize, comment: &str) -> Result<Self, Box<ValidationIssue>> {
         Self::check_non_empty_comment(line_number, comment)?;
 
         let sanitized = comment.replace("#", "").trim().to_string();
         Ok(CodeOwnersEntry::Comment(sanitized))
     }
 
-    fn try_new_rule(line_number: usize, glob: Glob, owners: Vec<Owner>) -> Result<Self, ValidationIssue> {
+    fn try_new_rule(line_number: usize, glob: Glob, owners: Vec<Owner>) -> Result<Self, Box<ValidationIssue>> {
         Self::check_non_empty_owners_list(line_number, &owners)?;
 
         let ownership = OwnershipRule {
@@ -50,7 +292,7 @@ impl CodeOwnersEntry {
         glob: Glob,
         owners: Vec<Owner>,
         comment: &str,
-    ) -> Result<Self, ValidationIssue> {
+    ) -> Result<Self, Box<ValidationIssue>> {
         Self::check_non_empty_comment(line_number, comment)?;
         Self::check_non_empty_owners_list(line_number, &owners)?;
 
@@ -64,7 +306,7 @@ impl CodeOwnersEntry {
         Ok(CodeOwnersEntry::Rule(ownership))
     }
 
-    fn check_non_empty_comment(line_number: usize, comment: &str) -> Result<(), ValidationIssue> {
+    fn check_non_empty_comment(line_number: usize, comment: &str) -> Result<(), Box<ValidationIssue>> {
         if comment.is_empty() {
             let empty_comment = ValidationIssue::builder()
                 .kind(IssueKind::Structural(StructuralIssue::InvalidSyntax))
@@ -72,13 +314,13 @@ impl CodeOwnersEntry {
                 .description("expected non-empty comment")
                 .build();
 
-            return Err(empty_comment);
+            return Err(Box::new(empty_comment));
         };
 
         Ok(())
     }
 
-    fn check_non_empty_owners_list(line_number: usize, owners: &[Owner]) -> Result<(), ValidationIssue> {
+    fn check_non_empty_owners_list(line_number: usize, owners: &[Owner]) -> Result<(), Box<ValidationIssue>> {
         if owners.is_empty() {
             let empty_owners_list = ValidationIssue::builder()
                 .kind(IssueKind::Structural(StructuralIssue::InvalidSyntax))
@@ -86,7 +328,7 @@ impl CodeOwnersEntry {
                 .description("expected non-empty owners list")
                 .build();
 
-            return Err(empty_owners_list);
+            return Err(Box::new(empty_owners_list));
         }
 
         Ok(())
@@ -108,80 +350,98 @@ impl CodeOwnersEntry {
     }
 }
 
-impl TryFrom<(usize, &str)> for CodeOwnersEntry {
-    type Error = CodeownersParsingOutcome;
-
-    fn try_from((line_number, line_contents): (usize, &str)) -> Result<Self, CodeownersParsingOutcome> {
-        if line_contents.is_empty() {
-            Ok(CodeOwnersEntry::BlankLine)
-        } else if line_contents.starts_with("#") {
-            CodeOwnersEntry::try_new_comment(line_number, line_contents).map_err(|e| e.into())
-        } else {
-            let mut parts = line_contents.split_whitespace();
+impl CodeOwnersEntry {
+    /// Parses a line as much as possible, keeping a rule with the owners that could be parsed
+    /// alongside the issues found on the other ones, hence a typo does not hide the whole rule
+    pub fn parse(line_number: usize, line_contents: &str) -> (Option<Self>, Vec<ValidationIssue>) {
+        if line_contents.trim().is_empty() {
+            return (Some(CodeOwnersEntry::BlankLine), vec![]);
+        }
 
-            let Some(raw_pattern) = parts.next() else {
-                panic!("L{line_number} : expecting non-empty line")
+        if line_contents.starts_with("#") {
+            return match CodeOwnersEntry::try_new_comment(line_number, line_contents) {
+                Ok(comment) => (Some(comment), vec![]),
+                Err(issue) => (None, vec![*issue]),
             };
+        }
 
-            let mut issues: Vec<ValidationIssue> = vec![];
+        let RuleTokens { tokens, comment_start } = tokenize(line_contents);
+        let mut parts = tokens.into_iter().map(|token| &line_contents[token]);
+
+        let Some(raw_pattern) = parts.next() else {
+            panic!("L{line_number} : expecting non-empty line")
+        };
 
-            let glob_pattern = match Glob::new(raw_pattern) {
-                Ok(glob) => Some(glob),
+        let mut issues: Vec<ValidationIssue> = vec![];
+
+        let glob_pattern = match parse_glob(raw_pattern) {
+            Ok(glob) => Some(glob),
+            Err(_) => {
+                let invalid_glob = ValidationIssue::builder()
+                    .kind(IssueKind::Structural(StructuralIssue::InvalidSyntax))
+                    .line_number(line_number)
+                    .description("invalid glob pattern")
+                    .build();
+
+                issues.push(invalid_glob);
+                None
+            },
+        };
+
+        let mut owners: Vec<Owner> = vec![];
+        let mut unparsable_owners = 0;
+
+        for item in parts {
+            match Owner::try_from((line_number, item.to_string())) {
+                Ok(owner) => {
+                    owners.push(owner);
+                },
                 Err(_) => {
-                    let invalid_glob = ValidationIssue::builder()
+                    let invalid_owner = ValidationIssue::builder()
                         .kind(IssueKind::Structural(StructuralIssue::InvalidSyntax))
                         .line_number(line_number)
-                        .description("invalid glob pattern")
+                        .description("cannot parse owner")
                         .build();
 
-                    issues.push(invalid_glob);
-                    None
+                    unparsable_owners += 1;
+                    issues.push(invalid_owner)
                 },
-            };
+            }
+        }
 
-            let mut owners: Vec<Owner> = vec![];
-            let mut inline_comments: Vec<&str> = vec![];
-            let mut inline_comment_detected = false;
+        // Owners we could not parse were already flagged, no need to complain about an empty list
+        if unparsable_owners > 0 && owners.is_empty() {
+            return (None, issues);
+        }
 
-            for item in parts {
-                if item == "#" {
-                    inline_comment_detected = true;
-                    continue;
-                }
+        let Some(glob) = glob_pattern else {
+            return (None, issues);
+        };
 
-                if inline_comment_detected {
-                    inline_comments.push(item);
-                } else {
-                    match Owner::try_from((line_number, item.to_string())) {
-                        Ok(owner) => {
-                            owners.push(owner);
-                        },
-                        Err(_) => {
-                            let invalid_owner = ValidationIssue::builder()
-                                .kind(IssueKind::Structural(StructuralIssue::InvalidSyntax))
-                                .line_number(line_number)
-                                .description("cannot parse owner")
-                                .build();
-
-                            issues.push(invalid_owner)
-                        },
-                    }
-                }
-            }
+        let rule = if let Some(comment_start) = comment_start {
+            let inline_comment = line_contents[comment_start + 1..].split_whitespace().join(" ");
+            CodeOwnersEntry::try_new_commented_rule(line_number, glob, owners, &inline_comment)
+        } else {
+            CodeOwnersEntry::try_new_rule(line_number, glob, owners)
+        };
 
-            if !issues.is_empty() || glob_pattern.is_none() {
-                return Err(CodeownersParsingOutcome(issues));
-            }
+        match rule {
+            Ok(rule) => (Some(rule), issues),
+            Err(issue) => {
+                issues.push(*issue);
+                (None, issues)
+            },
+        }
+    }
+}
 
-            let glob = glob_pattern.unwrap();
+impl TryFrom<(usize, &str)> for CodeOwnersEntry {
+    type Error = CodeownersParsingOutcome;
 
-            if inline_comment_detected {
-                let inline_comment = inline_comments.join(" ");
-                CodeOwnersEntry::try_new_commented_rule(line_number, glob, owners, &inline_comment)
-                    .map_err(|e| e.into())
-            } else {
-                CodeOwnersEntry::try_new_rule(line_number, glob, owners).map_err(|e| e.into())
-            }
+    fn try_from((line_number, line_contents): (usize, &str)) -> Result<Self, CodeownersParsingOutcome> {
+        match CodeOwnersEntry::parse(line_number, line_contents) {
+            (Some(entry), issues) if issues.is_empty() => Ok(entry),
+            (_, issues) => Err(CodeownersParsingOutcome(issues)),
         }
     }
 }
@@ -197,14 +457,9 @@ impl CodeOwnersContext {
     fn check_conventional_codeowners_location(project_location: &Path) -> anyhow::Result<PathBuf> {
         log::info!("Project location : {}", project_location.to_string_lossy());
 
-        let possible_locations = [
-            project_location.join(".github/CODEOWNERS"),
-            project_location.join("CODEOWNERS"),
-            project_location.join("docs/CODEOWNERS"),
-        ];
-
-        let config_files = possible_locations
+        let config_files = CODEOWNERS_LOCATIONS
             .iter()
+            .map(|location| project_location.join(location))
             .filter(|path| path.exists())
             .collect::<Vec<_>>();
 
@@ -258,6 +513,14 @@ impl OwnershipRecord {
     }
 }
 
+/// Consecutive rules following a section comment, up to the next one. Rules preceding
+/// the first section comment belong to an unnamed section
+#[derive(Debug, PartialEq)]
+pub struct CodeOwnersSection<'a> {
+    pub name: Option<&'a str>,
+    pub rules: Vec<&'a OwnershipRule>,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct CodeOwners {
     pub entries: Vec<CodeOwnersEntry>,
@@ -278,6 +541,47 @@ impl CodeOwners {
         }
     }
 
+    /// Rules grouped per section, following the order of CODEOWNERS and skipping sections without rules
+    pub fn sections(&self) -> Vec<CodeOwnersSection<'_>> {
+        let mut sections = vec![CodeOwnersSection {
+            name: None,
+            rules: vec![],
+        }];
+
+        for entry in &self.entries {
+            match entry {
+                CodeOwnersEntry::Comment(comment) => {
+                    if let Some(name) = section_name(comment) {
+                        sections.push(CodeOwnersSection {
+                            name: Some(name),
+                            rules: vec![],
+                        });
+                    }
+                },
+                CodeOwnersEntry::Rule(rule) => {
+                    if let Some(current) = sections.last_mut() {
+                        current.rules.push(rule);
+                    }
+                },
+                CodeOwnersEntry::BlankLine => {},
+            }
+        }
+
+        sections
+            .into_iter()
+            .filter(|section| !section.rules.is_empty())
+            .collect()
+    }
+
+    /// Maps lines of rules belonging to a named section to the name of that section
+    pub fn section_per_line(&self) -> HashMap<usize, &str> {
+        self.sections()
+            .into_iter()
+            .filter_map(|section| section.name.map(|name| (name, section.rules)))
+            .flat_map(|(name, rules)| rules.into_iter().map(move |rule| (rule.line_number, name)))
+            .collect()
+    }
+
     pub fn unique_owners(&self) -> Vec<&Owner> {
         self.ownerships.keys().collect_vec()
     }
@@ -301,24 +605,21 @@ impl TryFrom<&str> for CodeOwners {
         let mut issues: Vec<ValidationIssue> = vec![];
 
         for (line_number, line_contents) in lines.enumerate() {
-            match CodeOwnersEntry::try_from((line_number, line_contents)) {
-                Ok(entry) => {
-                    entries.push(entry.clone());
-
-                    if let CodeOwnersEntry::Rule(rule) = entry {
-                        for owner in rule.owners {
-                            if !ownerships.contains_key(&owner) {
-                                ownerships.insert(owner.clone(), vec![]);
-                            }
-
-                            let new_record = OwnershipRecord::new(line_number, rule.glob.clone());
-                            let records = ownerships.get_mut(&owner).unwrap();
-                            records.push(new_record);
-                        }
-                    }
-                },
-                Err(mut error) => issues.append(&mut error.0),
+            let (entry, mut line_issues) = CodeOwnersEntry::parse(line_number, line_contents);
+            issues.append(&mut line_issues);
+
+            let Some(entry) = entry else {
+                continue;
+            };
+
+            if let CodeOwnersEntry::Rule(rule) = &entry {
+                for owner in &rule.owners {
+                    let new_record = OwnershipRecord::new(line_number, rule.glob.clone());
+                    ownerships.entry(owner.clone()).or_default().push(new_record);
+                }
             }
+
+            entries.push(entry);
         }
 
         let syntax_validation = if issues.is_empty() {
@@ -333,9 +634,12 @@ impl TryFrom<&str> for CodeOwners {
 
 #[cfg(test)]
 mod tests {
-    use crate::core::models::codeowners::CodeOwnersContext;
-    use assertor::StringAssertion;
+    use crate::core::models::ValidationOutcome;
+    use crate::core::models::codeowners;
+    use crate::core::models::codeowners::{CodeOwners, CodeOwnersContext, CodeOwnersEntry};
+    use assertor::{EqualityAssertion, StringAssertion};
     use indoc::indoc;
+    use itertools::Itertools;
     use std::fs;
     use temp_dir::TempDir;
 
@@ -373,4 +677,109 @@ mod tests {
 
         assertor::assert_that!(context.unwrap_err().to_string()).contains("multiple CODEOWNERS definitions");
     }
+
+    #[test]
+    fn should_canonicalize_equivalent_glob_patterns() {
+        let equivalent = ["docs/**", "/docs/", "/docs/**"].map(codeowners::canonical_glob);
+        let matching_anywhere = ["docs/", "**/docs/", "**/docs/**"].map(codeowners::canonical_glob);
+
+        assertor::assert_that!(equivalent.to_vec()).is_equal_to(vec!["/docs/".to_string(); 3]);
+        assertor::assert_that!(matching_anywhere.to_vec()).is_equal_to(vec!["docs/".to_string(); 3]);
+        assertor::assert_that!(codeowners::canonical_glob("src/*.rs")).is_equal_to("/src/*.rs".to_string());
+        assertor::assert_that!(codeowners::glob_stem("/docs/")).is_equal_to("docs");
+    }
+
+    #[test]
+    fn should_sort_owners_preserving_line_layout() {
+        let sorted = codeowners::with_sorted_owners("*.rs    @org/rustaceans @Ana  ubira@dotanuki.dev   # Rust");
+
+        assertor::assert_that!(sorted)
+            .is_equal_to("*.rs    @Ana @org/rustaceans ubira@dotanuki.dev   # Rust".to_string());
+    }
+
+    #[test]
+    fn should_align_owners_at_column() {
+        let aligned = codeowners::with_owners_at_column("docs/** @org/writers # Docs", 12);
+        let overflowing = codeowners::with_owners_at_column("crates/canopus/** @org/rustaceans", 12);
+
+        assertor::assert_that!(aligned).is_equal_to("docs/**    @org/writers # Docs".to_string());
+        assertor::assert_that!(codeowners::owners_column(&aligned)).is_equal_to(Some(12));
+        assertor::assert_that!(overflowing).is_equal_to("crates/canopus/** @org/rustaceans".to_string());
+        assertor::assert_that!(codeowners::owners_column("docs/**")).is_equal_to(None);
+    }
+
+    #[test]
+    fn should_parse_escaped_and_non_ascii_patterns() {
+        let codeowners = CodeOwners::try_from(indoc! {r"
+            docs/my\ guides/**   @org/writers
+            \#release-notes.md   @org/writers
+            src/[#]*.cs          @org/dotnet # C# sources
+            docs/日本語/**        @org/i18n   #translations
+        "})
+        .unwrap();
+
+        let rules = codeowners
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                CodeOwnersEntry::Rule(rule) => Some(rule),
+                _ => None,
+            })
+            .collect_vec();
+
+        let matched = [
+            "docs/my guides/setup.md",
+            "#release-notes.md",
+            "src/#main.cs",
+            "docs/日本語/index.md",
+        ];
+
+        assertor::assert_that!(codeowners.syntax_validation).is_equal_to(ValidationOutcome::NoIssues);
+        assertor::assert_that!(rules.len()).is_equal_to(4);
+
+        rules.iter().zip(matched).for_each(|(rule, path)| {
+            assertor::assert_that!(rule.glob.compile_matcher().is_match(path)).is_equal_to(true);
+        });
+
+        assertor::assert_that!(rules[2].inline_comment.clone()).is_equal_to(Some("C# sources".to_string()));
+        assertor::assert_that!(rules[3].inline_comment.clone()).is_equal_to(Some("translations".to_string()));
+    }
+
+    #[test]
+    fn should_sort_owners_of_escaped_patterns() {
+        let sorted = codeowners::with_sorted_owners(r"docs/my\ guides/  @org/writers @Ana # Guides");
+
+        assertor::assert_that!(sorted).is_equal_to(r"docs/my\ guides/  @Ana @org/writers # Guides".to_string());
+    }
+
+    #[test]
+    fn should_group_rules_per_section() {
+        let codeowners = CodeOwners::try_from(indoc! {"
+            *           @org/maintainers
+
+            # === Backend ===
+            # Services and shared libraries
+            services/   @org/backend
+            libs/       @org/backend
+
+            # ======== Frontend ========
+            web/        @org/frontend
+
+            # === Empty ===
+        "})
+        .unwrap();
+
+        let sections = codeowners
+            .sections()
+            .into_iter()
+            .map(|section| (section.name, section.rules.len()))
+            .collect_vec();
+
+        let expected = vec![(None, 1), (Some("Backend"), 2), (Some("Frontend"), 1)];
+
+        assertor::assert_that!(sections).is_equal_to(expected);
+        assertor::assert_that!(codeowners.section_per_line().get(&5).copied()).is_equal_to(Some("Backend"));
+        assertor::assert_that!(codeowners::section_name("=== ===")).is_equal_to(None);
+        assertor::assert_that!(codeowners::section_name("Backend ===")).is_equal_to(None);
+    }
 }
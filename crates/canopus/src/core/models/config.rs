@@ -1,36 +1,239 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use anyhow::bail;
+use crate::core::models::OwnerRole;
+use crate::core::models::checks::IssueSeverity;
+use crate::core::models::config::semantics::ConfigDiagnostic;
+use crate::core::models::deprecations;
+use crate::core::models::deprecations::{DEPRECATIONS, Deprecation};
+use crate::core::models::versions::{Version, VersionRequirement};
+use anyhow::{anyhow, bail};
+use itertools::Itertools;
 use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
 use std::path::Path;
+use std::str::FromStr;
+use toml::{Table, Value};
+
+mod semantics;
+mod yaml;
 
 /// Defaults for optional configuration values
+pub static DEFAULT_VALUE_PROVIDER: OwnersProvider = OwnersProvider::Github;
 pub static DEFAULT_VALUE_OFFLINE_CHECKS_ONLY: bool = false;
+pub static DEFAULT_VALUE_MAX_CONCURRENT_REQUESTS: usize = 8;
+pub static DEFAULT_VALUE_GITHUB_TIMEOUT_SECONDS: u64 = 30;
+pub static DEFAULT_VALUE_MAX_RETRIES: usize = 3;
+pub static DEFAULT_VALUE_RETRY_BASE_DELAY_MILLIS: u64 = 500;
+pub static DEFAULT_VALUE_RETRY_ON_STATUS: [u16; 5] = [429, 500, 502, 503, 504];
+pub static DEFAULT_VALUE_DIRECTORY_HANDLE_ATTRIBUTE: &str = "userName";
 pub static DEFAULT_VALUE_ENFORCE_GITHUB_TEAMS_OWNERS: bool = false;
 pub static DEFAULT_VALUE_ENFORCE_ONE_OWNER_PER_LINE: bool = false;
 pub static DEFAULT_VALUE_FORBID_EMAIL_ADDRESSES: bool = false;
+pub static DEFAULT_VALUE_REQUIRE_TEAM_MEMBERSHIP: bool = false;
+pub static DEFAULT_VALUE_REQUIRE_REVIEW_ASSIGNMENT: bool = false;
+pub static DEFAULT_VALUE_REPORT_EVERY_OCCURRENCE: bool = false;
+pub static DEFAULT_VALUE_REQUIRE_CODEOWNERS_OWNER: bool = false;
+pub static DEFAULT_VALUE_ALLOW_OUTSIDE_COLLABORATORS: bool = false;
+pub static DEFAULT_VALUE_SYMLINKS: SymlinksPolicy = SymlinksPolicy::Skip;
+pub static DEFAULT_VALUE_INCLUDE_UNTRACKED: bool = true;
+pub static DEFAULT_VALUE_CACHE_PATHS: bool = false;
+pub static DEFAULT_VALUE_INCLUDE_SUBMODULES: bool = false;
+pub static DEFAULT_VALUE_STORAGE_LOCATION: &str = "canopus-audits";
+pub static DEFAULT_VALUE_SORT_OWNERS_ALPHABETICALLY: bool = false;
+pub static DEFAULT_VALUE_SORT_RULES_BY_PATH: bool = false;
+pub static DEFAULT_VALUE_FORBID_TABS: bool = false;
+pub static DEFAULT_VALUE_FORBID_TRAILING_WHITESPACE: bool = false;
+pub static DEFAULT_VALUE_REQUIRE_FINAL_NEWLINE: bool = false;
+pub static DEFAULT_VALUE_REQUIRE_SECTIONS: bool = false;
+
+/// Configuration file names accepted under the .github folder, one per supported format
+pub static CONFIG_FILE_NAMES: [&str; 4] = ["canopus.toml", "canopus.yaml", "canopus.yml", "canopus.json"];
+
+/// All keys accepted by canopus.toml, in the dotted <section>.<key> notation
+pub static SUPPORTED_CONFIG_KEYS: [&str; 55] = [
+    "general.github-organization",
+    "general.github-organizations",
+    "general.extends",
+    "general.provider",
+    "general.offline-checks-only",
+    "general.max-concurrent-requests",
+    "general.github-timeout-seconds",
+    "general.max-issues",
+    "general.required-canopus-version",
+    "general.roster-file",
+    "ownership.enforce-github-teams-owners",
+    "ownership.enforce-one-owner-per-line",
+    "ownership.forbid-email-owners",
+    "ownership.require-team-membership",
+    "ownership.membership-teams",
+    "ownership.require-review-assignment",
+    "ownership.report-every-occurrence",
+    "ownership.require-codeowners-owner",
+    "ownership.codeowners-admins",
+    "ownership.allow-outside-collaborators",
+    "ownership.collaborators-repository",
+    "ownership.outside-collaborators",
+    "coverage.minimum",
+    "coverage.base-ref",
+    "coverage.exclude",
+    "paths.include",
+    "paths.exclude",
+    "paths.follow-symlinks",
+    "paths.symlinks",
+    "paths.include-untracked",
+    "paths.include-submodules",
+    "paths.cache",
+    "storage.backend",
+    "storage.location",
+    "storage.bucket",
+    "storage.region",
+    "style.sort-owners-alphabetically",
+    "style.sort-rules-by-path",
+    "style.forbid-tabs",
+    "style.forbid-trailing-whitespace",
+    "style.require-final-newline",
+    "style.owners-column",
+    "style.require-sections",
+    "limits.max-rules",
+    "limits.max-line-length",
+    "checks.external",
+    "retries.max-retries",
+    "retries.base-delay-millis",
+    "retries.retry-on-status",
+    "directory.scim-endpoint",
+    "directory.handle-attribute",
+    "protection.branch",
+    "protection.repository",
+    "overrides",
+    "policies",
+];
 
 /// The configuration options for canopus
 #[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct CanopusConfig {
     pub general: GeneralConfig,
     pub ownership: OwnershipConfig,
+    #[serde(default)]
+    pub coverage: CoverageConfig,
+    #[serde(default)]
+    pub paths: PathsConfig,
+    #[serde(default)]
+    pub storage: StorageConfig,
+    #[serde(default)]
+    pub style: StyleConfig,
+    #[serde(default)]
+    pub limits: LimitsConfig,
+    #[serde(default)]
+    pub checks: ChecksConfig,
+    #[serde(default)]
+    pub retries: RetriesConfig,
+    #[serde(default)]
+    pub directory: DirectoryConfig,
+    #[serde(default)]
+    pub protection: ProtectionConfig,
+    #[serde(default)]
+    pub overrides: Vec<OverrideConfig>,
+    #[serde(default)]
+    pub policies: Vec<PolicyConfig>,
+
+    /// Deprecated keys found when parsing this configuration
+    #[serde(skip)]
+    pub deprecated_keys: Vec<&'static Deprecation>,
+
+    /// Redundant or ineffective combinations of options found when parsing this configuration
+    #[serde(skip)]
+    pub diagnostics: Vec<ConfigDiagnostic>,
+
+    /// Whether every issue is reported as an error, set from the command line only
+    #[serde(skip)]
+    pub strict: bool,
 }
 
 #[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct GeneralConfig {
     /// The Github organization that owns the target project
     #[serde(rename(deserialize = "github-organization"))]
+    #[serde(default)]
     pub github_organization: String,
 
+    /// Sibling organizations whose teams and members are accepted as owners as well
+    #[serde(rename(deserialize = "github-organizations"))]
+    #[serde(default)]
+    pub github_organizations: Vec<String>,
+
+    /// Remote configuration this one overrides, as github:<owner>/<repo>[@ref]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+
+    /// Where owners are verified, the organization being a top-level group for GitLab
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<OwnersProvider>,
+
     /// Whether we should run verifications against Github API
     #[serde(rename(deserialize = "offline-checks-only"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offline_checks_only: Option<bool>,
+
+    /// How many owners can be verified against Github API at the same time
+    #[serde(rename(deserialize = "max-concurrent-requests"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_requests: Option<usize>,
+
+    /// How long verifying a single owner against Github API may take
+    #[serde(rename(deserialize = "github-timeout-seconds"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub github_timeout_seconds: Option<u64>,
+
+    /// How many issues are reported at most, skipping remaining checks once reached
+    #[serde(rename(deserialize = "max-issues"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_issues: Option<usize>,
+
+    /// Which canopus versions may validate the target project, like ">=0.4"
+    #[serde(rename(deserialize = "required-canopus-version"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_canopus_version: Option<String>,
+
+    /// Committed roster of organization members and teams owners are verified against, instead of Github API
+    #[serde(rename(deserialize = "roster-file"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roster_file: Option<String>,
+}
+
+impl GeneralConfig {
+    /// All organizations owners may belong to, starting with github-organization when defined
+    pub fn organizations(&self) -> Vec<&str> {
+        std::iter::once(&self.github_organization)
+            .chain(&self.github_organizations)
+            .map(|organization| organization.trim())
+            .filter(|organization| !organization.is_empty())
+            .unique_by(|organization| organization.to_lowercase())
+            .collect_vec()
+    }
+}
+
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum OwnersProvider {
+    Github,
+    Gitlab,
+}
+
+impl Display for OwnersProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OwnersProvider::Github => write!(f, "github"),
+            OwnersProvider::Gitlab => write!(f, "gitlab"),
+        }
+    }
 }
 
 #[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct OwnershipConfig {
     /// Whether we should enforce only Github teams as owners
     #[serde(rename(deserialize = "enforce-github-teams-owners"))]
@@ -46,6 +249,501 @@ pub struct OwnershipConfig {
     #[serde(rename(deserialize = "forbid-email-owners"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forbid_email_owners: Option<bool>,
+
+    /// Whether user owners must belong to at least one owning team
+    #[serde(rename(deserialize = "require-team-membership"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_team_membership: Option<bool>,
+
+    /// Teams user owners may belong to, as @org/team, defaulting to team owners found in CODEOWNERS
+    #[serde(rename(deserialize = "membership-teams"))]
+    #[serde(default)]
+    pub membership_teams: Vec<String>,
+
+    /// Whether team owners must assign review requests to their members, instead of notifying the whole team
+    #[serde(rename(deserialize = "require-review-assignment"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_review_assignment: Option<bool>,
+
+    /// Whether issues about an owner point to every line listing it, rather than the first one
+    #[serde(rename(deserialize = "report-every-occurrence"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_every_occurrence: Option<bool>,
+
+    /// Whether the CODEOWNERS file itself must be matched by some rule
+    #[serde(rename(deserialize = "require-codeowners-owner"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_codeowners_owner: Option<bool>,
+
+    /// Owners, as @org/team or @user, of which at least one must own the CODEOWNERS file itself
+    #[serde(rename(deserialize = "codeowners-admins"))]
+    #[serde(default)]
+    pub codeowners_admins: Vec<String>,
+
+    /// Whether users outside the organization are accepted when collaborating on a repository
+    #[serde(rename(deserialize = "allow-outside-collaborators"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_outside_collaborators: Option<bool>,
+
+    /// The repository outside collaborators are verified against, as owner/repo
+    #[serde(rename(deserialize = "collaborators-repository"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collaborators_repository: Option<String>,
+
+    /// Users outside the organization accepted as owners regardless of their collaborator status
+    #[serde(rename(deserialize = "outside-collaborators"))]
+    #[serde(default)]
+    pub outside_collaborators: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct CoverageConfig {
+    /// Minimum percentage of project files matched by ownership rules
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f64>,
+
+    /// Git revision whose ownership coverage must not be decreased
+    #[serde(rename(deserialize = "base-ref"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_ref: Option<String>,
+
+    /// Glob patterns for project paths not accounted for coverage, relative to project root
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct PathsConfig {
+    /// Glob patterns selecting which project paths are considered, relative to project root
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    /// Glob patterns for project paths to ignore, relative to project root
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    /// Whether we should follow symbolic links when walking the project, superseded by symlinks
+    #[serde(rename(deserialize = "follow-symlinks"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follow_symlinks: Option<bool>,
+
+    /// How symbolic links are handled when walking the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symlinks: Option<SymlinksPolicy>,
+
+    /// Whether we should consider files not tracked by git
+    #[serde(rename(deserialize = "include-untracked"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_untracked: Option<bool>,
+
+    /// Whether we should walk into submodules and nested checkouts, which belong to other repositories
+    #[serde(rename(deserialize = "include-submodules"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_submodules: Option<bool>,
+
+    /// Whether walked paths are cached between runs, as long as the git working tree does not change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache: Option<bool>,
+}
+
+impl PathsConfig {
+    /// The symlinks policy in use, honoring follow-symlinks when symlinks is not defined
+    pub fn symlinks_policy(&self) -> SymlinksPolicy {
+        match (self.symlinks, self.follow_symlinks) {
+            (Some(policy), _) => policy,
+            (None, Some(true)) => SymlinksPolicy::Follow,
+            (None, _) => DEFAULT_VALUE_SYMLINKS,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SymlinksPolicy {
+    /// Symbolic links are yielded as paths, but never traversed
+    Skip,
+    /// Symbolic links are traversed, skipping the ones leading back to an ancestor directory
+    Follow,
+    /// Symbolic links are traversed, failing the walk on the first one leading back to an ancestor directory
+    ErrorOnCycle,
+}
+
+impl Display for SymlinksPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SymlinksPolicy::Skip => write!(f, "skip"),
+            SymlinksPolicy::Follow => write!(f, "follow"),
+            SymlinksPolicy::ErrorOnCycle => write!(f, "error-on-cycle"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    Local,
+    S3,
+    Gcs,
+}
+
+impl Display for StorageBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageBackend::Local => write!(f, "local"),
+            StorageBackend::S3 => write!(f, "s3"),
+            StorageBackend::Gcs => write!(f, "gcs"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct StorageConfig {
+    /// Where audit results are stored, if anywhere
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backend: Option<StorageBackend>,
+
+    /// Directory for local storage, or objects prefix for remote ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    /// The bucket holding audit results, for remote storages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<String>,
+
+    /// The region of the S3 bucket holding audit results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// Detects the format of a configuration file from its extension
+    pub fn from_path(path: &Path) -> anyhow::Result<Self> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Ok(ConfigFormat::Toml),
+            Some("yaml" | "yml") => Ok(ConfigFormat::Yaml),
+            Some("json") => Ok(ConfigFormat::Json),
+            _ => bail!("unsupported configuration format : {}", path.display()),
+        }
+    }
+
+    /// Parses contents written in this format as a table, leaving keys unchecked
+    pub fn parse_table(self, contents: &str) -> anyhow::Result<Table> {
+        let table = match self {
+            ConfigFormat::Toml => {
+                toml::from_str::<Table>(contents).map_err(|error| describe_parsing_error(contents, &error))?
+            },
+            ConfigFormat::Yaml => yaml::parse_table(contents)?,
+            ConfigFormat::Json => {
+                serde_json::from_str::<Table>(contents).map_err(|error| anyhow!("invalid configuration : {error}"))?
+            },
+        };
+
+        Ok(table)
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ChecksConfig {
+    /// Commands enforcing policies canopus does not know about
+    #[serde(default)]
+    pub external: Vec<ExternalCheckConfig>,
+}
+
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalCheckConfig {
+    /// Name identifying this check in reported issues
+    pub name: String,
+
+    /// Program to run followed by its arguments, resolved against the project root
+    pub command: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ProtectionConfig {
+    /// Branch whose protection and rulesets must require reviews from code owners, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+
+    /// Repository holding this branch as owner/repo, detected from the origin remote when not defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+}
+
+/// Check toggles and severities scoped to the CODEOWNERS rules selected by their patterns
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct OverrideConfig {
+    /// Glob patterns matched against patterns of CODEOWNERS rules, written without leading slash
+    pub patterns: Vec<String>,
+
+    /// Checks, by name, not reported for the selected rules
+    #[serde(default)]
+    pub disable: Vec<String>,
+
+    /// Severity reported by each check, by name, for the selected rules
+    #[serde(default)]
+    pub severity: BTreeMap<String, IssueSeverity>,
+}
+
+/// Ownership requirements scoped to the CODEOWNERS rules selected by their patterns,
+/// on top of the ones defined by the ownership section
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyConfig {
+    /// Glob patterns matched against patterns of CODEOWNERS rules, written without leading slash
+    pub patterns: Vec<String>,
+
+    /// Whether the selected rules accept Github teams as owners only
+    #[serde(default, rename(deserialize = "require-team-owners"))]
+    pub require_team_owners: bool,
+
+    /// Whether the selected rules reject owners defined by email
+    #[serde(default, rename(deserialize = "forbid-email-owners"))]
+    pub forbid_email_owners: bool,
+
+    /// How many owners each project file matching patterns needs at least, resolved from its effective rule
+    #[serde(rename(deserialize = "min-owners"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_owners: Option<usize>,
+
+    /// Whether only distinct Github teams count towards min-owners
+    #[serde(default, rename(deserialize = "distinct-teams"))]
+    pub distinct_teams: bool,
+
+    /// Which organization or team role owners of the selected rules hold at least, either maintainer or admin
+    #[serde(rename(deserialize = "required-role"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_role: Option<OwnerRole>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct DirectoryConfig {
+    /// SCIM endpoint user owners are verified against, instead of organization membership
+    #[serde(rename(deserialize = "scim-endpoint"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scim_endpoint: Option<String>,
+
+    /// Directory attribute holding Github handles of users
+    #[serde(rename(deserialize = "handle-attribute"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle_attribute: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RetriesConfig {
+    /// How many times a Github API request failing for transient reasons is retried
+    #[serde(rename(deserialize = "max-retries"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<usize>,
+
+    /// Delay before the first retry, doubling for each following one
+    #[serde(rename(deserialize = "base-delay-millis"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_delay_millis: Option<u64>,
+
+    /// HTTP status codes worth retrying a request for, besides network failures
+    #[serde(rename(deserialize = "retry-on-status"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_on_status: Option<Vec<u16>>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct LimitsConfig {
+    /// Maximum number of ownership rules CODEOWNERS may define
+    #[serde(rename(deserialize = "max-rules"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_rules: Option<usize>,
+
+    /// Maximum number of characters per CODEOWNERS line
+    #[serde(rename(deserialize = "max-line-length"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_line_length: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct StyleConfig {
+    /// Whether owners of each rule must be sorted alphabetically
+    #[serde(rename(deserialize = "sort-owners-alphabetically"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_owners_alphabetically: Option<bool>,
+
+    /// Whether rules must be sorted by glob pattern, within blocks of consecutive rules
+    #[serde(rename(deserialize = "sort-rules-by-path"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_rules_by_path: Option<bool>,
+
+    /// Whether lines must be indented and aligned with spaces only
+    #[serde(rename(deserialize = "forbid-tabs"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forbid_tabs: Option<bool>,
+
+    /// Whether lines must not end with whitespace
+    #[serde(rename(deserialize = "forbid-trailing-whitespace"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forbid_trailing_whitespace: Option<bool>,
+
+    /// Whether CODEOWNERS must end with a newline
+    #[serde(rename(deserialize = "require-final-newline"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_final_newline: Option<bool>,
+
+    /// Minimum column owners of each rule start at, counting from 1
+    #[serde(rename(deserialize = "owners-column"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owners_column: Option<usize>,
+
+    /// Whether every rule must follow a section comment, like `# === Backend ===`
+    #[serde(rename(deserialize = "require-sections"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_sections: Option<bool>,
+}
+
+/// A configuration file whose keys are checked, not yet merged with the configuration it extends
+#[derive(Clone, Debug)]
+pub struct ConfigLayer {
+    table: Table,
+
+    /// Original TOML contents, which let us point to offending lines
+    toml_source: Option<String>,
+}
+
+impl ConfigLayer {
+    pub fn parse(contents: &str, format: ConfigFormat) -> anyhow::Result<Self> {
+        let table = format.parse_table(contents)?;
+        ensure_supported_keys(&table_keys(&table))?;
+
+        let toml_source = (format == ConfigFormat::Toml).then(|| contents.to_string());
+        Ok(Self { table, toml_source })
+    }
+
+    /// Builds a configuration from CANOPUS_<SECTION>_<KEY> variables, like CANOPUS_GENERAL_GITHUB_ORGANIZATION
+    /// for general.github-organization. Values are read as TOML, falling back to plain strings
+    pub fn from_env_vars(vars: &[(String, String)]) -> anyhow::Result<Self> {
+        let mut table = Table::new();
+
+        for (name, raw_value) in vars {
+            let Some(key) = SUPPORTED_CONFIG_KEYS.iter().find(|key| config_env_var(key) == *name) else {
+                bail!("unsupported environment variable {name}");
+            };
+
+            let value = toml::from_str::<Table>(&format!("value = {raw_value}"))
+                .ok()
+                .and_then(|mut parsed| parsed.remove("value"))
+                .unwrap_or_else(|| Value::String(raw_value.clone()));
+
+            let Some((section, entry)) = key.split_once('.') else {
+                table.insert(key.to_string(), value);
+                continue;
+            };
+
+            table
+                .entry(section)
+                .or_insert_with(|| Value::Table(Table::new()))
+                .as_table_mut()
+                .expect("config section")
+                .insert(entry.to_string(), value);
+        }
+
+        // There is no way to declare an empty section through variables, while canopus.toml requires it
+        table.entry("ownership").or_insert_with(|| Value::Table(Table::new()));
+
+        Ok(Self {
+            table,
+            toml_source: None,
+        })
+    }
+
+    /// Removes commands declared through [[checks.external]], telling whether there were any
+    pub fn discard_external_checks(&mut self) -> bool {
+        self.table
+            .get_mut("checks")
+            .and_then(Value::as_table_mut)
+            .and_then(|checks| checks.remove("external"))
+            .is_some()
+    }
+
+    /// The remote configuration this layer extends, if any
+    pub fn extends(&self) -> Option<&str> {
+        self.table
+            .get("general")
+            .and_then(|general| general.get("extends"))
+            .and_then(Value::as_str)
+    }
+
+    /// Overrides keys of a base configuration with the ones defined by this layer, section by section
+    pub fn merged_onto(self, base: ConfigLayer) -> ConfigLayer {
+        let mut table = base.table;
+
+        for (section, values) in self.table {
+            match (table.get_mut(&section), values) {
+                (Some(Value::Table(base_entries)), Value::Table(entries)) => base_entries.extend(entries),
+                (_, values) => {
+                    table.insert(section, values);
+                },
+            }
+        }
+
+        ConfigLayer {
+            table,
+            toml_source: None,
+        }
+    }
+
+    pub fn resolve(self) -> anyhow::Result<CanopusConfig> {
+        let keys = table_keys(&self.table);
+
+        let mut parsed: CanopusConfig = match &self.toml_source {
+            Some(contents) => toml::from_str(contents).map_err(|error| describe_parsing_error(contents, &error))?,
+            // Other formats and merged layers carry no TOML spans, hence errors only name the offending value
+            None => Value::Table(self.table)
+                .try_into()
+                .map_err(|error: toml::de::Error| anyhow!("invalid configuration : {}", error.message().trim_end()))?,
+        };
+
+        if parsed.general.organizations().is_empty() {
+            bail!("expecting general.github-organization or general.github-organizations");
+        }
+
+        if let Some(raw_requirement) = &parsed.general.required_canopus_version {
+            let requirement = VersionRequirement::from_str(raw_requirement)?;
+            let current = Version::current();
+
+            if !requirement.matches(&current) {
+                bail!(
+                    "this project requires canopus {requirement}, but {current} is running. Upgrade it with `canopus self-update`"
+                );
+            }
+        }
+
+        let (errors, warnings): (Vec<_>, Vec<_>) = semantics::diagnose(&parsed)
+            .into_iter()
+            .partition(|diagnostic| diagnostic.severity == IssueSeverity::Error);
+
+        if !errors.is_empty() {
+            bail!(errors.iter().join("\n"));
+        }
+
+        parsed.diagnostics = warnings;
+        parsed.deprecated_keys = deprecations::deprecated_config_keys(&keys, &DEPRECATIONS);
+        Ok(parsed)
+    }
 }
 
 /// Parsing the configuration file from a path
@@ -53,11 +751,33 @@ impl TryFrom<&Path> for CanopusConfig {
     type Error = anyhow::Error;
 
     fn try_from(value: &Path) -> Result<Self, Self::Error> {
-        let config_location = value.join(".github").join("canopus.toml");
+        ConfigLayer::try_from(value)?.resolve()
+    }
+}
 
-        if !config_location.exists() {
-            bail!("expecting configuration at : {}", config_location.display())
-        }
+impl TryFrom<&Path> for ConfigLayer {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &Path) -> Result<Self, Self::Error> {
+        let github_dir = value.join(".github");
+
+        let config_locations = CONFIG_FILE_NAMES
+            .iter()
+            .map(|file_name| github_dir.join(file_name))
+            .filter(|location| location.exists())
+            .collect_vec();
+
+        let config_location = match config_locations.as_slice() {
+            [] => bail!(
+                "expecting configuration at : {}",
+                github_dir.join("canopus.toml").display()
+            ),
+            [single] => single,
+            _ => bail!(
+                "found multiple canopus configuration files : {}",
+                config_locations.iter().map(|location| location.display()).join(", ")
+            ),
+        };
 
         if !config_location.is_file() {
             bail!("expecting a file not a directory : {}", config_location.display())
@@ -66,17 +786,866 @@ impl TryFrom<&Path> for CanopusConfig {
         log::debug!("Found canopus config at : {:?}", config_location);
 
         let contents = std::fs::read_to_string(config_location)?;
-        let parsed = toml::from_str(&contents)?;
-        Ok(parsed)
+        Self::parse(&contents, ConfigFormat::from_path(config_location)?)
+    }
+}
+
+impl FromStr for CanopusConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        Self::parse(contents, ConfigFormat::Toml)
     }
 }
 
+impl CanopusConfig {
+    /// Parses a configuration written in any of the supported formats
+    pub fn parse(contents: &str, format: ConfigFormat) -> anyhow::Result<Self> {
+        ConfigLayer::parse(contents, format)?.resolve()
+    }
+
+    /// Enables every optional check not asking for a value or restricting which owners are accepted,
+    /// re-enables checks disabled by overrides and reports every issue as an error. Owners must be
+    /// verified against Github API, hence offline checks only are rejected
+    pub fn enforce_strict_mode(&mut self) -> anyhow::Result<()> {
+        if self.general.offline_checks_only == Some(true) {
+            bail!("strict mode requires online checks, while general.offline-checks-only is enabled");
+        }
+
+        self.ownership.forbid_email_owners = Some(true);
+        self.ownership.require_team_membership = Some(true);
+        self.ownership.require_review_assignment = Some(true);
+        self.ownership.require_codeowners_owner = Some(true);
+
+        self.style.sort_owners_alphabetically = Some(true);
+        self.style.sort_rules_by_path = Some(true);
+        self.style.forbid_tabs = Some(true);
+        self.style.forbid_trailing_whitespace = Some(true);
+        self.style.require_final_newline = Some(true);
+
+        self.overrides.iter_mut().for_each(|scoped| scoped.disable.clear());
+        self.strict = true;
+        Ok(())
+    }
+
+    /// Renders this configuration as TOML, filling omitted keys with their default values
+    pub fn effective(&self) -> String {
+        let mut general = Table::new();
+
+        if !self.general.github_organization.is_empty() {
+            general.insert(
+                "github-organization".into(),
+                self.general.github_organization.clone().into(),
+            );
+        }
+
+        general.insert(
+            "github-organizations".into(),
+            self.general.github_organizations.clone().into(),
+        );
+
+        if let Some(extends) = &self.general.extends {
+            general.insert("extends".into(), extends.clone().into());
+        }
+
+        general.insert(
+            "provider".into(),
+            self.general
+                .provider
+                .unwrap_or(DEFAULT_VALUE_PROVIDER)
+                .to_string()
+                .into(),
+        );
+
+        general.insert(
+            "offline-checks-only".into(),
+            self.general
+                .offline_checks_only
+                .unwrap_or(DEFAULT_VALUE_OFFLINE_CHECKS_ONLY)
+                .into(),
+        );
+        general.insert(
+            "max-concurrent-requests".into(),
+            (self
+                .general
+                .max_concurrent_requests
+                .unwrap_or(DEFAULT_VALUE_MAX_CONCURRENT_REQUESTS) as i64)
+                .into(),
+        );
+        general.insert(
+            "github-timeout-seconds".into(),
+            (self
+                .general
+                .github_timeout_seconds
+                .unwrap_or(DEFAULT_VALUE_GITHUB_TIMEOUT_SECONDS) as i64)
+                .into(),
+        );
+
+        if let Some(max_issues) = self.general.max_issues {
+            general.insert("max-issues".into(), (max_issues as i64).into());
+        }
+
+        if let Some(required_version) = &self.general.required_canopus_version {
+            general.insert("required-canopus-version".into(), required_version.clone().into());
+        }
+
+        if let Some(roster_file) = &self.general.roster_file {
+            general.insert("roster-file".into(), roster_file.clone().into());
+        }
+
+        let mut ownership = Table::new();
+        ownership.insert(
+            "forbid-email-owners".into(),
+            self.ownership
+                .forbid_email_owners
+                .unwrap_or(DEFAULT_VALUE_FORBID_EMAIL_ADDRESSES)
+                .into(),
+        );
+        ownership.insert(
+            "enforce-github-teams-owners".into(),
+            self.ownership
+                .enforce_github_teams_owners
+                .unwrap_or(DEFAULT_VALUE_ENFORCE_GITHUB_TEAMS_OWNERS)
+                .into(),
+        );
+        ownership.insert(
+            "enforce-one-owner-per-line".into(),
+            self.ownership
+                .enforce_one_owner_per_line
+                .unwrap_or(DEFAULT_VALUE_ENFORCE_ONE_OWNER_PER_LINE)
+                .into(),
+        );
+        ownership.insert(
+            "require-team-membership".into(),
+            self.ownership
+                .require_team_membership
+                .unwrap_or(DEFAULT_VALUE_REQUIRE_TEAM_MEMBERSHIP)
+                .into(),
+        );
+        ownership.insert(
+            "membership-teams".into(),
+            self.ownership.membership_teams.clone().into(),
+        );
+        ownership.insert(
+            "require-review-assignment".into(),
+            self.ownership
+                .require_review_assignment
+                .unwrap_or(DEFAULT_VALUE_REQUIRE_REVIEW_ASSIGNMENT)
+                .into(),
+        );
+        ownership.insert(
+            "report-every-occurrence".into(),
+            self.ownership
+                .report_every_occurrence
+                .unwrap_or(DEFAULT_VALUE_REPORT_EVERY_OCCURRENCE)
+                .into(),
+        );
+        ownership.insert(
+            "require-codeowners-owner".into(),
+            self.ownership
+                .require_codeowners_owner
+                .unwrap_or(DEFAULT_VALUE_REQUIRE_CODEOWNERS_OWNER)
+                .into(),
+        );
+        ownership.insert(
+            "codeowners-admins".into(),
+            self.ownership.codeowners_admins.clone().into(),
+        );
+        ownership.insert(
+            "allow-outside-collaborators".into(),
+            self.ownership
+                .allow_outside_collaborators
+                .unwrap_or(DEFAULT_VALUE_ALLOW_OUTSIDE_COLLABORATORS)
+                .into(),
+        );
+
+        if let Some(collaborators_repository) = &self.ownership.collaborators_repository {
+            ownership.insert(
+                "collaborators-repository".into(),
+                collaborators_repository.clone().into(),
+            );
+        }
+
+        ownership.insert(
+            "outside-collaborators".into(),
+            self.ownership.outside_collaborators.clone().into(),
+        );
+
+        let mut coverage = Table::new();
+
+        if let Some(minimum) = self.coverage.minimum {
+            coverage.insert("minimum".into(), minimum.into());
+        }
+
+        if let Some(base_ref) = &self.coverage.base_ref {
+            coverage.insert("base-ref".into(), base_ref.clone().into());
+        }
+
+        coverage.insert("exclude".into(), self.coverage.exclude.clone().into());
+
+        let mut paths = Table::new();
+        paths.insert("include".into(), self.paths.include.clone().into());
+        paths.insert("exclude".into(), self.paths.exclude.clone().into());
+        paths.insert("symlinks".into(), self.paths.symlinks_policy().to_string().into());
+        paths.insert(
+            "include-untracked".into(),
+            self.paths
+                .include_untracked
+                .unwrap_or(DEFAULT_VALUE_INCLUDE_UNTRACKED)
+                .into(),
+        );
+        paths.insert(
+            "include-submodules".into(),
+            self.paths
+                .include_submodules
+                .unwrap_or(DEFAULT_VALUE_INCLUDE_SUBMODULES)
+                .into(),
+        );
+        paths.insert(
+            "cache".into(),
+            self.paths.cache.unwrap_or(DEFAULT_VALUE_CACHE_PATHS).into(),
+        );
+
+        let mut storage = Table::new();
+
+        if let Some(backend) = self.storage.backend {
+            storage.insert("backend".into(), backend.to_string().into());
+        }
+
+        let location = self
+            .storage
+            .location
+            .as_deref()
+            .unwrap_or(DEFAULT_VALUE_STORAGE_LOCATION);
+        storage.insert("location".into(), location.into());
+
+        if let Some(bucket) = &self.storage.bucket {
+            storage.insert("bucket".into(), bucket.clone().into());
+        }
+
+        if let Some(region) = &self.storage.region {
+            storage.insert("region".into(), region.clone().into());
+        }
+
+        let mut style = Table::new();
+        style.insert(
+            "sort-owners-alphabetically".into(),
+            self.style
+                .sort_owners_alphabetically
+                .unwrap_or(DEFAULT_VALUE_SORT_OWNERS_ALPHABETICALLY)
+                .into(),
+        );
+        style.insert(
+            "sort-rules-by-path".into(),
+            self.style
+                .sort_rules_by_path
+                .unwrap_or(DEFAULT_VALUE_SORT_RULES_BY_PATH)
+                .into(),
+        );
+        style.insert(
+            "forbid-tabs".into(),
+            self.style.forbid_tabs.unwrap_or(DEFAULT_VALUE_FORBID_TABS).into(),
+        );
+        style.insert(
+            "forbid-trailing-whitespace".into(),
+            self.style
+                .forbid_trailing_whitespace
+                .unwrap_or(DEFAULT_VALUE_FORBID_TRAILING_WHITESPACE)
+                .into(),
+        );
+        style.insert(
+            "require-final-newline".into(),
+            self.style
+                .require_final_newline
+                .unwrap_or(DEFAULT_VALUE_REQUIRE_FINAL_NEWLINE)
+                .into(),
+        );
+
+        if let Some(column) = self.style.owners_column {
+            style.insert("owners-column".into(), (column as i64).into());
+        }
+
+        style.insert(
+            "require-sections".into(),
+            self.style
+                .require_sections
+                .unwrap_or(DEFAULT_VALUE_REQUIRE_SECTIONS)
+                .into(),
+        );
+
+        let mut limits = Table::new();
+
+        if let Some(max_rules) = self.limits.max_rules {
+            limits.insert("max-rules".into(), (max_rules as i64).into());
+        }
+
+        if let Some(max_line_length) = self.limits.max_line_length {
+            limits.insert("max-line-length".into(), (max_line_length as i64).into());
+        }
+
+        let external_checks = self
+            .checks
+            .external
+            .iter()
+            .map(|check| {
+                Value::Table(Table::from_iter([
+                    ("name".to_string(), check.name.clone().into()),
+                    ("command".to_string(), check.command.clone().into()),
+                ]))
+            })
+            .collect_vec();
+
+        let checks = Table::from_iter([("external".to_string(), Value::Array(external_checks))]);
+
+        let retry_on_status = self
+            .retries
+            .retry_on_status
+            .clone()
+            .unwrap_or(DEFAULT_VALUE_RETRY_ON_STATUS.to_vec())
+            .into_iter()
+            .map(|status| Value::Integer(status.into()))
+            .collect_vec();
+
+        let retries = Table::from_iter([
+            (
+                "max-retries".to_string(),
+                (self.retries.max_retries.unwrap_or(DEFAULT_VALUE_MAX_RETRIES) as i64).into(),
+            ),
+            (
+                "base-delay-millis".to_string(),
+                (self
+                    .retries
+                    .base_delay_millis
+                    .unwrap_or(DEFAULT_VALUE_RETRY_BASE_DELAY_MILLIS) as i64)
+                    .into(),
+            ),
+            ("retry-on-status".to_string(), Value::Array(retry_on_status)),
+        ]);
+
+        let mut directory = Table::new();
+
+        if let Some(scim_endpoint) = &self.directory.scim_endpoint {
+            directory.insert("scim-endpoint".into(), scim_endpoint.clone().into());
+        }
+
+        directory.insert(
+            "handle-attribute".into(),
+            self.directory
+                .handle_attribute
+                .clone()
+                .unwrap_or(DEFAULT_VALUE_DIRECTORY_HANDLE_ATTRIBUTE.to_string())
+                .into(),
+        );
+
+        let overrides = self
+            .overrides
+            .iter()
+            .map(|scoped| {
+                let severity = scoped
+                    .severity
+                    .iter()
+                    .map(|(check, severity)| (check.clone(), severity.to_string().into()));
+
+                Value::Table(Table::from_iter([
+                    ("patterns".to_string(), scoped.patterns.clone().into()),
+                    ("disable".to_string(), scoped.disable.clone().into()),
+                    ("severity".to_string(), Value::Table(Table::from_iter(severity))),
+                ]))
+            })
+            .collect_vec();
+
+        let policies = self
+            .policies
+            .iter()
+            .map(|policy| {
+                let mut table = Table::from_iter([
+                    ("patterns".to_string(), policy.patterns.clone().into()),
+                    ("require-team-owners".to_string(), policy.require_team_owners.into()),
+                    ("forbid-email-owners".to_string(), policy.forbid_email_owners.into()),
+                    ("distinct-teams".to_string(), policy.distinct_teams.into()),
+                ]);
+
+                if let Some(min_owners) = policy.min_owners {
+                    table.insert("min-owners".to_string(), (min_owners as i64).into());
+                }
+
+                if let Some(required_role) = policy.required_role {
+                    table.insert("required-role".to_string(), required_role.to_string().into());
+                }
+
+                Value::Table(table)
+            })
+            .collect_vec();
+
+        let mut effective = Table::from_iter([
+            ("general".to_string(), Value::Table(general)),
+            ("ownership".to_string(), Value::Table(ownership)),
+            ("coverage".to_string(), Value::Table(coverage)),
+            ("paths".to_string(), Value::Table(paths)),
+            ("storage".to_string(), Value::Table(storage)),
+            ("style".to_string(), Value::Table(style)),
+            ("limits".to_string(), Value::Table(limits)),
+            ("checks".to_string(), Value::Table(checks)),
+            ("retries".to_string(), Value::Table(retries)),
+            ("directory".to_string(), Value::Table(directory)),
+        ]);
+
+        // Configurations without overrides keep the same digest they had before overrides existed
+        if !overrides.is_empty() {
+            effective.insert("overrides".to_string(), Value::Array(overrides));
+        }
+
+        if !policies.is_empty() {
+            effective.insert("policies".to_string(), Value::Array(policies));
+        }
+
+        // Same goes for configurations not verifying branch protection
+        if let Some(branch) = &self.protection.branch {
+            let mut protection = Table::from_iter([("branch".to_string(), branch.clone().into())]);
+
+            if let Some(repository) = &self.protection.repository {
+                protection.insert("repository".to_string(), repository.clone().into());
+            }
+
+            effective.insert("protection".to_string(), Value::Table(protection));
+        }
+
+        effective.to_string()
+    }
+}
+
+/// Rejects keys not supported by this version, suggesting the closest supported ones
+fn ensure_supported_keys(keys: &[String]) -> anyhow::Result<()> {
+    let unknown_keys = keys
+        .iter()
+        .filter(|key| !SUPPORTED_CONFIG_KEYS.contains(&key.as_str()))
+        .map(|key| match closest_supported_key(key) {
+            Some(suggestion) => format!("unknown config key '{key}' (did you mean '{suggestion}'?)"),
+            None => format!("unknown config key '{key}'"),
+        })
+        .collect_vec();
+
+    if !unknown_keys.is_empty() {
+        bail!(unknown_keys.join("\n"));
+    }
+
+    Ok(())
+}
+
+fn closest_supported_key(key: &str) -> Option<&'static str> {
+    let name = key.rsplit('.').next().unwrap_or(key);
+
+    SUPPORTED_CONFIG_KEYS
+        .iter()
+        .map(|supported| (supported, strsim::levenshtein(key, supported)))
+        .filter(|(supported, distance)| *distance <= 3 || supported.ends_with(&format!(".{name}")))
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(supported, _)| *supported)
+}
+
+// Raw parsing errors mention byte offsets and Rust types, hence we point to the offending line instead
+fn describe_parsing_error(contents: &str, error: &toml::de::Error) -> anyhow::Error {
+    let message = error.message().trim_end_matches('\n');
+
+    match error.span() {
+        Some(span) => {
+            let line = contents[..span.start.min(contents.len())].matches('\n').count() + 1;
+
+            match contents
+                .lines()
+                .nth(line - 1)
+                .and_then(|content| content.split_once('='))
+            {
+                Some((key, _)) => anyhow!("invalid configuration at line {line} ({}) : {message}", key.trim()),
+                None => anyhow!("invalid configuration at line {line} : {message}"),
+            }
+        },
+        None => anyhow!("invalid configuration : {message}"),
+    }
+}
+
+/// Environment variable defining a configuration key, in the CANOPUS_<SECTION>_<KEY> notation
+pub fn config_env_var(key: &str) -> String {
+    format!("CANOPUS_{}", key.to_uppercase().replace(['.', '-'], "_"))
+}
+
+/// Lists all keys defined by a configuration table, in the dotted <section>.<key> notation
+fn table_keys(table: &Table) -> Vec<String> {
+    table
+        .iter()
+        .flat_map(|(section, values)| match values.as_table() {
+            Some(entries) => entries.keys().map(|key| format!("{section}.{key}")).collect_vec(),
+            None => vec![section.clone()],
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::core::models::config::CanopusConfig;
-    use assertor::StringAssertion;
+    use crate::core::models::OwnerRole;
+    use crate::core::models::checks::IssueSeverity;
+    use crate::core::models::config::{
+        CanopusConfig, ConfigFormat, ConfigLayer, ExternalCheckConfig, OverrideConfig, PolicyConfig,
+    };
+    use assertor::{EqualityAssertion, OptionAssertion, ResultAssertion, StringAssertion};
+    use indoc::{formatdoc, indoc};
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
     use temp_dir::TempDir;
 
+    #[test]
+    fn should_read_configuration_from_env_vars() {
+        let vars = [
+            ("CANOPUS_GENERAL_GITHUB_ORGANIZATION", "dotanuki-labs"),
+            ("CANOPUS_GENERAL_OFFLINE_CHECKS_ONLY", "true"),
+            ("CANOPUS_OWNERSHIP_CODEOWNERS_ADMINS", r#"["@dotanuki-labs/admins"]"#),
+            ("CANOPUS_LIMITS_MAX_RULES", "100"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()));
+
+        let config = ConfigLayer::from_env_vars(&vars).unwrap().resolve().unwrap();
+
+        assertor::assert_that!(config.general.github_organization).is_equal_to("dotanuki-labs".to_string());
+        assertor::assert_that!(config.general.offline_checks_only).is_equal_to(Some(true));
+        assertor::assert_that!(config.ownership.codeowners_admins)
+            .is_equal_to(vec!["@dotanuki-labs/admins".to_string()]);
+        assertor::assert_that!(config.limits.max_rules).is_equal_to(Some(100));
+
+        let misspelled = [(
+            "CANOPUS_GENERAL_GITHUB_ORGANISATION".to_string(),
+            "dotanuki-labs".to_string(),
+        )];
+
+        assertor::assert_that!(ConfigLayer::from_env_vars(&misspelled).unwrap_err().to_string())
+            .is_equal_to("unsupported environment variable CANOPUS_GENERAL_GITHUB_ORGANISATION".to_string());
+    }
+
+    #[test]
+    fn should_suggest_supported_keys_for_unknown_ones() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+            ofline-checks-only = true
+        "#};
+
+        let config = CanopusConfig::from_str(contents);
+
+        assertor::assert_that!(config.unwrap_err().to_string())
+            .contains("unknown config key 'general.ofline-checks-only' (did you mean 'general.offline-checks-only'?)");
+    }
+
+    #[test]
+    fn should_point_to_line_with_invalid_value() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+            forbid-email-owners = "yes"
+        "#};
+
+        let config = CanopusConfig::from_str(contents);
+
+        assertor::assert_that!(config.unwrap_err().to_string())
+            .contains("invalid configuration at line 5 (forbid-email-owners) : invalid type");
+    }
+
+    #[test]
+    fn should_render_effective_configuration_with_defaults() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+            forbid-email-owners = true
+        "#};
+
+        let effective = CanopusConfig::from_str(contents).unwrap().effective();
+
+        assertor::assert_that!(effective).contains("forbid-email-owners = true");
+        assertor::assert_that!(effective).contains("max-concurrent-requests = 8");
+        assertor::assert_that!(effective).contains(r#"location = "canopus-audits""#);
+    }
+
+    #[test]
+    fn should_accept_multiple_organizations() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+            github-organizations = ["dotanuki-tools", "Dotanuki-Labs"]
+
+            [ownership]
+        "#};
+
+        let missing_contents = indoc! {r#"
+            [general]
+            github-organizations = []
+
+            [ownership]
+        "#};
+
+        let config = CanopusConfig::from_str(contents).unwrap();
+        let missing = CanopusConfig::from_str(missing_contents);
+
+        assertor::assert_that!(config.general.organizations()).is_equal_to(vec!["dotanuki-labs", "dotanuki-tools"]);
+        assertor::assert_that!(missing.unwrap_err().to_string())
+            .is_equal_to("expecting general.github-organization or general.github-organizations".to_string());
+    }
+
+    #[test]
+    fn should_refuse_configurations_requiring_newer_canopus() {
+        let contents = |requirement: &str| {
+            formatdoc! {r#"
+                [general]
+                github-organization = "dotanuki-labs"
+                required-canopus-version = "{requirement}"
+
+                [ownership]
+            "#}
+        };
+
+        let compatible = CanopusConfig::from_str(&contents(">=0.1"));
+        let incompatible = CanopusConfig::from_str(&contents(">=99.0"));
+        let invalid = CanopusConfig::from_str(&contents(">=latest"));
+
+        assertor::assert_that!(compatible).is_ok();
+        assertor::assert_that!(incompatible.unwrap_err().to_string())
+            .starts_with("this project requires canopus >=99.0.0, but");
+        assertor::assert_that!(invalid.unwrap_err().to_string())
+            .is_equal_to("invalid version requirement : >=latest".to_string());
+    }
+
+    #[test]
+    fn should_parse_external_checks() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+
+            [[checks.external]]
+            name = "ldap-owners"
+            command = ["scripts/check-ldap.sh", "--strict"]
+        "#};
+
+        let config = CanopusConfig::from_str(contents).unwrap();
+
+        let expected = vec![ExternalCheckConfig {
+            name: "ldap-owners".to_string(),
+            command: vec!["scripts/check-ldap.sh".to_string(), "--strict".to_string()],
+        }];
+
+        assertor::assert_that!(config.checks.external).is_equal_to(expected);
+        assertor::assert_that!(config.effective()).contains(r#"name = "ldap-owners""#);
+    }
+
+    #[test]
+    fn should_parse_overrides() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+
+            [[overrides]]
+            patterns = ["generated/**"]
+            disable = ["dangling-glob"]
+            severity = { rules-not-sorted = "warning" }
+        "#};
+
+        let config = CanopusConfig::from_str(contents).unwrap();
+
+        let expected = vec![OverrideConfig {
+            patterns: vec!["generated/**".to_string()],
+            disable: vec!["dangling-glob".to_string()],
+            severity: BTreeMap::from([("rules-not-sorted".to_string(), IssueSeverity::Warning)]),
+        }];
+
+        assertor::assert_that!(config.overrides).is_equal_to(expected);
+        assertor::assert_that!(config.effective()).contains(r#"patterns = ["generated/**"]"#);
+    }
+
+    #[test]
+    fn should_parse_policies() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+
+            [[policies]]
+            patterns = ["infra/**", ".github/**"]
+            require-team-owners = true
+
+            [[policies]]
+            patterns = ["payments/**"]
+            min-owners = 2
+            distinct-teams = true
+            required-role = "maintainer"
+        "#};
+
+        let config = CanopusConfig::from_str(contents).unwrap();
+
+        let expected = vec![
+            PolicyConfig {
+                patterns: vec!["infra/**".to_string(), ".github/**".to_string()],
+                require_team_owners: true,
+                forbid_email_owners: false,
+                min_owners: None,
+                distinct_teams: false,
+                required_role: None,
+            },
+            PolicyConfig {
+                patterns: vec!["payments/**".to_string()],
+                require_team_owners: false,
+                forbid_email_owners: false,
+                min_owners: Some(2),
+                distinct_teams: true,
+                required_role: Some(OwnerRole::Maintainer),
+            },
+        ];
+
+        assertor::assert_that!(config.policies).is_equal_to(expected);
+        assertor::assert_that!(config.effective()).contains("require-team-owners = true");
+        assertor::assert_that!(config.effective()).contains("min-owners = 2");
+        assertor::assert_that!(config.effective()).contains(r#"required-role = "maintainer""#);
+    }
+
+    #[test]
+    fn should_parse_yaml_configuration() {
+        let contents = indoc! {"
+            general:
+              github-organization: dotanuki-labs
+              roster-file: owners-roster.yaml
+            ownership:
+              forbid-email-owners: true
+            paths:
+              exclude:
+                - vendor/**
+        "};
+
+        let config = CanopusConfig::parse(contents, ConfigFormat::Yaml).unwrap();
+
+        assertor::assert_that!(config.general.github_organization).is_equal_to("dotanuki-labs".to_string());
+        assertor::assert_that!(config.ownership.forbid_email_owners).is_equal_to(Some(true));
+        assertor::assert_that!(config.paths.exclude).is_equal_to(vec!["vendor/**".to_string()]);
+        assertor::assert_that!(config.general.roster_file).is_equal_to(Some("owners-roster.yaml".to_string()));
+    }
+
+    #[test]
+    fn should_parse_json_configuration() {
+        let contents = indoc! {r#"
+            {
+              "general": { "github-organization": "dotanuki-labs", "max-concurrent-requests": 4 },
+              "ownership": { "enforce-one-owner-per-line": true }
+            }
+        "#};
+
+        let config = CanopusConfig::parse(contents, ConfigFormat::Json).unwrap();
+
+        assertor::assert_that!(config.general.max_concurrent_requests).is_equal_to(Some(4));
+        assertor::assert_that!(config.ownership.enforce_one_owner_per_line).is_equal_to(Some(true));
+    }
+
+    #[test]
+    fn should_check_keys_of_json_configuration() {
+        let contents = r#"{ "general": { "github-organisation": "dotanuki-labs" }, "ownership": {} }"#;
+
+        let config = CanopusConfig::parse(contents, ConfigFormat::Json);
+
+        assertor::assert_that!(config.unwrap_err().to_string())
+            .contains("unknown config key 'general.github-organisation' (did you mean 'general.github-organization'?)");
+    }
+
+    #[test]
+    fn should_override_extended_configuration() {
+        let base = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+            max-concurrent-requests = 4
+
+            [ownership]
+            forbid-email-owners = true
+            enforce-github-teams-owners = true
+        "#};
+
+        let local = indoc! {r#"
+            [general]
+            extends = "github:dotanuki-labs/canopus-config"
+
+            [ownership]
+            enforce-github-teams-owners = false
+
+            [paths]
+            exclude = ["vendor"]
+        "#};
+
+        let base_layer = ConfigLayer::parse(base, ConfigFormat::Toml).unwrap();
+        let local_layer = ConfigLayer::parse(local, ConfigFormat::Toml).unwrap();
+
+        let config = local_layer.merged_onto(base_layer).resolve().unwrap();
+
+        assertor::assert_that!(config.general.github_organization).is_equal_to("dotanuki-labs".to_string());
+        assertor::assert_that!(config.general.max_concurrent_requests).is_equal_to(Some(4));
+        assertor::assert_that!(config.ownership.forbid_email_owners).is_equal_to(Some(true));
+        assertor::assert_that!(config.ownership.enforce_github_teams_owners).is_equal_to(Some(false));
+        assertor::assert_that!(config.paths.exclude).is_equal_to(vec!["vendor".to_string()]);
+    }
+
+    #[test]
+    fn should_find_configuration_in_any_supported_format() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let github_dir = temp_dir.path().join(".github");
+        std::fs::create_dir_all(&github_dir).unwrap();
+
+        let yaml_config = "general:\n  github-organization: dotanuki-labs\nownership:\n  forbid-email-owners: true\n";
+        std::fs::write(github_dir.join("canopus.yml"), yaml_config).unwrap();
+
+        let config = CanopusConfig::try_from(temp_dir.path()).unwrap();
+
+        std::fs::write(github_dir.join("canopus.toml"), "[general]\n").unwrap();
+        let ambiguous = CanopusConfig::try_from(temp_dir.path());
+
+        assertor::assert_that!(config.ownership.forbid_email_owners).is_some();
+        assertor::assert_that!(ambiguous.unwrap_err().to_string())
+            .contains("found multiple canopus configuration files");
+    }
+
+    #[test]
+    fn should_enable_every_optional_check_in_strict_mode() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+            require-team-membership = false
+
+            [[overrides]]
+            patterns = ["legacy/**"]
+            disable = ["owners-not-sorted"]
+        "#};
+
+        let mut config = CanopusConfig::from_str(contents).unwrap();
+        config.enforce_strict_mode().unwrap();
+
+        let offline_contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+            offline-checks-only = true
+
+            [ownership]
+        "#};
+
+        let mut offline = CanopusConfig::from_str(offline_contents).unwrap();
+
+        assertor::assert_that!(config.strict).is_equal_to(true);
+        assertor::assert_that!(config.ownership.require_team_membership).is_equal_to(Some(true));
+        assertor::assert_that!(config.style.sort_owners_alphabetically).is_equal_to(Some(true));
+        assertor::assert_that!(config.overrides[0].disable.is_empty()).is_equal_to(true);
+        assertor::assert_that!(offline.enforce_strict_mode().unwrap_err().to_string())
+            .contains("strict mode requires online checks");
+    }
+
     #[test]
     fn should_report_config_not_found() {
         let temp_dir = TempDir::new().expect("Cant create temp dir");
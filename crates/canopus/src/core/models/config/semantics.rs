@@ -0,0 +1,285 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::OwnerRole;
+use crate::core::models::checks::{CHECKS, IssueSeverity};
+use crate::core::models::config::{CanopusConfig, DEFAULT_VALUE_OFFLINE_CHECKS_ONLY};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+
+/// A combination of options that contradict each other (errors) or do not do what they suggest (warnings)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigDiagnostic {
+    pub severity: IssueSeverity,
+    pub message: String,
+}
+
+impl ConfigDiagnostic {
+    fn error(message: &str) -> Self {
+        Self {
+            severity: IssueSeverity::Error,
+            message: message.to_string(),
+        }
+    }
+
+    fn warning(message: &str) -> Self {
+        Self {
+            severity: IssueSeverity::Warning,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for ConfigDiagnostic {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.message)
+    }
+}
+
+// Options left out stand for their defaults, hence only explicit opt-ins count here
+fn enabled(option: Option<bool>) -> bool {
+    option.unwrap_or(false)
+}
+
+/// Cross-field checks over a parsed configuration, each one suggesting how to fix it
+pub fn diagnose(config: &CanopusConfig) -> Vec<ConfigDiagnostic> {
+    let general = &config.general;
+    let ownership = &config.ownership;
+    let offline_checks_only = general.offline_checks_only.unwrap_or(DEFAULT_VALUE_OFFLINE_CHECKS_ONLY);
+
+    // A committed roster answers owner checks without reaching Github API
+    let offline_without_roster = offline_checks_only && general.roster_file.is_none();
+
+    let diagnostics = [
+        (offline_without_roster && enabled(ownership.require_team_membership)).then(|| {
+            ConfigDiagnostic::error(
+                "ownership.require-team-membership verifies owners against Github API, which \
+                general.offline-checks-only disables. Drop one of them, or define general.roster-file",
+            )
+        }),
+        (offline_checks_only && enabled(ownership.allow_outside_collaborators)).then(|| {
+            ConfigDiagnostic::error(
+                "ownership.allow-outside-collaborators verifies collaborators against Github API, which \
+                general.offline-checks-only disables. Drop one of them",
+            )
+        }),
+        (offline_checks_only && config.directory.scim_endpoint.is_some()).then(|| {
+            ConfigDiagnostic::error(
+                "directory.scim-endpoint verifies owners against a directory, which \
+                general.offline-checks-only disables. Drop one of them",
+            )
+        }),
+        (offline_checks_only && enabled(ownership.require_review_assignment)).then(|| {
+            ConfigDiagnostic::error(
+                "ownership.require-review-assignment verifies teams against Github API, which \
+                general.offline-checks-only disables. Drop one of them",
+            )
+        }),
+        (offline_checks_only && config.protection.branch.is_some()).then(|| {
+            ConfigDiagnostic::error(
+                "protection.branch verifies branch protection against Github API, which \
+                general.offline-checks-only disables. Drop one of them",
+            )
+        }),
+        (enabled(ownership.allow_outside_collaborators) && ownership.collaborators_repository.is_none()).then(|| {
+            ConfigDiagnostic::error(
+                "ownership.allow-outside-collaborators requires ownership.collaborators-repository, as owner/repo",
+            )
+        }),
+        (enabled(ownership.enforce_github_teams_owners) && enabled(ownership.forbid_email_owners)).then(|| {
+            ConfigDiagnostic::warning(
+                "ownership.forbid-email-owners is redundant, since ownership.enforce-github-teams-owners \
+                already accepts Github teams only. Drop ownership.forbid-email-owners",
+            )
+        }),
+        (!enabled(ownership.require_team_membership) && !ownership.membership_teams.is_empty()).then(|| {
+            ConfigDiagnostic::warning(
+                "ownership.membership-teams has no effect unless ownership.require-team-membership = true",
+            )
+        }),
+        (!enabled(ownership.allow_outside_collaborators)
+            && (ownership.collaborators_repository.is_some() || !ownership.outside_collaborators.is_empty()))
+        .then(|| {
+            ConfigDiagnostic::warning(
+                "ownership.collaborators-repository and ownership.outside-collaborators have no effect unless \
+                ownership.allow-outside-collaborators = true",
+            )
+        }),
+        (ownership.require_codeowners_owner == Some(false) && !ownership.codeowners_admins.is_empty()).then(|| {
+            ConfigDiagnostic::warning(
+                "ownership.codeowners-admins requires CODEOWNERS to be owned, overriding \
+                ownership.require-codeowners-owner = false. Drop ownership.require-codeowners-owner",
+            )
+        }),
+        (config.directory.scim_endpoint.is_none() && config.directory.handle_attribute.is_some()).then(|| {
+            ConfigDiagnostic::warning(
+                "directory.handle-attribute has no effect unless directory.scim-endpoint is defined",
+            )
+        }),
+        config
+            .coverage
+            .minimum
+            .filter(|minimum| !(0.0..=100.0).contains(minimum))
+            .map(|_| ConfigDiagnostic::error("coverage.minimum must be a percentage, between 0 and 100")),
+        (config.protection.branch.is_none() && config.protection.repository.is_some()).then(|| {
+            ConfigDiagnostic::warning("protection.repository has no effect unless protection.branch is defined")
+        }),
+    ];
+
+    let overrides = config.overrides.iter().flat_map(|scoped| {
+        let unknown_checks = scoped
+            .disable
+            .iter()
+            .chain(scoped.severity.keys())
+            .filter(|name| !CHECKS.iter().any(|check| check.name == name.as_str()))
+            .unique()
+            .join(", ");
+
+        [
+            scoped.patterns.is_empty().then(|| {
+                ConfigDiagnostic::error("overrides must select CODEOWNERS rules, with at least one entry in patterns")
+            }),
+            (!unknown_checks.is_empty())
+                .then(|| ConfigDiagnostic::error(&format!("overrides refer to unknown checks : {unknown_checks}"))),
+        ]
+    });
+
+    let policies = config.policies.iter().flat_map(|policy| {
+        [
+            policy.patterns.is_empty().then(|| {
+                ConfigDiagnostic::error("policies must select CODEOWNERS rules, with at least one entry in patterns")
+            }),
+            (!policy.require_team_owners
+                && !policy.forbid_email_owners
+                && policy.min_owners.is_none()
+                && policy.required_role.is_none())
+            .then(|| {
+                ConfigDiagnostic::warning(
+                    "policies have no effect unless require-team-owners, forbid-email-owners, min-owners \
+                    or required-role is defined",
+                )
+            }),
+            (policy.min_owners == Some(0))
+                .then(|| ConfigDiagnostic::error("policies must require at least one owner with min-owners")),
+            (policy.distinct_teams && policy.min_owners.is_none()).then(|| {
+                ConfigDiagnostic::warning(
+                    "policies define distinct-teams, which has no effect unless min-owners is defined",
+                )
+            }),
+            (policy.required_role == Some(OwnerRole::Member)).then(|| {
+                ConfigDiagnostic::warning(
+                    "policies define required-role = member, which every owner holds. Use maintainer or admin",
+                )
+            }),
+            (offline_checks_only && policy.required_role.is_some()).then(|| {
+                ConfigDiagnostic::error(
+                    "policies define required-role, verifying roles against Github API, which \
+                    general.offline-checks-only disables. Drop one of them",
+                )
+            }),
+        ]
+    });
+
+    diagnostics
+        .into_iter()
+        .flatten()
+        .chain(overrides.flatten())
+        .chain(policies.flatten())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::checks::IssueSeverity;
+    use crate::core::models::config::CanopusConfig;
+    use crate::core::models::config::semantics::diagnose;
+    use assertor::EqualityAssertion;
+    use indoc::indoc;
+
+    // Contradictions are rejected when resolving configurations, hence we parse them as they are
+    fn toml_config(contents: &str) -> CanopusConfig {
+        toml::from_str(contents).unwrap()
+    }
+
+    #[test]
+    fn should_accept_consistent_configuration() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+            forbid-email-owners = true
+            require-team-membership = true
+            membership-teams = ["@dotanuki-labs/maintainers"]
+        "#};
+
+        assertor::assert_that!(diagnose(&toml_config(contents))).is_equal_to(vec![]);
+    }
+
+    #[test]
+    fn should_diagnose_contradictory_and_redundant_options() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+            offline-checks-only = true
+
+            [ownership]
+            enforce-github-teams-owners = true
+            forbid-email-owners = true
+            require-team-membership = true
+            outside-collaborators = ["@daigoro"]
+        "#};
+
+        let severities = diagnose(&toml_config(contents))
+            .into_iter()
+            .map(|diagnostic| diagnostic.severity)
+            .collect::<Vec<_>>();
+
+        let expected = vec![IssueSeverity::Error, IssueSeverity::Warning, IssueSeverity::Warning];
+        assertor::assert_that!(severities).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_diagnose_overrides_of_unknown_checks() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+
+            [[overrides]]
+            patterns = ["generated/**"]
+            disable = ["dangling-glob", "dangling-globs"]
+            severity = { rules-not-sorted = "warning", unsorted-rules = "warning" }
+        "#};
+
+        let messages = diagnose(&toml_config(contents))
+            .into_iter()
+            .map(|diagnostic| diagnostic.message)
+            .collect::<Vec<_>>();
+
+        let expected = vec!["overrides refer to unknown checks : dangling-globs, unsorted-rules".to_string()];
+        assertor::assert_that!(messages).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_diagnose_coverage_minimum_outside_percentage_range() {
+        let contents = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+
+            [coverage]
+            minimum = 120.0
+        "#};
+
+        let messages = diagnose(&toml_config(contents))
+            .into_iter()
+            .map(|diagnostic| diagnostic.message)
+            .collect::<Vec<_>>();
+
+        let expected = vec!["coverage.minimum must be a percentage, between 0 and 100".to_string()];
+        assertor::assert_that!(messages).is_equal_to(expected);
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use anyhow::{anyhow, bail};
+use toml::{Table, Value};
+
+// Configuration files are made of sections holding scalars and lists, hence we only
+// support the subset of YAML needed to describe them: block mappings up to two levels,
+// block or flow sequences of scalars, quoted or plain scalars and comments
+pub fn parse_table(contents: &str) -> anyhow::Result<Table> {
+    let mut root = Table::new();
+    let mut section: Option<(String, Option<usize>)> = None;
+    let mut pending_list: Option<String> = None;
+
+    for (index, raw_line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        let line = strip_comment(raw_line).trim_end();
+
+        if line.trim().is_empty() || line.trim() == "---" {
+            continue;
+        }
+
+        let indentation = line.len() - line.trim_start().len();
+        let content = line.trim_start();
+        let invalid = |reason: &str| anyhow!("invalid configuration at line {line_number} : {reason}");
+
+        if let Some(item) = content.strip_prefix('-') {
+            let (Some((section_name, _)), Some(list_key)) = (&section, &pending_list) else {
+                return Err(invalid("list item does not belong to any key"));
+            };
+
+            let item = scalar(item.trim()).ok_or_else(|| invalid("list items must not be empty"))?;
+
+            if let Some(Value::Array(items)) = root
+                .get_mut(section_name)
+                .and_then(Value::as_table_mut)
+                .and_then(|table| table.get_mut(list_key))
+            {
+                items.push(item);
+            }
+
+            continue;
+        }
+
+        let Some((raw_key, raw_value)) = content.split_once(':') else {
+            return Err(invalid("expecting a 'key: value' entry"));
+        };
+
+        let key = unquote(raw_key.trim()).to_string();
+        let raw_value = raw_value.trim();
+        pending_list = None;
+
+        if indentation == 0 {
+            if raw_value.is_empty() {
+                root.insert(key.clone(), Value::Table(Table::new()));
+                section = Some((key, None));
+            } else {
+                section = None;
+
+                if let Some(value) = value(raw_value) {
+                    root.insert(key, value);
+                }
+            }
+
+            continue;
+        }
+
+        let Some((section_name, section_indentation)) = &mut section else {
+            return Err(invalid("unexpected indentation"));
+        };
+
+        if *section_indentation.get_or_insert(indentation) != indentation {
+            return Err(invalid("nested mappings are not supported"));
+        }
+
+        let Some(table) = root.get_mut(section_name.as_str()).and_then(Value::as_table_mut) else {
+            bail!("FATAL: {section_name} section must be a table");
+        };
+
+        if raw_value.is_empty() {
+            table.insert(key.clone(), Value::Array(Vec::new()));
+            pending_list = Some(key);
+        } else if let Some(value) = value(raw_value) {
+            table.insert(key, value);
+        }
+    }
+
+    Ok(root)
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+
+    for (position, character) in line.char_indices() {
+        match (quote, character) {
+            (None, '"' | '\'') => quote = Some(character),
+            (Some(opening), _) if opening == character => quote = None,
+            (None, '#') if previous.is_whitespace() => return &line[..position],
+            _ => {},
+        }
+
+        previous = character;
+    }
+
+    line
+}
+
+fn unquote(raw: &str) -> &str {
+    let is_quoted = raw.len() >= 2
+        && ((raw.starts_with('"') && raw.ends_with('"')) || (raw.starts_with('\'') && raw.ends_with('\'')));
+
+    if is_quoted { &raw[1..raw.len() - 1] } else { raw }
+}
+
+fn value(raw: &str) -> Option<Value> {
+    let Some(flow_items) = raw.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) else {
+        return scalar(raw);
+    };
+
+    let items = flow_items
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .filter_map(scalar)
+        .collect();
+
+    Some(Value::Array(items))
+}
+
+fn scalar(raw: &str) -> Option<Value> {
+    if raw.starts_with('"') || raw.starts_with('\'') {
+        return Some(Value::String(unquote(raw).to_string()));
+    }
+
+    let parsed = match raw {
+        "" | "~" | "null" => return None,
+        "true" => Value::Boolean(true),
+        "false" => Value::Boolean(false),
+        _ => match (raw.parse::<i64>(), raw.parse::<f64>()) {
+            (Ok(integer), _) => Value::Integer(integer),
+            (_, Ok(float)) => Value::Float(float),
+            _ => Value::String(raw.to_string()),
+        },
+    };
+
+    Some(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::config::yaml;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use toml::Table;
+
+    #[test]
+    fn should_parse_sections_scalars_and_lists() {
+        let contents = indoc! {r#"
+            # Shared across the organization
+            general:
+              github-organization: "dotanuki-labs"
+              max-concurrent-requests: 4
+
+            coverage:
+              minimum: 92.5
+              exclude: ["legacy/**", 'vendor']
+
+            paths:
+              follow-symlinks: false
+              include:
+                - src/**  # Rust sources
+                - "docs/#drafts"
+        "#};
+
+        let expected = indoc! {r##"
+            [general]
+            github-organization = "dotanuki-labs"
+            max-concurrent-requests = 4
+
+            [coverage]
+            minimum = 92.5
+            exclude = ["legacy/**", "vendor"]
+
+            [paths]
+            follow-symlinks = false
+            include = ["src/**", "docs/#drafts"]
+        "##};
+
+        let table = yaml::parse_table(contents).unwrap();
+
+        assertor::assert_that!(table).is_equal_to(toml::from_str::<Table>(expected).unwrap());
+    }
+
+    #[test]
+    fn should_reject_unsupported_yaml() {
+        let contents = indoc! {"
+            general:
+              github-organization: dotanuki-labs
+                offline-checks-only: true
+        "};
+
+        let parsed = yaml::parse_table(contents);
+
+        assertor::assert_that!(parsed.unwrap_err().to_string())
+            .contains("invalid configuration at line 3 : nested mappings are not supported");
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use anyhow::bail;
+use console::style;
+use itertools::Itertools;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(tag = "kind", content = "name", rename_all = "kebab-case")]
+#[allow(dead_code, reason = "flags are constructed as soon as some of them get deprecated")]
+pub enum DeprecatedItem {
+    Flag(&'static str),
+    ConfigKey(&'static str),
+}
+
+/// Static metadata describing a command line flag or configuration key scheduled for removal
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Deprecation {
+    pub item: DeprecatedItem,
+    pub since: &'static str,
+    pub replacement: Option<&'static str>,
+}
+
+impl Display for Deprecation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.item {
+            DeprecatedItem::Flag(flag) => write!(f, "flag --{flag}")?,
+            DeprecatedItem::ConfigKey(key) => write!(f, "config key {key}")?,
+        };
+
+        write!(f, " is deprecated since {} and will be removed", self.since)?;
+
+        match (self.item, self.replacement) {
+            (DeprecatedItem::Flag(_), Some(replacement)) => write!(f, ", use --{replacement} instead"),
+            (DeprecatedItem::ConfigKey(_), Some(replacement)) => write!(f, ", use {replacement} instead"),
+            (_, None) => Ok(()),
+        }
+    }
+}
+
+/// The registry of flags and configuration keys deprecated by this version of canopus
+pub static DEPRECATIONS: [Deprecation; 1] = [Deprecation {
+    item: DeprecatedItem::ConfigKey("paths.follow-symlinks"),
+    since: "0.1.2",
+    replacement: Some("paths.symlinks"),
+}];
+
+/// Finds deprecated flags among raw command line arguments
+pub fn deprecated_flags(arguments: &[String], registry: &'static [Deprecation]) -> Vec<&'static Deprecation> {
+    registry
+        .iter()
+        .filter(|deprecation| match deprecation.item {
+            DeprecatedItem::Flag(flag) => arguments.iter().any(|argument| {
+                let name = argument.split('=').next().unwrap_or(argument);
+                name.strip_prefix("--") == Some(flag)
+            }),
+            DeprecatedItem::ConfigKey(_) => false,
+        })
+        .collect_vec()
+}
+
+/// Finds deprecated keys among the ones defined by a configuration file, in dotted notation
+pub fn deprecated_config_keys(keys: &[String], registry: &'static [Deprecation]) -> Vec<&'static Deprecation> {
+    registry
+        .iter()
+        .filter(|deprecation| match deprecation.item {
+            DeprecatedItem::ConfigKey(deprecated) => keys.iter().any(|key| key == deprecated),
+            DeprecatedItem::Flag(_) => false,
+        })
+        .collect_vec()
+}
+
+/// How canopus reacts when deprecated flags or configuration keys are used
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeprecationPolicy {
+    Warn,
+    Deny,
+}
+
+impl DeprecationPolicy {
+    pub fn enforce(&self, usages: &[&'static Deprecation]) -> anyhow::Result<()> {
+        usages.iter().for_each(|usage| {
+            eprintln!("{} {}", style("warning:").yellow(), usage);
+        });
+
+        if *self == DeprecationPolicy::Deny && !usages.is_empty() {
+            bail!("found {} deprecated usages, denied by --deny-deprecated", usages.len());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::deprecations::{
+        DeprecatedItem, Deprecation, DeprecationPolicy, deprecated_config_keys, deprecated_flags,
+    };
+    use assertor::{EqualityAssertion, ResultAssertion};
+
+    static TEST_DEPRECATIONS: [Deprecation; 2] = [
+        Deprecation {
+            item: DeprecatedItem::Flag("coverage-base"),
+            since: "0.5.0",
+            replacement: Some("base-ref"),
+        },
+        Deprecation {
+            item: DeprecatedItem::ConfigKey("general.offline-checks-only"),
+            since: "0.5.0",
+            replacement: None,
+        },
+    ];
+
+    #[test]
+    fn should_find_deprecated_usages() {
+        let arguments = vec!["validate".to_string(), "--coverage-base=main".to_string()];
+        let config_keys = vec!["general.offline-checks-only".to_string()];
+
+        let flags = deprecated_flags(&arguments, &TEST_DEPRECATIONS);
+        let keys = deprecated_config_keys(&config_keys, &TEST_DEPRECATIONS);
+
+        assertor::assert_that!(flags).is_equal_to(vec![&TEST_DEPRECATIONS[0]]);
+        assertor::assert_that!(keys).is_equal_to(vec![&TEST_DEPRECATIONS[1]]);
+        assertor::assert_that!(TEST_DEPRECATIONS[0].to_string()).is_equal_to(
+            "flag --coverage-base is deprecated since 0.5.0 and will be removed, use --base-ref instead".to_string(),
+        );
+    }
+
+    #[test]
+    fn should_deny_deprecated_usages_when_requested() {
+        let usages = vec![&TEST_DEPRECATIONS[1]];
+
+        assertor::assert_that!(DeprecationPolicy::Warn.enforce(&usages)).is_ok();
+        assertor::assert_that!(DeprecationPolicy::Deny.enforce(&usages)).is_err();
+    }
+}
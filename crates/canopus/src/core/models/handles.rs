@@ -4,6 +4,9 @@
 use crate::core::models::{IssueKind, StructuralIssue, ValidationIssue};
 use itertools::Itertools;
 use lazy_regex::{Lazy, Regex};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 
 type ParsedLine = (usize, String);
 
@@ -32,7 +35,9 @@ impl TryFrom<ParsedLine> for EmailHandle {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Github logins and organizations are case-insensitive, hence handles compare regardless of case
+/// while keeping their original text for output
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GithubIdentityHandle(String);
 
 impl GithubIdentityHandle {
@@ -45,6 +50,26 @@ impl GithubIdentityHandle {
     }
 }
 
+impl PartialEq for GithubIdentityHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for GithubIdentityHandle {}
+
+impl Hash for GithubIdentityHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_ignoring_case(&self.0, state);
+    }
+}
+
+// Must agree with eq_ignore_ascii_case, hence handles equal regardless of case hash the same
+fn hash_ignoring_case<H: Hasher>(value: &str, state: &mut H) {
+    value.bytes().for_each(|byte| state.write_u8(byte.to_ascii_lowercase()));
+    state.write_u8(0xff);
+}
+
 impl TryFrom<ParsedLine> for GithubIdentityHandle {
     type Error = ValidationIssue;
 
@@ -63,7 +88,8 @@ impl TryFrom<ParsedLine> for GithubIdentityHandle {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Team slugs are case-insensitive as well, see [GithubIdentityHandle]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GithubTeamHandle {
     pub organization: GithubIdentityHandle,
     pub name: String,
@@ -75,6 +101,21 @@ impl GithubTeamHandle {
     }
 }
 
+impl PartialEq for GithubTeamHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.organization == other.organization && self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+
+impl Eq for GithubTeamHandle {}
+
+impl Hash for GithubTeamHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.organization.hash(state);
+        hash_ignoring_case(&self.name, state);
+    }
+}
+
 impl TryFrom<ParsedLine> for GithubTeamHandle {
     type Error = ValidationIssue;
 
@@ -117,6 +158,16 @@ pub enum Owner {
     EmailAddress(EmailHandle),
 }
 
+impl Display for Owner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Owner::GithubUser(identity) => write!(f, "@{}", identity.inner()),
+            Owner::GithubTeam(team) => write!(f, "@{}/{}", team.organization.inner(), team.name),
+            Owner::EmailAddress(email) => write!(f, "{}", email.0),
+        }
+    }
+}
+
 impl TryFrom<ParsedLine> for Owner {
     type Error = ValidationIssue;
 
@@ -0,0 +1,208 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use anyhow::{Context, bail};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A canopus version, like 0.4.1 or 0.6.0-beta.1. Missing minor or patch components
+/// are considered zero, and pre-releases come before the release they lead to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre_release: Option<String>,
+}
+
+impl Version {
+    /// The version of the running canopus binary
+    pub fn current() -> Self {
+        Self::from_str(env!("CARGO_PKG_VERSION")).expect("crate version must be valid")
+    }
+}
+
+impl FromStr for Version {
+    type Err = anyhow::Error;
+
+    fn from_str(raw: &str) -> anyhow::Result<Self> {
+        let trimmed = raw.trim();
+        let unprefixed = trimmed.strip_prefix('v').unwrap_or(trimmed);
+
+        let (numbers, pre_release) = match unprefixed.split_once('-') {
+            Some((numbers, pre_release)) => (numbers, Some(pre_release.to_string())),
+            None => (unprefixed, None),
+        };
+
+        let components = numbers
+            .split('.')
+            .map(|component| {
+                component
+                    .parse::<u64>()
+                    .with_context(|| format!("invalid version : {raw}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        if components.len() > 3 {
+            bail!("invalid version : {raw}");
+        }
+
+        Ok(Self {
+            major: components[0],
+            minor: components.get(1).copied().unwrap_or_default(),
+            patch: components.get(2).copied().unwrap_or_default(),
+            pre_release,
+        })
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre_release, &other.pre_release) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(mine), Some(theirs)) => mine.cmp(theirs),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        match &self.pre_release {
+            Some(pre_release) => write!(f, "-{pre_release}"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Comparator {
+    Greater,
+    GreaterOrEqual,
+    Lower,
+    LowerOrEqual,
+    Exact,
+}
+
+impl Comparator {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Comparator::Greater => ">",
+            Comparator::GreaterOrEqual => ">=",
+            Comparator::Lower => "<",
+            Comparator::LowerOrEqual => "<=",
+            Comparator::Exact => "=",
+        }
+    }
+}
+
+/// A range of accepted canopus versions, like ">=0.4" or ">=0.4, <0.6". A bare version is
+/// understood as a lower bound, and all comma-separated bounds must be satisfied
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionRequirement {
+    bounds: Vec<(Comparator, Version)>,
+}
+
+impl VersionRequirement {
+    pub fn matches(&self, version: &Version) -> bool {
+        self.bounds.iter().all(|(comparator, bound)| match comparator {
+            Comparator::Greater => version > bound,
+            Comparator::GreaterOrEqual => version >= bound,
+            Comparator::Lower => version < bound,
+            Comparator::LowerOrEqual => version <= bound,
+            Comparator::Exact => version == bound,
+        })
+    }
+}
+
+impl FromStr for VersionRequirement {
+    type Err = anyhow::Error;
+
+    fn from_str(raw: &str) -> anyhow::Result<Self> {
+        // Two-characters operators come first, so that ">=" is not taken for ">"
+        let comparators = [
+            Comparator::GreaterOrEqual,
+            Comparator::LowerOrEqual,
+            Comparator::Greater,
+            Comparator::Lower,
+            Comparator::Exact,
+        ];
+
+        let bounds = raw
+            .split(',')
+            .map(|bound| {
+                let bound = bound.trim();
+
+                let (comparator, version) = comparators
+                    .iter()
+                    .find_map(|comparator| {
+                        bound
+                            .strip_prefix(comparator.symbol())
+                            .map(|version| (*comparator, version))
+                    })
+                    .unwrap_or((Comparator::GreaterOrEqual, bound));
+
+                Version::from_str(version)
+                    .map(|version| (comparator, version))
+                    .with_context(|| format!("invalid version requirement : {raw}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self { bounds })
+    }
+}
+
+impl Display for VersionRequirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let bounds = self
+            .bounds
+            .iter()
+            .map(|(comparator, version)| format!("{}{version}", comparator.symbol()))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", bounds.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::versions::{Version, VersionRequirement};
+    use assertor::{BooleanAssertion, EqualityAssertion, ResultAssertion};
+    use std::str::FromStr;
+
+    #[test]
+    fn should_parse_and_compare_versions() {
+        let version = |raw: &str| Version::from_str(raw).unwrap();
+
+        assertor::assert_that!(version("v0.4").to_string()).is_equal_to("0.4.0".to_string());
+        assertor::assert_that!(version("0.6.0-beta.1") < version("0.6.0")).is_true();
+        assertor::assert_that!(version("0.10.0") > version("0.9.3")).is_true();
+        assertor::assert_that!(Version::from_str("0.x")).is_err();
+        assertor::assert_that!(Version::from_str("1.2.3.4")).is_err();
+    }
+
+    #[test]
+    fn should_match_versions_against_requirements() {
+        let version = |raw: &str| Version::from_str(raw).unwrap();
+        let requirement = VersionRequirement::from_str(">=0.4, <0.6").unwrap();
+
+        assertor::assert_that!(requirement.to_string()).is_equal_to(">=0.4.0, <0.6.0".to_string());
+        assertor::assert_that!(requirement.matches(&version("0.5.2"))).is_true();
+        assertor::assert_that!(requirement.matches(&version("0.3.9"))).is_false();
+        assertor::assert_that!(requirement.matches(&version("0.6.0"))).is_false();
+        assertor::assert_that!(VersionRequirement::from_str("0.4").unwrap().matches(&version("0.4.0"))).is_true();
+        assertor::assert_that!(VersionRequirement::from_str("~0.4")).is_err();
+    }
+}
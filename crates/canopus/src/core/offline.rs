@@ -0,0 +1,760 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+// Checks relying on nothing but CODEOWNERS contents and canopus configuration,
+// hence free of I/O and available wherever the parser is
+
+use crate::core::models::checks::IssueSeverity;
+use crate::core::models::codeowners;
+use crate::core::models::codeowners::{CodeOwners, CodeOwnersEntry, GITHUB_CODEOWNERS_SIZE_LIMIT};
+use crate::core::models::config::{
+    CanopusConfig, DEFAULT_VALUE_ENFORCE_GITHUB_TEAMS_OWNERS, DEFAULT_VALUE_ENFORCE_ONE_OWNER_PER_LINE,
+    DEFAULT_VALUE_FORBID_EMAIL_ADDRESSES, DEFAULT_VALUE_FORBID_TABS, DEFAULT_VALUE_FORBID_TRAILING_WHITESPACE,
+    DEFAULT_VALUE_REQUIRE_CODEOWNERS_OWNER, DEFAULT_VALUE_REQUIRE_FINAL_NEWLINE, DEFAULT_VALUE_REQUIRE_SECTIONS,
+    DEFAULT_VALUE_SORT_OWNERS_ALPHABETICALLY, DEFAULT_VALUE_SORT_RULES_BY_PATH, LimitsConfig, OwnershipConfig,
+    StyleConfig,
+};
+use crate::core::models::handles::Owner;
+use crate::core::models::{
+    ConfigurationIssue, IssueFix, IssueKind, StructuralIssue, StyleIssue, ValidationIssue, ValidationOutcome,
+};
+use crate::core::ownership::OwnershipResolver;
+use crate::core::policies::PolicyEngine;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Checks relying on nothing but CODEOWNERS contents and the configuration, run alike by the command line
+/// and by [`crate::validate_offline`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ContentCheck {
+    Syntax,
+    Limits,
+    DuplicateOwnership,
+    RepeatedOwners,
+    OwnersPerEntry,
+    AllowedOwners,
+    Ordering,
+    Whitespace,
+}
+
+impl ContentCheck {
+    /// Every content check, in the order validation runs them
+    pub const ALL: [ContentCheck; 8] = [
+        ContentCheck::Syntax,
+        ContentCheck::Limits,
+        ContentCheck::DuplicateOwnership,
+        ContentCheck::RepeatedOwners,
+        ContentCheck::OwnersPerEntry,
+        ContentCheck::AllowedOwners,
+        ContentCheck::Ordering,
+        ContentCheck::Whitespace,
+    ];
+
+    fn run(
+        self,
+        code_owners: &CodeOwners,
+        contents: &str,
+        config: &CanopusConfig,
+    ) -> anyhow::Result<ValidationOutcome> {
+        match self {
+            ContentCheck::Syntax => Ok(code_owners.syntax_validation.clone()),
+            ContentCheck::Limits => check_limits(code_owners, contents, &config.limits),
+            ContentCheck::DuplicateOwnership => check_duplicated_owners(code_owners),
+            ContentCheck::RepeatedOwners => check_repeated_owners(code_owners, contents, &config.style),
+            ContentCheck::OwnersPerEntry => check_multiple_ownership_per_entry(code_owners, config),
+            ContentCheck::AllowedOwners => check_allowed_owners(code_owners, config),
+            ContentCheck::Ordering => check_ordering(code_owners, contents, &config.style),
+            ContentCheck::Whitespace => check_whitespace(code_owners, contents, &config.style),
+        }
+    }
+}
+
+/// Runs every content check in order, each one through `run_phase`, which lets callers apply
+/// overrides, skip checks once reaching max issues or observe them
+pub fn check_contents(
+    code_owners: &CodeOwners,
+    contents: &str,
+    config: &CanopusConfig,
+    mut run_phase: impl FnMut(
+        ContentCheck,
+        &mut dyn FnMut() -> anyhow::Result<ValidationOutcome>,
+    ) -> anyhow::Result<ValidationOutcome>,
+) -> anyhow::Result<Vec<ValidationOutcome>> {
+    ContentCheck::ALL
+        .into_iter()
+        .map(|check| run_phase(check, &mut || check.run(code_owners, contents, config)))
+        .collect()
+}
+
+/// Gathers issues found by every check ordered by line, keeping up to general.max-issues of them
+pub fn collect_issues(validations: Vec<ValidationOutcome>, config: &CanopusConfig) -> ValidationOutcome {
+    if validations
+        .iter()
+        .all(|outcome| matches!(outcome, ValidationOutcome::NoIssues))
+    {
+        return ValidationOutcome::NoIssues;
+    }
+
+    let all_issues = validations
+        .into_iter()
+        .filter_map(|outcome| match outcome {
+            ValidationOutcome::NoIssues => None,
+            ValidationOutcome::IssuesDetected(issues) => Some(issues),
+        })
+        .flatten()
+        .sorted_by_key(|issue| issue.line)
+        .take(config.general.max_issues.unwrap_or(usize::MAX))
+        .map(|issue| {
+            // Strict mode leaves no room for warnings
+            if config.strict {
+                ValidationIssue {
+                    severity: IssueSeverity::Error,
+                    ..issue
+                }
+            } else {
+                issue
+            }
+        })
+        .collect_vec();
+
+    ValidationOutcome::IssuesDetected(all_issues)
+}
+
+pub fn check_duplicated_owners(code_owners: &CodeOwners) -> anyhow::Result<ValidationOutcome> {
+    let ownerships = code_owners
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            CodeOwnersEntry::Rule(ownership) => Some(ownership),
+            _ => None,
+        })
+        .collect_vec();
+
+    // Patterns are compared in their canonical form, hence docs/** and /docs/ are duplicates
+    let grouped_per_glob = ownerships
+        .iter()
+        .into_group_map_by(|rule| rule.canonical_glob())
+        .into_values()
+        .filter(|rules| rules.len() > 1)
+        .map(|rules| {
+            let globs = rules.iter().map(|rule| rule.glob.glob()).unique().join(", ");
+            let lines = rules.iter().map(|rule| rule.line_number).collect_vec();
+
+            let same_owners = rules
+                .iter()
+                .map(|rule| rule.owners.iter().collect::<HashSet<_>>())
+                .all_equal();
+
+            (globs, lines, same_owners)
+        })
+        .sorted_by_key(|(_, lines, _)| lines[0])
+        .collect_vec();
+
+    // Patterns sharing the same stem but not the same canonical form likely target the same paths
+    let near_duplicates = ownerships
+        .iter()
+        .into_group_map_by(|rule| codeowners::glob_stem(rule.glob.glob()).to_string())
+        .into_values()
+        .filter(|rules| rules.iter().map(|rule| rule.canonical_glob()).unique().count() > 1)
+        .map(|rules| {
+            let globs = rules.iter().map(|rule| rule.glob.glob()).unique().join(", ");
+            let lines = rules.iter().map(|rule| rule.line_number).collect_vec();
+            (globs, lines)
+        })
+        .sorted_by_key(|(_, lines)| lines[0])
+        .collect_vec();
+
+    if !grouped_per_glob.is_empty() || !near_duplicates.is_empty() {
+        let duplicates = grouped_per_glob.iter().map(|(globs, lines, same_owners)| {
+            let builder = ValidationIssue::builder()
+                .kind(IssueKind::Structural(StructuralIssue::DuplicateOwnership))
+                .line_number(lines[0]);
+
+            // Rules sharing the same owners are merged into the earliest one when repairing
+            if *same_owners {
+                builder
+                    .message(format!(
+                        "{} defined multiple times with the same owners : lines {:?}",
+                        globs, lines
+                    ))
+                    .fix(IssueFix::MergeDuplicates(lines[1..].to_vec()))
+                    .build()
+            } else {
+                builder
+                    .message(format!("{} defined multiple times : lines {:?}", globs, lines))
+                    .build()
+            }
+        });
+
+        let similar = near_duplicates.iter().map(|(globs, lines)| {
+            ValidationIssue::builder()
+                .kind(IssueKind::Structural(StructuralIssue::NearDuplicateOwnership))
+                .line_number(lines[0])
+                .message(format!("{} likely target the same paths : lines {:?}", globs, lines))
+                .build()
+        });
+
+        let issues = duplicates.chain(similar).collect_vec();
+
+        log::info!("Found some duplicated ownership rules");
+        return Ok(ValidationOutcome::IssuesDetected(issues));
+    }
+
+    log::info!("Duplicated code owners : not found");
+    Ok(ValidationOutcome::NoIssues)
+}
+
+pub fn check_repeated_owners(
+    code_owners: &CodeOwners,
+    contents: &str,
+    style_config: &StyleConfig,
+) -> anyhow::Result<ValidationOutcome> {
+    let raw_lines = contents.lines().collect_vec();
+
+    let issues = code_owners
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            CodeOwnersEntry::Rule(ownership) => Some(ownership),
+            _ => None,
+        })
+        .flat_map(|rule| {
+            let repeated = rule.owners.iter().duplicates().collect_vec();
+            let fixed_line = raw_lines
+                .get(rule.line_number)
+                .map(|raw_line| styled_line(raw_line, true, style_config));
+
+            repeated.into_iter().map(move |owner| {
+                let builder = ValidationIssue::builder()
+                    .kind(IssueKind::Structural(StructuralIssue::RepeatedOwner))
+                    .line_number(rule.line_number)
+                    .message(format!("{} listed more than once for {}", owner, rule.glob.glob()));
+
+                match &fixed_line {
+                    Some(fixed_line) => builder
+                        .fix(IssueFix::RewriteLine(rule.line_number, fixed_line.clone()))
+                        .build(),
+                    None => builder.build(),
+                }
+            })
+        })
+        .collect_vec();
+
+    if issues.is_empty() {
+        log::info!("Repeated owners within rules : not found");
+        return Ok(ValidationOutcome::NoIssues);
+    }
+
+    log::info!("Found some rules listing the same owner more than once");
+    Ok(ValidationOutcome::IssuesDetected(issues))
+}
+
+pub fn check_multiple_ownership_per_entry(
+    code_owners: &CodeOwners,
+    canopus_config: &CanopusConfig,
+) -> anyhow::Result<ValidationOutcome> {
+    // We short circuit if an opt-in disables this check
+    if !canopus_config
+        .ownership
+        .enforce_one_owner_per_line
+        .unwrap_or(DEFAULT_VALUE_ENFORCE_ONE_OWNER_PER_LINE)
+    {
+        return Ok(ValidationOutcome::NoIssues);
+    };
+
+    let entries_with_multiple_owners = code_owners
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            CodeOwnersEntry::Rule(ownership) => {
+                if ownership.owners.len() != 1 {
+                    Some(ownership)
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        })
+        .collect_vec();
+
+    if entries_with_multiple_owners.is_empty() {
+        log::info!("All ownership entries have a single owner defined");
+        return Ok(ValidationOutcome::NoIssues);
+    };
+
+    let issues = entries_with_multiple_owners
+        .iter()
+        .map(|rule| {
+            ValidationIssue::builder()
+                .kind(IssueKind::Configuration(ConfigurationIssue::OnlyOneOwnerPerEntry))
+                .line_number(rule.line_number)
+                .description("Entry defines more than one owner for this glob")
+                .build()
+        })
+        .collect_vec();
+
+    log::info!("Found some CodeOwners entries with multiple owners for the same glob");
+    Ok(ValidationOutcome::IssuesDetected(issues))
+}
+
+pub fn check_allowed_owners(
+    code_owners: &CodeOwners,
+    canopus_config: &CanopusConfig,
+) -> anyhow::Result<ValidationOutcome> {
+    let global_outcome = check_globally_allowed_owners(code_owners, canopus_config)?;
+    let policies_outcome =
+        PolicyEngine::new(&canopus_config.policies, &canopus_config.ownership)?.evaluate(code_owners);
+
+    let issues = [global_outcome, policies_outcome]
+        .into_iter()
+        .flat_map(|outcome| match outcome {
+            ValidationOutcome::NoIssues => vec![],
+            ValidationOutcome::IssuesDetected(issues) => issues,
+        })
+        .collect_vec();
+
+    if issues.is_empty() {
+        return Ok(ValidationOutcome::NoIssues);
+    }
+
+    Ok(ValidationOutcome::IssuesDetected(issues))
+}
+
+fn check_globally_allowed_owners(
+    code_owners: &CodeOwners,
+    canopus_config: &CanopusConfig,
+) -> anyhow::Result<ValidationOutcome> {
+    // This option takes precedence over email owners
+    if canopus_config
+        .ownership
+        .enforce_github_teams_owners
+        .unwrap_or(DEFAULT_VALUE_ENFORCE_GITHUB_TEAMS_OWNERS)
+    {
+        return check_only_github_teams_owners(code_owners);
+    };
+
+    if canopus_config
+        .ownership
+        .forbid_email_owners
+        .unwrap_or(DEFAULT_VALUE_FORBID_EMAIL_ADDRESSES)
+    {
+        return check_non_email_owners(code_owners);
+    };
+
+    Ok(ValidationOutcome::NoIssues)
+}
+
+fn check_non_email_owners(code_owners: &CodeOwners) -> anyhow::Result<ValidationOutcome> {
+    let email_owners = code_owners
+        .unique_owners()
+        .into_iter()
+        .filter(|owner| matches!(owner, Owner::EmailAddress(_)))
+        .collect_vec();
+
+    if email_owners.is_empty() {
+        log::info!("Email owners : not found");
+        return Ok(ValidationOutcome::NoIssues);
+    };
+
+    let issues = email_owners
+        .into_iter()
+        .map(|owner| {
+            ValidationIssue::builder()
+                .kind(IssueKind::Configuration(ConfigurationIssue::EmailOwnerForbidden))
+                .line_number(code_owners.occurrences(owner)[0])
+                .message("email owner is not allowed".to_string())
+                .build()
+        })
+        .collect_vec();
+
+    log::info!("Found owners defined by email");
+    Ok(ValidationOutcome::IssuesDetected(issues))
+}
+
+fn check_only_github_teams_owners(code_owners: &CodeOwners) -> anyhow::Result<ValidationOutcome> {
+    let non_github_team_owners = code_owners
+        .unique_owners()
+        .into_iter()
+        .filter(|owner| !matches!(owner, Owner::GithubTeam(_)))
+        .collect_vec();
+
+    if non_github_team_owners.is_empty() {
+        log::info!("All owners are Github teams");
+        return Ok(ValidationOutcome::NoIssues);
+    };
+
+    let issues = non_github_team_owners
+        .into_iter()
+        .map(|owner| {
+            ValidationIssue::builder()
+                .kind(IssueKind::Configuration(ConfigurationIssue::OnlyGithubTeamOwnerAllowed))
+                .line_number(code_owners.occurrences(owner)[0])
+                .message("only github team owner is allowed".to_string())
+                .build()
+        })
+        .collect_vec();
+
+    log::info!("Found owners defined by email or github users");
+    Ok(ValidationOutcome::IssuesDetected(issues))
+}
+
+pub fn check_ordering(
+    code_owners: &CodeOwners,
+    contents: &str,
+    style_config: &StyleConfig,
+) -> anyhow::Result<ValidationOutcome> {
+    let sort_owners = style_config
+        .sort_owners_alphabetically
+        .unwrap_or(DEFAULT_VALUE_SORT_OWNERS_ALPHABETICALLY);
+
+    let sort_rules = style_config
+        .sort_rules_by_path
+        .unwrap_or(DEFAULT_VALUE_SORT_RULES_BY_PATH);
+
+    let require_sections = style_config.require_sections.unwrap_or(DEFAULT_VALUE_REQUIRE_SECTIONS);
+
+    // We short circuit if no ordering policy is enabled
+    if !sort_owners && !sort_rules && !require_sections {
+        return Ok(ValidationOutcome::NoIssues);
+    }
+
+    let raw_lines = contents.lines().collect_vec();
+
+    let rules = code_owners
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            CodeOwnersEntry::Rule(ownership) => Some(ownership),
+            _ => None,
+        })
+        .collect_vec();
+
+    let mut issues = Vec::new();
+
+    if sort_owners {
+        let unsorted_owners = rules.iter().filter_map(|rule| {
+            let raw_line = raw_lines.get(rule.line_number)?;
+            let unsorted = codeowners::with_sorted_owners(raw_line) != *raw_line;
+            unsorted.then(|| (rule.line_number, styled_line(raw_line, true, style_config)))
+        });
+
+        issues.extend(unsorted_owners.map(|(line, fixed_line)| {
+            ValidationIssue::builder()
+                .kind(IssueKind::Configuration(ConfigurationIssue::OwnersNotSorted))
+                .line_number(line)
+                .description("Owners are not sorted alphabetically")
+                .fix(IssueFix::RewriteLine(line, fixed_line))
+                .build()
+        }));
+    }
+
+    if sort_rules {
+        // Comments and blank lines usually split CODEOWNERS in sections, hence we sort rules within blocks
+        let blocks = rules
+            .iter()
+            .enumerate()
+            .chunk_by(|(index, rule)| rule.line_number - index)
+            .into_iter()
+            .map(|(_, block)| block.map(|(_, rule)| *rule).collect_vec())
+            .collect_vec();
+
+        for block in blocks {
+            let sorted_lines = block
+                .iter()
+                .sorted_by_key(|rule| rule.glob.glob().trim_start_matches('/'))
+                .map(|rule| rule.line_number)
+                .collect_vec();
+
+            let lines = block.iter().map(|rule| rule.line_number).collect_vec();
+
+            if sorted_lines != lines {
+                issues.push(
+                    ValidationIssue::builder()
+                        .kind(IssueKind::Configuration(ConfigurationIssue::RulesNotSorted))
+                        .line_number(lines[0])
+                        .message(format!(
+                            "Rules are not sorted by path : lines {} to {}",
+                            lines[0] + 1,
+                            lines[lines.len() - 1] + 1
+                        ))
+                        .fix(IssueFix::ReorderLines(sorted_lines))
+                        .build(),
+                );
+            }
+        }
+    }
+
+    if require_sections {
+        let unsectioned_rules = code_owners
+            .sections()
+            .into_iter()
+            .filter(|section| section.name.is_none())
+            .flat_map(|section| section.rules);
+
+        issues.extend(unsectioned_rules.map(|rule| {
+            ValidationIssue::builder()
+                .kind(IssueKind::Configuration(ConfigurationIssue::RuleOutsideSection))
+                .line_number(rule.line_number)
+                .description("Rule does not belong to any section, like # === Backend ===")
+                .build()
+        }));
+    }
+
+    if issues.is_empty() {
+        log::info!("CodeOwners entries follow the configured ordering");
+        return Ok(ValidationOutcome::NoIssues);
+    }
+
+    log::info!("Found some CodeOwners entries not following the configured ordering");
+    Ok(ValidationOutcome::IssuesDetected(issues))
+}
+
+pub fn check_whitespace(
+    code_owners: &CodeOwners,
+    contents: &str,
+    style_config: &StyleConfig,
+) -> anyhow::Result<ValidationOutcome> {
+    let forbid_tabs = style_config.forbid_tabs.unwrap_or(DEFAULT_VALUE_FORBID_TABS);
+
+    let forbid_trailing_whitespace = style_config
+        .forbid_trailing_whitespace
+        .unwrap_or(DEFAULT_VALUE_FORBID_TRAILING_WHITESPACE);
+
+    let require_final_newline = style_config
+        .require_final_newline
+        .unwrap_or(DEFAULT_VALUE_REQUIRE_FINAL_NEWLINE);
+
+    // We short circuit if no whitespace policy is enabled
+    if !forbid_tabs && !forbid_trailing_whitespace && !require_final_newline && style_config.owners_column.is_none() {
+        return Ok(ValidationOutcome::NoIssues);
+    }
+
+    let rule_lines = code_owners
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            CodeOwnersEntry::Rule(ownership) => Some(ownership.line_number),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    let mut issues = Vec::new();
+
+    for (line, raw_line) in contents.lines().enumerate() {
+        let is_rule = rule_lines.contains(&line);
+        let mut violations = Vec::new();
+
+        if forbid_tabs && raw_line.contains('\t') {
+            violations.push((StyleIssue::TabCharacters, "Line contains tab characters".to_string()));
+        }
+
+        if forbid_trailing_whitespace && raw_line.trim_end() != raw_line {
+            violations.push((StyleIssue::TrailingWhitespace, "Line ends with whitespace".to_string()));
+        }
+
+        if let Some(column) = style_config.owners_column
+            && is_rule
+            && codeowners::owners_column(raw_line).is_some_and(|current| current < column)
+        {
+            violations.push((
+                StyleIssue::MisalignedOwners,
+                format!("Owners start before column {column}"),
+            ));
+        }
+
+        if violations.is_empty() {
+            continue;
+        }
+
+        let fixed_line = styled_line(raw_line, is_rule, style_config);
+
+        issues.extend(violations.into_iter().map(|(issue, reason)| {
+            ValidationIssue::builder()
+                .kind(IssueKind::Style(issue))
+                .line_number(line)
+                .message(reason)
+                .fix(IssueFix::RewriteLine(line, fixed_line.clone()))
+                .build()
+        }));
+    }
+
+    if require_final_newline && !contents.is_empty() && !contents.ends_with('\n') {
+        issues.push(
+            ValidationIssue::builder()
+                .kind(IssueKind::Style(StyleIssue::MissingFinalNewline))
+                .line_number(contents.lines().count() - 1)
+                .description("File does not end with a newline")
+                .fix(IssueFix::InsertFinalNewline)
+                .build(),
+        );
+    }
+
+    if issues.is_empty() {
+        log::info!("CodeOwners lines follow the configured whitespace style");
+        return Ok(ValidationOutcome::NoIssues);
+    }
+
+    log::info!("Found some CodeOwners lines not following the configured whitespace style");
+    Ok(ValidationOutcome::IssuesDetected(issues))
+}
+
+pub fn check_limits(
+    code_owners: &CodeOwners,
+    contents: &str,
+    limits_config: &LimitsConfig,
+) -> anyhow::Result<ValidationOutcome> {
+    let mut issues = Vec::new();
+
+    // Github does not complain about such files, it simply stops requesting reviews
+    if contents.len() > GITHUB_CODEOWNERS_SIZE_LIMIT {
+        issues.push(
+            ValidationIssue::builder()
+                .kind(IssueKind::Structural(StructuralIssue::FileSizeLimitExceeded))
+                .line_number(usize::MAX)
+                .message(format!(
+                    "CODEOWNERS has {} bytes, exceeding the {} bytes accepted by Github",
+                    contents.len(),
+                    GITHUB_CODEOWNERS_SIZE_LIMIT
+                ))
+                .build(),
+        );
+    }
+
+    if let Some(max_rules) = limits_config.max_rules {
+        let total_rules = code_owners
+            .entries
+            .iter()
+            .filter(|entry| matches!(entry, CodeOwnersEntry::Rule(_)))
+            .count();
+
+        if total_rules > max_rules {
+            issues.push(
+                ValidationIssue::builder()
+                    .kind(IssueKind::Configuration(ConfigurationIssue::TooManyRules))
+                    .line_number(usize::MAX)
+                    .message(format!(
+                        "CODEOWNERS defines {total_rules} rules, exceeding the maximum of {max_rules}"
+                    ))
+                    .build(),
+            );
+        }
+    }
+
+    if let Some(max_line_length) = limits_config.max_line_length {
+        let long_lines = contents
+            .lines()
+            .enumerate()
+            .map(|(line, raw_line)| (line, raw_line.chars().count()))
+            .filter(|(_, length)| *length > max_line_length);
+
+        issues.extend(long_lines.map(|(line, length)| {
+            ValidationIssue::builder()
+                .kind(IssueKind::Configuration(ConfigurationIssue::LineTooLong))
+                .line_number(line)
+                .message(format!(
+                    "Line has {length} characters, exceeding the maximum of {max_line_length}"
+                ))
+                .build()
+        }));
+    }
+
+    if issues.is_empty() {
+        log::info!("CodeOwners is within size limits");
+        return Ok(ValidationOutcome::NoIssues);
+    }
+
+    log::info!("Found CodeOwners exceeding size limits");
+    Ok(ValidationOutcome::IssuesDetected(issues))
+}
+
+/// Checks that changes to CODEOWNERS itself require a review, optionally from configured administrators
+pub fn check_codeowners_ownership(
+    code_owners: &CodeOwners,
+    codeowners_location: &Path,
+    ownership_config: &OwnershipConfig,
+) -> anyhow::Result<ValidationOutcome> {
+    let admins = &ownership_config.codeowners_admins;
+
+    // Listing administrators implies CODEOWNERS must be owned
+    let required = ownership_config
+        .require_codeowners_owner
+        .unwrap_or(DEFAULT_VALUE_REQUIRE_CODEOWNERS_OWNER)
+        || !admins.is_empty();
+
+    if !required {
+        return Ok(ValidationOutcome::NoIssues);
+    }
+
+    let resolver = OwnershipResolver::new(code_owners);
+    let location = codeowners_location.to_string_lossy();
+
+    let issue = match resolver.resolve(codeowners_location) {
+        None => ValidationIssue::builder()
+            .kind(IssueKind::Configuration(ConfigurationIssue::UnownedCodeOwners))
+            .line_number(usize::MAX)
+            .message(format!(
+                "No rule owns {location}, hence anyone can change ownership without a review"
+            ))
+            .build(),
+        Some(rule)
+            if !admins.is_empty()
+                && !rule.owners.iter().any(|owner| {
+                    admins
+                        .iter()
+                        .any(|admin| admin.eq_ignore_ascii_case(&owner.to_string()))
+                }) =>
+        {
+            ValidationIssue::builder()
+                .kind(IssueKind::Configuration(ConfigurationIssue::UnownedCodeOwners))
+                .line_number(rule.line_number)
+                .message(format!(
+                    "{location} is not owned by any of the CODEOWNERS administrators ({})",
+                    admins.join(", ")
+                ))
+                .build()
+        },
+        Some(_) => {
+            log::info!("CodeOwners is owned by itself");
+            return Ok(ValidationOutcome::NoIssues);
+        },
+    };
+
+    log::info!("Found CodeOwners not owned as required by configuration");
+    Ok(ValidationOutcome::IssuesDetected(vec![issue]))
+}
+
+/// Rewrites a CODEOWNERS line following every enabled style policy, hence all fixes for a line agree
+fn styled_line(raw_line: &str, is_rule: bool, style_config: &StyleConfig) -> String {
+    let mut line = raw_line.to_string();
+
+    // Repeated owners are never intended, hence every rewrite drops them
+    if is_rule {
+        line = codeowners::with_deduplicated_owners(&line);
+    }
+
+    if style_config.forbid_tabs.unwrap_or(DEFAULT_VALUE_FORBID_TABS) {
+        line = line.replace('\t', "    ");
+    }
+
+    if is_rule
+        && style_config
+            .sort_owners_alphabetically
+            .unwrap_or(DEFAULT_VALUE_SORT_OWNERS_ALPHABETICALLY)
+    {
+        line = codeowners::with_sorted_owners(&line);
+    }
+
+    if is_rule && let Some(column) = style_config.owners_column {
+        line = codeowners::with_owners_at_column(&line, column);
+    }
+
+    if style_config
+        .forbid_trailing_whitespace
+        .unwrap_or(DEFAULT_VALUE_FORBID_TRAILING_WHITESPACE)
+    {
+        line.truncate(line.trim_end().len());
+    }
+
+    line
+}
@@ -0,0 +1,144 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners::{CodeOwners, CodeOwnersEntry, RuleSelector};
+use crate::core::models::config::OverrideConfig;
+use crate::core::models::{ValidationIssue, ValidationOutcome};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// Resolves which configuration overrides apply to each CODEOWNERS rule, selecting rules
+/// whenever their patterns match the globs of an override
+#[derive(Debug, Default)]
+pub struct LineOverrides {
+    per_line: HashMap<usize, Vec<OverrideConfig>>,
+}
+
+impl LineOverrides {
+    pub fn new(code_owners: &CodeOwners, overrides: &[OverrideConfig]) -> anyhow::Result<Self> {
+        if overrides.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let selectors = overrides
+            .iter()
+            .map(|scoped| Ok((scoped, RuleSelector::new(&scoped.patterns)?)))
+            .collect::<anyhow::Result<Vec<(&OverrideConfig, RuleSelector)>>>()?;
+
+        let per_line = code_owners
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                CodeOwnersEntry::Rule(rule) => Some(rule),
+                _ => None,
+            })
+            .filter_map(|rule| {
+                let matching = selectors
+                    .iter()
+                    .filter(|(_, selector)| selector.selects(rule))
+                    .map(|(scoped, _)| (*scoped).clone())
+                    .collect_vec();
+
+                (!matching.is_empty()).then_some((rule.line_number, matching))
+            })
+            .collect();
+
+        Ok(Self { per_line })
+    }
+
+    /// The issue as reported for its line, or nothing when its check is disabled there
+    pub fn apply(&self, mut issue: ValidationIssue) -> Option<ValidationIssue> {
+        let Some(overrides) = self.per_line.get(&issue.line) else {
+            return Some(issue);
+        };
+
+        let check = issue.kind.definition().name;
+
+        if overrides
+            .iter()
+            .any(|scoped| scoped.disable.iter().any(|name| name == check))
+        {
+            return None;
+        }
+
+        // Later overrides win, as later rules do in CODEOWNERS
+        if let Some(severity) = overrides.iter().rev().find_map(|scoped| scoped.severity.get(check)) {
+            issue.severity = *severity;
+        }
+
+        Some(issue)
+    }
+
+    pub fn apply_to_outcome(&self, outcome: ValidationOutcome) -> ValidationOutcome {
+        match outcome {
+            ValidationOutcome::NoIssues => ValidationOutcome::NoIssues,
+            ValidationOutcome::IssuesDetected(issues) => {
+                let remaining = issues.into_iter().filter_map(|issue| self.apply(issue)).collect_vec();
+
+                if remaining.is_empty() {
+                    ValidationOutcome::NoIssues
+                } else {
+                    ValidationOutcome::IssuesDetected(remaining)
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::ValidationIssue;
+    use crate::core::models::checks::IssueSeverity;
+    use crate::core::models::codeowners::CodeOwners;
+    use crate::core::models::config::OverrideConfig;
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::overrides::LineOverrides;
+    use assertor::{EqualityAssertion, OptionAssertion};
+    use indoc::indoc;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_apply_overrides_to_selected_rules_only() -> anyhow::Result<()> {
+        let codeowners_rules = indoc! {"
+            *.rs                    @dotanuki-labs/rustaceans
+            /generated/protos/      @dotanuki-labs/rustaceans
+            /generated/schemas/     @dotanuki-labs/rustaceans
+        "};
+
+        let code_owners = CodeOwners::try_from(codeowners_rules)?;
+
+        let overrides = vec![
+            OverrideConfig {
+                patterns: vec!["generated/**".to_string()],
+                disable: vec!["dangling-glob".to_string()],
+                severity: BTreeMap::from([("duplicate-ownership".to_string(), IssueSeverity::Warning)]),
+            },
+            OverrideConfig {
+                patterns: vec!["generated/schemas".to_string()],
+                disable: vec![],
+                severity: BTreeMap::from([("duplicate-ownership".to_string(), IssueSeverity::Error)]),
+            },
+        ];
+
+        let line_overrides = LineOverrides::new(&code_owners, &overrides)?;
+
+        let issue = |line: usize, kind| {
+            ValidationIssue::builder()
+                .kind(kind)
+                .line_number(line)
+                .description("flagged")
+                .build()
+        };
+
+        let untouched = line_overrides.apply(issue(0, ValidationIssueKindFactory::dangling_glob_pattern()));
+        let disabled = line_overrides.apply(issue(1, ValidationIssueKindFactory::dangling_glob_pattern()));
+        let relaxed = line_overrides.apply(issue(1, ValidationIssueKindFactory::duplicate_ownership()));
+        let restored = line_overrides.apply(issue(2, ValidationIssueKindFactory::duplicate_ownership()));
+
+        assertor::assert_that!(untouched.map(|issue| issue.severity)).is_equal_to(Some(IssueSeverity::Error));
+        assertor::assert_that!(disabled).is_none();
+        assertor::assert_that!(relaxed.map(|issue| issue.severity)).is_equal_to(Some(IssueSeverity::Warning));
+        assertor::assert_that!(restored.map(|issue| issue.severity)).is_equal_to(Some(IssueSeverity::Error));
+        Ok(())
+    }
+}
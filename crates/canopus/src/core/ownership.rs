@@ -0,0 +1,121 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners::{CodeOwners, CodeOwnersEntry, OwnershipRule};
+use globset::GlobMatcher;
+use itertools::Itertools;
+use std::path::Path;
+
+/// Resolves effective ownership for project paths, following Github semantics
+/// where the last matching rule takes precedence
+pub struct OwnershipResolver<'a> {
+    rules: Vec<(&'a OwnershipRule, GlobMatcher)>,
+}
+
+impl<'a> OwnershipResolver<'a> {
+    pub fn new(code_owners: &'a CodeOwners) -> Self {
+        let rules = code_owners
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                CodeOwnersEntry::Rule(rule) => Some((rule, rule.glob.compile_matcher())),
+                _ => None,
+            })
+            .collect_vec();
+
+        Self { rules }
+    }
+
+    pub fn resolve(&self, path: &Path) -> Option<&'a OwnershipRule> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|(_, matcher)| matcher.is_match(path))
+            .map(|(rule, _)| *rule)
+    }
+
+    /// Every rule matching a path, in CODEOWNERS order, hence the last one owns it
+    pub fn matching(&self, path: &Path) -> Vec<&'a OwnershipRule> {
+        self.rules
+            .iter()
+            .filter(|(_, matcher)| matcher.is_match(path))
+            .map(|(rule, _)| *rule)
+            .collect()
+    }
+}
+
+/// How many project files are matched by at least one ownership rule
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OwnershipCoverage {
+    pub total_files: usize,
+    pub owned_files: usize,
+}
+
+impl OwnershipCoverage {
+    pub fn compute<P: AsRef<Path>>(code_owners: &CodeOwners, files: impl IntoIterator<Item = P>) -> Self {
+        let resolver = OwnershipResolver::new(code_owners);
+        let mut total_files = 0;
+        let mut owned_files = 0;
+
+        for file in files {
+            total_files += 1;
+
+            if resolver.resolve(file.as_ref()).is_some() {
+                owned_files += 1;
+            }
+        }
+
+        Self {
+            total_files,
+            owned_files,
+        }
+    }
+
+    pub fn percentage(&self) -> f64 {
+        if self.total_files == 0 {
+            return 100.0;
+        }
+
+        (self.owned_files as f64 / self.total_files as f64) * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::codeowners::CodeOwners;
+    use crate::core::ownership::{OwnershipCoverage, OwnershipResolver};
+    use assertor::{EqualityAssertion, OptionAssertion};
+    use indoc::indoc;
+    use std::path::Path;
+
+    #[test]
+    fn should_resolve_last_matching_rule() {
+        let codeowners_rules = indoc! {"
+            *.rs            @dotanuki-labs/rustaceans
+            crates/**/*.rs  @dotanuki-labs/crabbers
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+        let resolver = OwnershipResolver::new(&codeowners);
+
+        let owning_rule = resolver.resolve(Path::new("crates/canopus/main.rs")).unwrap();
+
+        assertor::assert_that!(owning_rule.line_number).is_equal_to(1);
+        assertor::assert_that!(resolver.resolve(Path::new("README.md"))).is_none();
+    }
+
+    #[test]
+    fn should_compute_ownership_coverage() {
+        let codeowners_rules = indoc! {"
+            *.rs    @dotanuki-labs/rustaceans
+        "};
+
+        let codeowners = CodeOwners::try_from(codeowners_rules).unwrap();
+        let files = vec!["main.rs", "lib.rs", "README.md", "Cargo.toml"];
+
+        let coverage = OwnershipCoverage::compute(&codeowners, &files);
+
+        assertor::assert_that!(coverage.owned_files).is_equal_to(2);
+        assertor::assert_that!(coverage.percentage()).is_equal_to(50.0);
+    }
+}
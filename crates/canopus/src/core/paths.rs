@@ -0,0 +1,65 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use std::path::{Path, PathBuf};
+
+/// Project paths packed back to back into a single buffer.
+///
+/// Huge repositories yield millions of paths, and keeping each one as a separate `PathBuf`
+/// costs an allocation plus 24 bytes of bookkeeping per path. Here every path costs only its
+/// own bytes and one offset. Paths are stored as UTF-8, like CODEOWNERS patterns matching them
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PathArena {
+    buffer: String,
+    ends: Vec<usize>,
+}
+
+impl PathArena {
+    pub fn push(&mut self, path: &Path) {
+        self.buffer.push_str(&path.to_string_lossy());
+        self.ends.push(self.buffer.len());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Path> {
+        let starts = std::iter::once(0).chain(self.ends.iter().copied());
+        starts
+            .zip(self.ends.iter().copied())
+            .map(|(start, end)| Path::new(&self.buffer[start..end]))
+    }
+
+    /// Copies paths out of the arena, for consumers still expecting owned paths
+    pub fn to_path_bufs(&self) -> Vec<PathBuf> {
+        self.iter().map(Path::to_path_buf).collect()
+    }
+}
+
+impl<P: AsRef<Path>> FromIterator<P> for PathArena {
+    fn from_iter<I: IntoIterator<Item = P>>(paths: I) -> Self {
+        let mut arena = PathArena::default();
+        paths.into_iter().for_each(|path| arena.push(path.as_ref()));
+        arena.buffer.shrink_to_fit();
+        arena.ends.shrink_to_fit();
+        arena
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::paths::PathArena;
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn should_pack_and_yield_paths_in_order() {
+        let paths = ["src/main.rs", "docs", "", "docs/using.md"];
+
+        let arena = paths.iter().collect::<PathArena>();
+        let yielded = arena.iter().collect::<Vec<_>>();
+
+        let expected = paths.iter().map(Path::new).collect::<Vec<_>>();
+
+        assertor::assert_that!(yielded).is_equal_to(expected);
+        assertor::assert_that!(arena.to_path_bufs()[3].clone()).is_equal_to(PathBuf::from("docs/using.md"));
+        assertor::assert_that!(PathArena::default().iter().next().is_none()).is_true();
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners::{CodeOwners, CodeOwnersEntry, OwnershipRule, RuleSelector};
+use crate::core::models::config::{
+    DEFAULT_VALUE_ENFORCE_GITHUB_TEAMS_OWNERS, DEFAULT_VALUE_FORBID_EMAIL_ADDRESSES, OwnershipConfig, PolicyConfig,
+};
+use crate::core::models::handles::Owner;
+use crate::core::models::{ConfigurationIssue, IssueKind, OwnerRole, ValidationIssue, ValidationOutcome};
+use crate::core::ownership::OwnershipResolver;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Evaluates ownership policies against the CODEOWNERS rules they select, reporting only
+/// the requirements not already enforced for every rule by the ownership section
+#[derive(Debug)]
+pub struct PolicyEngine<'a> {
+    policies: Vec<(&'a PolicyConfig, RuleSelector)>,
+    teams_only: bool,
+    emails_forbidden: bool,
+}
+
+impl<'a> PolicyEngine<'a> {
+    pub fn new(policies: &'a [PolicyConfig], ownership_config: &OwnershipConfig) -> anyhow::Result<Self> {
+        let policies = policies
+            .iter()
+            .map(|policy| Ok((policy, RuleSelector::new(&policy.patterns)?)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let teams_only = ownership_config
+            .enforce_github_teams_owners
+            .unwrap_or(DEFAULT_VALUE_ENFORCE_GITHUB_TEAMS_OWNERS);
+
+        let emails_forbidden = teams_only
+            || ownership_config
+                .forbid_email_owners
+                .unwrap_or(DEFAULT_VALUE_FORBID_EMAIL_ADDRESSES);
+
+        Ok(Self {
+            policies,
+            teams_only,
+            emails_forbidden,
+        })
+    }
+
+    pub fn evaluate(&self, code_owners: &CodeOwners) -> ValidationOutcome {
+        let issues = code_owners
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                CodeOwnersEntry::Rule(rule) => Some(rule),
+                _ => None,
+            })
+            .flat_map(|rule| {
+                let selecting = self
+                    .policies
+                    .iter()
+                    .filter(|(_, selector)| selector.selects(rule))
+                    .map(|(policy, _)| *policy)
+                    .collect_vec();
+
+                // Each owner is reported once per rule, requiring teams taking precedence over forbidding emails
+                rule.owners
+                    .iter()
+                    .unique()
+                    .filter_map(|owner| self.violation(&selecting, owner))
+                    .map(|(kind, patterns)| {
+                        ValidationIssue::builder()
+                            .kind(IssueKind::Configuration(kind.clone()))
+                            .line_number(rule.line_number)
+                            .message(match kind {
+                                ConfigurationIssue::OnlyGithubTeamOwnerAllowed => {
+                                    format!("only github team owner is allowed for {patterns}")
+                                },
+                                _ => format!("email owner is not allowed for {patterns}"),
+                            })
+                            .build()
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        if issues.is_empty() {
+            log::info!("All rules comply with ownership policies");
+            return ValidationOutcome::NoIssues;
+        }
+
+        log::info!("Found rules violating ownership policies");
+        ValidationOutcome::IssuesDetected(issues)
+    }
+
+    /// Whether some policy requires a minimum number of owners, hence project files to be evaluated
+    pub fn requires_project_files(&self) -> bool {
+        self.policies.iter().any(|(policy, _)| policy.min_owners.is_some())
+    }
+
+    /// Resolves effective owners of each project file selected by policies requiring a minimum
+    /// number of owners, reporting files short of owners grouped by their owning rule
+    pub fn evaluate_per_path<P: AsRef<Path>>(
+        &self,
+        code_owners: &CodeOwners,
+        project_files: impl IntoIterator<Item = P>,
+    ) -> ValidationOutcome {
+        let resolver = OwnershipResolver::new(code_owners);
+        let mut shortages: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+
+        for file in project_files {
+            let file = file.as_ref();
+
+            let requiring = self
+                .policies
+                .iter()
+                .enumerate()
+                .filter(|(_, (policy, selector))| policy.min_owners.is_some() && selector.selects_path(file))
+                .collect_vec();
+
+            if requiring.is_empty() {
+                continue;
+            }
+
+            let owning_rule = resolver.resolve(file);
+
+            // The first policy the file falls short of is enough to report it
+            let failing = requiring.into_iter().find(|(_, (policy, _))| {
+                let owners = owning_rule
+                    .map(|rule| {
+                        rule.owners
+                            .iter()
+                            .filter(|owner| !policy.distinct_teams || matches!(owner, Owner::GithubTeam(_)))
+                            .unique()
+                            .count()
+                    })
+                    .unwrap_or_default();
+
+                owners < policy.min_owners.unwrap_or_default()
+            });
+
+            if let Some((index, _)) = failing {
+                let line = owning_rule.map(|rule| rule.line_number).unwrap_or(usize::MAX);
+                *shortages.entry((line, index)).or_default() += 1;
+            }
+        }
+
+        if shortages.is_empty() {
+            log::info!("All critical paths have enough owners");
+            return ValidationOutcome::NoIssues;
+        }
+
+        let issues = shortages
+            .into_iter()
+            .map(|((line, index), files)| {
+                let (policy, _) = &self.policies[index];
+                let scope = format!("{files} files under {}", policy.patterns.join(", "));
+                let owners = if policy.distinct_teams {
+                    "distinct teams"
+                } else {
+                    "owners"
+                };
+                let requirement = format!("require at least {} {owners}", policy.min_owners.unwrap_or_default());
+
+                let message = if line == usize::MAX {
+                    format!("{scope} are not owned, while they {requirement}")
+                } else {
+                    format!("{scope} are owned by this rule, while they {requirement}")
+                };
+
+                ValidationIssue::builder()
+                    .kind(IssueKind::Configuration(ConfigurationIssue::NotEnoughOwners))
+                    .line_number(line)
+                    .message(message)
+                    .build()
+            })
+            .collect_vec();
+
+        log::info!("Found critical paths without enough owners");
+        ValidationOutcome::IssuesDetected(issues)
+    }
+
+    /// Rules selected by policies requiring a role from their owners, along with the highest role required
+    pub fn required_roles<'b>(&self, code_owners: &'b CodeOwners) -> Vec<(&'b OwnershipRule, OwnerRole)> {
+        code_owners
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                CodeOwnersEntry::Rule(rule) => Some(rule),
+                _ => None,
+            })
+            .filter_map(|rule| {
+                self.policies
+                    .iter()
+                    .filter(|(_, selector)| selector.selects(rule))
+                    .filter_map(|(policy, _)| policy.required_role)
+                    .max()
+                    .map(|required| (rule, required))
+            })
+            .collect_vec()
+    }
+
+    fn violation(&self, selecting: &[&PolicyConfig], owner: &Owner) -> Option<(ConfigurationIssue, String)> {
+        let is_team = matches!(owner, Owner::GithubTeam(_));
+        let is_email = matches!(owner, Owner::EmailAddress(_));
+
+        if !self.teams_only && !is_team {
+            let requiring_teams = selecting.iter().find(|policy| policy.require_team_owners);
+
+            if let Some(policy) = requiring_teams {
+                return Some((
+                    ConfigurationIssue::OnlyGithubTeamOwnerAllowed,
+                    policy.patterns.join(", "),
+                ));
+            }
+        }
+
+        if !self.emails_forbidden && is_email {
+            let forbidding_emails = selecting.iter().find(|policy| policy.forbid_email_owners);
+
+            if let Some(policy) = forbidding_emails {
+                return Some((ConfigurationIssue::EmailOwnerForbidden, policy.patterns.join(", ")));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::codeowners::CodeOwners;
+    use crate::core::models::config::{OwnershipConfig, PolicyConfig};
+    use crate::core::models::test_helpers::ValidationIssueKindFactory;
+    use crate::core::models::{ValidationIssue, ValidationOutcome};
+    use crate::core::policies::PolicyEngine;
+    use assertor::EqualityAssertion;
+    use indoc::indoc;
+
+    #[test]
+    fn should_require_team_owners_for_selected_rules_only() -> anyhow::Result<()> {
+        let codeowners_rules = indoc! {"
+            *.rs            @ubiratansoares
+            /infra/         @ubiratansoares @dotanuki-labs/platform
+            infra/*.tf      devops@dotanuki.dev
+        "};
+
+        let code_owners = CodeOwners::try_from(codeowners_rules)?;
+
+        let policies = vec![
+            PolicyConfig {
+                patterns: vec!["infra/**".to_string()],
+                require_team_owners: true,
+                forbid_email_owners: false,
+                min_owners: None,
+                distinct_teams: false,
+                required_role: None,
+            },
+            PolicyConfig {
+                patterns: vec!["**".to_string()],
+                require_team_owners: false,
+                forbid_email_owners: true,
+                min_owners: None,
+                distinct_teams: false,
+                required_role: None,
+            },
+        ];
+
+        let engine = PolicyEngine::new(&policies, &OwnershipConfig::default())?;
+
+        let expected = ValidationOutcome::IssuesDetected(vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::only_github_team_owner_allowed())
+                .line_number(1)
+                .message("only github team owner is allowed for infra/**".to_string())
+                .build(),
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::only_github_team_owner_allowed())
+                .line_number(2)
+                .message("only github team owner is allowed for infra/**".to_string())
+                .build(),
+        ]);
+
+        assertor::assert_that!(engine.evaluate(&code_owners)).is_equal_to(expected);
+        Ok(())
+    }
+
+    #[test]
+    fn should_require_minimum_owners_per_critical_path() -> anyhow::Result<()> {
+        let codeowners_rules = indoc! {"
+            *                   @dotanuki-labs/maintainers
+            payments/**         @dotanuki-labs/payments @ubiratansoares
+            payments/ledger/**  @dotanuki-labs/payments @dotanuki-labs/finance
+        "};
+
+        let code_owners = CodeOwners::try_from(codeowners_rules)?;
+
+        let policies = vec![PolicyConfig {
+            patterns: vec!["payments/**".to_string()],
+            require_team_owners: false,
+            forbid_email_owners: false,
+            min_owners: Some(2),
+            distinct_teams: true,
+            required_role: None,
+        }];
+
+        let project_files = [
+            "README.md",
+            "payments/api/charges.rs",
+            "payments/api/refunds.rs",
+            "payments/ledger/entries.rs",
+        ];
+
+        let engine = PolicyEngine::new(&policies, &OwnershipConfig::default())?;
+
+        let expected = ValidationOutcome::IssuesDetected(vec![
+            ValidationIssue::builder()
+                .kind(ValidationIssueKindFactory::not_enough_owners())
+                .line_number(1)
+                .message(
+                    "2 files under payments/** are owned by this rule, while they require at least 2 distinct teams"
+                        .to_string(),
+                )
+                .build(),
+        ]);
+
+        assertor::assert_that!(engine.requires_project_files()).is_equal_to(true);
+        assertor::assert_that!(engine.evaluate_per_path(&code_owners, project_files)).is_equal_to(expected);
+        Ok(())
+    }
+}
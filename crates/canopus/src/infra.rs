@@ -1,6 +1,21 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+pub mod checks;
 pub mod cli;
+pub mod directory;
+pub mod external;
+pub mod fixtures;
+pub mod git;
 pub mod github;
+pub mod gitlab;
 pub mod paths;
+pub mod progress;
+pub mod prometheus;
+pub mod pulls;
+pub mod releases;
+pub mod remote;
+pub mod retries;
+pub mod roster;
+pub mod server;
+pub mod storage;
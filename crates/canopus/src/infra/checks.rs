@@ -0,0 +1,157 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::infra::remote::RemoteRepository;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/// Github accepts at most this many annotations per check run request
+static ANNOTATIONS_PER_REQUEST: usize = 50;
+
+static CHECK_RUN_NAME: &str = "canopus";
+
+/// A finding attached to a line of a file, displayed by Github alongside the pull request diff
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CheckRunAnnotation {
+    pub path: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub annotation_level: &'static str,
+    pub title: String,
+    pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CheckConclusion {
+    Success,
+    Failure,
+}
+
+impl CheckConclusion {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CheckConclusion::Success => "success",
+            CheckConclusion::Failure => "failure",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CreatedCheckRun {
+    id: u64,
+}
+
+#[derive(Deserialize)]
+struct UpdatedCheckRun {}
+
+/// Publishes a completed check run on a commit, sending annotations in batches as Github requires.
+/// Creating check runs is restricted to Github Apps, hence the client must carry an installation token
+pub async fn publish_check_run(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+    head_sha: &str,
+    conclusion: CheckConclusion,
+    summary: &str,
+    annotations: &[CheckRunAnnotation],
+) -> anyhow::Result<()> {
+    let check_runs_route = format!("/repos/{}/{}/check-runs", repository.owner, repository.name);
+    let title = format!("{} annotations", annotations.len());
+
+    let mut batches = annotations.chunks(ANNOTATIONS_PER_REQUEST);
+
+    let payload = json!({
+        "name": CHECK_RUN_NAME,
+        "head_sha": head_sha,
+        "status": "completed",
+        "conclusion": conclusion.as_str(),
+        "output": {
+            "title": title,
+            "summary": summary,
+            "annotations": batches.next().unwrap_or_default(),
+        },
+    });
+
+    let created: CreatedCheckRun = github_client
+        .post(&check_runs_route, Some(&payload))
+        .await
+        .with_context(|| format!("cannot create check run on {}/{}", repository.owner, repository.name))?;
+
+    let check_run_route = format!("{check_runs_route}/{}", created.id);
+
+    for batch in batches {
+        let payload = json!({
+            "output": {
+                "title": title,
+                "summary": summary,
+                "annotations": batch,
+            },
+        });
+
+        let _: UpdatedCheckRun = github_client
+            .patch(&check_run_route, Some(&payload))
+            .await
+            .with_context(|| format!("cannot annotate check run #{}", created.id))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::checks;
+    use crate::infra::checks::{CheckConclusion, CheckRunAnnotation};
+    use crate::infra::github::test_helpers::create_github_client;
+    use crate::infra::remote::RemoteRepository;
+    use httpmock::MockServer;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn should_publish_annotations_in_batches() {
+        let mock_server = MockServer::start();
+
+        let created = mock_server.mock(|when, then| {
+            when.method("POST")
+                .path("/repos/dotanuki-labs/canopus/check-runs")
+                .json_body_includes(r#"{"head_sha": "cafebabe", "conclusion": "failure"}"#);
+
+            then.status(201)
+                .header("content-type", "application/json")
+                .body(r#"{"id": 4}"#);
+        });
+
+        let annotated = mock_server.mock(|when, then| {
+            when.method("PATCH").path("/repos/dotanuki-labs/canopus/check-runs/4");
+
+            then.status(200).header("content-type", "application/json").body("{}");
+        });
+
+        let annotations = (1..=60)
+            .map(|line| CheckRunAnnotation {
+                path: ".github/CODEOWNERS".to_string(),
+                start_line: line,
+                end_line: line,
+                annotation_level: "failure",
+                title: "dangling-glob".to_string(),
+                message: "pattern does not match any project path".to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let github_client = create_github_client(mock_server.base_url());
+        let repository = RemoteRepository::from_str("dotanuki-labs/canopus").unwrap();
+
+        checks::publish_check_run(
+            &github_client,
+            &repository,
+            "cafebabe",
+            CheckConclusion::Failure,
+            "60 issues",
+            &annotations,
+        )
+        .await
+        .unwrap();
+
+        created.assert();
+        annotated.assert_calls(1);
+    }
+}
@@ -1,18 +1,148 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::canopus::CanopusCommand;
-use crate::canopus::CanopusCommand::{RepairCodeowners, ValidateCodeowners};
+use crate::canopus::CanopusCommand::{
+    AuditOrganization, CheckConfiguration, ComputeStatistics, DescribeChecks, DiffOwnership, ExplainPattern,
+    ExportOwnership, GenerateCompletions, GenerateHeatmap, GenerateManPage, GenerateReport, InitProject, ListReviewers,
+    RepairCodeowners, ReportCoverage, RunDemo, RunOneShot, SelfUpdate, ServeValidation, ShowCapabilities,
+    ShowReportSchema, SnapshotOwnership, SyncRoster, ValidateCodeowners, VerifyOwnershipSnapshot,
+};
+use crate::canopus::diff::DiffFormat;
+use crate::canopus::formats::OutputFormat;
+use crate::canopus::patterns::ExplainFormat;
+use crate::canopus::repairing::RepairScope;
+use crate::canopus::reporting::ReportFormat;
+use crate::canopus::reporting::heatmap::HeatmapFormat;
+use crate::canopus::reviewers::ReviewersFormat;
+use crate::canopus::stats::StatsFormat;
+use crate::canopus::stats::export::ExportFormat;
+use crate::canopus::{CanopusCommand, ProjectLocation};
+use crate::core::models::checks::CHECKS;
+use crate::core::models::deprecations;
+use crate::core::models::deprecations::{DEPRECATIONS, DeprecationPolicy};
 use crate::infra::cli::Commands::Validate;
-use Commands::Repair;
-use clap::{Args, Parser, Subcommand};
-use std::path::PathBuf;
+use crate::infra::fixtures::FixturesMode;
+use crate::infra::progress;
+use crate::infra::progress::ProgressFormat;
+use crate::infra::remote::RemoteRepository;
+use Commands::{
+    Audit, Capabilities, Completions, Config, Coverage, Demo, Diff, Docs, Explain, Export, Init, Oneshot, Repair,
+    Report, Reviewers, Roster, Schema, SelfUpdateCanopus, Serve, Snapshot, Stats, VerifySnapshot,
+};
+use anyhow::{Context, bail};
+use clap::{Args, Command, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use clap_complete::engine::{ArgValueCompleter, CompletionCandidate};
+use itertools::Itertools;
+use log::LevelFilter;
+use std::ffi::OsStr;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 
 #[derive(Args, Debug)]
 #[command(version, about, long_about = None)]
 struct ValidateArguments {
-    #[arg(short, long, help = "Path pointing to project root")]
-    pub path: PathBuf,
+    #[arg(
+        short,
+        long,
+        required_unless_present_any = ["remote", "manifest"],
+        help = "Path pointing to project root, repeatable to validate several projects"
+    )]
+    pub path: Vec<PathBuf>,
+
+    #[arg(long, help = "File listing project roots to validate, one per line")]
+    pub manifest: Option<PathBuf>,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["path", "manifest"],
+        help = "Github repository to validate without cloning, as owner/repo@ref"
+    )]
+    pub remote: Option<RemoteRepository>,
+
+    #[arg(
+        long,
+        value_parser = parse_percentage,
+        help = "Minimum percentage of project files that must have an owner"
+    )]
+    pub min_coverage: Option<f64>,
+
+    #[arg(long, help = "Git revision whose ownership coverage must not be decreased")]
+    pub coverage_base: Option<String>,
+
+    #[arg(
+        long,
+        help = "Maximum number of issues to report, skipping remaining checks once reached"
+    )]
+    pub max_issues: Option<usize>,
+
+    #[arg(
+        long,
+        action,
+        conflicts_with = "max_issues",
+        help = "Whether to stop validation at the first issue found"
+    )]
+    pub fail_fast: bool,
+
+    #[arg(
+        long,
+        action,
+        conflicts_with = "replay",
+        help = "Whether to enable every optional check, report warnings as errors and verify owners online"
+    )]
+    pub strict: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value = "text",
+        help = "Format of validation results, repeated to write several reports with --output-dir"
+    )]
+    pub format: Vec<OutputFormat>,
+
+    #[arg(
+        long,
+        help = "Directory where a report is written for each machine-readable format, like canopus-report.json"
+    )]
+    pub output_dir: Option<PathBuf>,
+
+    #[arg(short, long, action, help = "Whether to print nothing but detected issues")]
+    pub quiet: bool,
+
+    #[arg(
+        long,
+        value_name = "NUMBER",
+        help = "Pull request commented with validation results, updating the previous comment if any"
+    )]
+    pub post_to_pr: Option<u64>,
+
+    #[arg(
+        long,
+        action,
+        requires = "post_to_pr",
+        help = "Whether to also set a commit status on the head of the pull request"
+    )]
+    pub commit_status: bool,
+
+    #[arg(
+        long,
+        action,
+        help = "Whether to publish results as a check run annotating CODEOWNERS, requiring a Github App token"
+    )]
+    pub check_run: bool,
+
+    #[arg(long, help = "File where answers from Github API are recorded for later replays")]
+    pub record: Option<PathBuf>,
+
+    #[arg(
+        long,
+        conflicts_with = "record",
+        help = "File with recorded answers from Github API, replayed instead of calling it"
+    )]
+    pub replay: Option<PathBuf>,
+
+    #[arg(long, help = "File where usage metrics for this run are written, as JSON")]
+    pub metrics_out: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug)]
@@ -26,6 +156,307 @@ struct RepairArguments {
 
     #[arg(short, long, action, help = "Whether to remove problematic lines when repairing")]
     pub remove_lines: bool,
+
+    #[arg(
+        long,
+        value_delimiter = ',',
+        add = ArgValueCompleter::new(complete_check_names),
+        help = "Checks whose issues are repaired, as comma-separated names (default : all checks)"
+    )]
+    pub only: Vec<String>,
+
+    #[arg(
+        long,
+        value_delimiter = ',',
+        add = ArgValueCompleter::new(complete_check_names),
+        help = "Checks whose issues are never repaired, as comma-separated names"
+    )]
+    pub except: Vec<String>,
+
+    #[arg(
+        long,
+        action,
+        help = "Whether to also repair lines flagged because Github API could not be reached"
+    )]
+    pub force: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct CoverageArguments {
+    #[arg(short, long, help = "Path pointing to project root")]
+    pub path: PathBuf,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct ReportArguments {
+    #[arg(short, long, help = "Path pointing to project root")]
+    pub path: PathBuf,
+
+    #[arg(long, value_enum, default_value = "md", help = "Format of the generated report")]
+    pub format: ReportFormat,
+
+    #[arg(
+        long,
+        value_enum,
+        conflicts_with = "format",
+        help = "Rolls up effective ownership per directory instead, rendered with this format"
+    )]
+    pub heatmap: Option<HeatmapFormat>,
+
+    #[arg(
+        long,
+        default_value_t = 1,
+        requires = "heatmap",
+        value_parser = clap::value_parser!(u16).range(1..),
+        help = "How many directory levels the heat map keeps"
+    )]
+    pub depth: u16,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct StatsArguments {
+    #[arg(short, long, help = "Path pointing to project root")]
+    pub path: PathBuf,
+
+    #[arg(long, value_enum, default_value = "table", help = "Format of computed statistics")]
+    pub format: StatsFormat,
+
+    #[arg(
+        long,
+        action,
+        help = "Whether to estimate how many recent pull requests each owner would review"
+    )]
+    pub review_load: bool,
+
+    #[arg(
+        long,
+        default_value_t = 30,
+        requires = "review_load",
+        help = "How many days of pull requests are considered for review load"
+    )]
+    pub review_days: u32,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct ExportArguments {
+    #[arg(short, long, help = "Path pointing to project root")]
+    pub path: PathBuf,
+
+    #[arg(long, value_enum, default_value = "csv", help = "Format of exported ownership")]
+    pub format: ExportFormat,
+
+    #[arg(short, long, help = "File receiving exported ownership, instead of stdout")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct DiffArguments {
+    #[arg(short, long, default_value = ".", help = "Path pointing to project root")]
+    pub path: PathBuf,
+
+    #[arg(long, help = "Git revision ownership is compared from")]
+    pub from: String,
+
+    #[arg(long, default_value = "HEAD", help = "Git revision ownership is compared to")]
+    pub to: String,
+
+    #[arg(long, value_enum, default_value = "text", help = "Format of reported changes")]
+    pub format: DiffFormat,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct ReviewersArguments {
+    #[arg(short, long, default_value = ".", help = "Path pointing to project root")]
+    pub path: PathBuf,
+
+    #[arg(
+        long,
+        required_unless_present = "files",
+        help = "Git revision whose changes up to the working tree are reviewed"
+    )]
+    pub changed_since: Option<String>,
+
+    #[arg(
+        long,
+        num_args = 1..,
+        conflicts_with = "changed_since",
+        help = "Changed files to review, relative to project root"
+    )]
+    pub files: Vec<PathBuf>,
+
+    #[arg(long, value_enum, default_value = "text", help = "Format of requested reviewers")]
+    pub format: ReviewersFormat,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct ExplainArguments {
+    #[arg(help = "CODEOWNERS pattern to explain, quoted to prevent shell expansion")]
+    pub pattern: String,
+
+    #[arg(short, long, default_value = ".", help = "Path pointing to project root")]
+    pub path: PathBuf,
+
+    #[arg(long, value_enum, default_value = "text", help = "Format of the explanation")]
+    pub format: ExplainFormat,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct SnapshotArguments {
+    #[arg(short, long, default_value = ".", help = "Path pointing to project root")]
+    pub path: PathBuf,
+
+    #[arg(
+        long,
+        help = "Path to the ownership snapshot, defaults to canopus.lock at project root"
+    )]
+    pub snapshot: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct AuditArguments {
+    #[arg(long, help = "Github organization whose repositories will be audited")]
+    pub org: String,
+
+    #[arg(short, long, help = "Path to a canopus.toml defining where audit results are stored")]
+    pub config: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "ADDRESS",
+        help = "Address where audit progress and Github API usage are exposed as Prometheus metrics"
+    )]
+    pub metrics_listen: Option<SocketAddr>,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct ConfigCheckArguments {
+    #[arg(short, long, help = "Path pointing to project root")]
+    pub path: PathBuf,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct InitArguments {
+    #[arg(short, long, default_value = ".", help = "Path pointing to project root")]
+    pub path: PathBuf,
+
+    #[arg(
+        long,
+        help = "Github organization owning the project, detected from git remote when omitted"
+    )]
+    pub org: Option<String>,
+
+    #[arg(long, help = "Github team owning every project path in the starter CODEOWNERS")]
+    pub team: Option<String>,
+
+    #[arg(long, action, help = "Whether to overwrite an existing canopus.toml")]
+    pub force: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct RosterSyncArguments {
+    #[arg(long, help = "Github organization whose members and teams are synced")]
+    pub org: String,
+
+    #[arg(
+        short,
+        long,
+        default_value = "owners-roster.yaml",
+        help = "Path to the roster file, written as YAML, JSON or TOML according to its extension"
+    )]
+    pub output: PathBuf,
+}
+
+#[derive(Subcommand)]
+enum RosterCommands {
+    /// Writes members, teams and team memberships of an organization to a roster file
+    Sync(RosterSyncArguments),
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Validates canopus.toml, printing the effective configuration
+    Check(ConfigCheckArguments),
+}
+
+#[derive(Subcommand)]
+enum DocsCommands {
+    /// Prints the canopus man page, in roff format
+    Man,
+
+    /// Prints a Markdown reference of every check, with codes, descriptions and config keys
+    Checks,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct CapabilitiesArguments {
+    #[arg(long, action, help = "Whether to describe capabilities as JSON")]
+    pub json: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct CompletionsArguments {
+    #[arg(value_enum, help = "Shell to generate the completion script for")]
+    pub shell: Shell,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct ServeArguments {
+    #[arg(
+        short,
+        long,
+        default_value = ".",
+        help = "Path pointing to the project whose configuration is served"
+    )]
+    pub path: PathBuf,
+
+    #[arg(
+        long,
+        value_name = "ADDRESS",
+        default_value = "127.0.0.1:8080",
+        help = "Address where the HTTP API listens to"
+    )]
+    pub listen: SocketAddr,
+
+    #[arg(
+        long,
+        value_name = "ADDRESS",
+        help = "Address where the gRPC API listens to, described by canopus/v1/ownership.proto"
+    )]
+    pub grpc_listen: Option<SocketAddr>,
+
+    #[arg(
+        long,
+        value_name = "BYTES",
+        default_value = "10485760",
+        help = "Largest HTTP request body accepted, answering 413 beyond it"
+    )]
+    pub max_body_bytes: usize,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct SelfUpdateArguments {
+    #[arg(
+        long,
+        action,
+        help = "Whether to only check for a newer version, without installing it"
+    )]
+    pub check: bool,
 }
 
 #[derive(Parser)]
@@ -34,6 +465,42 @@ struct RepairArguments {
 struct CliParser {
     #[command(subcommand)]
     pub command: Commands,
+
+    #[arg(
+        long,
+        global = true,
+        action,
+        help = "Whether to fail when deprecated flags or config keys are used"
+    )]
+    pub deny_deprecated: bool,
+
+    #[arg(
+        long,
+        global = true,
+        action,
+        help = "Whether to run external checks declared by configurations extended through general.extends"
+    )]
+    pub trust_extended_checks: bool,
+
+    #[arg(long, global = true, action, help = "Whether to disable colored output")]
+    pub no_color: bool,
+
+    #[arg(
+        short,
+        long,
+        global = true,
+        action = clap::ArgAction::Count,
+        help = "Increases logging verbosity, repeatable up to -vvv"
+    )]
+    pub verbose: u8,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        help = "Emits progress events on stderr using this format"
+    )]
+    pub progress: Option<ProgressFormat>,
 }
 
 #[derive(Subcommand)]
@@ -42,20 +509,459 @@ enum Commands {
     Repair(RepairArguments),
 
     /// Validates a CodeOwners file within a project
-    Validate(ValidateArguments),
+    Validate(Box<ValidateArguments>),
+
+    /// Reports how many project files are owned by CodeOwners rules
+    Coverage(CoverageArguments),
+
+    /// Generates a human-readable ownership report for a project
+    Report(ReportArguments),
+
+    /// Computes per-owner ownership statistics for a project
+    Stats(StatsArguments),
+
+    /// Exports one row per rule and owner, with matched files, for ingestion into data warehouses
+    Export(ExportArguments),
+
+    /// Reports ownership changes between two git revisions of a project
+    Diff(DiffArguments),
+
+    /// Lists owners whose review Github would request for a set of changed files
+    Reviewers(ReviewersArguments),
+
+    /// Explains which project files a CODEOWNERS pattern matches, warning about common pitfalls
+    Explain(ExplainArguments),
+
+    /// Records which files each owner resolves to, as an auditable ownership snapshot
+    Snapshot(SnapshotArguments),
+
+    /// Fails when effective ownership drifted from the recorded ownership snapshot
+    VerifySnapshot(SnapshotArguments),
+
+    /// Validates CodeOwners files across all repositories of a Github organization
+    Audit(AuditArguments),
+
+    /// Inspects canopus configuration for a project
+    #[command(subcommand)]
+    Config(ConfigCommands),
+
+    /// Manages the roster owners are verified against offline
+    #[command(subcommand)]
+    Roster(RosterCommands),
+
+    /// Scaffolds canopus.toml and a starter CODEOWNERS for a project
+    Init(InitArguments),
+
+    /// Runs validation over an example project, explaining each finding
+    Demo,
+
+    /// Describes checks, formats and configuration keys supported by this version
+    Capabilities(CapabilitiesArguments),
+
+    /// Prints the JSON schema describing reports produced with --format json
+    Schema,
+
+    /// Generates a completion script for bash, zsh, fish, elvish or powershell
+    Completions(CompletionsArguments),
+
+    /// Generates reference documentation for this version
+    #[command(subcommand)]
+    Docs(DocsCommands),
+
+    /// Replaces this binary with the latest canopus release, after verifying its checksum
+    #[command(name = "self-update")]
+    SelfUpdateCanopus(SelfUpdateArguments),
+
+    /// Serves validation and ownership queries over HTTP, keeping Github caches warm between requests
+    Serve(ServeArguments),
+
+    /// Validates a project configured through CANOPUS_* environment variables only, writing the report
+    /// to CANOPUS_OUTPUT_DIR. Exits with 0 when valid, 1 when issues are found and 2 otherwise
+    Oneshot,
+}
+
+fn evaluate_projects(
+    paths: Vec<PathBuf>,
+    manifest: Option<PathBuf>,
+    remote: Option<RemoteRepository>,
+) -> anyhow::Result<Vec<ProjectLocation>> {
+    if let Some(repository) = remote {
+        return Ok(vec![ProjectLocation::Remote(repository)]);
+    }
+
+    let mut project_roots = paths;
+
+    if let Some(manifest) = manifest {
+        project_roots.extend(read_manifest(&manifest)?);
+    }
+
+    if project_roots.is_empty() {
+        bail!("expecting at least one project to validate");
+    }
+
+    Ok(project_roots.into_iter().unique().map(ProjectLocation::Local).collect())
+}
+
+/// Several formats only make sense when written to files, while a single one may go to the standard output
+fn evaluate_formats(formats: Vec<OutputFormat>, output_dir: Option<&Path>) -> anyhow::Result<Vec<OutputFormat>> {
+    let formats = formats.into_iter().unique().collect_vec();
+
+    match output_dir {
+        None if formats.len() > 1 => bail!("writing several formats requires --output-dir"),
+        Some(_) if !formats.iter().any(OutputFormat::is_machine_readable) => {
+            bail!("writing reports to --output-dir requires a machine-readable format, like json or junit")
+        },
+        _ => Ok(formats),
+    }
+}
+
+/// Percentages such as coverage thresholds, from 0 to 100
+fn parse_percentage(value: &str) -> Result<f64, String> {
+    let percentage = value
+        .parse::<f64>()
+        .map_err(|_| format!("expecting a percentage, found {value}"))?;
+
+    if !(0.0..=100.0).contains(&percentage) {
+        return Err(format!("expecting a percentage between 0 and 100, found {value}"));
+    }
+
+    Ok(percentage)
+}
+
+/// Reads project roots from a manifest file, resolving them against the manifest location
+fn read_manifest(manifest: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let contents = std::fs::read_to_string(manifest)
+        .with_context(|| format!("cannot read projects manifest at {}", manifest.display()))?;
+
+    let manifest_dir = manifest.parent().unwrap_or(Path::new("."));
+
+    let project_roots = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| manifest_dir.join(line))
+        .collect();
+
+    Ok(project_roots)
+}
+
+/// The clap command describing every subcommand and flag of this canopus version
+pub fn command() -> Command {
+    CliParser::command()
+}
+
+/// Names of registered checks starting with what the user typed so far
+fn complete_check_names(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return vec![];
+    };
+
+    CHECKS
+        .iter()
+        .filter(|check| check.name.starts_with(current))
+        .map(|check| CompletionCandidate::new(check.name).help(Some(check.description.into())))
+        .collect()
+}
+
+/// Everything requested by the user through the command line
+pub struct Invocation {
+    pub command: CanopusCommand,
+    pub deprecation_policy: DeprecationPolicy,
+    pub trust_extended_checks: bool,
+    pub log_level: Option<LevelFilter>,
+}
+
+/// Maps verbosity flags to log levels, leaving logging to RUST_LOG when no flags are given
+fn log_level(verbosity: u8) -> Option<LevelFilter> {
+    match verbosity {
+        0 => None,
+        1 => Some(LevelFilter::Info),
+        2 => Some(LevelFilter::Debug),
+        _ => Some(LevelFilter::Trace),
+    }
 }
 
-pub fn parse_arguments() -> anyhow::Result<CanopusCommand> {
+pub fn parse_arguments() -> anyhow::Result<Invocation> {
     let cli = CliParser::parse();
 
+    // Honors the NO_COLOR convention as well, see https://no-color.org
+    // Single-shot runs happen as container steps, where nobody is watching the terminal
+    if cli.no_color || std::env::var_os("NO_COLOR").is_some() || matches!(cli.command, Oneshot) {
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+
+    if let Some(progress_format) = cli.progress {
+        progress::enable(progress_format);
+    }
+
+    let deprecation_policy = if cli.deny_deprecated {
+        DeprecationPolicy::Deny
+    } else {
+        DeprecationPolicy::Warn
+    };
+
+    let arguments = std::env::args().collect_vec();
+    deprecation_policy.enforce(&deprecations::deprecated_flags(&arguments, &DEPRECATIONS))?;
+
     let execution = match cli.command {
-        Validate(args) => ValidateCodeowners(args.path),
+        Validate(args) => ValidateCodeowners {
+            projects: evaluate_projects(args.path, args.manifest, args.remote)?,
+            min_coverage: args.min_coverage,
+            coverage_base: args.coverage_base,
+            max_issues: if args.fail_fast { Some(1) } else { args.max_issues },
+            strict: args.strict,
+            formats: evaluate_formats(args.format, args.output_dir.as_deref())?,
+            output_dir: args.output_dir,
+            quiet: args.quiet,
+            post_to_pr: args.post_to_pr,
+            commit_status: args.commit_status,
+            check_run: args.check_run,
+            fixtures: match (args.record, args.replay) {
+                (Some(fixtures_file), _) => Some(FixturesMode::Record(fixtures_file)),
+                (_, Some(fixtures_file)) => Some(FixturesMode::Replay(fixtures_file)),
+                _ => None,
+            },
+            metrics_out: args.metrics_out,
+        },
         Repair(args) => RepairCodeowners {
             project_root: args.path,
+            scope: RepairScope::new(args.only, args.except, args.force)?,
             dry_run: args.dry_run,
             remove_lines: args.remove_lines,
         },
+        Coverage(args) => ReportCoverage(args.path),
+        Report(args) => match args.heatmap {
+            Some(format) => GenerateHeatmap {
+                project_root: args.path,
+                depth: args.depth.into(),
+                format,
+            },
+            None => GenerateReport {
+                project_root: args.path,
+                format: args.format,
+            },
+        },
+        Stats(args) => ComputeStatistics {
+            project_root: args.path,
+            format: args.format,
+            review_load_days: args.review_load.then_some(args.review_days),
+        },
+        Export(args) => ExportOwnership {
+            project_root: args.path,
+            format: args.format,
+            output: args.output,
+        },
+        Diff(args) => DiffOwnership {
+            project_root: args.path,
+            from: args.from,
+            to: args.to,
+            format: args.format,
+        },
+        Reviewers(args) => ListReviewers {
+            project_root: args.path,
+            changed_since: args.changed_since,
+            files: args.files,
+            format: args.format,
+        },
+        Explain(args) => ExplainPattern {
+            project_root: args.path,
+            pattern: args.pattern,
+            format: args.format,
+        },
+        Snapshot(args) => SnapshotOwnership {
+            project_root: args.path,
+            snapshot_file: args.snapshot,
+        },
+        VerifySnapshot(args) => VerifyOwnershipSnapshot {
+            project_root: args.path,
+            snapshot_file: args.snapshot,
+        },
+        Audit(args) => AuditOrganization {
+            organization: args.org,
+            config_file: args.config,
+            metrics_listen: args.metrics_listen,
+        },
+        Config(ConfigCommands::Check(args)) => CheckConfiguration(args.path),
+        Roster(RosterCommands::Sync(args)) => SyncRoster {
+            organization: args.org,
+            roster_file: args.output,
+        },
+        Init(args) => InitProject {
+            project_root: args.path,
+            organization: args.org,
+            team: args.team,
+            force: args.force,
+        },
+        Demo => RunDemo,
+        Capabilities(args) => ShowCapabilities { json: args.json },
+        Schema => ShowReportSchema,
+        Completions(args) => GenerateCompletions(args.shell),
+        Docs(DocsCommands::Man) => GenerateManPage,
+        Docs(DocsCommands::Checks) => DescribeChecks,
+        SelfUpdateCanopus(args) => SelfUpdate { check_only: args.check },
+        Oneshot => RunOneShot,
+        Serve(args) => ServeValidation {
+            project_root: args.path,
+            address: args.listen,
+            grpc_address: args.grpc_listen,
+            max_body_bytes: args.max_body_bytes,
+        },
     };
 
-    Ok(execution)
+    Ok(Invocation {
+        command: execution,
+        deprecation_policy,
+        trust_extended_checks: cli.trust_extended_checks,
+        log_level: log_level(cli.verbose),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canopus::ProjectLocation;
+    use crate::infra::cli::{command, complete_check_names, evaluate_projects, read_manifest};
+    use crate::infra::remote::RemoteRepository;
+    use assertor::{BooleanAssertion, EqualityAssertion, IteratorAssertion, ResultAssertion, StringAssertion};
+    use clap_complete::Shell;
+    use indoc::indoc;
+    use std::ffi::OsStr;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+    use temp_dir::TempDir;
+
+    fn create_manifest(contents: &str) -> (TempDir, PathBuf) {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let manifest = temp_dir.path().join("projects.txt");
+        std::fs::write(&manifest, contents).unwrap();
+        (temp_dir, manifest)
+    }
+
+    #[test]
+    fn should_generate_completion_script_covering_subcommands() {
+        let mut script = Vec::new();
+        clap_complete::generate(Shell::Bash, &mut command(), "canopus", &mut script);
+        let script = String::from_utf8(script).expect("completion script should be UTF-8");
+
+        assertor::assert_that!(script).contains("canopus__subcmd__validate");
+        assertor::assert_that!(script).contains("canopus__subcmd__repair");
+        assertor::assert_that!(script).contains("complete -F _canopus");
+    }
+
+    #[test]
+    fn should_render_man_page_covering_subcommands() {
+        let mut man_page = Vec::new();
+        clap_mangen::Man::new(command())
+            .render(&mut man_page)
+            .expect("man page should render");
+        let man_page = String::from_utf8(man_page).expect("man page should be UTF-8");
+
+        assertor::assert_that!(man_page).contains(".TH canopus 1");
+        assertor::assert_that!(man_page).contains(r"canopus\-validate(1)");
+    }
+
+    #[test]
+    fn should_complete_check_names_from_registry() {
+        let candidates = complete_check_names(OsStr::new("du"))
+            .into_iter()
+            .map(|candidate| candidate.get_value().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+
+        assertor::assert_that!(candidates.iter()).contains(&"duplicate-ownership".to_string());
+        assertor::assert_that!(candidates.iter().all(|name| name.starts_with("du"))).is_true();
+    }
+
+    #[test]
+    fn should_read_project_roots_from_manifest() {
+        let (temp_dir, manifest) = create_manifest(indoc! {"
+            # Services
+            services/payments
+
+              services/checkout  
+            apps/android
+        "});
+
+        let project_roots = read_manifest(&manifest).unwrap();
+
+        let expected = vec![
+            temp_dir.path().join("services/payments"),
+            temp_dir.path().join("services/checkout"),
+            temp_dir.path().join("apps/android"),
+        ];
+
+        assertor::assert_that!(project_roots).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_merge_paths_with_manifest_entries_once() {
+        let (temp_dir, manifest) = create_manifest(indoc! {"
+            services/payments
+            apps/android
+        "});
+
+        let payments = temp_dir.path().join("services/payments");
+        let ios = temp_dir.path().join("apps/ios");
+
+        let projects = evaluate_projects(vec![ios.clone(), payments.clone()], Some(manifest), None).unwrap();
+
+        let expected = vec![
+            ProjectLocation::Local(ios),
+            ProjectLocation::Local(payments),
+            ProjectLocation::Local(temp_dir.path().join("apps/android")),
+        ];
+
+        assertor::assert_that!(projects).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_validate_remote_project_alone() {
+        let remote = RemoteRepository::from_str("dotanuki-labs/canopus@main").unwrap();
+
+        let projects = evaluate_projects(vec![], None, Some(remote.clone())).unwrap();
+
+        assertor::assert_that!(projects).is_equal_to(vec![ProjectLocation::Remote(remote)]);
+    }
+
+    #[test]
+    fn should_reject_mixing_remote_and_local_projects() {
+        let mixed = command().try_get_matches_from([
+            "canopus",
+            "validate",
+            "--path",
+            ".",
+            "--remote",
+            "dotanuki-labs/canopus@main",
+        ]);
+
+        let malformed = command().try_get_matches_from(["canopus", "validate", "--remote", "dotanuki-labs"]);
+
+        assertor::assert_that!(mixed).is_err();
+        assertor::assert_that!(malformed.err().unwrap().to_string()).contains("expecting a remote repository");
+    }
+
+    #[test]
+    fn should_reject_coverage_outside_percentage_range() {
+        let accepted = command().try_get_matches_from(["canopus", "validate", "-p", ".", "--min-coverage", "100"]);
+        let above = command().try_get_matches_from(["canopus", "validate", "-p", ".", "--min-coverage", "150"]);
+        let negative = command().try_get_matches_from(["canopus", "validate", "-p", ".", "--min-coverage=-5"]);
+
+        assertor::assert_that!(accepted).is_ok();
+        assertor::assert_that!(above.err().unwrap().to_string())
+            .contains("expecting a percentage between 0 and 100, found 150");
+        assertor::assert_that!(negative).is_err();
+    }
+
+    #[test]
+    fn should_reject_manifests_without_projects() {
+        let (_temp_dir, manifest) = create_manifest(indoc! {"
+            # Nothing to validate yet
+
+        "});
+
+        let empty = evaluate_projects(vec![], Some(manifest.clone()), None);
+        let missing = evaluate_projects(vec![], Some(manifest.with_file_name("missing.txt")), None);
+
+        assertor::assert_that!(empty.err().unwrap().to_string()).contains("expecting at least one project");
+        assertor::assert_that!(missing.err().unwrap().to_string()).contains("cannot read projects manifest");
+    }
 }
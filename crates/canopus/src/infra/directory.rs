@@ -0,0 +1,242 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::config::{CanopusConfig, DEFAULT_VALUE_DIRECTORY_HANDLE_ATTRIBUTE, DirectoryConfig};
+use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
+use crate::core::models::{ConsistencyIssue, OwnerRole};
+use crate::infra::github;
+use crate::infra::github::{ApiCapability, BranchProtection, CheckGithubConsistency, TeamReviewAssignment};
+use crate::infra::retries::RetryPolicy;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+static ENV_VAR_SCIM_TOKEN: &str = "SCIM_TOKEN";
+
+#[derive(Deserialize)]
+struct ScimListResponse {
+    #[serde(rename = "Resources", default)]
+    resources: Vec<ScimUser>,
+}
+
+#[derive(Deserialize)]
+struct ScimUser {
+    active: Option<bool>,
+}
+
+/// A SCIM directory acting as the source of truth for user owners
+pub struct ScimDirectory {
+    scim_client: octocrab::Octocrab,
+    handle_attribute: String,
+}
+
+impl ScimDirectory {
+    pub fn new(scim_client: octocrab::Octocrab, handle_attribute: &str) -> Self {
+        Self {
+            scim_client,
+            handle_attribute: handle_attribute.to_string(),
+        }
+    }
+
+    /// Connects to the directory defined by canopus.toml, if any, authenticating with SCIM_TOKEN
+    pub fn from_config(directory: &DirectoryConfig) -> anyhow::Result<Option<Self>> {
+        let Some(scim_endpoint) = &directory.scim_endpoint else {
+            return Ok(None);
+        };
+
+        let scim_token = std::env::var(ENV_VAR_SCIM_TOKEN).unwrap_or_default();
+        let scim_client = github::build_github_client(scim_token, Some(scim_endpoint.clone()))?;
+
+        let handle_attribute = directory
+            .handle_attribute
+            .as_deref()
+            .unwrap_or(DEFAULT_VALUE_DIRECTORY_HANDLE_ATTRIBUTE);
+
+        Ok(Some(Self::new(scim_client, handle_attribute)))
+    }
+
+    async fn check_user_in_directory(
+        &self,
+        retries: &RetryPolicy,
+        identity: &GithubIdentityHandle,
+    ) -> Result<(), ConsistencyIssue> {
+        let filter = format!(r#"{} eq "{}""#, self.handle_attribute, identity.inner());
+        let parameters = [("filter", filter.as_str())];
+
+        let listed: ScimListResponse = retries
+            .get("/Users", || self.scim_client.get("/Users", Some(&parameters)))
+            .await
+            .map_err(|_| ConsistencyIssue::CannotVerifyUser(identity.clone()))?;
+
+        // Deactivated accounts are still listed by most directories
+        let active = listed.resources.iter().any(|user| user.active.unwrap_or(true));
+
+        if active {
+            Ok(())
+        } else {
+            Err(ConsistencyIssue::OutsiderUser(identity.clone()))
+        }
+    }
+}
+
+/// Verifies user owners against a directory, leaving teams to another checker
+pub struct DirectoryChecker<'a> {
+    directory: ScimDirectory,
+    checker: &'a dyn CheckGithubConsistency,
+}
+
+impl<'a> DirectoryChecker<'a> {
+    pub fn new(directory: ScimDirectory, checker: &'a dyn CheckGithubConsistency) -> Self {
+        Self { directory, checker }
+    }
+}
+
+#[async_trait]
+impl CheckGithubConsistency for DirectoryChecker<'_> {
+    async fn github_identity(
+        &self,
+        _: &str,
+        identity: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        self.directory.check_user_in_directory(retries, identity).await
+    }
+
+    async fn github_team(
+        &self,
+        organization: &str,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        self.checker.github_team(organization, handle, retries).await
+    }
+
+    async fn team_members(
+        &self,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        self.checker.team_members(handle, retries).await
+    }
+
+    async fn team_review_assignment(
+        &self,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<TeamReviewAssignment, ConsistencyIssue> {
+        self.checker.team_review_assignment(handle, retries).await
+    }
+
+    async fn repository_collaborator(
+        &self,
+        repository: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        self.checker.repository_collaborator(repository, handle, retries).await
+    }
+
+    async fn branch_protection(
+        &self,
+        repository: &str,
+        branch: &str,
+        retries: &RetryPolicy,
+    ) -> Result<BranchProtection, ConsistencyIssue> {
+        self.checker.branch_protection(repository, branch, retries).await
+    }
+    async fn organization_role(
+        &self,
+        organization: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        self.checker.organization_role(organization, handle, retries).await
+    }
+
+    async fn team_role(
+        &self,
+        team: &GithubTeamHandle,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        self.checker.team_role(team, handle, retries).await
+    }
+    async fn preflight(&self, canopus_config: &CanopusConfig, retries: &RetryPolicy) -> anyhow::Result<()> {
+        self.checker.preflight(canopus_config, retries).await
+    }
+
+    fn capability(&self) -> ApiCapability {
+        self.checker.capability()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::ConsistencyIssue;
+    use crate::core::models::handles::GithubIdentityHandle;
+    use crate::infra::directory::{DirectoryChecker, ScimDirectory};
+    use crate::infra::github::test_helpers::{create_github_client, no_retries};
+    use crate::infra::github::{CheckGithubConsistency, ConsistentGithubState};
+    use assertor::{EqualityAssertion, ResultAssertion};
+    use httpmock::MockServer;
+
+    fn responds_with_directory_users<'a>(
+        mock_server: &'a MockServer,
+        handle: &str,
+        resources: &str,
+    ) -> httpmock::Mock<'a> {
+        let filter = format!(r#"externalId eq "{handle}""#);
+        let body = format!(r#"{{"totalResults": 1, "Resources": {resources}}}"#);
+
+        mock_server.mock(|when, then| {
+            when.method("GET").path("/scim/v2/Users").query_param("filter", filter);
+
+            then.status(200)
+                .header("content-type", "application/scim+json")
+                .body(body);
+        })
+    }
+
+    #[tokio::test]
+    async fn should_verify_users_against_directory() {
+        let mock_server = MockServer::start();
+
+        let active_user = responds_with_directory_users(&mock_server, "ubiratansoares", r#"[{"active": true}]"#);
+        let former_user = responds_with_directory_users(&mock_server, "itto-ogami", r#"[{"active": false}]"#);
+
+        let directory = ScimDirectory::new(create_github_client(mock_server.url("/scim/v2")), "externalId");
+        let checker = DirectoryChecker::new(directory, &ConsistentGithubState);
+
+        let ubiratansoares = GithubIdentityHandle::new("ubiratansoares".to_string());
+        let itto_ogami = GithubIdentityHandle::new("itto-ogami".to_string());
+
+        let active_check = checker
+            .github_identity("dotanuki-labs", &ubiratansoares, &no_retries())
+            .await;
+
+        let former_check = checker
+            .github_identity("dotanuki-labs", &itto_ogami, &no_retries())
+            .await;
+
+        active_user.assert();
+        former_user.assert();
+        assertor::assert_that!(active_check).is_ok();
+        assertor::assert_that!(former_check).is_equal_to(Err(ConsistencyIssue::OutsiderUser(itto_ogami)));
+    }
+
+    #[tokio::test]
+    async fn should_report_users_missing_from_directory() {
+        let mock_server = MockServer::start();
+
+        let no_users = responds_with_directory_users(&mock_server, "dotanuki-bot", "[]");
+
+        let directory = ScimDirectory::new(create_github_client(mock_server.url("/scim/v2")), "externalId");
+        let checker = DirectoryChecker::new(directory, &ConsistentGithubState);
+
+        let identity = GithubIdentityHandle::new("dotanuki-bot".to_string());
+        let check = checker.github_identity("dotanuki-labs", &identity, &no_retries()).await;
+
+        no_users.assert();
+        assertor::assert_that!(check).is_equal_to(Err(ConsistencyIssue::OutsiderUser(identity)));
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::codeowners::{CodeOwners, CodeOwnersEntry};
+use crate::core::models::config::ExternalCheckConfig;
+use anyhow::{Context, bail};
+use itertools::Itertools;
+use serde_json::json;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// A finding reported by an external check, optionally pointing to a CODEOWNERS line (0-based)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExternalFinding {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+/// Describes parsed rules as the JSON document external checks receive on stdin
+pub fn rules_as_json(code_owners: &CodeOwners) -> String {
+    let rules = code_owners
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            CodeOwnersEntry::Rule(rule) => Some(json!({
+                "line": rule.line_number + 1,
+                "pattern": rule.glob.glob(),
+                "owners": rule.owners.iter().map(|owner| owner.to_string()).collect_vec(),
+            })),
+            _ => None,
+        })
+        .collect_vec();
+
+    json!({ "rules": rules }).to_string()
+}
+
+/// Runs an external check from the project root, turning each non-empty line it prints into a finding.
+/// Lines formatted as `<line>: <message>` point to a CODEOWNERS line, counting from 1
+pub fn run_external_check(
+    check: &ExternalCheckConfig,
+    project_root: &Path,
+    rules_json: &str,
+) -> anyhow::Result<Vec<ExternalFinding>> {
+    let Some((program, arguments)) = check.command.split_first() else {
+        bail!("external check {} does not define a command", check.name);
+    };
+
+    // Relative programs like scripts/check.sh live in the project, not where canopus runs from
+    let program = if program.contains('/') {
+        project_root.join(program).into_os_string()
+    } else {
+        program.into()
+    };
+
+    log::debug!("Running external check {} : {}", check.name, check.command.join(" "));
+
+    let mut child = Command::new(program)
+        .args(arguments)
+        .current_dir(project_root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("cannot run external check {}", check.name))?;
+
+    // Writing from another thread avoids a deadlock when the command prints a lot before reading stdin
+    let mut stdin = child
+        .stdin
+        .take()
+        .expect("FATAL: stdin of external check must be piped");
+    let input = rules_json.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let output = child.wait_with_output()?;
+
+    // Commands are free to exit before reading their input
+    let _ = writer.join();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if stdout.trim().is_empty() && !output.status.success() {
+        bail!(
+            "external check {} failed : {}",
+            check.name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let findings = stdout
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse_finding)
+        .collect();
+
+    Ok(findings)
+}
+
+fn parse_finding(raw: &str) -> ExternalFinding {
+    let located = raw.split_once(':').and_then(|(line, message)| {
+        let line = line.trim().parse::<usize>().ok().filter(|line| *line > 0)?;
+        Some((line - 1, message.trim()))
+    });
+
+    match located {
+        Some((line, message)) => ExternalFinding {
+            line: Some(line),
+            message: message.to_string(),
+        },
+        None => ExternalFinding {
+            line: None,
+            message: raw.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::codeowners::CodeOwners;
+    use crate::core::models::config::ExternalCheckConfig;
+    use crate::infra::external;
+    use crate::infra::external::ExternalFinding;
+    use assertor::{EqualityAssertion, ResultAssertion, StringAssertion};
+    use temp_dir::TempDir;
+
+    fn shell_check(script: &str) -> ExternalCheckConfig {
+        ExternalCheckConfig {
+            name: "ldap-owners".to_string(),
+            command: vec!["sh".to_string(), "-c".to_string(), script.to_string()],
+        }
+    }
+
+    #[test]
+    fn should_turn_external_check_output_into_findings() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let code_owners = CodeOwners::try_from("*.rs @dotanuki-labs/rustaceans\n").unwrap();
+        let rules_json = external::rules_as_json(&code_owners);
+
+        let check = shell_check(
+            r#"grep -q '"pattern":"\*.rs"' && echo "1: @dotanuki-labs/rustaceans is not in LDAP"; echo "LDAP is slow"; exit 1"#,
+        );
+
+        let findings = external::run_external_check(&check, temp_dir.path(), &rules_json).unwrap();
+
+        let expected = vec![
+            ExternalFinding {
+                line: Some(0),
+                message: "@dotanuki-labs/rustaceans is not in LDAP".to_string(),
+            },
+            ExternalFinding {
+                line: None,
+                message: "LDAP is slow".to_string(),
+            },
+        ];
+
+        assertor::assert_that!(findings).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_fail_when_external_check_fails_silently() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        let check = shell_check("echo 'cannot reach LDAP' >&2; exit 2");
+
+        let execution = external::run_external_check(&check, temp_dir.path(), "{}");
+
+        assertor::assert_that!(execution).is_err();
+        assertor::assert_that!(execution.unwrap_err().to_string())
+            .contains("external check ldap-owners failed : cannot reach LDAP");
+    }
+}
@@ -0,0 +1,486 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::config::CanopusConfig;
+use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
+use crate::core::models::{ConsistencyIssue, OwnerRole};
+use crate::infra::github::{ApiCapability, BranchProtection, CheckGithubConsistency, TeamReviewAssignment};
+use crate::infra::retries::RetryPolicy;
+use anyhow::Context;
+use async_trait::async_trait;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Whether answers for owner checks are recorded to, or replayed from, a fixtures file
+#[derive(Clone, Debug, PartialEq)]
+pub enum FixturesMode {
+    Record(PathBuf),
+    Replay(PathBuf),
+}
+
+type RecordedMembers = Result<Vec<GithubIdentityHandle>, ConsistencyIssue>;
+type RecordedRole = Result<Option<OwnerRole>, ConsistencyIssue>;
+
+/// Answers for owner checks, keyed by what each check asked for
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixtures {
+    #[serde(default)]
+    identities: BTreeMap<String, Result<(), ConsistencyIssue>>,
+
+    #[serde(default)]
+    teams: BTreeMap<String, Result<(), ConsistencyIssue>>,
+
+    #[serde(default, rename = "team-members")]
+    team_members: BTreeMap<String, RecordedMembers>,
+
+    #[serde(default)]
+    collaborators: BTreeMap<String, Result<(), ConsistencyIssue>>,
+
+    #[serde(default, rename = "review-assignments")]
+    review_assignments: BTreeMap<String, Result<TeamReviewAssignment, ConsistencyIssue>>,
+
+    #[serde(default, rename = "branch-protections")]
+    branch_protections: BTreeMap<String, Result<BranchProtection, ConsistencyIssue>>,
+
+    #[serde(default, rename = "organization-roles")]
+    organization_roles: BTreeMap<String, RecordedRole>,
+
+    #[serde(default, rename = "team-roles")]
+    team_roles: BTreeMap<String, RecordedRole>,
+}
+
+// Handles are case-insensitive, hence keys are lowercased in order to match them in any case
+fn identity_key(organization: &str, handle: &GithubIdentityHandle) -> String {
+    format!("{}:{}", organization, handle.inner()).to_lowercase()
+}
+
+fn team_key(organization: &str, handle: &GithubTeamHandle) -> String {
+    format!("{}:{}", organization.to_lowercase(), team_members_key(handle))
+}
+
+fn team_members_key(handle: &GithubTeamHandle) -> String {
+    format!("{}/{}", handle.organization.inner(), handle.name).to_lowercase()
+}
+
+fn team_role_key(team: &GithubTeamHandle, handle: &GithubIdentityHandle) -> String {
+    format!("{}:{}", team_members_key(team), handle.inner().to_lowercase())
+}
+
+fn collaborator_key(repository: &str, handle: &GithubIdentityHandle) -> String {
+    format!("{}:{}", repository, handle.inner()).to_lowercase()
+}
+
+// Branch names are case-sensitive, unlike repositories
+fn branch_key(repository: &str, branch: &str) -> String {
+    format!("{}:{}", repository.to_lowercase(), branch)
+}
+
+/// Builds the checker answering owner checks according to the fixtures mode
+pub fn checker_with_fixtures(
+    checker: Box<dyn CheckGithubConsistency>,
+    mode: &FixturesMode,
+) -> anyhow::Result<Box<dyn CheckGithubConsistency>> {
+    match mode {
+        FixturesMode::Record(fixtures_file) => Ok(Box::new(RecordingChecker::new(checker, fixtures_file))),
+        FixturesMode::Replay(fixtures_file) => Ok(Box::new(ReplayingChecker::load(fixtures_file)?)),
+    }
+}
+
+/// Wraps another checker, saving the answers it gives into a fixtures file once owner checks end
+pub struct RecordingChecker {
+    checker: Box<dyn CheckGithubConsistency>,
+    fixtures_file: PathBuf,
+    fixtures: Mutex<Fixtures>,
+}
+
+impl RecordingChecker {
+    pub fn new(checker: Box<dyn CheckGithubConsistency>, fixtures_file: &Path) -> Self {
+        Self {
+            checker,
+            fixtures_file: fixtures_file.to_path_buf(),
+            fixtures: Mutex::new(Fixtures::default()),
+        }
+    }
+
+    fn record(&self, update: impl FnOnce(&mut Fixtures)) {
+        update(&mut self.fixtures.lock().unwrap());
+    }
+
+    async fn save(&self) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(&*self.fixtures.lock().unwrap())?;
+        tokio::fs::write(&self.fixtures_file, json).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CheckGithubConsistency for RecordingChecker {
+    async fn github_identity(
+        &self,
+        organization: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let answer = self.checker.github_identity(organization, handle, retries).await;
+
+        self.record(|fixtures| {
+            fixtures
+                .identities
+                .insert(identity_key(organization, handle), answer.clone());
+        });
+
+        answer
+    }
+
+    async fn github_team(
+        &self,
+        organization: &str,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let answer = self.checker.github_team(organization, handle, retries).await;
+
+        self.record(|fixtures| {
+            fixtures.teams.insert(team_key(organization, handle), answer.clone());
+        });
+
+        answer
+    }
+
+    async fn team_members(
+        &self,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        let answer = self.checker.team_members(handle, retries).await;
+
+        // Sorting members keeps fixtures stable across recordings
+        let recorded = answer.clone().map(|members| {
+            members
+                .into_iter()
+                .sorted_by_key(|member| member.inner().to_lowercase())
+                .collect_vec()
+        });
+
+        self.record(|fixtures| {
+            fixtures.team_members.insert(team_members_key(handle), recorded);
+        });
+
+        answer
+    }
+
+    async fn repository_collaborator(
+        &self,
+        repository: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let answer = self.checker.repository_collaborator(repository, handle, retries).await;
+
+        self.record(|fixtures| {
+            fixtures
+                .collaborators
+                .insert(collaborator_key(repository, handle), answer.clone());
+        });
+
+        answer
+    }
+
+    async fn team_review_assignment(
+        &self,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<TeamReviewAssignment, ConsistencyIssue> {
+        let answer = self.checker.team_review_assignment(handle, retries).await;
+
+        self.record(|fixtures| {
+            fixtures
+                .review_assignments
+                .insert(team_members_key(handle), answer.clone());
+        });
+
+        answer
+    }
+
+    async fn branch_protection(
+        &self,
+        repository: &str,
+        branch: &str,
+        retries: &RetryPolicy,
+    ) -> Result<BranchProtection, ConsistencyIssue> {
+        let answer = self.checker.branch_protection(repository, branch, retries).await;
+
+        self.record(|fixtures| {
+            fixtures
+                .branch_protections
+                .insert(branch_key(repository, branch), answer.clone());
+        });
+
+        answer
+    }
+
+    async fn organization_role(
+        &self,
+        organization: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        let answer = self.checker.organization_role(organization, handle, retries).await;
+
+        self.record(|fixtures| {
+            fixtures
+                .organization_roles
+                .insert(identity_key(organization, handle), answer.clone());
+        });
+
+        answer
+    }
+
+    async fn team_role(
+        &self,
+        team: &GithubTeamHandle,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        let answer = self.checker.team_role(team, handle, retries).await;
+
+        self.record(|fixtures| {
+            fixtures.team_roles.insert(team_role_key(team, handle), answer.clone());
+        });
+
+        answer
+    }
+
+    // Replays never reach Github API, hence nothing worth recording about credentials
+    async fn preflight(&self, canopus_config: &CanopusConfig, retries: &RetryPolicy) -> anyhow::Result<()> {
+        self.checker.preflight(canopus_config, retries).await
+    }
+
+    fn capability(&self) -> ApiCapability {
+        self.checker.capability()
+    }
+
+    // Validation flushes once owner checks end, including when interrupted by Ctrl-C
+    async fn flush(&self) {
+        if let Err(incoming) = self.save().await {
+            log::warn!(
+                "Cannot record fixtures at {} : {}",
+                self.fixtures_file.display(),
+                incoming
+            );
+        }
+    }
+}
+
+/// Answers owner checks from a fixtures file, never reaching Github API
+pub struct ReplayingChecker {
+    fixtures: Fixtures,
+}
+
+impl ReplayingChecker {
+    pub fn load(fixtures_file: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(fixtures_file)
+            .with_context(|| format!("cannot read fixtures at {}", fixtures_file.display()))?;
+
+        let fixtures = serde_json::from_str(&contents)
+            .with_context(|| format!("invalid fixtures at {}", fixtures_file.display()))?;
+
+        Ok(Self { fixtures })
+    }
+}
+
+#[async_trait]
+impl CheckGithubConsistency for ReplayingChecker {
+    async fn github_identity(
+        &self,
+        organization: &str,
+        handle: &GithubIdentityHandle,
+        _: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let key = identity_key(organization, handle);
+
+        self.fixtures.identities.get(&key).cloned().unwrap_or_else(|| {
+            log::warn!("No recorded answer for {}", key);
+            Err(ConsistencyIssue::CannotVerifyUser(handle.clone()))
+        })
+    }
+
+    async fn github_team(
+        &self,
+        organization: &str,
+        handle: &GithubTeamHandle,
+        _: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let key = team_key(organization, handle);
+
+        self.fixtures.teams.get(&key).cloned().unwrap_or_else(|| {
+            log::warn!("No recorded answer for {}", key);
+            Err(ConsistencyIssue::CannotVerifyTeam(handle.clone()))
+        })
+    }
+
+    async fn team_members(
+        &self,
+        handle: &GithubTeamHandle,
+        _: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        let key = team_members_key(handle);
+
+        match self.fixtures.team_members.get(&key) {
+            Some(recorded) => recorded.clone().map(HashSet::from_iter),
+            None => {
+                log::warn!("No recorded answer for members of {}", key);
+                Err(ConsistencyIssue::CannotVerifyTeam(handle.clone()))
+            },
+        }
+    }
+
+    async fn repository_collaborator(
+        &self,
+        repository: &str,
+        handle: &GithubIdentityHandle,
+        _: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let key = collaborator_key(repository, handle);
+
+        self.fixtures.collaborators.get(&key).cloned().unwrap_or_else(|| {
+            log::warn!("No recorded answer for {}", key);
+            Err(ConsistencyIssue::CannotVerifyUser(handle.clone()))
+        })
+    }
+
+    async fn team_review_assignment(
+        &self,
+        handle: &GithubTeamHandle,
+        _: &RetryPolicy,
+    ) -> Result<TeamReviewAssignment, ConsistencyIssue> {
+        let key = team_members_key(handle);
+
+        self.fixtures.review_assignments.get(&key).cloned().unwrap_or_else(|| {
+            log::warn!("No recorded answer for review assignment of {}", key);
+            Err(ConsistencyIssue::CannotVerifyTeam(handle.clone()))
+        })
+    }
+
+    async fn branch_protection(
+        &self,
+        repository: &str,
+        branch: &str,
+        _: &RetryPolicy,
+    ) -> Result<BranchProtection, ConsistencyIssue> {
+        let key = branch_key(repository, branch);
+
+        self.fixtures.branch_protections.get(&key).cloned().unwrap_or_else(|| {
+            log::warn!("No recorded answer for {}", key);
+            Err(ConsistencyIssue::CannotVerifyBranchProtection(branch.to_string()))
+        })
+    }
+
+    async fn organization_role(
+        &self,
+        organization: &str,
+        handle: &GithubIdentityHandle,
+        _: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        let key = identity_key(organization, handle);
+
+        self.fixtures.organization_roles.get(&key).cloned().unwrap_or_else(|| {
+            log::warn!("No recorded answer for organization role of {}", key);
+            Err(ConsistencyIssue::CannotVerifyUser(handle.clone()))
+        })
+    }
+
+    async fn team_role(
+        &self,
+        team: &GithubTeamHandle,
+        handle: &GithubIdentityHandle,
+        _: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        let key = team_role_key(team, handle);
+
+        self.fixtures.team_roles.get(&key).cloned().unwrap_or_else(|| {
+            log::warn!("No recorded answer for team role of {}", key);
+            Err(ConsistencyIssue::CannotVerifyUser(handle.clone()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::ConsistencyIssue;
+    use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
+    use crate::infra::fixtures::{RecordingChecker, ReplayingChecker};
+    use crate::infra::github::{CheckGithubConsistency, FakeGithubState};
+    use crate::infra::retries::RetryPolicy;
+    use assertor::{BooleanAssertion, EqualityAssertion, ResultAssertion, StringAssertion};
+    use std::collections::HashSet;
+    use temp_dir::TempDir;
+
+    #[tokio::test]
+    async fn should_replay_recorded_answers() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let fixtures_file = temp_dir.path().join("fixtures.json");
+        let retries = RetryPolicy::default();
+
+        let state = FakeGithubState::builder()
+            .add_known_user("@ubiratansoares")
+            .add_known_team("@dotanuki-labs/rustaceans")
+            .add_team_member("@dotanuki-labs/rustaceans", "@ubiratansoares")
+            .build();
+
+        let organization = GithubIdentityHandle::new("dotanuki-labs".to_string());
+        let known_user = GithubIdentityHandle::new("ubiratansoares".to_string());
+        let outsider = GithubIdentityHandle::new("itto-ogami".to_string());
+        let team = GithubTeamHandle::new(organization, "rustaceans".to_string());
+
+        let recorder = RecordingChecker::new(Box::new(state), &fixtures_file);
+        let _ = recorder.github_identity("dotanuki-labs", &known_user, &retries).await;
+        let _ = recorder.github_identity("dotanuki-labs", &outsider, &retries).await;
+        let _ = recorder.github_team("dotanuki-labs", &team, &retries).await;
+        let _ = recorder.team_members(&team, &retries).await;
+
+        assertor::assert_that!(fixtures_file.exists()).is_false();
+        recorder.flush().await;
+
+        let replayer = ReplayingChecker::load(&fixtures_file).unwrap();
+
+        let shouting_user = GithubIdentityHandle::new("UbiratanSoares".to_string());
+        let known_user_check = replayer
+            .github_identity("dotanuki-labs", &shouting_user, &retries)
+            .await;
+        let outsider_check = replayer.github_identity("dotanuki-labs", &outsider, &retries).await;
+        let team_check = replayer.github_team("dotanuki-labs", &team, &retries).await;
+        let members = replayer.team_members(&team, &retries).await;
+
+        assertor::assert_that!(known_user_check).is_ok();
+        assertor::assert_that!(outsider_check).is_equal_to(Err(ConsistencyIssue::OutsiderUser(outsider)));
+        assertor::assert_that!(team_check).is_ok();
+        assertor::assert_that!(members).is_equal_to(Ok(HashSet::from([known_user])));
+    }
+
+    #[tokio::test]
+    async fn should_report_owners_without_recorded_answers_as_unverified() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let fixtures_file = temp_dir.path().join("fixtures.json");
+        std::fs::write(&fixtures_file, "{}").unwrap();
+
+        let replayer = ReplayingChecker::load(&fixtures_file).unwrap();
+        let unknown_user = GithubIdentityHandle::new("dotanuki-bot".to_string());
+        let check = replayer
+            .github_identity("dotanuki-labs", &unknown_user, &RetryPolicy::default())
+            .await;
+
+        assertor::assert_that!(check).is_equal_to(Err(ConsistencyIssue::CannotVerifyUser(unknown_user)));
+        assertor::assert_that!(
+            ReplayingChecker::load(&temp_dir.path().join("missing.json"))
+                .err()
+                .unwrap()
+                .to_string()
+        )
+        .contains("cannot read fixtures");
+    }
+}
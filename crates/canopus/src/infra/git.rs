@@ -0,0 +1,251 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use anyhow::bail;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Reads the contents of a file as recorded by a given git revision
+pub fn file_at_revision(project_root: &Path, revision: &str, relative_path: &Path) -> anyhow::Result<String> {
+    let object = format!("{}:./{}", revision, relative_path.to_string_lossy());
+
+    log::debug!("Reading {} from git", object);
+
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .arg("show")
+        .arg(&object)
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "cannot read {} from git : {}",
+            object,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Reads the URL of the origin remote, when the project defines one
+pub fn origin_url(project_root: &Path) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["remote", "get-url", "origin"])
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "cannot read origin remote from git : {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// Resolves the root of the git repository the given path belongs to
+pub fn repository_root(path: &Path) -> anyhow::Result<PathBuf> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(["rev-parse", "--show-toplevel"])
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "cannot resolve git repository root : {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(PathBuf::from(String::from_utf8(output.stdout)?.trim()))
+}
+
+/// Resolves the commit currently checked out, as a full SHA
+pub fn head_commit(project_root: &Path) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["rev-parse", "HEAD"])
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "cannot resolve HEAD commit : {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// Resolves the directory holding git metadata for the given project, as an absolute path
+pub fn git_dir(project_root: &Path) -> anyhow::Result<PathBuf> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["rev-parse", "--absolute-git-dir"])
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "cannot resolve git directory : {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(PathBuf::from(String::from_utf8(output.stdout)?.trim()))
+}
+
+/// Lists paths with uncommitted changes, untracked ones included, as reported by git status
+pub fn working_tree_status(project_root: &Path) -> anyhow::Result<Vec<u8>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["status", "--porcelain=v1", "-z", "--untracked-files=all"])
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "cannot read working tree status : {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(output.stdout)
+}
+
+/// Lists files tracked by git, relative to the given directory
+pub fn tracked_files(project_root: &Path) -> anyhow::Result<HashSet<PathBuf>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["ls-files", "-z", "--cached"])
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "cannot list files tracked by git : {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let tracked = String::from_utf8(output.stdout)?
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .collect();
+
+    Ok(tracked)
+}
+
+/// Lists files recorded by a given git revision, relative to the given directory
+pub fn files_at_revision(project_root: &Path, revision: &str) -> anyhow::Result<HashSet<PathBuf>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["ls-tree", "-r", "-z", "--name-only"])
+        .arg(revision)
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "cannot list files at {} from git : {}",
+            revision,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let recorded = String::from_utf8(output.stdout)?
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .collect();
+
+    Ok(recorded)
+}
+
+/// Lists files changed since a given git revision, including uncommitted changes
+pub fn changed_files(project_root: &Path, revision: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["diff", "-z", "--name-only", "--relative"])
+        .arg(revision)
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "cannot list files changed since {} from git : {}",
+            revision,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let changed = String::from_utf8(output.stdout)?
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .collect();
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use std::path::Path;
+    use std::process::Command;
+
+    pub fn git(project_root: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(project_root)
+            .args(["-c", "user.name=canopus", "-c", "user.email=canopus@dotanuki.dev"])
+            .args(args)
+            .output()
+            .expect("failed to run git");
+
+        assert!(status.status.success(), "{}", String::from_utf8_lossy(&status.stderr));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::git;
+    use crate::infra::git::test_helpers;
+    use assertor::{EqualityAssertion, ResultAssertion};
+    use std::path::Path;
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_read_file_at_revision() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let project_root = temp_dir.path();
+
+        std::fs::write(project_root.join("CODEOWNERS"), "*.rs @dotanuki-labs/devs\n").unwrap();
+        test_helpers::git(project_root, &["init", "-q"]);
+        test_helpers::git(project_root, &["add", "-A"]);
+        test_helpers::git(project_root, &["commit", "-q", "-m", "first"]);
+
+        std::fs::write(project_root.join("CODEOWNERS"), "*.md @dotanuki-labs/devs\n").unwrap();
+
+        let contents = git::file_at_revision(project_root, "HEAD", Path::new("CODEOWNERS"));
+
+        assertor::assert_that!(contents.unwrap()).is_equal_to("*.rs @dotanuki-labs/devs\n".to_string());
+    }
+
+    #[test]
+    fn should_fail_for_unknown_revision() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        test_helpers::git(temp_dir.path(), &["init", "-q"]);
+
+        let contents = git::file_at_revision(temp_dir.path(), "main", Path::new("CODEOWNERS"));
+
+        assertor::assert_that!(contents).is_err();
+    }
+}
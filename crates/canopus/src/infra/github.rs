@@ -1,55 +1,399 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::ConsistencyIssue;
 use crate::core::models::ConsistencyIssue::CannotListMembersInTheOrganization;
+use crate::core::models::config::{CanopusConfig, DEFAULT_VALUE_ALLOW_OUTSIDE_COLLABORATORS};
 use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
+use crate::core::models::{ConsistencyIssue, OwnerRole};
+use crate::infra::progress;
+use crate::infra::progress::ProgressEvent;
+use crate::infra::retries::RetryPolicy;
+use crate::infra::roster::Roster;
+use anyhow::{Context, anyhow, bail};
+use async_trait::async_trait;
 use http::StatusCode;
 use itertools::Itertools;
 use octocrab::Page;
+use octocrab::service::middleware::retry::RetryConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+
+/// Creates the Github API client shared by all canopus commands, targeting Github Enterprise Server
+/// when an API URL is given. Retries are handled per request, according to canopus.toml
+pub fn build_github_client(token: String, api_url: Option<String>) -> anyhow::Result<octocrab::Octocrab> {
+    let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+
+    let mut builder = octocrab::OctocrabBuilder::new()
+        .add_retry_config(RetryConfig::None)
+        .add_header(http::header::USER_AGENT, user_agent);
+
+    // Without a token, requests go anonymously, seeing public information only
+    if !token.is_empty() {
+        builder = builder.personal_token(token);
+    }
+
+    if let Some(api_url) = api_url {
+        builder = builder.base_uri(api_url)?;
+    }
+
+    Ok(builder.build()?)
+}
+
+/// What an identity provider answers, given the credentials it was set up with
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ApiCapability {
+    /// Answers every owner check
+    Authenticated,
+    /// Answers whether users exist only, since organization members and teams are not public
+    Anonymous,
+}
+
+/// How merges into a branch are protected, combining classic branch protection and rulesets
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BranchProtection {
+    #[serde(rename = "require-code-owner-reviews")]
+    pub require_code_owner_reviews: bool,
+    #[serde(rename = "required-approvals")]
+    pub required_approvals: u32,
+}
+
+/// How review requests for a team are assigned to its members, as set in team settings
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TeamReviewAssignment {
+    /// Whether review requests are assigned to some members instead of notifying the whole team
+    pub enabled: bool,
+
+    /// Either round-robin or load-balance, when enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<String>,
 
-pub trait CheckGithubConsistency {
-    async fn github_identity(&self, organization: &str, handle: &GithubIdentityHandle) -> Result<(), ConsistencyIssue>;
+    #[serde(rename = "member-count", skip_serializing_if = "Option::is_none")]
+    pub member_count: Option<u32>,
+
+    #[serde(rename = "notify-team")]
+    pub notify_team: bool,
+}
+
+static TEAMS_PER_PAGE: usize = 100;
+
+static TEAM_REVIEW_ASSIGNMENT_QUERY: &str = "
+    query($organization: String!, $team: String!) {
+        organization(login: $organization) {
+            team(slug: $team) {
+                reviewRequestDelegationEnabled
+                reviewRequestDelegationAlgorithm
+                reviewRequestDelegationMemberCount
+                reviewRequestDelegationNotifyTeam
+            }
+        }
+    }
+";
+
+#[derive(Deserialize)]
+struct TeamSettingsData {
+    organization: Option<TeamSettingsOrganization>,
+}
+
+#[derive(Deserialize)]
+struct TeamSettingsOrganization {
+    team: Option<TeamSettings>,
+}
 
-    async fn github_team(&self, organization: &str, handle: &GithubTeamHandle) -> Result<(), ConsistencyIssue>;
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamSettings {
+    review_request_delegation_enabled: bool,
+    review_request_delegation_algorithm: Option<String>,
+    review_request_delegation_member_count: Option<u32>,
+    review_request_delegation_notify_team: bool,
 }
 
-pub enum GithubConsistencyChecker {
-    ApiBased(octocrab::Octocrab),
-
-    // We opt for a define test doubles with test-only
-    // visibility, pattern-matching them when needed.
-    // In this case, it helps us to avoid compilation struggles
-    // with async fn in traits
-    #[cfg(test)]
-    FakeChecks(FakeGithubState),
-    #[cfg(test)]
-    ConsistentState,
+#[derive(Deserialize)]
+struct Membership {
+    role: String,
+    state: String,
 }
 
-impl GithubConsistencyChecker {
+#[derive(Deserialize)]
+struct ListedTeam {
+    slug: String,
+}
+
+#[derive(Deserialize)]
+struct ClassicBranchProtection {
+    required_pull_request_reviews: Option<RequiredReviews>,
+}
+
+#[derive(Deserialize)]
+struct RequiredReviews {
+    #[serde(default)]
+    require_code_owner_reviews: bool,
+    #[serde(default)]
+    required_approving_review_count: u32,
+}
+
+#[derive(Deserialize)]
+struct BranchRule {
+    #[serde(rename = "type")]
+    kind: String,
+    parameters: Option<PullRequestRuleParameters>,
+}
+
+#[derive(Deserialize)]
+struct PullRequestRuleParameters {
+    #[serde(default)]
+    require_code_owner_review: bool,
+    #[serde(default)]
+    required_approving_review_count: u32,
+}
+
+/// Verifies owners against an identity provider, Github API being the one canopus ships with.
+/// Alternative backends plug into validation by implementing this trait
+#[async_trait]
+pub trait CheckGithubConsistency: Send + Sync {
+    async fn github_identity(
+        &self,
+        organization: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue>;
+
+    async fn github_team(
+        &self,
+        organization: &str,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue>;
+
+    async fn team_members(
+        &self,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue>;
+
+    /// Verifies a user outside the organization collaborates on a repository, given as owner/repo.
+    /// Backends without such a notion keep considering these users as outsiders
+    async fn repository_collaborator(
+        &self,
+        _repository: &str,
+        handle: &GithubIdentityHandle,
+        _retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        Err(ConsistencyIssue::OutsiderUser(handle.clone()))
+    }
+
+    /// Fetches how review requests for a team are assigned to its members.
+    /// Backends without such a notion cannot verify it
+    async fn team_review_assignment(
+        &self,
+        handle: &GithubTeamHandle,
+        _retries: &RetryPolicy,
+    ) -> Result<TeamReviewAssignment, ConsistencyIssue> {
+        Err(ConsistencyIssue::CannotVerifyTeam(handle.clone()))
+    }
+
+    /// Fetches how merges into a branch of a repository, given as owner/repo, are protected.
+    /// Backends without such a notion cannot verify it
+    async fn branch_protection(
+        &self,
+        _repository: &str,
+        branch: &str,
+        _retries: &RetryPolicy,
+    ) -> Result<BranchProtection, ConsistencyIssue> {
+        Err(ConsistencyIssue::CannotVerifyBranchProtection(branch.to_string()))
+    }
+
+    /// Fetches the role of a user within an organization, either member or admin, if any.
+    /// Backends without such a notion cannot verify it
+    async fn organization_role(
+        &self,
+        _organization: &str,
+        handle: &GithubIdentityHandle,
+        _retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        Err(ConsistencyIssue::CannotVerifyUser(handle.clone()))
+    }
+
+    /// Fetches the role of a user within a team, either member or maintainer, if any.
+    /// Backends without such a notion cannot verify it
+    async fn team_role(
+        &self,
+        _team: &GithubTeamHandle,
+        handle: &GithubIdentityHandle,
+        _retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        Err(ConsistencyIssue::CannotVerifyUser(handle.clone()))
+    }
+
+    /// Verifies upfront that credentials allow every check the configuration enables, failing with
+    /// what they lack. Backends unable to tell consider credentials as sufficient
+    async fn preflight(&self, _canopus_config: &CanopusConfig, _retries: &RetryPolicy) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Tells which owner checks are answered at all, letting validation skip the other ones
+    /// instead of reporting owners it cannot verify
+    fn capability(&self) -> ApiCapability {
+        ApiCapability::Authenticated
+    }
+
+    /// Persists whatever answers the backend kept around, once owner checks for a project end.
+    /// Backends keeping nothing have nothing to persist
+    async fn flush(&self) {}
+}
+
+type MembersListing = Result<HashSet<GithubIdentityHandle>, ConsistencyIssue>;
+
+/// Members of each organization, listed at most once per run and shared by all owner checks
+#[derive(Default)]
+pub struct OrganizationMembers {
+    listings: Mutex<HashMap<String, Arc<OnceCell<MembersListing>>>>,
+}
+
+impl OrganizationMembers {
+    fn listing(&self, organization: &str) -> Arc<OnceCell<MembersListing>> {
+        self.listings
+            .lock()
+            .unwrap()
+            .entry(organization.to_string())
+            .or_default()
+            .clone()
+    }
+}
+
+/// Verifies owners against Github REST API
+pub struct GithubApiChecker {
+    github_client: octocrab::Octocrab,
+    organization_members: OrganizationMembers,
+    token_scopes: OnceCell<Option<BTreeSet<String>>>,
+    capability: ApiCapability,
+}
+
+impl GithubApiChecker {
+    pub fn new(github_client: octocrab::Octocrab) -> Self {
+        Self {
+            github_client,
+            organization_members: OrganizationMembers::default(),
+            token_scopes: OnceCell::new(),
+            capability: ApiCapability::Authenticated,
+        }
+    }
+
+    /// Verifies owners without credentials, hence only whether users exist
+    pub fn anonymous(github_client: octocrab::Octocrab) -> Self {
+        Self {
+            capability: ApiCapability::Anonymous,
+            ..Self::new(github_client)
+        }
+    }
+
+    // Only classic personal access tokens declare their scopes, while fine-grained tokens and
+    // Github App tokens do not, hence nothing to tell in advance for them. Failing to reach
+    // Github API is left to owner checks, which report owners they cannot verify
+    async fn fetch_token_scopes(&self, retries: &RetryPolicy) -> anyhow::Result<Option<BTreeSet<String>>> {
+        let fetched = retries
+            .get("/rate_limit", || async {
+                let response = self.github_client._get("/rate_limit").await?;
+                octocrab::map_github_error(response).await
+            })
+            .await;
+
+        let response = match fetched {
+            Ok(response) => response,
+            Err(incoming) if Self::is_unauthorized(&incoming) => {
+                return Err(incoming).context("the token defined by GITHUB_TOKEN was rejected by Github API");
+            },
+            Err(incoming) => {
+                log::info!("Cannot verify the token defined by GITHUB_TOKEN : {}", incoming);
+                return Ok(None);
+            },
+        };
+
+        let scopes = response
+            .headers()
+            .get("x-oauth-scopes")
+            .and_then(|scopes| scopes.to_str().ok())
+            .map(|scopes| {
+                scopes
+                    .split(',')
+                    .map(|scope| scope.trim().to_string())
+                    .filter(|scope| !scope.is_empty())
+                    .collect()
+            });
+
+        Ok(scopes)
+    }
+
+    fn is_unauthorized(error: &octocrab::Error) -> bool {
+        matches!(error, octocrab::Error::GitHub { source, .. } if source.status_code == StatusCode::UNAUTHORIZED)
+    }
+
+    // Broader scopes imply narrower ones, like admin:org implying read:org
+    fn grants(scopes: &BTreeSet<String>, required: &str) -> bool {
+        let implying: &[&str] = match required {
+            "read:org" => &["write:org", "admin:org"],
+            _ => &[],
+        };
+
+        scopes.contains(required) || implying.iter().any(|scope| scopes.contains(*scope))
+    }
+
+    fn required_token_scopes(canopus_config: &CanopusConfig) -> Vec<(&'static str, &'static str)> {
+        let mut required = vec![("read:org", "verify organization members and teams")];
+
+        if canopus_config.protection.branch.is_some() {
+            required.push(("repo", "verify branch protection"));
+        }
+
+        let allow_outside_collaborators = canopus_config
+            .ownership
+            .allow_outside_collaborators
+            .unwrap_or(DEFAULT_VALUE_ALLOW_OUTSIDE_COLLABORATORS);
+
+        if allow_outside_collaborators {
+            required.push(("repo", "verify repository collaborators"));
+        }
+
+        required
+    }
+
     async fn get_github_users_per_page(
         github_client: &octocrab::Octocrab,
+        retries: &RetryPolicy,
         page: u32,
         organization: &str,
     ) -> Result<Vec<GithubIdentityHandle>, ConsistencyIssue> {
-        let members = github_client
-            .orgs(organization)
-            .list_members()
-            .page(page)
-            .per_page(100)
-            .send()
-            .await
-            .or_else(|error| match error {
-                octocrab::Error::GitHub { source, .. } => {
-                    if source.status_code == StatusCode::NOT_FOUND {
-                        Ok(Page::default())
-                    } else {
-                        Err(CannotListMembersInTheOrganization(organization.to_string()))
-                    }
-                },
-                _ => Err(CannotListMembersInTheOrganization(organization.to_string())),
-            })?;
+        let route = format!("/orgs/{organization}/members?page={page}");
+
+        let fetched = retries
+            .get(&route, || async {
+                github_client
+                    .orgs(organization)
+                    .list_members()
+                    .page(page)
+                    .per_page(100)
+                    .send()
+                    .await
+            })
+            .await;
+
+        progress::report(ProgressEvent::GithubPageFetched {
+            route: format!("/orgs/{organization}/members"),
+            page,
+        });
+
+        let members = fetched.or_else(|error| match error {
+            octocrab::Error::GitHub { source, .. } => {
+                if source.status_code == StatusCode::NOT_FOUND {
+                    Ok(Page::default())
+                } else {
+                    Err(CannotListMembersInTheOrganization(organization.to_string()))
+                }
+            },
+            _ => Err(CannotListMembersInTheOrganization(organization.to_string())),
+        })?;
 
         let handles = members
             .into_iter()
@@ -60,16 +404,16 @@ impl GithubConsistencyChecker {
     }
 
     async fn find_all_users_for_organization(
-        &self,
         github_client: &octocrab::Octocrab,
+        retries: &RetryPolicy,
         organization: &str,
-    ) -> Result<Vec<GithubIdentityHandle>, ConsistencyIssue> {
-        let mut all_handles = Vec::new();
+    ) -> MembersListing {
+        let mut all_handles = HashSet::new();
         let mut page = 0;
 
         loop {
             page += 1;
-            let handles = Self::get_github_users_per_page(github_client, page, organization).await?;
+            let handles = Self::get_github_users_per_page(github_client, retries, page, organization).await?;
 
             if handles.is_empty() {
                 break;
@@ -83,13 +427,17 @@ impl GithubConsistencyChecker {
 
     async fn check_user_on_github(
         &self,
-        github_client: &octocrab::Octocrab,
+        retries: &RetryPolicy,
         organization: &str,
         user: &str,
     ) -> Result<(), ConsistencyIssue> {
-        let users_in_organization = self
-            .find_all_users_for_organization(github_client, organization)
-            .await?;
+        // Concurrent checks for the same organization wait for a single listing
+        let listing = self.organization_members.listing(organization);
+        let users_in_organization = listing
+            .get_or_init(|| Self::find_all_users_for_organization(&self.github_client, retries, organization))
+            .await
+            .as_ref()
+            .map_err(Clone::clone)?;
 
         let target_user = GithubIdentityHandle::new(user.to_string());
 
@@ -99,14 +447,23 @@ impl GithubConsistencyChecker {
             return Ok(());
         }
 
-        github_client
-            .users(user)
-            .profile()
-            .await
+        self.check_user_exists(retries, user).await?;
+
+        Err(ConsistencyIssue::OutsiderUser(target_user))
+    }
+
+    async fn check_user_exists(&self, retries: &RetryPolicy, user: &str) -> Result<(), ConsistencyIssue> {
+        let fetched = retries
+            .get(&format!("/users/{user}"), || async {
+                self.github_client.users(user).profile().await
+            })
+            .await;
+
+        fetched
             .map_err(|incoming| {
                 log::info!("Failed to fetch info for {} user on Github", user);
 
-                let handle = target_user.clone();
+                let handle = GithubIdentityHandle::new(user.to_string());
 
                 let octocrab::Error::GitHub { source, .. } = incoming else {
                     return ConsistencyIssue::CannotVerifyUser(handle);
@@ -117,25 +474,22 @@ impl GithubConsistencyChecker {
                     _ => ConsistencyIssue::CannotVerifyUser(handle),
                 }
             })
-            .map(|_| ())?;
-
-        if !user_listed_in_organization {
-            return Err(ConsistencyIssue::OutsiderUser(target_user));
-        };
-
-        Ok(())
+            .map(|_| ())
     }
 
     async fn check_team_on_github(
         &self,
-        github_client: &octocrab::Octocrab,
+        retries: &RetryPolicy,
         organization: &str,
         team: &str,
     ) -> Result<(), ConsistencyIssue> {
-        github_client
-            .teams(organization)
-            .get(team)
-            .await
+        let fetched = retries
+            .get(&format!("/orgs/{organization}/teams/{team}"), || async {
+                self.github_client.teams(organization).get(team).await
+            })
+            .await;
+
+        fetched
             .map_err(|incoming| {
                 log::info!("Failed to fetch info for {}/{} team on Github", organization, team);
 
@@ -154,9 +508,411 @@ impl GithubConsistencyChecker {
             .map(|_| ())
     }
 
-    #[cfg(test)]
-    fn check_registered_fake_user(&self, state: &FakeGithubState, username: &str) -> Result<(), ConsistencyIssue> {
-        if state.known_users.contains(&username.to_string()) {
+    async fn find_all_members_for_team(
+        github_client: &octocrab::Octocrab,
+        retries: &RetryPolicy,
+        handle: &GithubTeamHandle,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        let organization = handle.organization.inner();
+        let team = handle.name.as_str();
+        let mut all_handles = HashSet::new();
+        let mut page = 0u32;
+
+        loop {
+            page += 1;
+            let route = format!("/orgs/{organization}/teams/{team}/members?page={page}");
+
+            let fetched = retries
+                .get(&route, || async {
+                    github_client
+                        .teams(organization)
+                        .members(team)
+                        .page(page)
+                        .per_page(100)
+                        .send()
+                        .await
+                })
+                .await;
+
+            progress::report(ProgressEvent::GithubPageFetched {
+                route: format!("/orgs/{organization}/teams/{team}/members"),
+                page,
+            });
+
+            // Missing teams are reported by team checks, hence they simply have no members here
+            let members = fetched.or_else(|error| match error {
+                octocrab::Error::GitHub { source, .. } if source.status_code == StatusCode::NOT_FOUND => {
+                    Ok(Page::default())
+                },
+                _ => Err(ConsistencyIssue::CannotVerifyTeam(handle.clone())),
+            })?;
+
+            if members.items.is_empty() {
+                break;
+            }
+
+            all_handles.extend(
+                members
+                    .into_iter()
+                    .map(|member| GithubIdentityHandle::new(member.login)),
+            );
+        }
+
+        Ok(all_handles)
+    }
+
+    // A page shorter than requested is the last one, sparing a request for an empty page
+    async fn list_team_slugs(&self, retries: &RetryPolicy, organization: &str) -> anyhow::Result<Vec<String>> {
+        let route = format!("/orgs/{organization}/teams");
+        let mut slugs = Vec::new();
+        let mut page = 0u32;
+
+        loop {
+            page += 1;
+            let parameters = [("page", page.to_string()), ("per_page", TEAMS_PER_PAGE.to_string())];
+
+            let listed = retries
+                .get(&format!("{route}?page={page}"), || {
+                    self.github_client
+                        .get::<Vec<ListedTeam>, _, _>(&route, Some(&parameters))
+                })
+                .await
+                .with_context(|| format!("cannot list teams of {organization}"))?;
+
+            progress::report(ProgressEvent::GithubPageFetched {
+                route: route.clone(),
+                page,
+            });
+
+            let last_page = listed.len() < TEAMS_PER_PAGE;
+            slugs.extend(listed.into_iter().map(|team| team.slug));
+
+            if last_page {
+                break;
+            }
+        }
+
+        Ok(slugs)
+    }
+
+    /// Lists members, teams and team members of an organization, as a roster owners are verified against
+    pub async fn fetch_roster(&self, retries: &RetryPolicy, organization: &str) -> anyhow::Result<Roster> {
+        let lowercased = |handles: HashSet<GithubIdentityHandle>| {
+            handles
+                .into_iter()
+                .map(|handle| handle.inner().to_lowercase())
+                .collect::<BTreeSet<_>>()
+        };
+
+        let members = Self::find_all_users_for_organization(&self.github_client, retries, organization)
+            .await
+            .map_err(|_| anyhow!("cannot list members of {organization}"))?;
+
+        let organization_handle = GithubIdentityHandle::new(organization.to_string());
+        let mut teams = BTreeMap::new();
+
+        for slug in self.list_team_slugs(retries, organization).await? {
+            let handle = GithubTeamHandle::new(organization_handle.clone(), slug.clone());
+
+            let team_members = Self::find_all_members_for_team(&self.github_client, retries, &handle)
+                .await
+                .map_err(|_| anyhow!("cannot list members of {organization}/{slug} team"))?;
+
+            teams.insert(slug.to_lowercase(), lowercased(team_members));
+        }
+
+        Ok(Roster {
+            organization: organization.to_string(),
+            members: lowercased(members),
+            teams,
+        })
+    }
+
+    // Users without membership get a 404, while invited users remain pending until they accept
+    async fn fetch_membership_role(
+        &self,
+        retries: &RetryPolicy,
+        route: &str,
+        handle: &GithubIdentityHandle,
+    ) -> Result<Option<String>, ConsistencyIssue> {
+        let fetched = retries
+            .get(route, || {
+                self.github_client.get::<Membership, _, ()>(route, None::<&()>)
+            })
+            .await;
+
+        match fetched {
+            Ok(membership) if membership.state == "active" => Ok(Some(membership.role)),
+            Ok(_) => Ok(None),
+            Err(octocrab::Error::GitHub { source, .. }) if source.status_code == StatusCode::NOT_FOUND => Ok(None),
+            Err(incoming) => {
+                log::info!("Failed to fetch membership at {} : {}", route, incoming);
+                Err(ConsistencyIssue::CannotVerifyUser(handle.clone()))
+            },
+        }
+    }
+
+    // Team settings are only exposed by Github GraphQL API
+    async fn fetch_team_review_assignment(
+        &self,
+        retries: &RetryPolicy,
+        handle: &GithubTeamHandle,
+    ) -> Result<TeamReviewAssignment, ConsistencyIssue> {
+        let organization = handle.organization.inner();
+        let team = handle.name.as_str();
+
+        let payload = serde_json::json!({
+            "query": TEAM_REVIEW_ASSIGNMENT_QUERY,
+            "variables": { "organization": organization, "team": team },
+        });
+
+        let fetched = retries
+            .get(&format!("/graphql (team {organization}/{team})"), || {
+                self.github_client.graphql::<TeamSettingsData>(&payload)
+            })
+            .await
+            .map_err(|incoming| {
+                log::info!(
+                    "Failed to fetch settings of {}/{} team : {}",
+                    organization,
+                    team,
+                    incoming
+                );
+                ConsistencyIssue::CannotVerifyTeam(handle.clone())
+            })?;
+
+        let Some(settings) = fetched.organization.and_then(|found| found.team) else {
+            return Err(ConsistencyIssue::TeamDoesNotExist(handle.clone()));
+        };
+
+        Ok(TeamReviewAssignment {
+            enabled: settings.review_request_delegation_enabled,
+            algorithm: settings.review_request_delegation_algorithm,
+            member_count: settings.review_request_delegation_member_count,
+            notify_team: settings.review_request_delegation_notify_team,
+        })
+    }
+
+    // Branches without classic protection answer with 404, while rules still apply through rulesets
+    async fn fetch_branch_protection(
+        &self,
+        retries: &RetryPolicy,
+        repository: &str,
+        branch: &str,
+    ) -> Result<BranchProtection, octocrab::Error> {
+        let classic_route = format!("/repos/{repository}/branches/{branch}/protection");
+
+        let classic = retries
+            .get(&classic_route, || {
+                self.github_client
+                    .get::<ClassicBranchProtection, _, ()>(&classic_route, None::<&()>)
+            })
+            .await
+            .map(|protection| protection.required_pull_request_reviews)
+            .or_else(|error| match error {
+                octocrab::Error::GitHub { source, .. } if source.status_code == StatusCode::NOT_FOUND => Ok(None),
+                _ => Err(error),
+            })?;
+
+        let rules_route = format!("/repos/{repository}/rules/branches/{branch}");
+
+        let rules = retries
+            .get(&rules_route, || {
+                self.github_client
+                    .get::<Vec<BranchRule>, _, ()>(&rules_route, None::<&()>)
+            })
+            .await?;
+
+        let pull_request_rules = rules
+            .into_iter()
+            .filter(|rule| rule.kind == "pull_request")
+            .filter_map(|rule| rule.parameters)
+            .collect_vec();
+
+        let protection = BranchProtection {
+            require_code_owner_reviews: classic
+                .as_ref()
+                .is_some_and(|reviews| reviews.require_code_owner_reviews)
+                || pull_request_rules.iter().any(|rule| rule.require_code_owner_review),
+            required_approvals: pull_request_rules
+                .iter()
+                .map(|rule| rule.required_approving_review_count)
+                .chain(classic.map(|reviews| reviews.required_approving_review_count))
+                .max()
+                .unwrap_or_default(),
+        };
+
+        Ok(protection)
+    }
+}
+
+#[async_trait]
+impl CheckGithubConsistency for GithubApiChecker {
+    async fn preflight(&self, canopus_config: &CanopusConfig, retries: &RetryPolicy) -> anyhow::Result<()> {
+        if self.capability == ApiCapability::Anonymous {
+            return Ok(());
+        }
+
+        let scopes = self
+            .token_scopes
+            .get_or_try_init(|| self.fetch_token_scopes(retries))
+            .await?;
+
+        let Some(scopes) = scopes else {
+            log::info!("Cannot tell scopes of the token defined by GITHUB_TOKEN in advance");
+            return Ok(());
+        };
+
+        let missing = Self::required_token_scopes(canopus_config)
+            .into_iter()
+            .find(|(scope, _)| !Self::grants(scopes, scope));
+
+        if let Some((scope, purpose)) = missing {
+            bail!("the token defined by GITHUB_TOKEN lacks the {scope} scope, required to {purpose}");
+        }
+
+        Ok(())
+    }
+
+    async fn github_identity(
+        &self,
+        organization: &str,
+        identity: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        // Anonymous requests only list public members, hence anyone else would look like an outsider
+        if self.capability == ApiCapability::Anonymous {
+            return self.check_user_exists(retries, identity.inner()).await;
+        }
+
+        self.check_user_on_github(retries, organization, identity.inner()).await
+    }
+
+    fn capability(&self) -> ApiCapability {
+        self.capability
+    }
+
+    async fn github_team(
+        &self,
+        organization: &str,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let defined_organization = handle.organization.inner();
+
+        // Simple offline guard
+        if !defined_organization.eq_ignore_ascii_case(organization) {
+            return Err(ConsistencyIssue::TeamDoesNotMatchOrganization(handle.clone()));
+        };
+
+        self.check_team_on_github(retries, handle.organization.inner(), handle.name.as_str())
+            .await
+    }
+
+    async fn team_members(
+        &self,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        Self::find_all_members_for_team(&self.github_client, retries, handle).await
+    }
+
+    async fn team_review_assignment(
+        &self,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<TeamReviewAssignment, ConsistencyIssue> {
+        self.fetch_team_review_assignment(retries, handle).await
+    }
+
+    async fn repository_collaborator(
+        &self,
+        repository: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        let Some((owner, name)) = repository.split_once('/') else {
+            return Err(ConsistencyIssue::CannotVerifyUser(handle.clone()));
+        };
+
+        let user = handle.inner();
+
+        let fetched = retries
+            .get(&format!("/repos/{repository}/collaborators/{user}"), || async {
+                self.github_client.repos(owner, name).is_collaborator(user).await
+            })
+            .await;
+
+        match fetched {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(ConsistencyIssue::OutsiderUser(handle.clone())),
+            Err(_) => {
+                log::info!("Failed to check whether {} collaborates on {}", user, repository);
+                Err(ConsistencyIssue::CannotVerifyUser(handle.clone()))
+            },
+        }
+    }
+
+    async fn branch_protection(
+        &self,
+        repository: &str,
+        branch: &str,
+        retries: &RetryPolicy,
+    ) -> Result<BranchProtection, ConsistencyIssue> {
+        self.fetch_branch_protection(retries, repository, branch)
+            .await
+            .map_err(|incoming| {
+                log::info!(
+                    "Failed to fetch protection of {} branch on {} : {}",
+                    branch,
+                    repository,
+                    incoming
+                );
+                ConsistencyIssue::CannotVerifyBranchProtection(branch.to_string())
+            })
+    }
+
+    async fn organization_role(
+        &self,
+        organization: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        let route = format!("/orgs/{organization}/memberships/{}", handle.inner());
+        let role = self.fetch_membership_role(retries, &route, handle).await?;
+
+        Ok(role.map(|role| match role.as_str() {
+            "admin" => OwnerRole::Admin,
+            _ => OwnerRole::Member,
+        }))
+    }
+
+    async fn team_role(
+        &self,
+        team: &GithubTeamHandle,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        let route = format!(
+            "/orgs/{}/teams/{}/memberships/{}",
+            team.organization.inner(),
+            team.name,
+            handle.inner()
+        );
+
+        let role = self.fetch_membership_role(retries, &route, handle).await?;
+
+        Ok(role.map(|role| match role.as_str() {
+            "maintainer" => OwnerRole::Maintainer,
+            _ => OwnerRole::Member,
+        }))
+    }
+}
+
+#[cfg(test)]
+impl FakeGithubState {
+    fn check_registered_user(&self, username: &str) -> Result<(), ConsistencyIssue> {
+        if self.known_users.contains(&username.to_string()) {
             return Ok(());
         };
 
@@ -164,63 +920,203 @@ impl GithubConsistencyChecker {
         Err(ConsistencyIssue::OutsiderUser(handle))
     }
 
-    #[cfg(test)]
-    fn check_registered_fake_team(
+    fn check_registered_team(&self, org_name: &str, team_name: &str) -> Result<(), ConsistencyIssue> {
+        let formatted = format!("{}/{}", org_name, team_name);
+        if self.known_teams.contains(&formatted) {
+            return Ok(());
+        };
+
+        let org_handle = GithubIdentityHandle::new(org_name.to_owned());
+        let handle = GithubTeamHandle::new(org_handle, team_name.to_owned());
+
+        Err(ConsistencyIssue::TeamDoesNotExist(handle))
+    }
+}
+
+/// Answers owner checks from known users, teams and team members
+#[cfg(test)]
+#[async_trait]
+impl CheckGithubConsistency for FakeGithubState {
+    async fn github_identity(
+        &self,
+        _: &str,
+        identity: &GithubIdentityHandle,
+        _: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        // Known users simply exist when anonymous, since organization members are not public
+        if self.anonymous {
+            return self
+                .check_registered_user(identity.inner())
+                .map_err(|_| ConsistencyIssue::UserDoesNotExist(identity.clone()));
+        }
+
+        self.check_registered_user(identity.inner())
+    }
+
+    async fn github_team(
+        &self,
+        organization: &str,
+        handle: &GithubTeamHandle,
+        _: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        if !handle.organization.inner().eq_ignore_ascii_case(organization) {
+            return Err(ConsistencyIssue::TeamDoesNotMatchOrganization(handle.clone()));
+        };
+
+        self.check_registered_team(handle.organization.inner(), handle.name.as_str())
+    }
+
+    async fn team_members(
+        &self,
+        handle: &GithubTeamHandle,
+        _: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        let team = format!("{}/{}", handle.organization.inner(), handle.name);
+
+        let members = self
+            .team_members
+            .iter()
+            .filter(|(member_of, _)| *member_of == team)
+            .map(|(_, username)| GithubIdentityHandle::new(username.clone()))
+            .collect();
+
+        Ok(members)
+    }
+
+    async fn repository_collaborator(
+        &self,
+        _: &str,
+        handle: &GithubIdentityHandle,
+        _: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        if self.collaborators.contains(&handle.inner().to_string()) {
+            return Ok(());
+        }
+
+        Err(ConsistencyIssue::OutsiderUser(handle.clone()))
+    }
+
+    async fn team_review_assignment(
+        &self,
+        handle: &GithubTeamHandle,
+        _: &RetryPolicy,
+    ) -> Result<TeamReviewAssignment, ConsistencyIssue> {
+        let formatted = format!("{}/{}", handle.organization.inner(), handle.name);
+
+        Ok(TeamReviewAssignment {
+            enabled: !self.unassigning_teams.contains(&formatted),
+            ..Default::default()
+        })
+    }
+
+    async fn branch_protection(
+        &self,
+        _: &str,
+        branch: &str,
+        _: &RetryPolicy,
+    ) -> Result<BranchProtection, ConsistencyIssue> {
+        self.branch_protection
+            .clone()
+            .ok_or(ConsistencyIssue::CannotVerifyBranchProtection(branch.to_string()))
+    }
+
+    async fn organization_role(
+        &self,
+        _: &str,
+        handle: &GithubIdentityHandle,
+        _: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        let username = handle.inner().to_string();
+
+        if self.organization_admins.contains(&username) {
+            return Ok(Some(OwnerRole::Admin));
+        }
+
+        Ok(self.known_users.contains(&username).then_some(OwnerRole::Member))
+    }
+
+    async fn team_role(
+        &self,
+        team: &GithubTeamHandle,
+        handle: &GithubIdentityHandle,
+        _: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        let membership = (
+            format!("{}/{}", team.organization.inner(), team.name),
+            handle.inner().to_string(),
+        );
+
+        if self.team_maintainers.contains(&membership) {
+            return Ok(Some(OwnerRole::Maintainer));
+        }
+
+        Ok(self.team_members.contains(&membership).then_some(OwnerRole::Member))
+    }
+
+    fn capability(&self) -> ApiCapability {
+        if self.anonymous {
+            return ApiCapability::Anonymous;
+        }
+
+        ApiCapability::Authenticated
+    }
+}
+
+/// Considers every owner as consistent with Github
+#[cfg(test)]
+pub struct ConsistentGithubState;
+
+#[cfg(test)]
+#[async_trait]
+impl CheckGithubConsistency for ConsistentGithubState {
+    async fn github_identity(
         &self,
-        state: &FakeGithubState,
-        org_name: &str,
-        team_name: &str,
+        _: &str,
+        _: &GithubIdentityHandle,
+        _: &RetryPolicy,
     ) -> Result<(), ConsistencyIssue> {
-        let formatted = format!("{}/{}", org_name, team_name);
-        if state.known_teams.contains(&formatted) {
-            return Ok(());
-        };
+        Ok(())
+    }
 
-        let org_handle = GithubIdentityHandle::new(org_name.to_owned());
-        let handle = GithubTeamHandle::new(org_handle, team_name.to_owned());
+    async fn github_team(&self, _: &str, _: &GithubTeamHandle, _: &RetryPolicy) -> Result<(), ConsistencyIssue> {
+        Ok(())
+    }
 
-        Err(ConsistencyIssue::TeamDoesNotExist(handle))
+    async fn team_members(
+        &self,
+        _: &GithubTeamHandle,
+        _: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        Ok(HashSet::new())
     }
 }
 
-impl CheckGithubConsistency for GithubConsistencyChecker {
+/// Never answers, like Github API hanging during an outage
+#[cfg(test)]
+pub struct UnresponsiveGithub;
+
+#[cfg(test)]
+#[async_trait]
+impl CheckGithubConsistency for UnresponsiveGithub {
     async fn github_identity(
         &self,
-        organization: &str,
-        identity: &GithubIdentityHandle,
+        _: &str,
+        _: &GithubIdentityHandle,
+        _: &RetryPolicy,
     ) -> Result<(), ConsistencyIssue> {
-        match self {
-            GithubConsistencyChecker::ApiBased(github_client) => {
-                self.check_user_on_github(github_client, organization, identity.inner())
-                    .await
-            },
-            #[cfg(test)]
-            GithubConsistencyChecker::FakeChecks(state) => self.check_registered_fake_user(state, identity.inner()),
-            #[cfg(test)]
-            GithubConsistencyChecker::ConsistentState => Ok(()),
-        }
+        std::future::pending().await
     }
 
-    async fn github_team(&self, organization: &str, handle: &GithubTeamHandle) -> Result<(), ConsistencyIssue> {
-        match self {
-            GithubConsistencyChecker::ApiBased(github_client) => {
-                let defined_organization = handle.organization.inner();
-
-                // Simple offline guard
-                if defined_organization != organization {
-                    return Err(ConsistencyIssue::TeamDoesNotMatchOrganization(handle.clone()));
-                };
+    async fn github_team(&self, _: &str, _: &GithubTeamHandle, _: &RetryPolicy) -> Result<(), ConsistencyIssue> {
+        std::future::pending().await
+    }
 
-                self.check_team_on_github(github_client, handle.organization.inner(), handle.name.as_str())
-                    .await
-            },
-            #[cfg(test)]
-            GithubConsistencyChecker::FakeChecks(state) => {
-                self.check_registered_fake_team(state, handle.organization.inner(), handle.name.as_str())
-            },
-            #[cfg(test)]
-            GithubConsistencyChecker::ConsistentState => Ok(()),
-        }
+    async fn team_members(
+        &self,
+        _: &GithubTeamHandle,
+        _: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        std::future::pending().await
     }
 }
 
@@ -228,6 +1124,13 @@ impl CheckGithubConsistency for GithubConsistencyChecker {
 pub struct FakeGithubState {
     known_users: Vec<String>,
     known_teams: Vec<String>,
+    team_members: Vec<(String, String)>,
+    collaborators: Vec<String>,
+    unassigning_teams: Vec<String>,
+    branch_protection: Option<BranchProtection>,
+    organization_admins: Vec<String>,
+    team_maintainers: Vec<(String, String)>,
+    anonymous: bool,
 }
 
 #[cfg(test)]
@@ -235,6 +1138,13 @@ pub struct FakeGithubState {
 pub struct FakeGithubStateBuilder {
     known_users: Vec<String>,
     known_teams: Vec<String>,
+    team_members: Vec<(String, String)>,
+    collaborators: Vec<String>,
+    unassigning_teams: Vec<String>,
+    branch_protection: Option<BranchProtection>,
+    organization_admins: Vec<String>,
+    team_maintainers: Vec<(String, String)>,
+    anonymous: bool,
 }
 
 #[cfg(test)]
@@ -249,8 +1159,59 @@ impl FakeGithubStateBuilder {
         self
     }
 
+    pub fn add_team_member(mut self, team: &str, username: &str) -> Self {
+        self.team_members
+            .push((team.replace("@", ""), username.replace("@", "")));
+        self
+    }
+
+    pub fn add_repository_collaborator(mut self, username: &str) -> Self {
+        self.collaborators.push(username.replace("@", ""));
+        self
+    }
+
+    pub fn disable_review_assignment(mut self, team: &str) -> Self {
+        self.unassigning_teams.push(team.replace("@", ""));
+        self
+    }
+
+    pub fn protect_branch(mut self, require_code_owner_reviews: bool, required_approvals: u32) -> Self {
+        self.branch_protection = Some(BranchProtection {
+            require_code_owner_reviews,
+            required_approvals,
+        });
+        self
+    }
+
+    pub fn add_organization_admin(mut self, username: &str) -> Self {
+        self.organization_admins.push(username.replace("@", ""));
+        self
+    }
+
+    pub fn add_team_maintainer(mut self, team: &str, username: &str) -> Self {
+        let membership = (team.replace("@", ""), username.replace("@", ""));
+        self.team_members.push(membership.clone());
+        self.team_maintainers.push(membership);
+        self
+    }
+
+    pub fn anonymous(mut self) -> Self {
+        self.anonymous = true;
+        self
+    }
+
     pub fn build(self) -> FakeGithubState {
-        FakeGithubState::new(self.known_users, self.known_teams)
+        FakeGithubState {
+            known_users: self.known_users,
+            known_teams: self.known_teams,
+            team_members: self.team_members,
+            collaborators: self.collaborators,
+            unassigning_teams: self.unassigning_teams,
+            branch_protection: self.branch_protection,
+            organization_admins: self.organization_admins,
+            team_maintainers: self.team_maintainers,
+            anonymous: self.anonymous,
+        }
     }
 }
 
@@ -259,45 +1220,43 @@ impl FakeGithubState {
     pub fn builder() -> FakeGithubStateBuilder {
         FakeGithubStateBuilder::default()
     }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use crate::infra::github::build_github_client;
+    use crate::infra::retries::RetryPolicy;
+    use std::time::Duration;
+
+    pub fn create_github_client(base_url: String) -> octocrab::Octocrab {
+        build_github_client(String::new(), Some(base_url)).unwrap()
+    }
 
-    fn new(known_users: Vec<String>, known_teams: Vec<String>) -> Self {
-        Self {
-            known_users,
-            known_teams,
+    pub fn no_retries() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 0,
+            base_delay: Duration::ZERO,
+            retry_on_status: vec![],
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::core::models::ConsistencyIssue;
+    use crate::core::models::config::{CanopusConfig, OwnershipConfig, ProtectionConfig};
     use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
-    use crate::infra::github::{CheckGithubConsistency, GithubConsistencyChecker};
+    use crate::core::models::{ConsistencyIssue, OwnerRole};
+    use crate::infra::github::test_helpers::{create_github_client, no_retries};
+    use crate::infra::github::{
+        ApiCapability, BranchProtection, CheckGithubConsistency, GithubApiChecker, TeamReviewAssignment,
+    };
+    use crate::infra::retries::RetryPolicy;
+    use crate::infra::roster::Roster;
     use assertor::{EqualityAssertion, ResultAssertion};
-    use http::Uri;
     use httpmock::{MockServer, Then, When};
     use itertools::Itertools;
-    use octocrab::service::middleware::retry::RetryConfig;
-    use std::str::FromStr;
-
-    struct ServerUriFactory(String);
-
-    impl TryInto<Uri> for ServerUriFactory {
-        type Error = http::uri::InvalidUri;
-
-        fn try_into(self) -> Result<Uri, Self::Error> {
-            Uri::from_str(self.0.as_str())
-        }
-    }
-
-    fn create_github_client(base_url: String) -> octocrab::Octocrab {
-        octocrab::Octocrab::builder()
-            .base_uri(ServerUriFactory(base_url))
-            .unwrap()
-            .add_retry_config(RetryConfig::Simple(0))
-            .build()
-            .unwrap()
-    }
+    use std::collections::{BTreeMap, BTreeSet, HashSet};
+    use std::time::Duration;
 
     fn responds_with_existing_github_user(username: &str) -> impl FnOnce(When, Then) {
         move |when, then| {
@@ -385,7 +1344,7 @@ mod tests {
         }
     }
 
-    fn responds_with_members_of_an_organization(organization: &str, usernames: Vec<&str>) -> impl FnOnce(When, Then) {
+    fn members_as_json(usernames: Vec<&str>) -> String {
         let member_template = r#"
                   {
                     "login": "<username>",
@@ -409,14 +1368,18 @@ mod tests {
                   }
             "#;
 
-        move |when, then| {
-            let users = usernames
-                .into_iter()
-                .map(|username| member_template.replace("<username>", username))
-                .collect_vec()
-                .join(",");
+        let users = usernames
+            .into_iter()
+            .map(|username| member_template.replace("<username>", username))
+            .collect_vec()
+            .join(",");
+
+        format!("[{}]", users)
+    }
 
-            let json = format!("[{}]", users);
+    fn responds_with_members_of_an_organization(organization: &str, usernames: Vec<&str>) -> impl FnOnce(When, Then) {
+        move |when, then| {
+            let json = members_as_json(usernames);
 
             when.method("GET")
                 .path(format!("/orgs/{}/members", organization))
@@ -440,17 +1403,285 @@ mod tests {
 
         let organization_members = mock_server.mock(returns_members);
 
-        let consistency_checker = GithubConsistencyChecker::ApiBased(create_github_client(mock_server.base_url()));
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
 
         let identity = GithubIdentityHandle::new("ubiratansoares".to_string());
         let check = consistency_checker
-            .github_identity(github_organization, &identity)
+            .github_identity(github_organization, &identity, &no_retries())
             .await;
 
         organization_members.assert();
         assertor::assert_that!(check).is_ok();
     }
 
+    #[tokio::test]
+    async fn should_list_organization_members_once_per_run() {
+        let mock_server = MockServer::start();
+
+        let github_organization = "dotanuki-labs";
+        let members = vec!["ubiratansoares", "dotanuki-bot"];
+
+        let returns_members = responds_with_members_of_an_organization(github_organization, members);
+
+        let organization_members = mock_server.mock(returns_members);
+
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
+
+        let first_identity = GithubIdentityHandle::new("ubiratansoares".to_string());
+        let second_identity = GithubIdentityHandle::new("dotanuki-bot".to_string());
+
+        let retries = no_retries();
+
+        let (first_check, second_check) = futures::join!(
+            consistency_checker.github_identity(github_organization, &first_identity, &retries),
+            consistency_checker.github_identity(github_organization, &second_identity, &retries)
+        );
+
+        let third_check = consistency_checker
+            .github_identity(github_organization, &first_identity, &retries)
+            .await;
+
+        organization_members.assert_calls(1);
+        assertor::assert_that!(first_check).is_ok();
+        assertor::assert_that!(second_check).is_ok();
+        assertor::assert_that!(third_check).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_verify_only_whether_users_exist_when_anonymous() {
+        let mock_server = MockServer::start();
+
+        let github_organization = "dotanuki-labs";
+        let returns_members = responds_with_members_of_an_organization(github_organization, vec!["dotanuki-bot"]);
+
+        let organization_members = mock_server.mock(returns_members);
+        mock_server.mock(responds_with_existing_github_user("ubiratansoares"));
+        mock_server.mock(responds_with_user_not_found_on_github("itto-ogami"));
+
+        let consistency_checker = GithubApiChecker::anonymous(create_github_client(mock_server.base_url()));
+
+        let existing = GithubIdentityHandle::new("ubiratansoares".to_string());
+        let missing = GithubIdentityHandle::new("itto-ogami".to_string());
+        let retries = no_retries();
+
+        let existing_check = consistency_checker
+            .github_identity(github_organization, &existing, &retries)
+            .await;
+
+        let missing_check = consistency_checker
+            .github_identity(github_organization, &missing, &retries)
+            .await;
+
+        organization_members.assert_calls(0);
+        assertor::assert_that!(consistency_checker.capability()).is_equal_to(ApiCapability::Anonymous);
+        assertor::assert_that!(existing_check).is_ok();
+        assertor::assert_that!(missing_check).is_equal_to(Err(ConsistencyIssue::UserDoesNotExist(missing)));
+    }
+
+    #[tokio::test]
+    async fn should_list_members_of_github_team() {
+        let mock_server = MockServer::start();
+
+        let team_members = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/orgs/dotanuki-labs/teams/rustaceans/members")
+                .query_param("page", "1");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(members_as_json(vec!["ubiratansoares"]));
+        });
+
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
+
+        let organization = GithubIdentityHandle::new("dotanuki-labs".to_string());
+        let team = GithubTeamHandle::new(organization, "rustaceans".to_string());
+        let members = consistency_checker.team_members(&team, &no_retries()).await.unwrap();
+
+        team_members.assert();
+        let expected = HashSet::from([GithubIdentityHandle::new("ubiratansoares".to_string())]);
+        assertor::assert_that!(members).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_fetch_roster_of_organization() {
+        let mock_server = MockServer::start();
+
+        let returns_members =
+            responds_with_members_of_an_organization("dotanuki-labs", vec!["ubiratansoares", "Dotanuki-Bot"]);
+
+        let organization_members = mock_server.mock(returns_members);
+
+        let organization_teams = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/orgs/dotanuki-labs/teams")
+                .query_param("page", "1");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(r#"[{ "slug": "rustaceans", "name": "Rustaceans" }, { "slug": "writers", "name": "Writers" }]"#);
+        });
+
+        let rustaceans_members = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/orgs/dotanuki-labs/teams/rustaceans/members")
+                .query_param("page", "1");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(members_as_json(vec!["ubiratansoares"]));
+        });
+
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
+        let roster = consistency_checker
+            .fetch_roster(&no_retries(), "dotanuki-labs")
+            .await
+            .unwrap();
+
+        organization_members.assert();
+        organization_teams.assert();
+        rustaceans_members.assert();
+
+        let expected = Roster {
+            organization: "dotanuki-labs".to_string(),
+            members: BTreeSet::from(["dotanuki-bot".to_string(), "ubiratansoares".to_string()]),
+            teams: BTreeMap::from([
+                ("rustaceans".to_string(), BTreeSet::from(["ubiratansoares".to_string()])),
+                ("writers".to_string(), BTreeSet::new()),
+            ]),
+        };
+
+        assertor::assert_that!(roster).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_fail_early_when_token_lacks_required_scopes() {
+        let mock_server = MockServer::start();
+
+        let token_scopes = mock_server.mock(|when, then| {
+            when.method("GET").path("/rate_limit");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .header("x-oauth-scopes", "admin:org, read:user")
+                .body(r#"{ "resources": {} }"#);
+        });
+
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
+
+        let owners_only = CanopusConfig::default();
+
+        let protecting_branch = CanopusConfig {
+            protection: ProtectionConfig {
+                branch: Some("main".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let naming_collaborators_repository = CanopusConfig {
+            ownership: OwnershipConfig {
+                collaborators_repository: Some("dotanuki-labs/canopus".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let allowing_outside_collaborators = CanopusConfig {
+            ownership: OwnershipConfig {
+                allow_outside_collaborators: Some(true),
+                collaborators_repository: Some("dotanuki-labs/canopus".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let verifying_owners = consistency_checker.preflight(&owners_only, &no_retries()).await;
+        let verifying_protection = consistency_checker.preflight(&protecting_branch, &no_retries()).await;
+        let verifying_repository = consistency_checker
+            .preflight(&naming_collaborators_repository, &no_retries())
+            .await;
+        let verifying_collaborators = consistency_checker
+            .preflight(&allowing_outside_collaborators, &no_retries())
+            .await;
+
+        // Scopes are fetched once, whatever the number of validated projects
+        token_scopes.assert_calls(1);
+
+        assertor::assert_that!(verifying_owners).is_ok();
+        assertor::assert_that!(verifying_protection.unwrap_err().to_string()).is_equal_to(
+            "the token defined by GITHUB_TOKEN lacks the repo scope, required to verify branch protection".to_string(),
+        );
+        assertor::assert_that!(verifying_repository).is_ok();
+        assertor::assert_that!(verifying_collaborators.unwrap_err().to_string()).is_equal_to(
+            "the token defined by GITHUB_TOKEN lacks the repo scope, required to verify repository collaborators"
+                .to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_early_when_token_is_rejected() {
+        let mock_server = MockServer::start();
+
+        mock_server.mock(|when, then| {
+            when.method("GET").path("/rate_limit");
+
+            then.status(401)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(r#"{ "message": "Bad credentials" }"#);
+        });
+
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
+        let verified = consistency_checker
+            .preflight(&CanopusConfig::default(), &no_retries())
+            .await;
+
+        assertor::assert_that!(verified.unwrap_err().to_string())
+            .is_equal_to("the token defined by GITHUB_TOKEN was rejected by Github API".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_fetch_roles_of_active_memberships_only() {
+        let mock_server = MockServer::start();
+
+        let organization_admin = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/orgs/dotanuki-labs/memberships/ubiratansoares");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(r#"{ "role": "admin", "state": "active" }"#);
+        });
+
+        let pending_maintainer = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/orgs/dotanuki-labs/teams/rustaceans/memberships/dotanuki-bot");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(r#"{ "role": "maintainer", "state": "pending" }"#);
+        });
+
+        let organization = GithubIdentityHandle::new("dotanuki-labs".to_string());
+        let team = GithubTeamHandle::new(organization, "rustaceans".to_string());
+        let admin = GithubIdentityHandle::new("ubiratansoares".to_string());
+        let invited = GithubIdentityHandle::new("dotanuki-bot".to_string());
+
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
+        let admin_role = consistency_checker
+            .organization_role("dotanuki-labs", &admin, &no_retries())
+            .await;
+        let invited_role = consistency_checker.team_role(&team, &invited, &no_retries()).await;
+        let outsider_role = consistency_checker.team_role(&team, &admin, &no_retries()).await;
+
+        organization_admin.assert();
+        pending_maintainer.assert();
+
+        assertor::assert_that!(admin_role).is_equal_to(Ok(Some(OwnerRole::Admin)));
+        assertor::assert_that!(invited_role).is_equal_to(Ok(None));
+        assertor::assert_that!(outsider_role).is_equal_to(Ok(None));
+    }
+
     #[tokio::test]
     async fn should_report_user_outside_github_organization() {
         let mock_server = MockServer::start();
@@ -466,11 +1697,11 @@ mod tests {
         let organization_members = mock_server.mock(returns_members);
         let exists_on_github = mock_server.mock(returns_user_on_github);
 
-        let consistency_checker = GithubConsistencyChecker::ApiBased(create_github_client(mock_server.base_url()));
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
 
         let identity = GithubIdentityHandle::new(outside_organization.to_string());
         let check = consistency_checker
-            .github_identity(github_organization, &identity)
+            .github_identity(github_organization, &identity, &no_retries())
             .await;
 
         let expected = ConsistencyIssue::OutsiderUser(identity);
@@ -495,11 +1726,11 @@ mod tests {
         let organization_members = mock_server.mock(returns_members);
         let user_not_found = mock_server.mock(returns_user_not_found);
 
-        let consistency_checker = GithubConsistencyChecker::ApiBased(create_github_client(mock_server.base_url()));
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
 
         let identity = GithubIdentityHandle::new(not_on_github.to_string());
         let check = consistency_checker
-            .github_identity(github_organization, &identity)
+            .github_identity(github_organization, &identity, &no_retries())
             .await;
 
         let expected = ConsistencyIssue::UserDoesNotExist(identity);
@@ -515,13 +1746,12 @@ mod tests {
         let misspelled_organization = "dotanuki";
         let github_team = "crabbers";
 
-        let consistency_checker =
-            GithubConsistencyChecker::ApiBased(create_github_client("https://api.github.com".to_string()));
+        let consistency_checker = GithubApiChecker::new(create_github_client("https://api.github.com".to_string()));
 
         let organization = GithubIdentityHandle::new(misspelled_organization.to_string());
         let team_handle = GithubTeamHandle::new(organization, github_team.to_string());
         let check = consistency_checker
-            .github_team(provided_github_organization, &team_handle)
+            .github_team(provided_github_organization, &team_handle, &no_retries())
             .await;
 
         let expected = ConsistencyIssue::TeamDoesNotMatchOrganization(team_handle);
@@ -540,11 +1770,13 @@ mod tests {
 
         let team_not_found = mock_server.mock(returns_not_found);
 
-        let consistency_checker = GithubConsistencyChecker::ApiBased(create_github_client(mock_server.base_url()));
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
 
         let organization = GithubIdentityHandle::new(github_organization.to_string());
         let team_handle = GithubTeamHandle::new(organization, undefined_team.to_string());
-        let check = consistency_checker.github_team(github_organization, &team_handle).await;
+        let check = consistency_checker
+            .github_team(github_organization, &team_handle, &no_retries())
+            .await;
 
         let expected = ConsistencyIssue::TeamDoesNotExist(team_handle);
 
@@ -559,10 +1791,12 @@ mod tests {
         let returns_internal_error = responds_with_internal_error("/orgs/dotanuki/members");
         let internal_server_error = mock_server.mock(returns_internal_error);
 
-        let consistency_checker = GithubConsistencyChecker::ApiBased(create_github_client(mock_server.base_url()));
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
 
         let identity = GithubIdentityHandle::new("ubiratansoares".to_string());
-        let check = consistency_checker.github_identity("dotanuki", &identity).await;
+        let check = consistency_checker
+            .github_identity("dotanuki", &identity, &no_retries())
+            .await;
 
         let expected = ConsistencyIssue::CannotListMembersInTheOrganization("dotanuki".to_string());
 
@@ -577,15 +1811,134 @@ mod tests {
         let returns_internal_error = responds_with_internal_error("/orgs/dotanuki/teams/crabbers");
         let internal_server_error = mock_server.mock(returns_internal_error);
 
-        let consistency_checker = GithubConsistencyChecker::ApiBased(create_github_client(mock_server.base_url()));
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
 
         let organization = GithubIdentityHandle::new("dotanuki".to_string());
         let team_handle = GithubTeamHandle::new(organization, "crabbers".to_string());
-        let check = consistency_checker.github_team("dotanuki", &team_handle).await;
+        let check = consistency_checker
+            .github_team("dotanuki", &team_handle, &no_retries())
+            .await;
 
         let expected = ConsistencyIssue::CannotVerifyTeam(team_handle);
 
         internal_server_error.assert();
         assertor::assert_that!(check).is_equal_to(Err(expected));
     }
+
+    #[tokio::test]
+    async fn should_retry_requests_failing_with_listed_statuses() {
+        let mock_server = MockServer::start();
+
+        let returns_internal_error = responds_with_internal_error("/orgs/dotanuki/teams/crabbers");
+        let internal_server_error = mock_server.mock(returns_internal_error);
+
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
+
+        let retries = RetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            retry_on_status: vec![500],
+        };
+
+        let organization = GithubIdentityHandle::new("dotanuki".to_string());
+        let team_handle = GithubTeamHandle::new(organization, "crabbers".to_string());
+        let check = consistency_checker
+            .github_team("dotanuki", &team_handle, &retries)
+            .await;
+
+        let expected = ConsistencyIssue::CannotVerifyTeam(team_handle);
+
+        internal_server_error.assert_calls(3);
+        assertor::assert_that!(check).is_equal_to(Err(expected));
+    }
+
+    #[tokio::test]
+    async fn should_fetch_team_review_assignment() {
+        let mock_server = MockServer::start();
+
+        let team_settings = mock_server.mock(|when, then| {
+            when.method("POST").path("/graphql").body_includes("crabbers");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(
+                    r#"{
+                        "data": {
+                            "organization": {
+                                "team": {
+                                    "reviewRequestDelegationEnabled": true,
+                                    "reviewRequestDelegationAlgorithm": "LOAD_BALANCE",
+                                    "reviewRequestDelegationMemberCount": 2,
+                                    "reviewRequestDelegationNotifyTeam": false
+                                }
+                            }
+                        }
+                    }"#,
+                );
+        });
+
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
+
+        let organization = GithubIdentityHandle::new("dotanuki".to_string());
+        let team_handle = GithubTeamHandle::new(organization, "crabbers".to_string());
+        let assignment = consistency_checker
+            .team_review_assignment(&team_handle, &no_retries())
+            .await;
+
+        let expected = TeamReviewAssignment {
+            enabled: true,
+            algorithm: Some("LOAD_BALANCE".to_string()),
+            member_count: Some(2),
+            notify_team: false,
+        };
+
+        team_settings.assert();
+        assertor::assert_that!(assignment).is_equal_to(Ok(expected));
+    }
+
+    #[tokio::test]
+    async fn should_combine_branch_protection_with_rulesets() {
+        let mock_server = MockServer::start();
+
+        let classic_protection = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/repos/dotanuki-labs/canopus/branches/main/protection");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(r#"{ "required_pull_request_reviews": { "required_approving_review_count": 1 } }"#);
+        });
+
+        let rulesets = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/repos/dotanuki-labs/canopus/rules/branches/main");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(
+                    r#"[
+                        { "type": "deletion" },
+                        {
+                            "type": "pull_request",
+                            "parameters": { "require_code_owner_review": true, "required_approving_review_count": 2 }
+                        }
+                    ]"#,
+                );
+        });
+
+        let consistency_checker = GithubApiChecker::new(create_github_client(mock_server.base_url()));
+
+        let protection = consistency_checker
+            .branch_protection("dotanuki-labs/canopus", "main", &no_retries())
+            .await;
+
+        let expected = BranchProtection {
+            require_code_owner_reviews: true,
+            required_approvals: 2,
+        };
+
+        classic_protection.assert();
+        rulesets.assert();
+        assertor::assert_that!(protection).is_equal_to(Ok(expected));
+    }
 }
@@ -0,0 +1,273 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::ConsistencyIssue;
+use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
+use crate::infra::github;
+use crate::infra::github::CheckGithubConsistency;
+use crate::infra::progress;
+use crate::infra::progress::ProgressEvent;
+use crate::infra::retries::RetryPolicy;
+use async_trait::async_trait;
+use http::StatusCode;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+static DEFAULT_GITLAB_API_URL: &str = "https://gitlab.com/api/v4";
+
+#[derive(Deserialize)]
+struct GitlabUser {
+    id: u64,
+    username: String,
+}
+
+/// Creates a client for GitLab REST API, which accepts personal access tokens as bearer tokens
+pub fn build_gitlab_client(token: String, api_url: Option<String>) -> anyhow::Result<octocrab::Octocrab> {
+    github::build_github_client(token, Some(api_url.unwrap_or(DEFAULT_GITLAB_API_URL.to_string())))
+}
+
+// Groups are addressed by their full path, slashes included
+fn group_route(group_path: &str) -> String {
+    format!("/groups/{}", group_path.replace('/', "%2F"))
+}
+
+/// Verifies owners against GitLab REST API, with subgroups standing for Github teams
+pub struct GitlabApiChecker {
+    gitlab_client: octocrab::Octocrab,
+}
+
+impl GitlabApiChecker {
+    pub fn new(gitlab_client: octocrab::Octocrab) -> Self {
+        Self { gitlab_client }
+    }
+
+    fn is_not_found(error: &octocrab::Error) -> bool {
+        matches!(error, octocrab::Error::GitHub { source, .. } if source.status_code == StatusCode::NOT_FOUND)
+    }
+
+    async fn check_user_on_gitlab(
+        &self,
+        retries: &RetryPolicy,
+        group: &str,
+        identity: &GithubIdentityHandle,
+    ) -> Result<(), ConsistencyIssue> {
+        let username = identity.inner();
+        let route = format!("/users?username={username}");
+
+        let users: Vec<GitlabUser> = retries
+            .get(&route, || self.gitlab_client.get(&route, None::<&()>))
+            .await
+            .map_err(|_| ConsistencyIssue::CannotVerifyUser(identity.clone()))?;
+
+        let Some(user) = users
+            .into_iter()
+            .find(|user| user.username.eq_ignore_ascii_case(username))
+        else {
+            return Err(ConsistencyIssue::UserDoesNotExist(identity.clone()));
+        };
+
+        // Members inherited from parent groups have access as well
+        let route = format!("{}/members/all/{}", group_route(group), user.id);
+        let membership: Result<serde_json::Value, _> = retries
+            .get(&route, || self.gitlab_client.get(&route, None::<&()>))
+            .await;
+
+        match membership {
+            Ok(_) => Ok(()),
+            Err(error) if Self::is_not_found(&error) => Err(ConsistencyIssue::OutsiderUser(identity.clone())),
+            Err(_) => Err(ConsistencyIssue::CannotVerifyUser(identity.clone())),
+        }
+    }
+
+    async fn check_group_on_gitlab(
+        &self,
+        retries: &RetryPolicy,
+        handle: &GithubTeamHandle,
+    ) -> Result<(), ConsistencyIssue> {
+        let route = group_route(&format!("{}/{}", handle.organization.inner(), handle.name));
+
+        let group: Result<serde_json::Value, _> = retries
+            .get(&route, || self.gitlab_client.get(&route, None::<&()>))
+            .await;
+
+        match group {
+            Ok(_) => Ok(()),
+            Err(error) if Self::is_not_found(&error) => Err(ConsistencyIssue::TeamDoesNotExist(handle.clone())),
+            Err(_) => Err(ConsistencyIssue::CannotVerifyTeam(handle.clone())),
+        }
+    }
+
+    async fn find_all_members_for_group(
+        &self,
+        retries: &RetryPolicy,
+        handle: &GithubTeamHandle,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        let members_route = format!(
+            "{}/members/all",
+            group_route(&format!("{}/{}", handle.organization.inner(), handle.name))
+        );
+
+        let mut all_handles = HashSet::new();
+        let mut page = 0u32;
+
+        loop {
+            page += 1;
+            let route = format!("{members_route}?per_page=100&page={page}");
+
+            let fetched: Result<Vec<GitlabUser>, _> = retries
+                .get(&route, || self.gitlab_client.get(&route, None::<&()>))
+                .await;
+
+            progress::report(ProgressEvent::GithubPageFetched {
+                route: members_route.clone(),
+                page,
+            });
+
+            // Missing groups are reported by group checks, hence they simply have no members here
+            let members = match fetched {
+                Ok(members) => members,
+                Err(error) if Self::is_not_found(&error) => vec![],
+                Err(_) => return Err(ConsistencyIssue::CannotVerifyTeam(handle.clone())),
+            };
+
+            if members.is_empty() {
+                break;
+            }
+
+            all_handles.extend(
+                members
+                    .into_iter()
+                    .map(|member| GithubIdentityHandle::new(member.username)),
+            );
+        }
+
+        Ok(all_handles)
+    }
+}
+
+#[async_trait]
+impl CheckGithubConsistency for GitlabApiChecker {
+    async fn github_identity(
+        &self,
+        organization: &str,
+        identity: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        self.check_user_on_gitlab(retries, organization, identity).await
+    }
+
+    async fn github_team(
+        &self,
+        organization: &str,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        if !handle.organization.inner().eq_ignore_ascii_case(organization) {
+            return Err(ConsistencyIssue::TeamDoesNotMatchOrganization(handle.clone()));
+        };
+
+        self.check_group_on_gitlab(retries, handle).await
+    }
+
+    async fn team_members(
+        &self,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        self.find_all_members_for_group(retries, handle).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::ConsistencyIssue;
+    use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
+    use crate::infra::github::CheckGithubConsistency;
+    use crate::infra::github::test_helpers::no_retries;
+    use crate::infra::gitlab::{GitlabApiChecker, build_gitlab_client};
+    use assertor::{EqualityAssertion, ResultAssertion};
+    use httpmock::MockServer;
+    use std::collections::HashSet;
+
+    fn gitlab_checker(mock_server: &MockServer) -> GitlabApiChecker {
+        GitlabApiChecker::new(build_gitlab_client(String::new(), Some(mock_server.url("/api/v4"))).unwrap())
+    }
+
+    #[tokio::test]
+    async fn should_report_gitlab_user_outside_group() {
+        let mock_server = MockServer::start();
+
+        let user_lookup = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/api/v4/users")
+                .query_param("username", "itto-ogami");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"[{"id": 42, "username": "itto-ogami"}]"#);
+        });
+
+        let membership = mock_server.mock(|when, then| {
+            when.method("GET").path("/api/v4/groups/dotanuki-labs/members/all/42");
+
+            then.status(404)
+                .header("content-type", "application/json")
+                .body(r#"{"message": "404 Not found"}"#);
+        });
+
+        let identity = GithubIdentityHandle::new("itto-ogami".to_string());
+        let check = gitlab_checker(&mock_server)
+            .github_identity("dotanuki-labs", &identity, &no_retries())
+            .await;
+
+        user_lookup.assert();
+        membership.assert();
+        assertor::assert_that!(check).is_equal_to(Err(ConsistencyIssue::OutsiderUser(identity)));
+    }
+
+    #[tokio::test]
+    async fn should_verify_gitlab_subgroups_and_their_members() {
+        let mock_server = MockServer::start();
+
+        let group = mock_server.mock(|when, then| {
+            when.method("GET").path("/api/v4/groups/dotanuki-labs%2Frustaceans");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"id": 7, "full_path": "dotanuki-labs/rustaceans"}"#);
+        });
+
+        let first_page = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/api/v4/groups/dotanuki-labs%2Frustaceans/members/all")
+                .query_param("page", "1");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"[{"id": 42, "username": "ubiratansoares"}]"#);
+        });
+
+        let last_page = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/api/v4/groups/dotanuki-labs%2Frustaceans/members/all")
+                .query_param("page", "2");
+
+            then.status(200).header("content-type", "application/json").body("[]");
+        });
+
+        let checker = gitlab_checker(&mock_server);
+        let organization = GithubIdentityHandle::new("dotanuki-labs".to_string());
+        let team = GithubTeamHandle::new(organization, "rustaceans".to_string());
+
+        let check = checker.github_team("dotanuki-labs", &team, &no_retries()).await;
+        let members = checker.team_members(&team, &no_retries()).await;
+
+        group.assert();
+        first_page.assert();
+        last_page.assert();
+        assertor::assert_that!(check).is_ok();
+
+        let expected = HashSet::from([GithubIdentityHandle::new("ubiratansoares".to_string())]);
+        assertor::assert_that!(members).is_equal_to(Ok(expected));
+    }
+}
@@ -1,53 +1,709 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use ignore::WalkBuilder;
+use crate::core::models::config::{
+    DEFAULT_VALUE_CACHE_PATHS, DEFAULT_VALUE_INCLUDE_SUBMODULES, DEFAULT_VALUE_INCLUDE_UNTRACKED, PathsConfig,
+    SymlinksPolicy,
+};
+use crate::infra::git;
+use crate::infra::remote::GithubTreeWalker;
+use anyhow::{Context, bail};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::{DirEntry, WalkBuilder};
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+mod cache;
 
 pub trait DirWalking {
-    fn walk(&self, origin: &Path) -> Vec<PathBuf>;
+    /// Lazily yields project paths relative to origin, so callers can stop walking as soon as they are done
+    fn walk(&self, origin: &Path, settings: &PathsConfig) -> anyhow::Result<Box<dyn Iterator<Item = PathBuf> + '_>>;
+
+    /// Same as walk, but yielding only regular files
+    fn walk_files(
+        &self,
+        origin: &Path,
+        settings: &PathsConfig,
+    ) -> anyhow::Result<Box<dyn Iterator<Item = PathBuf> + '_>>;
+
+    /// Yields paths honoring include and exclude patterns, but skipped by walk since git ignores them
+    fn walk_ignored(
+        &self,
+        origin: &Path,
+        settings: &PathsConfig,
+    ) -> anyhow::Result<Box<dyn Iterator<Item = PathBuf> + '_>>;
 }
 
 pub enum PathWalker {
     GitAware,
+    GithubTree(GithubTreeWalker),
+    Listed(ListedPaths),
 
     // We opt for a define test doubles with test-only
     // visibility, pattern-matching them when needed
     #[cfg(test)]
-    FakePaths(Vec<String>),
+    FakePaths {
+        paths: Vec<String>,
+        ignored: Vec<String>,
+    },
+}
+
+/// Files submitted by clients of canopus serve, listed per pseudo project path, since the
+/// project they belong to does not live on this machine
+#[derive(Default)]
+pub struct ListedPaths {
+    listed: Mutex<HashMap<PathBuf, Vec<PathBuf>>>,
+}
+
+impl ListedPaths {
+    pub fn list(&self, origin: &Path, files: Vec<PathBuf>) {
+        self.listed.lock().unwrap().insert(origin.to_path_buf(), files);
+    }
+
+    pub fn forget(&self, origin: &Path) {
+        self.listed.lock().unwrap().remove(origin);
+    }
+
+    fn files(&self, origin: &Path) -> Vec<PathBuf> {
+        self.listed.lock().unwrap().get(origin).cloned().unwrap_or_default()
+    }
+
+    /// Listed files along with their parent folders, which directory patterns match
+    fn paths(&self, origin: &Path) -> Vec<PathBuf> {
+        let files = self.files(origin);
+
+        let folders = files
+            .iter()
+            .flat_map(|file| file.ancestors().skip(1))
+            .filter(|folder| !folder.as_os_str().is_empty())
+            .map(Path::to_path_buf)
+            .collect::<HashSet<_>>();
+
+        folders.into_iter().chain(files).collect()
+    }
+}
+
+/// Include and exclude patterns from configuration, evaluated against paths relative to the project root
+#[derive(Clone)]
+struct PathFilters {
+    include: Option<GlobSet>,
+    exclude: GlobSet,
+}
+
+impl PathFilters {
+    fn is_excluded(&self, relative_path: &Path) -> bool {
+        self.exclude.is_match(relative_path)
+    }
+
+    fn is_included(&self, relative_path: &Path) -> bool {
+        match &self.include {
+            None => true,
+            Some(include) => include.is_match(relative_path),
+        }
+    }
+}
+
+/// Compiles path patterns defined in configuration into a single matcher
+pub fn compile_path_patterns(patterns: &[String]) -> anyhow::Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        let glob = Glob::new(pattern).with_context(|| format!("invalid path pattern in config : {pattern}"))?;
+        builder.add(glob);
+    }
+
+    Ok(builder.build()?)
+}
+
+impl TryFrom<&PathsConfig> for PathFilters {
+    type Error = anyhow::Error;
+
+    fn try_from(settings: &PathsConfig) -> anyhow::Result<Self> {
+        let include = if settings.include.is_empty() {
+            None
+        } else {
+            Some(compile_path_patterns(&settings.include)?)
+        };
+
+        let exclude = compile_path_patterns(&settings.exclude)?;
+        Ok(Self { include, exclude })
+    }
 }
 
 impl DirWalking for PathWalker {
-    fn walk(&self, origin: &Path) -> Vec<PathBuf> {
+    fn walk(&self, origin: &Path, settings: &PathsConfig) -> anyhow::Result<Box<dyn Iterator<Item = PathBuf> + '_>> {
+        let filters = PathFilters::try_from(settings)?;
+
+        match self {
+            PathWalker::GitAware if settings.cache.unwrap_or(DEFAULT_VALUE_CACHE_PATHS) => {
+                let paths = cache::walk_with_cache(origin, settings, || walk_git_aware(origin, settings, filters))?;
+                Ok(Box::new(paths.into_iter()))
+            },
+            PathWalker::GitAware => walk_git_aware(origin, settings, filters),
+            PathWalker::GithubTree(tree_walker) => {
+                // Remote paths are already relative to the project root and cannot be pruned
+                // while traversing, hence we check whether any of their ancestors is excluded
+                let paths = tree_walker
+                    .entries(origin)?
+                    .into_iter()
+                    .map(|entry| entry.path)
+                    .filter(move |path| {
+                        !path.ancestors().any(|ancestor| filters.is_excluded(ancestor)) && filters.is_included(path)
+                    });
+
+                Ok(Box::new(paths))
+            },
+            PathWalker::Listed(listed_paths) => {
+                let paths = listed_paths.paths(origin).into_iter().filter(move |path| {
+                    !path.ancestors().any(|ancestor| filters.is_excluded(ancestor)) && filters.is_included(path)
+                });
+
+                Ok(Box::new(paths))
+            },
+            #[cfg(test)]
+            PathWalker::FakePaths { paths, .. } => {
+                let paths = paths
+                    .iter()
+                    .map(PathBuf::from)
+                    .filter(move |path| !filters.is_excluded(path) && filters.is_included(path));
+
+                Ok(Box::new(paths))
+            },
+        }
+    }
+
+    fn walk_files(
+        &self,
+        origin: &Path,
+        settings: &PathsConfig,
+    ) -> anyhow::Result<Box<dyn Iterator<Item = PathBuf> + '_>> {
+        if let PathWalker::Listed(listed_paths) = self {
+            let listed_files = listed_paths.files(origin).into_iter().collect::<HashSet<_>>();
+            let files = self
+                .walk(origin, settings)?
+                .filter(move |path| listed_files.contains(path));
+            return Ok(Box::new(files));
+        }
+
+        let PathWalker::GithubTree(tree_walker) = self else {
+            let project_root = origin.to_path_buf();
+            let files = self
+                .walk(origin, settings)?
+                .filter(move |path| !project_root.join(path).is_dir());
+            return Ok(Box::new(files));
+        };
+
+        // Remote paths don't exist locally, so we rely on the tree entry kinds instead
+        let remote_files = tree_walker
+            .entries(origin)?
+            .into_iter()
+            .filter(|entry| entry.is_file())
+            .map(|entry| entry.path)
+            .collect::<HashSet<_>>();
+
+        let files = self
+            .walk(origin, settings)?
+            .filter(move |path| remote_files.contains(path));
+        Ok(Box::new(files))
+    }
+
+    fn walk_ignored(
+        &self,
+        origin: &Path,
+        settings: &PathsConfig,
+    ) -> anyhow::Result<Box<dyn Iterator<Item = PathBuf> + '_>> {
+        let filters = PathFilters::try_from(settings)?;
+
         match self {
             PathWalker::GitAware => {
-                let current_dir = std::env::current_dir().unwrap();
-
-                WalkBuilder::new(origin)
-                    .hidden(false)
-                    .git_exclude(true)
-                    .filter_entry(|entry| !entry.path().to_string_lossy().contains(".git/"))
-                    .build()
-                    .filter_map(|entry| entry.ok())
-                    .map(|entry| {
-                        // We have to check whether this is sufficient
-                        if let Ok(normalized) = entry.path().to_path_buf().strip_prefix(&current_dir) {
-                            normalized.to_path_buf()
-                        } else {
-                            entry.path().to_path_buf()
-                        }
+                let project_root = origin.to_path_buf();
+                let walked_paths = self.walk(origin, settings)?.collect::<HashSet<_>>();
+                let exclusion_root = origin.to_path_buf();
+                let exclusion_filters = filters.clone();
+                let inclusion_root = origin.to_path_buf();
+                let include_submodules = settings.include_submodules.unwrap_or(DEFAULT_VALUE_INCLUDE_SUBMODULES);
+                let symlinks_policy = settings.symlinks_policy();
+
+                // Disabling standard filters brings back everything git ignores
+                let builder = WalkBuilder::new(origin)
+                    .standard_filters(false)
+                    .follow_links(symlinks_policy != SymlinksPolicy::Skip)
+                    .filter_entry(move |entry| {
+                        let relative = entry.path().strip_prefix(&exclusion_root).unwrap_or(entry.path());
+                        !is_git_metadata(entry.path())
+                            && !exclusion_filters.is_excluded(relative)
+                            && (include_submodules || !is_nested_repository(entry))
                     })
-                    .collect::<Vec<_>>()
+                    .to_owned();
+
+                let paths = walk_entries(&builder, symlinks_policy)?
+                    .filter(move |entry| {
+                        let relative = entry.path().strip_prefix(&inclusion_root).unwrap_or(entry.path());
+                        filters.is_included(relative)
+                    })
+                    .map(move |entry| with_forward_slashes(relative_to_project_root(entry.path(), &project_root)))
+                    .filter(move |path| !walked_paths.contains(path));
+
+                Ok(Box::new(paths))
             },
+            // Github trees and listed files never include ignored paths
+            PathWalker::GithubTree(_) | PathWalker::Listed(_) => Ok(Box::new(std::iter::empty())),
             #[cfg(test)]
-            PathWalker::FakePaths(paths) => paths.clone().into_iter().map(PathBuf::from).collect(),
+            PathWalker::FakePaths { ignored, .. } => {
+                let paths = ignored
+                    .iter()
+                    .map(PathBuf::from)
+                    .filter(move |path| !filters.is_excluded(path) && filters.is_included(path));
+
+                Ok(Box::new(paths))
+            },
+        }
+    }
+}
+
+/// Walks local project paths, honoring .gitignore and path filters from configuration
+fn walk_git_aware(
+    origin: &Path,
+    settings: &PathsConfig,
+    filters: PathFilters,
+) -> anyhow::Result<Box<dyn Iterator<Item = PathBuf>>> {
+    let project_root = origin.to_path_buf();
+
+    let tracked_files = if settings.include_untracked.unwrap_or(DEFAULT_VALUE_INCLUDE_UNTRACKED) {
+        None
+    } else {
+        Some(git::tracked_files(origin)?)
+    };
+
+    let exclusion_root = origin.to_path_buf();
+    let exclusion_filters = filters.clone();
+    let inclusion_root = origin.to_path_buf();
+    let include_submodules = settings.include_submodules.unwrap_or(DEFAULT_VALUE_INCLUDE_SUBMODULES);
+    let symlinks_policy = settings.symlinks_policy();
+
+    let builder = WalkBuilder::new(origin)
+        .hidden(false)
+        .git_exclude(true)
+        .follow_links(symlinks_policy != SymlinksPolicy::Skip)
+        .filter_entry(move |entry| {
+            let relative = entry.path().strip_prefix(&exclusion_root).unwrap_or(entry.path());
+            !is_git_metadata(entry.path())
+                && !exclusion_filters.is_excluded(relative)
+                && (include_submodules || !is_nested_repository(entry))
+        })
+        .to_owned();
+
+    let paths = walk_entries(&builder, symlinks_policy)?
+        .filter(move |entry| {
+            let relative = entry.path().strip_prefix(&inclusion_root).unwrap_or(entry.path());
+            let is_dir = entry.file_type().is_some_and(|file_type| file_type.is_dir());
+
+            let tracked = match &tracked_files {
+                Some(tracked_files) if !is_dir => tracked_files.contains(relative),
+                _ => true,
+            };
+
+            tracked && filters.is_included(relative)
+        })
+        .map(move |entry| with_forward_slashes(relative_to_project_root(entry.path(), &project_root)));
+
+    Ok(Box::new(paths))
+}
+
+/// Runs the walk, handling symbolic links leading back to an ancestor directory as the policy demands.
+/// Other walking errors, like unreadable directories, are skipped as they always were
+fn walk_entries(
+    builder: &WalkBuilder,
+    symlinks_policy: SymlinksPolicy,
+) -> anyhow::Result<Box<dyn Iterator<Item = DirEntry>>> {
+    if symlinks_policy == SymlinksPolicy::ErrorOnCycle {
+        let mut entries = Vec::new();
+
+        for walked in builder.build() {
+            match walked {
+                Ok(entry) => entries.push(entry),
+                Err(error) if is_symlinks_cycle(&error) => bail!("cannot walk project : {error}"),
+                Err(_) => continue,
+            }
         }
+
+        return Ok(Box::new(entries.into_iter()));
+    }
+
+    let entries = builder.build().filter_map(|walked| match walked {
+        Ok(entry) => Some(entry),
+        Err(error) => {
+            if is_symlinks_cycle(&error) {
+                log::warn!("Skipping symbolic link : {error}");
+            }
+            None
+        },
+    });
+
+    Ok(Box::new(entries))
+}
+
+fn is_symlinks_cycle(error: &ignore::Error) -> bool {
+    match error {
+        ignore::Error::Loop { .. } => true,
+        ignore::Error::WithPath { err, .. } | ignore::Error::WithDepth { err, .. } => is_symlinks_cycle(err),
+        _ => false,
+    }
+}
+
+// Matching whole components keeps directories like `website.git/` in the walk
+fn is_git_metadata(path: &Path) -> bool {
+    path.components().any(|component| component.as_os_str() == ".git")
+}
+
+// Submodules and nested checkouts carry their own .git, as a file or a directory, and belong to
+// other repositories. The walk origin is left alone, since it may be the root of the project itself
+fn is_nested_repository(entry: &DirEntry) -> bool {
+    entry.depth() > 0
+        && entry.file_type().is_some_and(|file_type| file_type.is_dir())
+        && entry.path().join(".git").exists()
+}
+
+// CODEOWNERS patterns always use forward slashes, hence walked paths must do the same on
+// platforms using another separator, like Windows
+fn with_forward_slashes(path: PathBuf) -> PathBuf {
+    if std::path::MAIN_SEPARATOR == '/' {
+        return path;
+    }
+
+    match path.to_str() {
+        Some(native) => PathBuf::from(native.replace(std::path::MAIN_SEPARATOR, "/")),
+        None => path,
+    }
+}
+
+// Walks start from the project root, hence every entry lives under it, wherever canopus runs from
+fn relative_to_project_root(path: &Path, project_root: &Path) -> PathBuf {
+    match path.strip_prefix(project_root) {
+        Ok(relative) => relative.to_path_buf(),
+        Err(_) => path.to_path_buf(),
     }
 }
 
 impl PathWalker {
+    /// Drops paths fetched for a remote project, since they are walked only while validating it
+    pub fn evict(&self, origin: &Path) {
+        if let PathWalker::GithubTree(tree_walker) = self {
+            tree_walker.evict(origin);
+        }
+    }
+
     #[cfg(test)]
     pub fn with_paths(paths: Vec<&str>) -> Self {
-        PathWalker::FakePaths(paths.into_iter().map(String::from).collect())
+        PathWalker::with_ignored_paths(paths, vec![])
+    }
+
+    #[cfg(test)]
+    pub fn with_ignored_paths(paths: Vec<&str>, ignored: Vec<&str>) -> Self {
+        PathWalker::FakePaths {
+            paths: paths.into_iter().map(String::from).collect(),
+            ignored: ignored.into_iter().map(String::from).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::config::{PathsConfig, SymlinksPolicy};
+    use crate::infra::git;
+    use crate::infra::github::test_helpers::create_github_client;
+    use crate::infra::paths::{DirWalking, ListedPaths, PathWalker};
+    use crate::infra::remote::{GithubTreeWalker, test_helpers};
+    use assertor::{BooleanAssertion, EqualityAssertion, ResultAssertion};
+    use httpmock::MockServer;
+    use itertools::Itertools;
+    use std::path::{Path, PathBuf};
+    use temp_dir::TempDir;
+
+    fn create_project(project_root: &Path) {
+        std::fs::create_dir_all(project_root.join("src")).unwrap();
+        std::fs::create_dir_all(project_root.join("vendor/acme")).unwrap();
+        std::fs::write(project_root.join("src/main.rs"), "fn main() {}").unwrap();
+        std::fs::write(project_root.join("vendor/acme/lib.rs"), "fn acme() {}").unwrap();
+        std::fs::write(project_root.join("README.md"), "# Hello").unwrap();
+    }
+
+    fn relative_files(project_root: &Path, paths: impl Iterator<Item = PathBuf>) -> Vec<String> {
+        paths
+            .filter(|path| project_root.join(path).is_file())
+            .map(|path| path.to_string_lossy().to_string())
+            .sorted()
+            .collect_vec()
+    }
+
+    #[test]
+    fn should_walk_project_paths_lazily() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        create_project(temp_dir.path());
+
+        let mut paths = PathWalker::GitAware
+            .walk(temp_dir.path(), &PathsConfig::default())
+            .unwrap();
+
+        assertor::assert_that!(paths.next().is_some()).is_true();
+        assertor::assert_that!(paths.count()).is_equal_to(6);
+    }
+
+    #[test]
+    fn should_honor_excluded_and_included_paths() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        create_project(temp_dir.path());
+
+        let settings = PathsConfig {
+            include: vec!["**/*.rs".to_string()],
+            exclude: vec!["vendor".to_string()],
+            ..Default::default()
+        };
+
+        let paths = PathWalker::GitAware.walk(temp_dir.path(), &settings).unwrap();
+
+        assertor::assert_that!(relative_files(temp_dir.path(), paths)).is_equal_to(vec!["src/main.rs".to_string()]);
+    }
+
+    #[test]
+    fn should_skip_untracked_files_when_requested() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        create_project(temp_dir.path());
+
+        git::test_helpers::git(temp_dir.path(), &["init", "-q"]);
+        git::test_helpers::git(temp_dir.path(), &["add", "src/main.rs"]);
+
+        let settings = PathsConfig {
+            include_untracked: Some(false),
+            ..Default::default()
+        };
+
+        let paths = PathWalker::GitAware.walk(temp_dir.path(), &settings).unwrap();
+
+        assertor::assert_that!(relative_files(temp_dir.path(), paths)).is_equal_to(vec!["src/main.rs".to_string()]);
+    }
+
+    #[test]
+    fn should_stop_at_nested_repositories_unless_asked() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        create_project(temp_dir.path());
+
+        std::fs::create_dir_all(temp_dir.path().join("modules/theme")).unwrap();
+        std::fs::write(temp_dir.path().join("modules/theme/style.css"), "").unwrap();
+        std::fs::write(
+            temp_dir.path().join("modules/theme/.git"),
+            "gitdir: ../../.git/modules/theme",
+        )
+        .unwrap();
+        git::test_helpers::git(temp_dir.path(), &["init", "-q"]);
+
+        let with_submodules = PathsConfig {
+            include_submodules: Some(true),
+            ..Default::default()
+        };
+
+        let default_paths = PathWalker::GitAware
+            .walk(temp_dir.path(), &PathsConfig::default())
+            .unwrap();
+
+        let submodule_paths = PathWalker::GitAware.walk(temp_dir.path(), &with_submodules).unwrap();
+
+        let default_files = relative_files(temp_dir.path(), default_paths);
+        let submodule_files = relative_files(temp_dir.path(), submodule_paths);
+
+        assertor::assert_that!(default_files.contains(&"modules/theme/style.css".to_string())).is_false();
+        assertor::assert_that!(submodule_files.contains(&"modules/theme/style.css".to_string())).is_true();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_handle_symlinks_cycles_according_to_policy() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        create_project(temp_dir.path());
+
+        std::fs::create_dir_all(temp_dir.path().join("assets/icons")).unwrap();
+        std::fs::write(temp_dir.path().join("assets/icons/logo.svg"), "").unwrap();
+        std::os::unix::fs::symlink("..", temp_dir.path().join("assets/icons/parent")).unwrap();
+        git::test_helpers::git(temp_dir.path(), &["init", "-q"]);
+
+        let following = PathsConfig {
+            symlinks: Some(SymlinksPolicy::Follow),
+            ..Default::default()
+        };
+
+        let erroring = PathsConfig {
+            symlinks: Some(SymlinksPolicy::ErrorOnCycle),
+            ..Default::default()
+        };
+
+        let skipped_paths = PathWalker::GitAware
+            .walk(temp_dir.path(), &PathsConfig::default())
+            .unwrap();
+
+        let followed_paths = PathWalker::GitAware.walk(temp_dir.path(), &following).unwrap();
+
+        let skipped_files = relative_files(temp_dir.path(), skipped_paths);
+        let followed_files = relative_files(temp_dir.path(), followed_paths);
+
+        assertor::assert_that!(skipped_files.contains(&"assets/icons/logo.svg".to_string())).is_true();
+        assertor::assert_that!(followed_files.contains(&"assets/icons/logo.svg".to_string())).is_true();
+        assertor::assert_that!(followed_files.contains(&"assets/icons/parent/icons/logo.svg".to_string())).is_false();
+        assertor::assert_that!(PathWalker::GitAware.walk(temp_dir.path(), &erroring).is_err()).is_true();
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn should_yield_paths_with_forward_slashes() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        create_project(temp_dir.path());
+        git::test_helpers::git(temp_dir.path(), &["init", "-q"]);
+
+        let paths = PathWalker::GitAware
+            .walk(temp_dir.path(), &PathsConfig::default())
+            .unwrap()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect_vec();
+
+        assertor::assert_that!(paths.iter().any(|path| path.ends_with("src/main.rs"))).is_true();
+        assertor::assert_that!(paths.iter().any(|path| path.contains('\\'))).is_false();
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn should_match_normalized_paths_against_codeowners_globs() {
+        let code_owners = crate::core::models::codeowners::CodeOwners::try_from(
+            "*.rs @ubiratansoares\nvendor/acme/** @dotanuki-labs/vendors",
+        )
+        .unwrap();
+        let resolver = crate::core::ownership::OwnershipResolver::new(&code_owners);
+
+        let normalized = crate::infra::paths::with_forward_slashes(PathBuf::from(r"vendor\acme\lib.rs"));
+        let owner = resolver.resolve(&normalized).map(|rule| rule.owners[0].to_string());
+
+        assertor::assert_that!(normalized).is_equal_to(PathBuf::from("vendor/acme/lib.rs"));
+        assertor::assert_that!(owner).is_equal_to(Some("@dotanuki-labs/vendors".to_string()));
+    }
+
+    #[test]
+    fn should_walk_paths_relative_to_project_root_from_anywhere() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        create_project(temp_dir.path());
+        git::test_helpers::git(temp_dir.path(), &["init", "-q"]);
+
+        // Tests run from the crate directory, which lives outside the project
+        let paths = PathWalker::GitAware
+            .walk_files(temp_dir.path(), &PathsConfig::default())
+            .unwrap()
+            .sorted()
+            .collect_vec();
+
+        let expected = ["README.md", "src/main.rs", "vendor/acme/lib.rs"]
+            .iter()
+            .map(PathBuf::from)
+            .collect_vec();
+
+        assertor::assert_that!(paths).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_walk_paths_ignored_by_git() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        create_project(temp_dir.path());
+
+        std::fs::create_dir_all(temp_dir.path().join("dist")).unwrap();
+        std::fs::write(temp_dir.path().join("dist/canopus.tar.gz"), "").unwrap();
+        std::fs::write(temp_dir.path().join(".gitignore"), "dist").unwrap();
+        git::test_helpers::git(temp_dir.path(), &["init", "-q"]);
+
+        let paths = PathWalker::GitAware
+            .walk_ignored(temp_dir.path(), &PathsConfig::default())
+            .unwrap();
+
+        assertor::assert_that!(relative_files(temp_dir.path(), paths))
+            .is_equal_to(vec!["dist/canopus.tar.gz".to_string()]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_walk_remote_project_files() {
+        let mock_server = MockServer::start();
+
+        let entries = vec![
+            ("src", "tree"),
+            ("src/main.rs", "blob"),
+            ("vendor", "tree"),
+            ("vendor/acme/lib.rs", "blob"),
+            ("README.md", "blob"),
+        ];
+
+        mock_server.mock(test_helpers::responds_with_project_tree(
+            "dotanuki-labs",
+            "canopus",
+            entries,
+        ));
+
+        let path_walker = PathWalker::GithubTree(GithubTreeWalker::new(create_github_client(mock_server.base_url())));
+
+        let settings = PathsConfig {
+            exclude: vec!["vendor".to_string()],
+            ..Default::default()
+        };
+
+        let files = path_walker
+            .walk_files(Path::new("dotanuki-labs/canopus@main"), &settings)
+            .unwrap()
+            .collect_vec();
+
+        let expected = vec![PathBuf::from("src/main.rs"), PathBuf::from("README.md")];
+        assertor::assert_that!(files).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_walk_listed_files_along_with_their_folders() {
+        let listed_paths = ListedPaths::default();
+        let origin = Path::new("portal-request-1");
+
+        let files = ["src/main.rs", "vendor/acme/lib.rs", "README.md"]
+            .iter()
+            .map(PathBuf::from)
+            .collect_vec();
+
+        listed_paths.list(origin, files);
+        let path_walker = PathWalker::Listed(listed_paths);
+
+        let settings = PathsConfig {
+            exclude: vec!["vendor".to_string()],
+            ..Default::default()
+        };
+
+        let paths = path_walker.walk(origin, &settings).unwrap().sorted().collect_vec();
+        let files = path_walker
+            .walk_files(origin, &settings)
+            .unwrap()
+            .sorted()
+            .collect_vec();
+
+        let expected_paths = ["README.md", "src", "src/main.rs"]
+            .iter()
+            .map(PathBuf::from)
+            .collect_vec();
+        let expected_files = ["README.md", "src/main.rs"].iter().map(PathBuf::from).collect_vec();
+
+        assertor::assert_that!(paths).is_equal_to(expected_paths);
+        assertor::assert_that!(files).is_equal_to(expected_files);
+    }
+
+    #[test]
+    fn should_reject_invalid_path_patterns() {
+        let settings = PathsConfig {
+            exclude: vec!["[z-a]".to_string()],
+            ..Default::default()
+        };
+
+        let walk = PathWalker::GitAware
+            .walk(Path::new("."), &settings)
+            .map(|paths| paths.count());
+
+        assertor::assert_that!(walk).is_err();
     }
 }
@@ -0,0 +1,142 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::config::PathsConfig;
+use crate::infra::git;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Bumped whenever the layout of cache files changes, invalidating older ones
+static CACHE_FORMAT: &str = "canopus-walked-paths-v2";
+
+static CACHE_DIR: &str = "canopus";
+
+/// Identifies which walk a cache file holds : where we walk from and with which settings
+fn walk_identity(origin: &Path, settings: &PathsConfig) -> anyhow::Result<String> {
+    let mut hasher = Sha256::new();
+    hasher.update(CACHE_FORMAT);
+    hasher.update(origin.canonicalize()?.to_string_lossy().as_bytes());
+    hasher.update(format!("{settings:?}"));
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Captures the working tree state, so that any commit, new file or deleted file invalidates the cache
+fn tree_state(origin: &Path) -> anyhow::Result<String> {
+    let mut hasher = Sha256::new();
+    hasher.update(git::head_commit(origin)?);
+    hasher.update(git::working_tree_status(origin)?);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn cache_file(origin: &Path, settings: &PathsConfig) -> anyhow::Result<PathBuf> {
+    let identity = walk_identity(origin, settings)?;
+    Ok(git::git_dir(origin)?
+        .join(CACHE_DIR)
+        .join(format!("walked-paths-{}", &identity[..16])))
+}
+
+fn read_cached_paths(cache_file: &Path, tree_state: &str) -> Option<Vec<PathBuf>> {
+    let contents = std::fs::read_to_string(cache_file).ok()?;
+    let (cached_state, paths) = contents.split_once('\n')?;
+
+    if cached_state != tree_state {
+        log::info!("Cached paths at {} are stale", cache_file.display());
+        return None;
+    }
+
+    let paths = paths
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .collect();
+
+    Some(paths)
+}
+
+fn write_cached_paths(cache_file: &Path, tree_state: &str, paths: &[PathBuf]) -> anyhow::Result<()> {
+    if let Some(cache_dir) = cache_file.parent() {
+        std::fs::create_dir_all(cache_dir)?;
+    }
+
+    let mut contents = format!("{tree_state}\n");
+
+    paths.iter().for_each(|path| {
+        contents.push_str(&path.to_string_lossy());
+        contents.push('\0');
+    });
+
+    std::fs::write(cache_file, contents)?;
+    Ok(())
+}
+
+/// Reuses paths walked by a previous run while the git working tree stays the same, walking
+/// and caching them otherwise. Projects outside git or without commits are always walked
+pub fn walk_with_cache(
+    origin: &Path,
+    settings: &PathsConfig,
+    walk: impl FnOnce() -> anyhow::Result<Box<dyn Iterator<Item = PathBuf>>>,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let cache = cache_file(origin, settings).and_then(|file| Ok((file, tree_state(origin)?)));
+
+    let (cache_file, tree_state) = match cache {
+        Ok(cache) => cache,
+        Err(reason) => {
+            log::info!("Walking without cache : {reason}");
+            return Ok(walk()?.collect());
+        },
+    };
+
+    if let Some(paths) = read_cached_paths(&cache_file, &tree_state) {
+        log::info!("Reusing {} paths cached at {}", paths.len(), cache_file.display());
+        return Ok(paths);
+    }
+
+    let paths = walk()?.collect::<Vec<_>>();
+
+    if let Err(reason) = write_cached_paths(&cache_file, &tree_state, &paths) {
+        log::warn!("Cannot cache walked paths at {} : {reason}", cache_file.display());
+    }
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::config::PathsConfig;
+    use crate::infra::git::test_helpers;
+    use crate::infra::paths::cache;
+    use assertor::EqualityAssertion;
+    use std::cell::Cell;
+    use std::path::PathBuf;
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_reuse_cached_paths_until_working_tree_changes() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let project_root = temp_dir.path();
+
+        std::fs::write(project_root.join("main.rs"), "fn main() {}").unwrap();
+        test_helpers::git(project_root, &["init", "-q"]);
+        test_helpers::git(project_root, &["add", "-A"]);
+        test_helpers::git(project_root, &["commit", "-q", "-m", "first"]);
+
+        let walks = Cell::new(0);
+        let settings = PathsConfig::default();
+
+        let walk = || {
+            walks.set(walks.get() + 1);
+            let paths = vec![PathBuf::from("main.rs")];
+            Ok(Box::new(paths.into_iter()) as Box<dyn Iterator<Item = PathBuf>>)
+        };
+
+        let first = cache::walk_with_cache(project_root, &settings, walk).unwrap();
+        let second = cache::walk_with_cache(project_root, &settings, walk).unwrap();
+
+        std::fs::write(project_root.join("lib.rs"), "fn lib() {}").unwrap();
+        cache::walk_with_cache(project_root, &settings, walk).unwrap();
+
+        assertor::assert_that!(first).is_equal_to(vec![PathBuf::from("main.rs")]);
+        assertor::assert_that!(second).is_equal_to(vec![PathBuf::from("main.rs")]);
+        assertor::assert_that!(walks.get()).is_equal_to(2);
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+// Progress is reported from deep within validation and Github API calls,
+// hence a process-wide sink avoids threading reporters through every layer
+static PROGRESS_FORMAT: OnceLock<ProgressFormat> = OnceLock::new();
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum ProgressFormat {
+    Ndjson,
+}
+
+/// Milestones of long runs, meant for wrapping tools showing progress to users
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+pub enum ProgressEvent {
+    WalkStarted {
+        project: PathBuf,
+    },
+    PathsFound {
+        project: PathBuf,
+        total: usize,
+    },
+    CheckFinished {
+        check: String,
+        issues: usize,
+        elapsed_ms: u128,
+    },
+    GithubPageFetched {
+        route: String,
+        page: u32,
+    },
+    RepositoryAudited {
+        repository: String,
+    },
+}
+
+impl ProgressEvent {
+    pub fn to_ndjson(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Enables progress reporting for the whole process, honoring only the first call
+pub fn enable(format: ProgressFormat) {
+    let _ = PROGRESS_FORMAT.set(format);
+}
+
+pub fn is_enabled() -> bool {
+    PROGRESS_FORMAT.get().is_some()
+}
+
+/// Writes progress events to stderr when enabled, keeping stdout for results
+pub fn report(event: ProgressEvent) {
+    if let Some(ProgressFormat::Ndjson) = PROGRESS_FORMAT.get() {
+        eprintln!("{}", event.to_ndjson());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::progress::ProgressEvent;
+    use assertor::EqualityAssertion;
+
+    #[test]
+    fn should_serialize_progress_events_as_json_lines() {
+        let event = ProgressEvent::GithubPageFetched {
+            route: "/orgs/dotanuki-labs/members".to_string(),
+            page: 2,
+        };
+
+        let expected = r#"{"event":"github-page-fetched","route":"/orgs/dotanuki-labs/members","page":2}"#;
+
+        assertor::assert_that!(event.to_ndjson()).is_equal_to(expected.to_string());
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use tokio::net::TcpListener;
+
+// Counters move from deep within audits and Github API calls, hence a process-wide registry
+// avoids threading it through every layer, as progress reporting does
+static METRICS: Mutex<AuditMetrics> = Mutex::new(AuditMetrics::new());
+
+static CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Progress and Github API usage of a long-running organization audit
+#[derive(Debug, PartialEq)]
+struct AuditMetrics {
+    repositories_listed: u64,
+    repositories_audited: BTreeMap<&'static str, u64>,
+    github_requests: u64,
+    github_retries: u64,
+}
+
+impl AuditMetrics {
+    const fn new() -> Self {
+        Self {
+            repositories_listed: 0,
+            repositories_audited: BTreeMap::new(),
+            github_requests: 0,
+            github_retries: 0,
+        }
+    }
+
+    /// Renders metrics following the Prometheus text exposition format
+    fn render(&self) -> String {
+        let mut rendered = String::new();
+
+        let mut describe = |name: &str, kind: &str, help: &str, samples: Vec<(String, u64)>| {
+            let _ = writeln!(rendered, "# HELP {name} {help}");
+            let _ = writeln!(rendered, "# TYPE {name} {kind}");
+
+            samples.into_iter().for_each(|(labels, value)| {
+                let _ = writeln!(rendered, "{name}{labels} {value}");
+            });
+        };
+
+        describe(
+            "canopus_audit_repositories",
+            "gauge",
+            "Repositories found within the audited organization",
+            vec![(String::new(), self.repositories_listed)],
+        );
+
+        describe(
+            "canopus_audit_repositories_audited_total",
+            "counter",
+            "Repositories audited so far, per audit status",
+            self.repositories_audited
+                .iter()
+                .map(|(status, audited)| (format!("{{status=\"{status}\"}}"), *audited))
+                .collect(),
+        );
+
+        describe(
+            "canopus_github_requests_total",
+            "counter",
+            "Requests sent to Github API, retries included",
+            vec![(String::new(), self.github_requests)],
+        );
+
+        describe(
+            "canopus_github_retries_total",
+            "counter",
+            "Requests to Github API retried after transient failures",
+            vec![(String::new(), self.github_retries)],
+        );
+
+        rendered
+    }
+}
+
+fn update(change: impl FnOnce(&mut AuditMetrics)) {
+    change(&mut METRICS.lock().unwrap());
+}
+
+pub fn repositories_listed(total: usize) {
+    update(|metrics| metrics.repositories_listed = total as u64);
+}
+
+pub fn repository_audited(status: &'static str) {
+    update(|metrics| *metrics.repositories_audited.entry(status).or_default() += 1);
+}
+
+pub fn github_request_sent() {
+    update(|metrics| metrics.github_requests += 1);
+}
+
+pub fn github_request_retried() {
+    update(|metrics| metrics.github_retries += 1);
+}
+
+async fn answer(request: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    let response = match (request.method(), request.uri().path()) {
+        (&Method::GET, "/metrics") => Response::builder()
+            .header(http::header::CONTENT_TYPE, CONTENT_TYPE)
+            .body(Full::from(METRICS.lock().unwrap().render())),
+        _ => Response::builder().status(StatusCode::NOT_FOUND).body(Full::default()),
+    };
+
+    Ok(response.expect("valid response"))
+}
+
+/// Exposes metrics at /metrics on the given address, answering scrapes in the background
+/// until the process exits. Returns the bound address, which matters when binding port 0
+pub async fn serve(address: SocketAddr) -> anyhow::Result<SocketAddr> {
+    let listener = TcpListener::bind(address)
+        .await
+        .with_context(|| format!("cannot expose metrics on {address}"))?;
+
+    let bound = listener.local_addr()?;
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                continue;
+            };
+
+            tokio::spawn(async move {
+                let connection = http1::Builder::new().serve_connection(TokioIo::new(stream), service_fn(answer));
+
+                if let Err(error) = connection.await {
+                    log::debug!("Failed to answer metrics scrape : {error}");
+                }
+            });
+        }
+    });
+
+    Ok(bound)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::prometheus;
+    use crate::infra::prometheus::AuditMetrics;
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+
+    #[test]
+    fn should_render_metrics_in_prometheus_format() {
+        let mut metrics = AuditMetrics::new();
+        metrics.repositories_listed = 3;
+        metrics.repositories_audited.insert("healthy", 1);
+        metrics.repositories_audited.insert("skipped", 1);
+        metrics.github_requests = 12;
+
+        let expected = indoc! {r#"
+            # HELP canopus_audit_repositories Repositories found within the audited organization
+            # TYPE canopus_audit_repositories gauge
+            canopus_audit_repositories 3
+            # HELP canopus_audit_repositories_audited_total Repositories audited so far, per audit status
+            # TYPE canopus_audit_repositories_audited_total counter
+            canopus_audit_repositories_audited_total{status="healthy"} 1
+            canopus_audit_repositories_audited_total{status="skipped"} 1
+            # HELP canopus_github_requests_total Requests sent to Github API, retries included
+            # TYPE canopus_github_requests_total counter
+            canopus_github_requests_total 12
+            # HELP canopus_github_retries_total Requests to Github API retried after transient failures
+            # TYPE canopus_github_retries_total counter
+            canopus_github_retries_total 0
+        "#};
+
+        assertor::assert_that!(metrics.render()).is_equal_to(expected.to_string());
+    }
+
+    #[tokio::test]
+    async fn should_expose_metrics_over_http() {
+        prometheus::repository_audited("issues-detected");
+
+        let address = prometheus::serve("127.0.0.1:0".parse().unwrap()).await.unwrap();
+        let mut stream = TcpStream::connect(address).await.unwrap();
+
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assertor::assert_that!(response).starts_with("HTTP/1.1 200 OK");
+        assertor::assert_that!(response).contains("content-type: text/plain; version=0.0.4");
+        assertor::assert_that!(response)
+            .contains(r#"canopus_audit_repositories_audited_total{status="issues-detected"}"#);
+    }
+}
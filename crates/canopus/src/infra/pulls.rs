@@ -0,0 +1,363 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::infra::progress;
+use crate::infra::progress::ProgressEvent;
+use crate::infra::remote::RemoteRepository;
+use crate::infra::retries::RetryPolicy;
+use anyhow::Context;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_json::json;
+use std::path::PathBuf;
+
+static PAGE_SIZE: usize = 100;
+
+#[derive(Deserialize)]
+struct SearchResults {
+    items: Vec<SearchedPullRequest>,
+}
+
+#[derive(Deserialize)]
+struct SearchedPullRequest {
+    number: u64,
+}
+
+#[derive(Deserialize)]
+struct PullRequestFile {
+    filename: String,
+}
+
+#[derive(Deserialize)]
+struct PullRequestDetails {
+    head: PullRequestHead,
+}
+
+#[derive(Deserialize)]
+struct PullRequestHead {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct IssueComment {
+    id: u64,
+    body: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CreatedResource {}
+
+/// Whether a pull request comment was posted for the first time or replaced a previous one
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CommentPosting {
+    Created,
+    Updated,
+}
+
+/// States of a commit status, as defined by Github API
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CommitState {
+    Success,
+    Failure,
+}
+
+impl CommitState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CommitState::Success => "success",
+            CommitState::Failure => "failure",
+        }
+    }
+}
+
+/// Searches pull requests opened on a repository since a given date, newest first,
+/// covering a single page of search results
+pub async fn recent_pull_requests(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+    since: NaiveDate,
+) -> anyhow::Result<Vec<u64>> {
+    let query = format!(
+        "repo:{}/{} is:pr created:>={}",
+        repository.owner,
+        repository.name,
+        since.format("%Y-%m-%d")
+    );
+
+    let page_size = PAGE_SIZE.to_string();
+    let parameters = [("q", query.as_str()), ("per_page", page_size.as_str())];
+
+    let found: SearchResults = RetryPolicy::default()
+        .get("/search/issues", || {
+            github_client.get("/search/issues", Some(&parameters))
+        })
+        .await
+        .with_context(|| {
+            format!(
+                "cannot search pull requests for {}/{}",
+                repository.owner, repository.name
+            )
+        })?;
+
+    Ok(found
+        .items
+        .into_iter()
+        .map(|pull_request| pull_request.number)
+        .collect())
+}
+
+/// Lists files changed by a pull request, walking all pages of results
+pub async fn changed_files(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+    number: u64,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let files_route = format!("/repos/{}/{}/pulls/{}/files", repository.owner, repository.name, number);
+    let retries = RetryPolicy::default();
+
+    let mut changed_files = Vec::new();
+    let mut page = 0;
+
+    loop {
+        page += 1;
+        let route = format!("{files_route}?per_page={PAGE_SIZE}&page={page}");
+
+        let files: Vec<PullRequestFile> = retries
+            .get(&route, || github_client.get(&route, None::<&()>))
+            .await
+            .with_context(|| format!("cannot list files changed by pull request #{number}"))?;
+
+        progress::report(ProgressEvent::GithubPageFetched {
+            route: files_route.clone(),
+            page,
+        });
+
+        let last_page = files.len() < PAGE_SIZE;
+        changed_files.extend(files.into_iter().map(|file| PathBuf::from(file.filename)));
+
+        if last_page {
+            break;
+        }
+    }
+
+    Ok(changed_files)
+}
+
+/// Comments on a pull request, replacing the body of the first comment containing the given marker if any
+pub async fn upsert_comment(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+    number: u64,
+    marker: &str,
+    body: &str,
+) -> anyhow::Result<CommentPosting> {
+    let comments_route = format!(
+        "/repos/{}/{}/issues/{}/comments",
+        repository.owner, repository.name, number
+    );
+
+    let retries = RetryPolicy::default();
+    let mut previous_comment = None;
+    let mut page = 0;
+
+    while previous_comment.is_none() {
+        page += 1;
+        let route = format!("{comments_route}?per_page={PAGE_SIZE}&page={page}");
+
+        let comments: Vec<IssueComment> = retries
+            .get(&route, || github_client.get(&route, None::<&()>))
+            .await
+            .with_context(|| format!("cannot list comments of pull request #{number}"))?;
+
+        let last_page = comments.len() < PAGE_SIZE;
+
+        previous_comment = comments
+            .into_iter()
+            .find(|comment| comment.body.as_deref().is_some_and(|body| body.contains(marker)))
+            .map(|comment| comment.id);
+
+        if last_page {
+            break;
+        }
+    }
+
+    let payload = json!({ "body": body });
+
+    match previous_comment {
+        Some(comment_id) => {
+            let route = format!(
+                "/repos/{}/{}/issues/comments/{}",
+                repository.owner, repository.name, comment_id
+            );
+
+            let _: CreatedResource = github_client
+                .patch(&route, Some(&payload))
+                .await
+                .with_context(|| format!("cannot update comment on pull request #{number}"))?;
+
+            Ok(CommentPosting::Updated)
+        },
+        None => {
+            let _: CreatedResource = github_client
+                .post(&comments_route, Some(&payload))
+                .await
+                .with_context(|| format!("cannot comment on pull request #{number}"))?;
+
+            Ok(CommentPosting::Created)
+        },
+    }
+}
+
+/// Resolves the SHA of the head commit of a pull request
+pub async fn head_sha(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+    number: u64,
+) -> anyhow::Result<String> {
+    let pull_route = format!("/repos/{}/{}/pulls/{}", repository.owner, repository.name, number);
+
+    let pull_request: PullRequestDetails = RetryPolicy::default()
+        .get(&pull_route, || github_client.get(&pull_route, None::<&()>))
+        .await
+        .with_context(|| format!("cannot read pull request #{number}"))?;
+
+    Ok(pull_request.head.sha)
+}
+
+/// Sets a commit status on the head commit of a pull request, under the canopus context
+pub async fn set_head_status(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+    number: u64,
+    state: CommitState,
+    description: &str,
+) -> anyhow::Result<()> {
+    let head_sha = head_sha(github_client, repository, number).await?;
+    let status_route = format!("/repos/{}/{}/statuses/{}", repository.owner, repository.name, head_sha);
+
+    let payload = json!({
+        "state": state.as_str(),
+        "description": description,
+        "context": "canopus",
+    });
+
+    let _: CreatedResource = github_client
+        .post(&status_route, Some(&payload))
+        .await
+        .with_context(|| format!("cannot set commit status on pull request #{number}"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::github::test_helpers::create_github_client;
+    use crate::infra::pulls;
+    use crate::infra::pulls::{CommentPosting, CommitState};
+    use crate::infra::remote::RemoteRepository;
+    use assertor::EqualityAssertion;
+    use chrono::NaiveDate;
+    use httpmock::MockServer;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn should_list_files_changed_by_recent_pull_requests() {
+        let mock_server = MockServer::start();
+
+        let search = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/search/issues")
+                .query_param("q", "repo:dotanuki-labs/canopus is:pr created:>=2025-06-01");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"total_count": 1, "items": [{"number": 42}]}"#);
+        });
+
+        let files = mock_server.mock(|when, then| {
+            when.method("GET").path("/repos/dotanuki-labs/canopus/pulls/42/files");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"[{"filename": "src/main.rs"}, {"filename": "README.md"}]"#);
+        });
+
+        let github_client = create_github_client(mock_server.base_url());
+        let repository = RemoteRepository::from_str("dotanuki-labs/canopus").unwrap();
+        let since = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+
+        let pull_requests = pulls::recent_pull_requests(&github_client, &repository, since)
+            .await
+            .unwrap();
+
+        let changed_files = pulls::changed_files(&github_client, &repository, 42).await.unwrap();
+
+        search.assert();
+        files.assert();
+        assertor::assert_that!(pull_requests).is_equal_to(vec![42]);
+        assertor::assert_that!(changed_files)
+            .is_equal_to(vec![PathBuf::from("src/main.rs"), PathBuf::from("README.md")]);
+    }
+
+    #[tokio::test]
+    async fn should_update_previous_comment_and_set_head_status() {
+        let mock_server = MockServer::start();
+
+        let comments = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/repos/dotanuki-labs/canopus/issues/7/comments");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"[{"id": 1, "body": "LGTM"}, {"id": 2, "body": "<!-- marker -->\nold results"}]"#);
+        });
+
+        let updated = mock_server.mock(|when, then| {
+            when.method("PATCH")
+                .path("/repos/dotanuki-labs/canopus/issues/comments/2")
+                .json_body(serde_json::json!({ "body": "<!-- marker -->\nnew results" }));
+
+            then.status(200).header("content-type", "application/json").body("{}");
+        });
+
+        let pull_request = mock_server.mock(|when, then| {
+            when.method("GET").path("/repos/dotanuki-labs/canopus/pulls/7");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"head": {"sha": "cafebabe"}}"#);
+        });
+
+        let status = mock_server.mock(|when, then| {
+            when.method("POST")
+                .path("/repos/dotanuki-labs/canopus/statuses/cafebabe")
+                .json_body_includes(r#"{"state": "failure", "context": "canopus"}"#);
+
+            then.status(201).header("content-type", "application/json").body("{}");
+        });
+
+        let github_client = create_github_client(mock_server.base_url());
+        let repository = RemoteRepository::from_str("dotanuki-labs/canopus").unwrap();
+
+        let posting = pulls::upsert_comment(
+            &github_client,
+            &repository,
+            7,
+            "<!-- marker -->",
+            "<!-- marker -->\nnew results",
+        )
+        .await
+        .unwrap();
+
+        pulls::set_head_status(&github_client, &repository, 7, CommitState::Failure, "2 issues found")
+            .await
+            .unwrap();
+
+        comments.assert();
+        updated.assert();
+        pull_request.assert();
+        status.assert();
+        assertor::assert_that!(posting).is_equal_to(CommentPosting::Updated);
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::versions::Version;
+use anyhow::{Context, anyhow, bail};
+use futures::TryStreamExt;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+static CANOPUS_REPOSITORY: &str = "dotanuki-labs/canopus";
+
+// Produced by xtasks alongside binaries, one `<artifact> : <sha256>` line per binary
+static CHECKSUMS_ASSET: &str = "checksums.txt";
+
+#[derive(Debug, Deserialize)]
+struct ReleaseAsset {
+    id: u64,
+    name: String,
+}
+
+/// A published canopus release, tagged after its version
+#[derive(Debug, Deserialize)]
+pub struct Release {
+    tag_name: String,
+    assets: Vec<ReleaseAsset>,
+}
+
+impl Release {
+    pub fn version(&self) -> anyhow::Result<Version> {
+        Version::from_str(&self.tag_name)
+    }
+
+    fn asset(&self, name: &str) -> anyhow::Result<&ReleaseAsset> {
+        self.assets
+            .iter()
+            .find(|asset| asset.name == name)
+            .ok_or_else(|| anyhow!("release {} does not ship {name}", self.tag_name))
+    }
+}
+
+/// Names the binary built by xtasks for a platform, following `canopus-<arch>-<target>`
+fn artifact_for(arch: &str, os: &str) -> anyhow::Result<String> {
+    let target = match os {
+        "linux" => "unknown-linux-musl",
+        "macos" => "apple-darwin",
+        _ => bail!("no canopus binaries are released for {os}"),
+    };
+
+    match arch {
+        "x86_64" | "aarch64" => Ok(format!("canopus-{arch}-{target}")),
+        _ => bail!("no canopus binaries are released for {arch}"),
+    }
+}
+
+pub fn platform_artifact() -> anyhow::Result<String> {
+    artifact_for(std::env::consts::ARCH, std::env::consts::OS)
+}
+
+fn expected_checksum(checksums: &str, artifact: &str) -> anyhow::Result<String> {
+    checksums
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim() == artifact)
+        .map(|(_, checksum)| checksum.trim().to_lowercase())
+        .ok_or_else(|| anyhow!("no published checksum for {artifact}"))
+}
+
+pub async fn latest_release(github_client: &octocrab::Octocrab) -> anyhow::Result<Release> {
+    let route = format!("/repos/{CANOPUS_REPOSITORY}/releases/latest");
+
+    github_client
+        .get(&route, None::<&()>)
+        .await
+        .context("cannot fetch the latest canopus release")
+}
+
+async fn download_asset(github_client: &octocrab::Octocrab, asset: &ReleaseAsset) -> anyhow::Result<Vec<u8>> {
+    let (owner, repository) = CANOPUS_REPOSITORY.split_once('/').expect("valid repository");
+
+    let contents = github_client
+        .repos(owner, repository)
+        .release_assets()
+        .stream(asset.id)
+        .await?
+        .try_fold(Vec::new(), |mut contents, chunk| async move {
+            contents.extend_from_slice(&chunk);
+            Ok(contents)
+        })
+        .await
+        .with_context(|| format!("cannot download {}", asset.name))?;
+
+    Ok(contents)
+}
+
+/// Downloads a released binary, refusing it unless it matches the checksum published with the release
+pub async fn download_verified(
+    github_client: &octocrab::Octocrab,
+    release: &Release,
+    artifact: &str,
+) -> anyhow::Result<Vec<u8>> {
+    let checksums = download_asset(github_client, release.asset(CHECKSUMS_ASSET)?).await?;
+    let expected = expected_checksum(&String::from_utf8_lossy(&checksums), artifact)?;
+
+    let binary = download_asset(github_client, release.asset(artifact)?).await?;
+    let actual = hex::encode(Sha256::digest(&binary));
+
+    if actual != expected {
+        bail!("checksum mismatch for {artifact} : expected {expected}, got {actual}");
+    }
+
+    Ok(binary)
+}
+
+/// Swaps the running binary for another one. The new binary is written next to the current one
+/// and renamed over it, so that an interrupted update never leaves a truncated binary behind
+pub fn replace_current_binary(binary: &[u8]) -> anyhow::Result<PathBuf> {
+    let current = std::env::current_exe()?.canonicalize()?;
+    let staged = current.with_extension("update");
+
+    std::fs::write(&staged, binary).with_context(|| format!("cannot write {}", staged.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    std::fs::rename(&staged, &current).with_context(|| format!("cannot replace {}", current.display()))?;
+    Ok(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::github::test_helpers::create_github_client;
+    use crate::infra::releases;
+    use assertor::{EqualityAssertion, ResultAssertion, StringAssertion};
+    use httpmock::MockServer;
+    use sha2::{Digest, Sha256};
+
+    fn mock_release(mock_server: &MockServer, binary: &[u8], checksum: &str) {
+        mock_server.mock(|when, then| {
+            when.method("GET").path("/repos/dotanuki-labs/canopus/releases/latest");
+
+            then.status(200).header("content-type", "application/json").body(
+                r#"{
+                    "tag_name": "0.9.0",
+                    "assets": [
+                        {"id": 1, "name": "canopus-x86_64-unknown-linux-musl"},
+                        {"id": 2, "name": "checksums.txt"}
+                    ]
+                }"#,
+            );
+        });
+
+        mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/repos/dotanuki-labs/canopus/releases/assets/1");
+            then.status(200).body(binary);
+        });
+
+        let checksums =
+            format!("canopus-aarch64-unknown-linux-musl : 00ff\ncanopus-x86_64-unknown-linux-musl : {checksum}");
+
+        mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/repos/dotanuki-labs/canopus/releases/assets/2");
+            then.status(200).body(checksums);
+        });
+    }
+
+    #[test]
+    fn should_name_artifacts_after_xtasks_targets() {
+        assertor::assert_that!(releases::artifact_for("aarch64", "macos").unwrap())
+            .is_equal_to("canopus-aarch64-apple-darwin".to_string());
+        assertor::assert_that!(releases::artifact_for("x86_64", "windows")).is_err();
+    }
+
+    #[tokio::test]
+    async fn should_download_binaries_matching_published_checksums() {
+        let mock_server = MockServer::start();
+        let binary = b"canopus binary".to_vec();
+        mock_release(&mock_server, &binary, &hex::encode(Sha256::digest(&binary)));
+
+        let github_client = create_github_client(mock_server.base_url());
+        let release = releases::latest_release(&github_client).await.unwrap();
+
+        let downloaded = releases::download_verified(&github_client, &release, "canopus-x86_64-unknown-linux-musl")
+            .await
+            .unwrap();
+
+        assertor::assert_that!(release.version().unwrap().to_string()).is_equal_to("0.9.0".to_string());
+        assertor::assert_that!(downloaded).is_equal_to(binary);
+    }
+
+    #[tokio::test]
+    async fn should_refuse_binaries_not_matching_published_checksums() {
+        let mock_server = MockServer::start();
+        mock_release(&mock_server, b"tampered binary", "cafebabe");
+
+        let github_client = create_github_client(mock_server.base_url());
+        let release = releases::latest_release(&github_client).await.unwrap();
+
+        let downloaded =
+            releases::download_verified(&github_client, &release, "canopus-x86_64-unknown-linux-musl").await;
+
+        assertor::assert_that!(downloaded.unwrap_err().to_string()).starts_with("checksum mismatch");
+    }
+}
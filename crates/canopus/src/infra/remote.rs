@@ -0,0 +1,693 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::canopus::errors::CanopusError;
+use crate::core::models::codeowners::CodeOwnersContext;
+use crate::core::models::config::{CONFIG_FILE_NAMES, CanopusConfig, ConfigFormat, ConfigLayer, GeneralConfig};
+use crate::infra::progress;
+use crate::infra::progress::ProgressEvent;
+use crate::infra::retries::RetryPolicy;
+use anyhow::{Context, anyhow, bail};
+use http::StatusCode;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+static DEFAULT_REMOTE_REFERENCE: &str = "HEAD";
+
+static CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+static CONFIG_DIRECTORY: &str = ".github";
+
+static EXTENDS_GITHUB_PREFIX: &str = "github:";
+
+/// A Github repository at a given git reference, written as `owner/repo@ref`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RemoteRepository {
+    pub owner: String,
+    pub name: String,
+    pub reference: String,
+}
+
+impl Display for RemoteRepository {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}@{}", self.owner, self.name, self.reference)
+    }
+}
+
+impl FromStr for RemoteRepository {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        let (slug, reference) = match value.split_once('@') {
+            Some((slug, reference)) => (slug, reference),
+            None => (value, DEFAULT_REMOTE_REFERENCE),
+        };
+
+        let Some((owner, name)) = slug.split_once('/') else {
+            bail!("expecting a remote repository as owner/repo@ref, got : {value}");
+        };
+
+        if owner.is_empty() || name.is_empty() || name.contains('/') || reference.is_empty() {
+            bail!("expecting a remote repository as owner/repo@ref, got : {value}");
+        }
+
+        Ok(Self {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            reference: reference.to_string(),
+        })
+    }
+}
+
+impl RemoteRepository {
+    /// The pseudo project path used to identify this repository when walking it
+    pub fn project_path(&self) -> PathBuf {
+        PathBuf::from(self.to_string())
+    }
+
+    /// Parses a git remote pointing to Github, either over SSH or HTTPS
+    pub fn from_remote_url(remote_url: &str) -> Option<Self> {
+        let (_, repository_path) = remote_url
+            .split_once("github.com:")
+            .or_else(|| remote_url.split_once("github.com/"))?;
+
+        let slug = repository_path.trim_end_matches('/').trim_end_matches(".git");
+        Self::from_str(slug).ok()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct RemoteTreeEntry {
+    pub path: PathBuf,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+impl RemoteTreeEntry {
+    pub fn is_file(&self) -> bool {
+        self.kind == "blob"
+    }
+}
+
+#[derive(Deserialize)]
+struct RemoteTree {
+    tree: Vec<RemoteTreeEntry>,
+    truncated: bool,
+}
+
+/// Lists project paths through the Github git-trees API, so repositories
+/// can be validated without a local checkout
+pub struct GithubTreeWalker {
+    github_client: octocrab::Octocrab,
+    fetched_trees: Mutex<HashMap<PathBuf, Vec<RemoteTreeEntry>>>,
+}
+
+impl GithubTreeWalker {
+    pub fn new(github_client: octocrab::Octocrab) -> Self {
+        Self {
+            github_client,
+            fetched_trees: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns all entries for the repository identified by a pseudo project path,
+    /// fetching them only once per repository
+    pub fn entries(&self, origin: &Path) -> anyhow::Result<Vec<RemoteTreeEntry>> {
+        if let Some(entries) = self.fetched_trees.lock().unwrap().get(origin) {
+            return Ok(entries.clone());
+        }
+
+        let repository = RemoteRepository::from_str(&origin.to_string_lossy())?;
+
+        // Walking is synchronous, hence we bridge with the async Github client here
+        let entries = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(fetch_tree(&self.github_client, &repository))
+        })?;
+
+        self.fetched_trees
+            .lock()
+            .unwrap()
+            .insert(origin.to_path_buf(), entries.clone());
+
+        Ok(entries)
+    }
+
+    /// Drops the tree fetched for a repository, once nothing walks it anymore
+    pub fn evict(&self, origin: &Path) {
+        self.fetched_trees.lock().unwrap().remove(origin);
+    }
+}
+
+async fn fetch_tree(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+) -> anyhow::Result<Vec<RemoteTreeEntry>> {
+    log::info!("Fetching project tree for {}", repository);
+
+    let route = format!(
+        "/repos/{}/{}/git/trees/{}",
+        repository.owner, repository.name, repository.reference
+    );
+
+    // Remote projects are fetched before their configuration is known, hence default retries
+    let fetched: Result<RemoteTree, _> = RetryPolicy::default()
+        .get(&route, || github_client.get(&route, Some(&[("recursive", "1")])))
+        .await;
+
+    let remote_tree = fetched.with_context(|| format!("cannot fetch project tree for {repository}"))?;
+
+    // Missing paths would show up as dangling patterns and unowned files, hence nothing to report on
+    if remote_tree.truncated {
+        return Err(CanopusError::user_error(anyhow!(
+            "project tree for {repository} is too large and has been truncated by Github, validate a local checkout instead"
+        )));
+    }
+
+    Ok(remote_tree.tree)
+}
+
+async fn fetch_file(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+    file_path: &str,
+) -> anyhow::Result<Option<String>> {
+    let route = format!("/repos/{}/{}/contents/{}", repository.owner, repository.name, file_path);
+
+    let fetched = RetryPolicy::default()
+        .get(&route, || async {
+            github_client
+                .repos(&repository.owner, &repository.name)
+                .get_content()
+                .path(file_path)
+                .r#ref(&repository.reference)
+                .send()
+                .await
+        })
+        .await;
+
+    match fetched {
+        Ok(mut contents) => Ok(contents.take_items().first().and_then(|item| item.decoded_content())),
+        Err(octocrab::Error::GitHub { source, .. }) if source.status_code == StatusCode::NOT_FOUND => Ok(None),
+        Err(incoming) => Err(incoming).with_context(|| format!("cannot fetch {file_path} from {repository}")),
+    }
+}
+
+/// Lists all active repositories within a Github organization, targeting their default branches
+pub async fn list_repositories(
+    github_client: &octocrab::Octocrab,
+    organization: &str,
+) -> anyhow::Result<Vec<RemoteRepository>> {
+    let retries = RetryPolicy::default();
+
+    let fetched = retries
+        .get(&format!("/orgs/{organization}/repos"), || async {
+            github_client.orgs(organization).list_repos().per_page(100).send().await
+        })
+        .await;
+
+    let mut current_page = fetched.with_context(|| format!("cannot list repositories for {organization}"))?;
+    let mut listed_repositories = Vec::new();
+    let mut page_number = 1;
+
+    // We walk pages ourselves in order to report progress for large organizations
+    loop {
+        progress::report(ProgressEvent::GithubPageFetched {
+            route: format!("/orgs/{organization}/repos"),
+            page: page_number,
+        });
+
+        listed_repositories.extend(current_page.take_items());
+
+        let next_page = retries
+            .get(&format!("/orgs/{organization}/repos?page={}", page_number + 1), || {
+                github_client.get_page(&current_page.next)
+            })
+            .await?;
+
+        match next_page {
+            Some(next_page) => {
+                current_page = next_page;
+                page_number += 1;
+            },
+            None => break,
+        }
+    }
+
+    let repositories = listed_repositories
+        .into_iter()
+        .filter(|repository| !repository.archived.unwrap_or(false))
+        .map(|repository| RemoteRepository {
+            owner: organization.to_string(),
+            name: repository.name,
+            reference: repository
+                .default_branch
+                .unwrap_or(DEFAULT_REMOTE_REFERENCE.to_string()),
+        })
+        .collect();
+
+    Ok(repositories)
+}
+
+/// Fetches the CODEOWNERS file and the canopus configuration of a remote repository,
+/// honoring the same conventional locations used for local projects
+pub async fn fetch_project(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+) -> anyhow::Result<(CodeOwnersContext, CanopusConfig)> {
+    let codeowners_context = fetch_codeowners(github_client, repository).await?;
+    let config_layer = fetch_config(github_client, repository).await?;
+    let canopus_config = resolve_config(github_client, config_layer, false).await?;
+    Ok((codeowners_context, canopus_config))
+}
+
+/// Fetches a remote repository as [`fetch_project`] does, falling back to the default configuration
+/// for the given organization when the repository has no canopus configuration of its own
+pub async fn fetch_project_or_default(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+    organization: &str,
+) -> anyhow::Result<(CodeOwnersContext, CanopusConfig)> {
+    let codeowners_context = fetch_codeowners(github_client, repository).await?;
+
+    let canopus_config = match find_config(github_client, repository).await? {
+        Some(config_layer) => resolve_config(github_client, config_layer, false).await?,
+        None => {
+            log::info!("No canopus configuration found in {repository}, using defaults");
+
+            CanopusConfig {
+                general: GeneralConfig {
+                    github_organization: organization.to_string(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        },
+    };
+
+    Ok((codeowners_context, canopus_config))
+}
+
+async fn fetch_codeowners(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+) -> anyhow::Result<CodeOwnersContext> {
+    let mut codeowners_files = Vec::new();
+
+    for location in CODEOWNERS_LOCATIONS {
+        if let Some(contents) = fetch_file(github_client, repository, location).await? {
+            codeowners_files.push((location, contents));
+        }
+    }
+
+    if codeowners_files.is_empty() {
+        return Err(CanopusError::user_error(anyhow!(
+            "no CODEOWNERS definition found in {repository}"
+        )));
+    }
+
+    if codeowners_files.len() > 1 {
+        return Err(CanopusError::user_error(anyhow!(
+            "found multiple CODEOWNERS definitions in {repository}"
+        )));
+    }
+
+    let (location, contents) = codeowners_files.remove(0);
+
+    log::info!("Codeowners config found at : {}/{}", repository, location);
+
+    Ok(CodeOwnersContext {
+        project_path: repository.project_path(),
+        codeowners_path: PathBuf::from(location),
+        contents,
+    })
+}
+
+/// Resolves a configuration, merging it onto the remote configuration it extends, if any.
+/// External checks declared by the extended configuration are kept only when trusted
+pub async fn resolve_config(
+    github_client: &octocrab::Octocrab,
+    config_layer: ConfigLayer,
+    trust_extended_checks: bool,
+) -> anyhow::Result<CanopusConfig> {
+    let Some(extends) = config_layer.extends() else {
+        return config_layer.resolve().map_err(CanopusError::user_error);
+    };
+
+    let Some(repository) = extends.strip_prefix(EXTENDS_GITHUB_PREFIX) else {
+        return Err(CanopusError::user_error(anyhow!(
+            "cannot extend configuration from '{extends}', expecting github:<owner>/<repo>[@ref]"
+        )));
+    };
+
+    let repository = RemoteRepository::from_str(repository).map_err(CanopusError::user_error)?;
+    let mut base_layer = fetch_config(github_client, &repository).await?;
+
+    // Chains of remote configurations are hard to reason about, hence we support a single level
+    if base_layer.extends().is_some() {
+        return Err(CanopusError::user_error(anyhow!(
+            "configuration extended from {repository} cannot extend another configuration"
+        )));
+    }
+
+    // External checks run commands on this machine, hence we don't take them from other repositories blindly
+    if !trust_extended_checks && base_layer.discard_external_checks() {
+        log::warn!("Ignoring external checks extended from {repository}, trust them with --trust-extended-checks");
+    }
+
+    log::info!("Extending configuration from : {}", repository);
+    config_layer
+        .merged_onto(base_layer)
+        .resolve()
+        .map_err(CanopusError::user_error)
+}
+
+async fn fetch_config(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+) -> anyhow::Result<ConfigLayer> {
+    let Some(config_layer) = find_config(github_client, repository).await? else {
+        return Err(CanopusError::user_error(anyhow!(
+            "expecting configuration at : {}/{}/canopus.toml",
+            repository,
+            CONFIG_DIRECTORY
+        )));
+    };
+
+    Ok(config_layer)
+}
+
+async fn find_config(
+    github_client: &octocrab::Octocrab,
+    repository: &RemoteRepository,
+) -> anyhow::Result<Option<ConfigLayer>> {
+    let mut config_files = Vec::new();
+
+    for file_name in CONFIG_FILE_NAMES {
+        let config_location = format!("{CONFIG_DIRECTORY}/{file_name}");
+
+        if let Some(contents) = fetch_file(github_client, repository, &config_location).await? {
+            config_files.push((config_location, contents));
+        }
+    }
+
+    if config_files.is_empty() {
+        return Ok(None);
+    }
+
+    if config_files.len() > 1 {
+        return Err(CanopusError::user_error(anyhow!(
+            "found multiple canopus configuration files in {repository}"
+        )));
+    }
+
+    let (config_location, config_contents) = config_files.remove(0);
+    let config_format = ConfigFormat::from_path(Path::new(&config_location)).map_err(CanopusError::user_error)?;
+
+    let config_layer = ConfigLayer::parse(&config_contents, config_format)
+        .map_err(CanopusError::user_error)
+        .with_context(|| format!("cannot parse {config_location} from {repository}"))?;
+
+    Ok(Some(config_layer))
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use base64::Engine;
+    use base64::prelude::BASE64_STANDARD;
+    use httpmock::{Then, When};
+
+    pub fn responds_with_repositories(organization: &str, repositories: Vec<(&str, bool)>) -> impl FnOnce(When, Then) {
+        let listed = repositories
+            .into_iter()
+            .enumerate()
+            .map(|(id, (name, archived))| {
+                format!(
+                    r#"{{ "id": {id}, "name": "{name}", "url": "https://api.github.com/repos/{organization}/{name}", "archived": {archived}, "default_branch": "main" }}"#
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let route = format!("/orgs/{organization}/repos");
+
+        move |when, then| {
+            when.method("GET").path(route);
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(format!("[{listed}]"));
+        }
+    }
+
+    pub fn responds_with_file(owner: &str, name: &str, path: &str, contents: &str) -> impl FnOnce(When, Then) {
+        let encoded = BASE64_STANDARD.encode(contents);
+        let route = format!("/repos/{owner}/{name}/contents/{path}");
+        let url = format!("https://api.github.com{route}");
+
+        move |when, then| {
+            when.method("GET").path(route);
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(format!(
+                    r#"{{ "name": "{path}", "path": "{path}", "sha": "abc", "size": {}, "url": "{url}", "type": "file", "encoding": "base64", "content": "{encoded}", "_links": {{ "self": "{url}" }} }}"#,
+                    contents.len()
+                ));
+        }
+    }
+
+    pub fn responds_with_project_tree(owner: &str, name: &str, paths: Vec<(&str, &str)>) -> impl FnOnce(When, Then) {
+        let entries = paths
+            .into_iter()
+            .map(|(path, kind)| format!(r#"{{ "path": "{path}", "mode": "100644", "type": "{kind}", "sha": "abc" }}"#))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let route = format!("/repos/{owner}/{name}/git/trees/main");
+
+        move |when, then| {
+            when.method("GET").path(route).query_param("recursive", "1");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(format!(
+                    r#"{{ "sha": "abc", "truncated": false, "tree": [{entries}] }}"#
+                ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::config::{ConfigFormat, ConfigLayer};
+    use crate::infra::github::test_helpers::create_github_client;
+    use crate::infra::remote;
+    use crate::infra::remote::{GithubTreeWalker, RemoteRepository, test_helpers};
+    use assertor::{BooleanAssertion, EqualityAssertion, ResultAssertion};
+    use httpmock::MockServer;
+    use indoc::indoc;
+    use itertools::Itertools;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_parse_remote_repository() {
+        let repository = RemoteRepository::from_str("dotanuki-labs/canopus@main").unwrap();
+
+        let expected = RemoteRepository {
+            owner: "dotanuki-labs".to_string(),
+            name: "canopus".to_string(),
+            reference: "main".to_string(),
+        };
+
+        assertor::assert_that!(repository).is_equal_to(expected);
+        assertor::assert_that!(RemoteRepository::from_str("dotanuki-labs/canopus").unwrap().reference)
+            .is_equal_to("HEAD".to_string());
+        assertor::assert_that!(RemoteRepository::from_str("canopus@main")).is_err();
+
+        let from_ssh = RemoteRepository::from_remote_url("git@github.com:dotanuki-labs/canopus.git").unwrap();
+        assertor::assert_that!(from_ssh.name).is_equal_to("canopus".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_list_active_repositories() {
+        let mock_server = MockServer::start();
+
+        mock_server.mock(test_helpers::responds_with_repositories(
+            "dotanuki-labs",
+            vec![("canopus", false), ("legacy", true)],
+        ));
+
+        let github_client = create_github_client(mock_server.base_url());
+        let repositories = remote::list_repositories(&github_client, "dotanuki-labs")
+            .await
+            .unwrap();
+
+        let listed = repositories
+            .iter()
+            .map(|repository| repository.to_string())
+            .collect_vec();
+
+        assertor::assert_that!(listed).is_equal_to(vec!["dotanuki-labs/canopus@main".to_string()]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fetch_project_tree_once_until_evicted() {
+        let mock_server = MockServer::start();
+
+        let project_tree = mock_server.mock(test_helpers::responds_with_project_tree(
+            "dotanuki-labs",
+            "canopus",
+            vec![("src", "tree"), ("src/main.rs", "blob")],
+        ));
+
+        let walker = GithubTreeWalker::new(create_github_client(mock_server.base_url()));
+        let origin = PathBuf::from("dotanuki-labs/canopus@main");
+
+        let first = walker.entries(&origin).unwrap();
+        let second = walker.entries(&origin).unwrap();
+
+        project_tree.assert_calls(1);
+
+        let paths = first.into_iter().map(|entry| entry.path).collect_vec();
+        let expected = vec![PathBuf::from("src"), PathBuf::from("src/main.rs")];
+
+        assertor::assert_that!(paths).is_equal_to(expected);
+        assertor::assert_that!(second.len()).is_equal_to(2);
+
+        walker.evict(&origin);
+        walker.entries(&origin).unwrap();
+        project_tree.assert_calls(2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_reject_truncated_project_tree() {
+        let mock_server = MockServer::start();
+
+        mock_server.mock(|when, then| {
+            when.method("GET").path("/repos/dotanuki-labs/canopus/git/trees/main");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(r#"{ "sha": "abc", "truncated": true, "tree": [] }"#);
+        });
+
+        let walker = GithubTreeWalker::new(create_github_client(mock_server.base_url()));
+        let entries = walker.entries(&PathBuf::from("dotanuki-labs/canopus@main"));
+
+        assertor::assert_that!(entries).is_err();
+    }
+
+    #[tokio::test]
+    async fn should_fall_back_to_default_config_when_auditing() {
+        let mock_server = MockServer::start();
+
+        mock_server.mock(test_helpers::responds_with_file(
+            "dotanuki-labs",
+            "canopus",
+            ".github/CODEOWNERS",
+            "*.rs @dotanuki-labs/rustaceans\n",
+        ));
+
+        let github_client = create_github_client(mock_server.base_url());
+        let with_codeowners = RemoteRepository::from_str("dotanuki-labs/canopus@main").unwrap();
+        let without_codeowners = RemoteRepository::from_str("dotanuki-labs/legacy@main").unwrap();
+
+        let (context, config) = remote::fetch_project_or_default(&github_client, &with_codeowners, "dotanuki-labs")
+            .await
+            .unwrap();
+
+        let skipped = remote::fetch_project_or_default(&github_client, &without_codeowners, "dotanuki-labs").await;
+
+        assertor::assert_that!(context.codeowners_path).is_equal_to(PathBuf::from(".github/CODEOWNERS"));
+        assertor::assert_that!(config.general.github_organization).is_equal_to("dotanuki-labs".to_string());
+        assertor::assert_that!(skipped).is_err();
+    }
+
+    #[tokio::test]
+    async fn should_resolve_config_extending_remote_one() {
+        let mock_server = MockServer::start();
+
+        let base_config = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+            forbid-email-owners = true
+            enforce-one-owner-per-line = true
+        "#};
+
+        let local_config = indoc! {r#"
+            [general]
+            extends = "github:dotanuki-labs/canopus-config@main"
+
+            [ownership]
+            enforce-one-owner-per-line = false
+        "#};
+
+        mock_server.mock(test_helpers::responds_with_file(
+            "dotanuki-labs",
+            "canopus-config",
+            ".github/canopus.toml",
+            base_config,
+        ));
+
+        let github_client = create_github_client(mock_server.base_url());
+        let config_layer = ConfigLayer::parse(local_config, ConfigFormat::Toml).unwrap();
+
+        let config = remote::resolve_config(&github_client, config_layer, false)
+            .await
+            .unwrap();
+
+        assertor::assert_that!(config.general.github_organization).is_equal_to("dotanuki-labs".to_string());
+        assertor::assert_that!(config.ownership.forbid_email_owners).is_equal_to(Some(true));
+        assertor::assert_that!(config.ownership.enforce_one_owner_per_line).is_equal_to(Some(false));
+    }
+
+    #[tokio::test]
+    async fn should_drop_extended_external_checks_unless_trusted() {
+        let mock_server = MockServer::start();
+
+        let base_config = indoc! {r#"
+            [general]
+            github-organization = "dotanuki-labs"
+
+            [ownership]
+
+            [[checks.external]]
+            name = "base-policy"
+            command = ["./check-owners.sh"]
+        "#};
+
+        let local_config = indoc! {r#"
+            [general]
+            extends = "github:dotanuki-labs/canopus-config@main"
+
+            [ownership]
+        "#};
+
+        mock_server.mock(test_helpers::responds_with_file(
+            "dotanuki-labs",
+            "canopus-config",
+            ".github/canopus.toml",
+            base_config,
+        ));
+
+        let github_client = create_github_client(mock_server.base_url());
+        let config_layer = ConfigLayer::parse(local_config, ConfigFormat::Toml).unwrap();
+
+        let untrusted = remote::resolve_config(&github_client, config_layer.clone(), false)
+            .await
+            .unwrap();
+        let trusted = remote::resolve_config(&github_client, config_layer, true)
+            .await
+            .unwrap();
+
+        assertor::assert_that!(untrusted.checks.external.is_empty()).is_true();
+        assertor::assert_that!(trusted.checks.external[0].name.clone()).is_equal_to("base-policy".to_string());
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::config::{
+    DEFAULT_VALUE_MAX_RETRIES, DEFAULT_VALUE_RETRY_BASE_DELAY_MILLIS, DEFAULT_VALUE_RETRY_ON_STATUS, RetriesConfig,
+};
+use crate::infra::prometheus;
+use itertools::Itertools;
+use std::time::{Duration, Instant};
+
+/// How Github API requests failing for transient reasons are retried, with exponential backoff
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: usize,
+    pub base_delay: Duration,
+    pub retry_on_status: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::from(&RetriesConfig::default())
+    }
+}
+
+impl From<&RetriesConfig> for RetryPolicy {
+    fn from(config: &RetriesConfig) -> Self {
+        Self {
+            max_retries: config.max_retries.unwrap_or(DEFAULT_VALUE_MAX_RETRIES),
+            base_delay: Duration::from_millis(
+                config
+                    .base_delay_millis
+                    .unwrap_or(DEFAULT_VALUE_RETRY_BASE_DELAY_MILLIS),
+            ),
+            retry_on_status: config
+                .retry_on_status
+                .clone()
+                .unwrap_or(DEFAULT_VALUE_RETRY_ON_STATUS.to_vec()),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Sends a GET request to the given route, retrying it while it fails for transient reasons
+    pub async fn get<T, Request, Sent>(&self, route: &str, request: Request) -> Result<T, octocrab::Error>
+    where
+        Request: Fn() -> Sent,
+        Sent: Future<Output = Result<T, octocrab::Error>>,
+    {
+        let mut retries = 0;
+
+        loop {
+            let started = Instant::now();
+            prometheus::github_request_sent();
+            let sent = request().await;
+            log::debug!("GET {} finished in {}ms", route, started.elapsed().as_millis());
+
+            let reason = match &sent {
+                Err(error) if retries < self.max_retries => self.transient_failure(error),
+                _ => None,
+            };
+
+            let Some(reason) = reason else {
+                return sent;
+            };
+
+            let delay = self.delay_before(retries);
+            retries += 1;
+            prometheus::github_request_retried();
+
+            log::debug!(
+                "Retrying GET {} after {} ({} of {} retries, waiting {}ms, retrying on status {})",
+                route,
+                reason,
+                retries,
+                self.max_retries,
+                delay.as_millis(),
+                self.retry_on_status.iter().join(", ")
+            );
+
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    fn transient_failure(&self, error: &octocrab::Error) -> Option<String> {
+        match error {
+            octocrab::Error::GitHub { source, .. } => {
+                let status = source.status_code.as_u16();
+                self.retry_on_status
+                    .contains(&status)
+                    .then(|| format!("status {status}"))
+            },
+            octocrab::Error::Hyper { .. } | octocrab::Error::Service { .. } => Some("network failure".to_string()),
+            _ => None,
+        }
+    }
+
+    fn delay_before(&self, retry: usize) -> Duration {
+        let factor = 2u32.saturating_pow(retry.try_into().unwrap_or(u32::MAX));
+        self.base_delay.saturating_mul(factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::config::RetriesConfig;
+    use crate::infra::retries::RetryPolicy;
+    use assertor::{EqualityAssertion, ResultAssertion};
+    use std::backtrace::Backtrace;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    fn network_failure() -> octocrab::Error {
+        octocrab::Error::Service {
+            source: "connection reset by peer".into(),
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    fn malformed_response() -> octocrab::Error {
+        octocrab::Error::Other {
+            source: "unexpected response body".into(),
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    fn patient_policy(max_retries: usize) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            base_delay: Duration::from_millis(1),
+            retry_on_status: vec![502, 503],
+        }
+    }
+
+    #[tokio::test]
+    async fn should_retry_requests_until_github_recovers() {
+        let attempts = AtomicUsize::new(0);
+
+        let sent = patient_policy(5)
+            .get("/users/ubiratansoares", || async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 | 1 => Err(network_failure()),
+                    _ => Ok("ubiratansoares"),
+                }
+            })
+            .await;
+
+        assertor::assert_that!(sent.unwrap()).is_equal_to("ubiratansoares");
+        assertor::assert_that!(attempts.load(Ordering::SeqCst)).is_equal_to(3);
+    }
+
+    #[tokio::test]
+    async fn should_give_up_after_max_retries() {
+        let attempts = AtomicUsize::new(0);
+
+        let sent: Result<(), _> = patient_policy(2)
+            .get("/users/ubiratansoares", || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(network_failure())
+            })
+            .await;
+
+        assertor::assert_that!(sent).is_err();
+        assertor::assert_that!(attempts.load(Ordering::SeqCst)).is_equal_to(3);
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_non_transient_failures() {
+        let attempts = AtomicUsize::new(0);
+
+        let sent: Result<(), _> = patient_policy(2)
+            .get("/users/ubiratansoares", || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(malformed_response())
+            })
+            .await;
+
+        assertor::assert_that!(sent).is_err();
+        assertor::assert_that!(attempts.load(Ordering::SeqCst)).is_equal_to(1);
+    }
+
+    #[test]
+    fn should_build_policy_from_configuration() {
+        let config = RetriesConfig {
+            max_retries: Some(10),
+            base_delay_millis: Some(2000),
+            retry_on_status: None,
+        };
+
+        let expected = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_secs(2),
+            retry_on_status: vec![429, 500, 502, 503, 504],
+        };
+
+        assertor::assert_that!(RetryPolicy::from(&config)).is_equal_to(expected);
+        assertor::assert_that!(patient_policy(3).delay_before(2)).is_equal_to(Duration::from_millis(4));
+    }
+}
@@ -0,0 +1,387 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::config::{CanopusConfig, ConfigFormat};
+use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
+use crate::core::models::{ConsistencyIssue, OwnerRole};
+use crate::infra::github::{ApiCapability, BranchProtection, CheckGithubConsistency, TeamReviewAssignment};
+use crate::infra::retries::RetryPolicy;
+use anyhow::{Context, bail};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+
+pub static ROSTER_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RosterDocument {
+    roster: RosterHeader,
+
+    #[serde(default)]
+    teams: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RosterHeader {
+    version: u32,
+    organization: String,
+
+    #[serde(default)]
+    members: Vec<String>,
+}
+
+/// Members and teams of a Github organization, as committed in a roster file. Handles are
+/// case-insensitive, hence they are kept lowercased in order to match them in any case
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Roster {
+    pub organization: String,
+    pub members: BTreeSet<String>,
+    pub teams: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Roster {
+    pub fn parse(contents: &str, format: ConfigFormat) -> anyhow::Result<Self> {
+        let document: RosterDocument = format.parse_table(contents)?.try_into()?;
+
+        if document.roster.version != ROSTER_FORMAT_VERSION {
+            bail!(
+                "unsupported roster version {} (expecting {})",
+                document.roster.version,
+                ROSTER_FORMAT_VERSION
+            );
+        }
+
+        let lowercased = |handles: Vec<String>| {
+            handles
+                .into_iter()
+                .map(|handle| handle.trim_start_matches('@').to_lowercase())
+                .collect::<BTreeSet<_>>()
+        };
+
+        Ok(Self {
+            organization: document.roster.organization,
+            members: lowercased(document.roster.members),
+            teams: document
+                .teams
+                .into_iter()
+                .map(|(team, members)| (team.to_lowercase(), lowercased(members)))
+                .collect(),
+        })
+    }
+
+    /// Loads a roster file, written in any of the formats supported for configuration files
+    pub fn load(roster_file: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(roster_file)
+            .with_context(|| format!("cannot read roster file {}", roster_file.display()))?;
+
+        Self::parse(&contents, ConfigFormat::from_path(roster_file)?)
+            .with_context(|| format!("invalid roster file {}", roster_file.display()))
+    }
+
+    /// Renders this roster in a stable order, hence syncing an unchanged organization produces no diff
+    pub fn render(&self, format: ConfigFormat) -> anyhow::Result<String> {
+        let document = RosterDocument {
+            roster: RosterHeader {
+                version: ROSTER_FORMAT_VERSION,
+                organization: self.organization.clone(),
+                members: self.members.iter().cloned().collect(),
+            },
+            teams: self
+                .teams
+                .iter()
+                .map(|(team, members)| (team.clone(), members.iter().cloned().collect()))
+                .collect(),
+        };
+
+        let rendered = match format {
+            ConfigFormat::Toml => toml::to_string(&document)?,
+            ConfigFormat::Json => format!("{}\n", serde_json::to_string_pretty(&document)?),
+            ConfigFormat::Yaml => render_yaml(&document),
+        };
+
+        Ok(rendered)
+    }
+
+    fn covers(&self, organization: &str) -> bool {
+        self.organization.eq_ignore_ascii_case(organization)
+    }
+}
+
+// Configuration files are parsed with a YAML subset, which this rendering sticks to
+fn render_yaml(document: &RosterDocument) -> String {
+    let mut lines = vec![
+        format!(
+            "# Members and teams of {}, as synced by canopus roster sync",
+            document.roster.organization
+        ),
+        "roster:".to_string(),
+        format!("  version: {}", document.roster.version),
+        format!("  organization: {}", yaml_scalar(&document.roster.organization)),
+    ];
+
+    lines.extend(yaml_list("  ", "members", &document.roster.members));
+
+    // Organizations without teams leave this section out, since the subset has no empty mappings
+    if !document.teams.is_empty() {
+        lines.push("teams:".to_string());
+
+        document
+            .teams
+            .iter()
+            .for_each(|(team, members)| lines.extend(yaml_list("  ", team, members)));
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn yaml_list(indentation: &str, key: &str, items: &[String]) -> Vec<String> {
+    if items.is_empty() {
+        return vec![format!("{indentation}{}: []", yaml_scalar(key))];
+    }
+
+    std::iter::once(format!("{indentation}{}:", yaml_scalar(key)))
+        .chain(
+            items
+                .iter()
+                .map(|item| format!("{indentation}  - {}", yaml_scalar(item))),
+        )
+        .collect()
+}
+
+// Handles looking like numbers or booleans must stay strings
+fn yaml_scalar(raw: &str) -> String {
+    let ambiguous = raw.parse::<f64>().is_ok() || matches!(raw, "true" | "false" | "null" | "~");
+
+    if ambiguous {
+        format!("\"{raw}\"")
+    } else {
+        raw.to_string()
+    }
+}
+
+/// Verifies users and teams against a roster, leaving checks the roster knows nothing about to another checker.
+/// Owners from organizations the roster does not cover cannot be verified
+pub struct RosterChecker<'a> {
+    roster: Roster,
+    checker: &'a dyn CheckGithubConsistency,
+}
+
+impl<'a> RosterChecker<'a> {
+    pub fn new(roster: Roster, checker: &'a dyn CheckGithubConsistency) -> Self {
+        Self { roster, checker }
+    }
+}
+
+#[async_trait]
+impl CheckGithubConsistency for RosterChecker<'_> {
+    async fn github_identity(
+        &self,
+        organization: &str,
+        identity: &GithubIdentityHandle,
+        _: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        if !self.roster.covers(organization) {
+            return Err(ConsistencyIssue::CannotVerifyUser(identity.clone()));
+        }
+
+        if self.roster.members.contains(&identity.inner().to_lowercase()) {
+            return Ok(());
+        }
+
+        Err(ConsistencyIssue::OutsiderUser(identity.clone()))
+    }
+
+    async fn github_team(
+        &self,
+        organization: &str,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        // Simple offline guard
+        if !handle.organization.inner().eq_ignore_ascii_case(organization) {
+            return Err(ConsistencyIssue::TeamDoesNotMatchOrganization(handle.clone()));
+        };
+
+        self.team_members(handle, retries).await.map(|_| ())
+    }
+
+    async fn team_members(
+        &self,
+        handle: &GithubTeamHandle,
+        _: &RetryPolicy,
+    ) -> Result<HashSet<GithubIdentityHandle>, ConsistencyIssue> {
+        if !self.roster.covers(handle.organization.inner()) {
+            return Err(ConsistencyIssue::CannotVerifyTeam(handle.clone()));
+        }
+
+        let Some(members) = self.roster.teams.get(&handle.name.to_lowercase()) else {
+            return Err(ConsistencyIssue::TeamDoesNotExist(handle.clone()));
+        };
+
+        let members = members
+            .iter()
+            .map(|member| GithubIdentityHandle::new(member.clone()))
+            .collect();
+
+        Ok(members)
+    }
+
+    async fn repository_collaborator(
+        &self,
+        repository: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<(), ConsistencyIssue> {
+        self.checker.repository_collaborator(repository, handle, retries).await
+    }
+
+    async fn team_review_assignment(
+        &self,
+        handle: &GithubTeamHandle,
+        retries: &RetryPolicy,
+    ) -> Result<TeamReviewAssignment, ConsistencyIssue> {
+        self.checker.team_review_assignment(handle, retries).await
+    }
+
+    async fn branch_protection(
+        &self,
+        repository: &str,
+        branch: &str,
+        retries: &RetryPolicy,
+    ) -> Result<BranchProtection, ConsistencyIssue> {
+        self.checker.branch_protection(repository, branch, retries).await
+    }
+    async fn organization_role(
+        &self,
+        organization: &str,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        self.checker.organization_role(organization, handle, retries).await
+    }
+
+    async fn team_role(
+        &self,
+        team: &GithubTeamHandle,
+        handle: &GithubIdentityHandle,
+        retries: &RetryPolicy,
+    ) -> Result<Option<OwnerRole>, ConsistencyIssue> {
+        self.checker.team_role(team, handle, retries).await
+    }
+    async fn preflight(&self, canopus_config: &CanopusConfig, retries: &RetryPolicy) -> anyhow::Result<()> {
+        self.checker.preflight(canopus_config, retries).await
+    }
+
+    fn capability(&self) -> ApiCapability {
+        self.checker.capability()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::ConsistencyIssue;
+    use crate::core::models::config::ConfigFormat;
+    use crate::core::models::handles::{GithubIdentityHandle, GithubTeamHandle};
+    use crate::infra::github::{CheckGithubConsistency, FakeGithubStateBuilder};
+    use crate::infra::retries::RetryPolicy;
+    use crate::infra::roster::{Roster, RosterChecker};
+    use assertor::{EqualityAssertion, StringAssertion};
+    use indoc::indoc;
+    use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+    fn team(organization: &str, name: &str) -> GithubTeamHandle {
+        GithubTeamHandle::new(GithubIdentityHandle::new(organization.to_string()), name.to_string())
+    }
+
+    #[tokio::test]
+    async fn should_verify_owners_against_roster() -> anyhow::Result<()> {
+        let contents = indoc! {"
+            roster:
+              version: 1
+              organization: dotanuki-labs
+              members:
+                - ubiratansoares
+                - Dotanuki-Bot
+            teams:
+              rustaceans: [ubiratansoares]
+        "};
+
+        let roster = Roster::parse(contents, ConfigFormat::Yaml)?;
+        let fallback = FakeGithubStateBuilder::default().build();
+        let checker = RosterChecker::new(roster, &fallback);
+        let retries = RetryPolicy::default();
+
+        let member = GithubIdentityHandle::new("dotanuki-bot".to_string());
+        let outsider = GithubIdentityHandle::new("octocat".to_string());
+        let members = HashSet::from([GithubIdentityHandle::new("ubiratansoares".to_string())]);
+
+        let verified_member = checker.github_identity("dotanuki-labs", &member, &retries).await;
+        let verified_outsider = checker.github_identity("dotanuki-labs", &outsider, &retries).await;
+        let verified_sibling = checker.github_identity("dotanuki-oss", &member, &retries).await;
+
+        assertor::assert_that!(verified_member).is_equal_to(Ok(()));
+        assertor::assert_that!(verified_outsider).is_equal_to(Err(ConsistencyIssue::OutsiderUser(outsider)));
+        assertor::assert_that!(verified_sibling).is_equal_to(Err(ConsistencyIssue::CannotVerifyUser(member)));
+
+        let rustaceans = team("dotanuki-labs", "Rustaceans");
+        let writers = team("dotanuki-labs", "writers");
+
+        let verified_team = checker.github_team("dotanuki-labs", &rustaceans, &retries).await;
+        let missing_team = checker.github_team("dotanuki-labs", &writers, &retries).await;
+        let listed_members = checker.team_members(&rustaceans, &retries).await;
+
+        assertor::assert_that!(verified_team).is_equal_to(Ok(()));
+        assertor::assert_that!(missing_team).is_equal_to(Err(ConsistencyIssue::TeamDoesNotExist(writers)));
+        assertor::assert_that!(listed_members).is_equal_to(Ok(members));
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_roster_readable_again() -> anyhow::Result<()> {
+        let roster = Roster {
+            organization: "dotanuki-labs".to_string(),
+            members: BTreeSet::from(["1337".to_string(), "ubiratansoares".to_string()]),
+            teams: BTreeMap::from([
+                ("rustaceans".to_string(), BTreeSet::from(["ubiratansoares".to_string()])),
+                ("writers".to_string(), BTreeSet::new()),
+            ]),
+        };
+
+        let expected = indoc! {r#"
+            # Members and teams of dotanuki-labs, as synced by canopus roster sync
+            roster:
+              version: 1
+              organization: dotanuki-labs
+              members:
+                - "1337"
+                - ubiratansoares
+            teams:
+              rustaceans:
+                - ubiratansoares
+              writers: []
+        "#};
+
+        let rendered = roster.render(ConfigFormat::Yaml)?;
+
+        assertor::assert_that!(rendered.as_str()).is_equal_to(expected);
+
+        for format in [ConfigFormat::Yaml, ConfigFormat::Json, ConfigFormat::Toml] {
+            assertor::assert_that!(Roster::parse(&roster.render(format)?, format)?).is_equal_to(roster.clone());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_unsupported_roster_version() {
+        let contents = r#"{ "roster": { "version": 2, "organization": "dotanuki-labs" } }"#;
+
+        let parsed = Roster::parse(contents, ConfigFormat::Json);
+
+        assertor::assert_that!(parsed.unwrap_err().to_string()).contains("unsupported roster version 2");
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde_json::json;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use tokio::net::TcpListener;
+
+/// A request received by canopus serve, with its body fully read
+#[derive(Debug)]
+pub struct ApiRequest {
+    pub method: Method,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub body: Bytes,
+}
+
+/// A JSON answer to an API request
+#[derive(Debug, PartialEq)]
+pub struct ApiResponse {
+    pub status: StatusCode,
+    pub body: String,
+}
+
+impl ApiResponse {
+    pub fn ok(body: String) -> Self {
+        Self {
+            status: StatusCode::OK,
+            body,
+        }
+    }
+
+    pub fn error(status: StatusCode, message: impl ToString) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.to_string() }).to_string(),
+        }
+    }
+}
+
+// Bodies are read up to the given size, so that clients cannot exhaust memory with huge submissions
+async fn read_request(request: Request<Incoming>, max_body_bytes: usize) -> Result<ApiRequest, ApiResponse> {
+    let (parts, body) = request.into_parts();
+
+    let query = parts
+        .uri
+        .query()
+        .map(|query| form_urlencoded::parse(query.as_bytes()).into_owned().collect())
+        .unwrap_or_default();
+
+    let body = match Limited::new(body, max_body_bytes).collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(error) if error.is::<LengthLimitError>() => {
+            return Err(ApiResponse::error(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!("request body exceeds {max_body_bytes} bytes"),
+            ));
+        },
+        Err(error) => return Err(ApiResponse::error(StatusCode::BAD_REQUEST, error)),
+    };
+
+    Ok(ApiRequest {
+        method: parts.method,
+        path: parts.uri.path().to_string(),
+        query,
+        body,
+    })
+}
+
+async fn answer<Handler, Answered>(
+    handler: &Handler,
+    request: Request<Incoming>,
+    max_body_bytes: usize,
+) -> Result<Response<Full<Bytes>>, Infallible>
+where
+    Handler: Fn(ApiRequest) -> Answered,
+    Answered: Future<Output = ApiResponse>,
+{
+    let answered = match read_request(request, max_body_bytes).await {
+        Ok(api_request) => {
+            log::info!("{} {}", api_request.method, api_request.path);
+            handler(api_request).await
+        },
+        Err(rejection) => rejection,
+    };
+
+    let response = Response::builder()
+        .status(answered.status)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(Full::from(answered.body))
+        .expect("valid response");
+
+    Ok(response)
+}
+
+/// Answers API requests until the process exits. Connections are served concurrently within
+/// the calling task, so that handlers can borrow whatever state lives as long as this call,
+/// like caches shared by all requests
+pub async fn serve<Handler, Answered>(
+    listener: TcpListener,
+    max_body_bytes: usize,
+    handler: Handler,
+) -> anyhow::Result<()>
+where
+    Handler: Fn(ApiRequest) -> Answered,
+    Answered: Future<Output = ApiResponse>,
+{
+    let handler = &handler;
+    let mut connections = FuturesUnordered::new();
+
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let service = service_fn(move |request| answer(handler, request, max_body_bytes));
+                    connections.push(http1::Builder::new().serve_connection(TokioIo::new(stream), service));
+                },
+                // Running out of file descriptors and alike should not bring the whole server down
+                Err(error) => log::warn!("Failed to accept API connection : {error}"),
+            },
+            Some(served) = connections.next() => {
+                if let Err(error) = served {
+                    log::debug!("Failed to answer API request : {error}");
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::server;
+    use crate::infra::server::{ApiRequest, ApiResponse};
+    use assertor::StringAssertion;
+    use hyper::StatusCode;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    async fn echo(request: ApiRequest) -> ApiResponse {
+        match request.query.get("path") {
+            Some(path) => ApiResponse::ok(format!("{} {} {path}", request.method, request.path)),
+            None => ApiResponse::error(StatusCode::NOT_FOUND, "missing path"),
+        }
+    }
+
+    async fn send(address: std::net::SocketAddr, raw_request: &str) -> String {
+        let mut stream = TcpStream::connect(address).await.unwrap();
+        stream.write_all(raw_request.as_bytes()).await.unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn should_answer_requests_with_decoded_queries() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(server::serve(listener, 1024, echo));
+
+        let found = send(
+            address,
+            "GET /owners?path=docs%2Fmy%20guides HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+        )
+        .await;
+
+        let missing = send(
+            address,
+            "GET /owners HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+        )
+        .await;
+
+        assertor::assert_that!(found).starts_with("HTTP/1.1 200 OK");
+        assertor::assert_that!(found).ends_with("GET /owners docs/my guides");
+        assertor::assert_that!(missing).starts_with("HTTP/1.1 404 Not Found");
+        assertor::assert_that!(missing).ends_with(r#"{"error":"missing path"}"#);
+    }
+
+    #[tokio::test]
+    async fn should_reject_request_bodies_past_limit() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(server::serve(listener, 16, echo));
+
+        let body = "*".repeat(32);
+
+        let rejected = send(
+            address,
+            &format!(
+                "POST /validate?path=src HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            ),
+        )
+        .await;
+
+        assertor::assert_that!(rejected).starts_with("HTTP/1.1 413 Payload Too Large");
+        assertor::assert_that!(rejected).ends_with(r#"{"error":"request body exceeds 16 bytes"}"#);
+    }
+}
@@ -0,0 +1,422 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::config::{DEFAULT_VALUE_STORAGE_LOCATION, StorageBackend, StorageConfig};
+use anyhow::{Context, bail};
+use aws_credential_types::Credentials;
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+static ENV_VAR_AWS_ACCESS_KEY_ID: &str = "AWS_ACCESS_KEY_ID";
+static ENV_VAR_AWS_SECRET_ACCESS_KEY: &str = "AWS_SECRET_ACCESS_KEY";
+static ENV_VAR_AWS_SESSION_TOKEN: &str = "AWS_SESSION_TOKEN";
+static ENV_VAR_GOOGLE_ACCESS_TOKEN: &str = "GOOGLE_OAUTH_ACCESS_TOKEN";
+static GCS_ENDPOINT: &str = "https://storage.googleapis.com";
+
+pub trait StoreAuditResults {
+    /// Persists serialized audit results under the given name, returning where they were stored
+    async fn store(&self, name: &str, contents: String) -> anyhow::Result<String>;
+}
+
+pub enum AuditStorage {
+    LocalFile(PathBuf),
+    S3 {
+        http_client: reqwest::Client,
+        endpoint: String,
+        bucket: String,
+        region: String,
+        prefix: String,
+        credentials: Credentials,
+    },
+    Gcs {
+        http_client: reqwest::Client,
+        endpoint: String,
+        bucket: String,
+        prefix: String,
+        access_token: String,
+    },
+}
+
+impl AuditStorage {
+    /// Creates the storage configured by canopus.toml, if any
+    pub fn from_config(config: &StorageConfig) -> anyhow::Result<Option<Self>> {
+        let Some(backend) = config.backend else {
+            return Ok(None);
+        };
+
+        let location = config
+            .location
+            .clone()
+            .unwrap_or(DEFAULT_VALUE_STORAGE_LOCATION.to_string());
+
+        let storage = match backend {
+            StorageBackend::Local => AuditStorage::LocalFile(PathBuf::from(location)),
+            StorageBackend::S3 => {
+                let bucket = Self::required(&config.bucket, "storage.bucket")?;
+                let region = Self::required(&config.region, "storage.region")?;
+
+                AuditStorage::S3 {
+                    http_client: Self::http_client()?,
+                    endpoint: format!("https://{bucket}.s3.{region}.amazonaws.com"),
+                    bucket,
+                    region,
+                    prefix: location,
+                    credentials: Credentials::new(
+                        Self::required_env(ENV_VAR_AWS_ACCESS_KEY_ID)?,
+                        Self::required_env(ENV_VAR_AWS_SECRET_ACCESS_KEY)?,
+                        std::env::var(ENV_VAR_AWS_SESSION_TOKEN).ok(),
+                        None,
+                        "environment",
+                    ),
+                }
+            },
+            StorageBackend::Gcs => AuditStorage::Gcs {
+                http_client: Self::http_client()?,
+                endpoint: GCS_ENDPOINT.to_string(),
+                bucket: Self::required(&config.bucket, "storage.bucket")?,
+                prefix: location,
+                access_token: Self::required_env(ENV_VAR_GOOGLE_ACCESS_TOKEN)?,
+            },
+        };
+
+        Ok(Some(storage))
+    }
+
+    fn http_client() -> anyhow::Result<reqwest::Client> {
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        Ok(reqwest::Client::builder().user_agent(user_agent).build()?)
+    }
+
+    fn required(value: &Option<String>, config_key: &str) -> anyhow::Result<String> {
+        value
+            .clone()
+            .with_context(|| format!("expecting {config_key} in config for this storage backend"))
+    }
+
+    fn required_env(variable: &str) -> anyhow::Result<String> {
+        std::env::var(variable).with_context(|| format!("expecting {variable} environment variable"))
+    }
+
+    async fn send_object(http_client: &reqwest::Client, request: http::Request<String>) -> anyhow::Result<()> {
+        let response = http_client.execute(reqwest::Request::try_from(request)?).await?;
+
+        if !response.status().is_success() {
+            bail!(
+                "object storage rejected audit results with status {}",
+                response.status()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl StoreAuditResults for AuditStorage {
+    async fn store(&self, name: &str, contents: String) -> anyhow::Result<String> {
+        match self {
+            AuditStorage::LocalFile(directory) => {
+                std::fs::create_dir_all(directory)?;
+                let destination = directory.join(name);
+                std::fs::write(&destination, contents)?;
+                Ok(destination.to_string_lossy().to_string())
+            },
+            AuditStorage::S3 {
+                http_client,
+                endpoint,
+                bucket,
+                region,
+                prefix,
+                credentials,
+            } => {
+                let object_key = format!("{}/{}", prefix.trim_end_matches('/'), name);
+
+                let mut request = http::Request::put(format!("{endpoint}/{}", percent_encode(&object_key, true)))
+                    .header(http::header::CONTENT_TYPE, "application/json")
+                    .body(contents)?;
+
+                sigv4::sign(
+                    &mut request,
+                    credentials,
+                    region,
+                    "s3",
+                    sigv4::s3_settings(),
+                    SystemTime::now(),
+                )?;
+                Self::send_object(http_client, request).await?;
+                Ok(format!("s3://{bucket}/{object_key}"))
+            },
+            AuditStorage::Gcs {
+                http_client,
+                endpoint,
+                bucket,
+                prefix,
+                access_token,
+            } => {
+                let object_key = format!("{}/{}", prefix.trim_end_matches('/'), name);
+
+                let upload_url = format!(
+                    "{}/upload/storage/v1/b/{}/o?uploadType=media&name={}",
+                    endpoint,
+                    bucket,
+                    percent_encode(&object_key, false)
+                );
+
+                let request = http::Request::post(upload_url)
+                    .header(http::header::AUTHORIZATION, format!("Bearer {access_token}"))
+                    .header(http::header::CONTENT_TYPE, "application/json")
+                    .body(contents)?;
+
+                Self::send_object(http_client, request).await?;
+                Ok(format!("gs://{bucket}/{object_key}"))
+            },
+        }
+    }
+}
+
+fn percent_encode(value: &str, keep_slashes: bool) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            b'/' if keep_slashes => "/".to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .join("")
+}
+
+/// Signs requests with AWS Signature Version 4, relying on the signer shipped with AWS SDKs
+mod sigv4 {
+    use aws_credential_types::Credentials;
+    use aws_sigv4::http_request::{
+        PayloadChecksumKind, PercentEncodingMode, SignableBody, SignableRequest, SigningSettings,
+        UriPathNormalizationMode,
+    };
+    use aws_sigv4::sign::v4;
+    use std::time::SystemTime;
+
+    // S3 expects object keys encoded once and left as is, besides a checksum of the uploaded contents
+    pub fn s3_settings() -> SigningSettings {
+        let mut settings = SigningSettings::default();
+        settings.percent_encoding_mode = PercentEncodingMode::Single;
+        settings.uri_path_normalization_mode = UriPathNormalizationMode::Disabled;
+        settings.payload_checksum_kind = PayloadChecksumKind::XAmzSha256;
+        settings
+    }
+
+    pub fn sign(
+        request: &mut http::Request<String>,
+        credentials: &Credentials,
+        region: &str,
+        service: &str,
+        settings: SigningSettings,
+        time: SystemTime,
+    ) -> anyhow::Result<()> {
+        let identity = credentials.clone().into();
+
+        let params = v4::SigningParams::builder()
+            .identity(&identity)
+            .region(region)
+            .name(service)
+            .time(time)
+            .settings(settings)
+            .build()?
+            .into();
+
+        let headers = request
+            .headers()
+            .iter()
+            .map(|(name, value)| Ok((name.as_str(), value.to_str()?)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let signable = SignableRequest::new(
+            request.method().as_str(),
+            request.uri().to_string(),
+            headers.into_iter(),
+            SignableBody::Bytes(request.body().as_bytes()),
+        )?;
+
+        let (instructions, _) = aws_sigv4::http_request::sign(signable, &params)?.into_parts();
+        instructions.apply_to_request_http1x(request);
+        Ok(())
+    }
+}
+
+/// Names audit results after the audited organization and the moment they were produced
+pub fn audit_results_name(organization: &str, produced_at: DateTime<Utc>) -> String {
+    format!("{}-{}.json", organization, produced_at.format("%Y%m%dT%H%M%SZ"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::storage::{AuditStorage, StoreAuditResults, sigv4};
+    use assertor::{EqualityAssertion, ResultAssertion};
+    use aws_credential_types::Credentials;
+    use aws_sigv4::http_request::SigningSettings;
+    use chrono::DateTime;
+    use httpmock::MockServer;
+    use std::time::SystemTime;
+    use temp_dir::TempDir;
+
+    // Requests, credentials and signatures from the AWS Signature Version 4 test suite
+    fn test_suite_authorization(method: &str, session_token: Option<&str>) -> String {
+        let credentials = Credentials::new(
+            "AKIDEXAMPLE",
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            session_token.map(str::to_string),
+            None,
+            "test-suite",
+        );
+
+        let signed_at = SystemTime::from(DateTime::parse_from_rfc3339("2015-08-30T12:36:00Z").unwrap());
+
+        let mut request = http::Request::builder()
+            .method(method)
+            .uri("https://example.amazonaws.com/")
+            .body(String::new())
+            .unwrap();
+
+        sigv4::sign(
+            &mut request,
+            &credentials,
+            "us-east-1",
+            "service",
+            SigningSettings::default(),
+            signed_at,
+        )
+        .unwrap();
+
+        request.headers()[http::header::AUTHORIZATION]
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    fn s3_storage(endpoint: String) -> AuditStorage {
+        AuditStorage::S3 {
+            http_client: reqwest::Client::new(),
+            endpoint,
+            bucket: "audits".to_string(),
+            region: "us-east-1".to_string(),
+            prefix: "canopus/".to_string(),
+            credentials: Credentials::new("AKIDEXAMPLE", "secret", None, None, "test"),
+        }
+    }
+
+    fn gcs_storage(endpoint: String) -> AuditStorage {
+        AuditStorage::Gcs {
+            http_client: reqwest::Client::new(),
+            endpoint,
+            bucket: "audits".to_string(),
+            prefix: "canopus".to_string(),
+            access_token: "ya29.token".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_store_audit_results_locally() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let storage = AuditStorage::LocalFile(temp_dir.path().join("audits"));
+
+        let location = storage.store("dotanuki-labs.json", "{}".to_string()).await.unwrap();
+
+        let stored = std::fs::read_to_string(location).unwrap();
+        assertor::assert_that!(stored).is_equal_to("{}".to_string());
+    }
+
+    #[test]
+    fn should_sign_requests_matching_aws_test_suite() {
+        assertor::assert_that!(test_suite_authorization("GET", None)).is_equal_to(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+                .to_string(),
+        );
+
+        assertor::assert_that!(test_suite_authorization("POST", None)).is_equal_to(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b"
+                .to_string(),
+        );
+
+        let session_token = "6e86291e8372ff2a2260956d9b8aae1d763fbf315fa00fa31553b73ebf194267";
+
+        assertor::assert_that!(test_suite_authorization("GET", Some(session_token))).is_equal_to(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date;x-amz-security-token, \
+             Signature=07ec1639c89043aa0e3e2de82b96708f198cceab042d4a97044c66dd9f74e7f8"
+                .to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_store_audit_results_on_s3() {
+        let mock_server = MockServer::start();
+
+        let uploaded = mock_server.mock(|when, then| {
+            when.method("PUT")
+                .path("/canopus/dotanuki-labs.json")
+                .header_exists("authorization")
+                .header(
+                    "x-amz-content-sha256",
+                    "44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a",
+                )
+                .body("{}");
+
+            then.status(200);
+        });
+
+        let location = s3_storage(mock_server.base_url())
+            .store("dotanuki-labs.json", "{}".to_string())
+            .await
+            .unwrap();
+
+        uploaded.assert();
+        assertor::assert_that!(location).is_equal_to("s3://audits/canopus/dotanuki-labs.json".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_store_audit_results_on_gcs() {
+        let mock_server = MockServer::start();
+
+        let uploaded = mock_server.mock(|when, then| {
+            when.method("POST")
+                .path("/upload/storage/v1/b/audits/o")
+                .query_param("uploadType", "media")
+                .query_param("name", "canopus/dotanuki-labs.json")
+                .header("authorization", "Bearer ya29.token")
+                .header("content-type", "application/json")
+                .body("{}");
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"name": "canopus/dotanuki-labs.json"}"#);
+        });
+
+        let location = gcs_storage(mock_server.base_url())
+            .store("dotanuki-labs.json", "{}".to_string())
+            .await
+            .unwrap();
+
+        uploaded.assert();
+        assertor::assert_that!(location).is_equal_to("gs://audits/canopus/dotanuki-labs.json".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_report_rejected_uploads() {
+        let mock_server = MockServer::start();
+
+        mock_server.mock(|when, then| {
+            when.method("POST").path("/upload/storage/v1/b/audits/o");
+            then.status(403);
+        });
+
+        let stored = gcs_storage(mock_server.base_url())
+            .store("dotanuki-labs.json", "{}".to_string())
+            .await;
+
+        assertor::assert_that!(stored).is_err();
+    }
+}
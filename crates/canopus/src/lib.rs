@@ -0,0 +1,25 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+//! The parser and offline checks of canopus, free of Github API calls, project walking and async runtimes.
+//! Building without default features makes them available for targets like `wasm32-unknown-unknown`
+
+pub mod core;
+
+use crate::core::models::ValidationOutcome;
+use crate::core::models::codeowners::CodeOwners;
+use crate::core::models::config::CanopusConfig;
+use crate::core::offline;
+use crate::core::overrides::LineOverrides;
+
+/// Validates CODEOWNERS contents with checks relying on nothing but these contents and the configuration
+pub fn validate_offline(contents: &str, config: &CanopusConfig) -> anyhow::Result<ValidationOutcome> {
+    let codeowners = CodeOwners::try_from(contents)?;
+    let overrides = LineOverrides::new(&codeowners, &config.overrides)?;
+
+    let validations = offline::check_contents(&codeowners, contents, config, |_, run| {
+        Ok(overrides.apply_to_outcome(run()?))
+    })?;
+
+    Ok(offline::collect_issues(validations, config))
+}
@@ -1,11 +1,19 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::canopus::Canopus;
+use crate::canopus::errors::CanopusError;
+use crate::canopus::events::{LoggingObserver, OwnersProgressBar, ProgressObserver};
 use crate::canopus::validation::CodeOwnersValidator;
-use crate::infra::github::GithubConsistencyChecker;
-use crate::infra::{cli, paths};
-use octocrab::service::middleware::retry::RetryConfig;
+use crate::canopus::{Canopus, CanopusCommand, ProjectLocation};
+use crate::core::models::config::OwnersProvider;
+use crate::core::models::deprecations::DeprecationPolicy;
+use crate::infra::github;
+use crate::infra::github::{CheckGithubConsistency, GithubApiChecker};
+use crate::infra::gitlab;
+use crate::infra::gitlab::GitlabApiChecker;
+use crate::infra::paths::ListedPaths;
+use crate::infra::remote::GithubTreeWalker;
+use crate::infra::{cli, fixtures, paths, progress};
 use tikv_jemallocator::Jemalloc;
 
 mod canopus;
@@ -16,33 +24,106 @@ mod infra;
 static GLOBAL: Jemalloc = Jemalloc;
 
 static ENV_VAR_GITHUB_TOKEN: &str = "GITHUB_TOKEN";
+static ENV_VAR_GITHUB_API_URL: &str = "GITHUB_API_URL";
+static ENV_VAR_GITLAB_TOKEN: &str = "GITLAB_TOKEN";
+static ENV_VAR_GITLAB_API_URL: &str = "GITLAB_API_URL";
 
-fn create_canopus() -> anyhow::Result<Canopus> {
-    let max_retries_per_request = 3;
+fn non_empty_env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+fn create_canopus(
+    command: &CanopusCommand,
+    deprecation_policy: DeprecationPolicy,
+    trust_extended_checks: bool,
+) -> anyhow::Result<Canopus> {
+    let github_pat = non_empty_env_var(ENV_VAR_GITHUB_TOKEN);
+    let github_api_url = non_empty_env_var(ENV_VAR_GITHUB_API_URL);
+    let anonymous = github_pat.is_none();
+
+    let github_client = github::build_github_client(github_pat.unwrap_or_default(), github_api_url)?;
+
+    // Without a token, checks Github only answers to organization members are skipped
+    let mut consistency_checker: Box<dyn CheckGithubConsistency> = if anonymous {
+        Box::new(GithubApiChecker::anonymous(github_client.clone()))
+    } else {
+        Box::new(GithubApiChecker::new(github_client.clone()))
+    };
 
-    let github_pat = std::env::var(ENV_VAR_GITHUB_TOKEN).unwrap_or("".to_string());
-    let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+    if let CanopusCommand::ValidateCodeowners {
+        fixtures: Some(fixtures_mode),
+        ..
+    } = command
+    {
+        consistency_checker = fixtures::checker_with_fixtures(consistency_checker, fixtures_mode)?;
+    }
 
-    // Configuration for the Github Client
-    let github_client = octocrab::OctocrabBuilder::new()
-        .personal_token(github_pat)
-        .add_retry_config(RetryConfig::Simple(max_retries_per_request))
-        .add_header(http::header::USER_AGENT, user_agent)
-        .build()?;
+    let path_walker = match command {
+        CanopusCommand::ValidateCodeowners { projects, .. }
+            if projects
+                .iter()
+                .any(|project| matches!(project, ProjectLocation::Remote(_))) =>
+        {
+            paths::PathWalker::GithubTree(GithubTreeWalker::new(github_client.clone()))
+        },
+        CanopusCommand::AuditOrganization { .. } => {
+            paths::PathWalker::GithubTree(GithubTreeWalker::new(github_client.clone()))
+        },
+        CanopusCommand::ServeValidation { .. } => paths::PathWalker::Listed(ListedPaths::default()),
+        _ => paths::PathWalker::GitAware,
+    };
 
-    let consistency_checker = GithubConsistencyChecker::ApiBased(github_client);
+    let mut codeowners_validator = CodeOwnersValidator::new(consistency_checker, path_walker);
 
-    let path_walker = paths::PathWalker::GitAware;
-    let codeowners_validator = CodeOwnersValidator::new(consistency_checker, path_walker);
-    let canopus = Canopus::new(codeowners_validator);
+    // Projects opting for GitLab through canopus.toml have their owners verified there instead
+    let gitlab_pat = non_empty_env_var(ENV_VAR_GITLAB_TOKEN).unwrap_or_default();
+    let gitlab_client = gitlab::build_gitlab_client(gitlab_pat, non_empty_env_var(ENV_VAR_GITLAB_API_URL))?;
+    codeowners_validator
+        .register_consistency_checker(OwnersProvider::Gitlab, Box::new(GitlabApiChecker::new(gitlab_client)));
+    codeowners_validator.register_observer(Box::new(LoggingObserver));
+    codeowners_validator.register_observer(Box::new(ProgressObserver));
+
+    // Ctrl-C stops verifying owners against Github API, still printing issues found so far
+    codeowners_validator.interrupt_on(async {
+        let _ = tokio::signal::ctrl_c().await;
+    });
+
+    // Progress bars only make sense when someone is watching the terminal
+    if console::user_attended_stderr() && command.is_decorated() && !progress::is_enabled() {
+        codeowners_validator.register_observer(Box::new(OwnersProgressBar::default()));
+    }
+    let canopus = Canopus::new(
+        codeowners_validator,
+        github_client,
+        deprecation_policy,
+        trust_extended_checks,
+    );
     Ok(canopus)
 }
 
+// Automation tells findings from wrong inputs and unavailable services through exit codes
+fn exit_with(error: CanopusError) -> ! {
+    eprintln!("Error: {error}");
+    std::process::exit(error.exit_code())
+}
+
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
+async fn main() {
     better_panic::install();
     human_panic::setup_panic!();
-    env_logger::builder()
+
+    // Answers completion requests from shells sourcing `COMPLETE=<shell> canopus`, exiting right after
+    clap_complete::CompleteEnv::with_factory(cli::command).complete();
+
+    let invocation = cli::parse_arguments().unwrap_or_else(|error| exit_with(CanopusError::UserError(error)));
+    let mut logger = env_logger::builder();
+
+    // Verbosity flags only affect canopus logs, leaving dependencies quiet
+    if let Some(log_level) = invocation.log_level {
+        logger.filter_module(env!("CARGO_CRATE_NAME"), log_level);
+    }
+
+    logger
         .format_timestamp(None)
         .format_module_path(false)
         .format_level(false)
@@ -50,7 +131,14 @@ async fn main() -> anyhow::Result<()> {
         .format_target(false)
         .init();
 
-    let command = cli::parse_arguments()?;
-    let canopus = create_canopus()?;
-    canopus.execute(command).await
+    let canopus = create_canopus(
+        &invocation.command,
+        invocation.deprecation_policy,
+        invocation.trust_extended_checks,
+    )
+    .unwrap_or_else(|error| exit_with(error.into()));
+
+    if let Err(error) = canopus.execute(invocation.command).await {
+        exit_with(error);
+    }
 }
@@ -2,8 +2,16 @@
 // SPDX-License-Identifier: MIT
 
 use assert_cmd::Command;
+use assertor::BooleanAssertion;
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use indoc::indoc;
 use predicates::str::contains;
 use std::env::current_dir;
+use std::fs;
+use temp_dir::TempDir;
 
 fn sut() -> Command {
     let _ = env_logger::builder().is_test(true).try_init();
@@ -43,3 +51,183 @@ fn self_repair_codeowners_configuration() {
         .success()
         .stdout(contains("Nothing to repair"));
 }
+
+static OFFLINE_CONFIG: &str = indoc! {r#"
+    [general]
+    github-organization = "dotanuki-labs"
+    offline-checks-only = true
+
+    [ownership]
+    enforce-one-owner-per-line = true
+"#};
+
+fn create_project(codeowners: Option<&str>, config: &str) -> TempDir {
+    let project_dir = TempDir::new().unwrap();
+    let github_dir = project_dir.child(".github");
+    fs::create_dir(&github_dir).unwrap();
+    fs::write(github_dir.join("canopus.toml"), config).unwrap();
+
+    if let Some(contents) = codeowners {
+        fs::write(github_dir.join("CODEOWNERS"), contents).unwrap();
+    }
+
+    project_dir
+}
+
+#[test]
+fn exit_with_findings_when_single_project_has_issues() {
+    let project = create_project(
+        Some("*.rs @dotanuki-labs/rustaceans\n*.rs @dotanuki-labs/crabbers\n"),
+        OFFLINE_CONFIG,
+    );
+    let project_root = project.path().to_str().unwrap();
+
+    sut()
+        .args(["validate", "-p", project_root])
+        .assert()
+        .code(1)
+        .stderr(contains("found issues in"));
+}
+
+#[test]
+fn exit_with_user_error_when_codeowners_is_missing() {
+    let project = create_project(None, OFFLINE_CONFIG);
+    let project_root = project.path().to_str().unwrap();
+
+    sut()
+        .args(["validate", "-p", project_root])
+        .assert()
+        .code(2)
+        .stderr(contains("CODEOWNERS"));
+}
+
+#[test]
+fn exit_with_user_error_when_any_project_cannot_be_validated() {
+    let with_issues = create_project(
+        Some("*.rs @dotanuki-labs/rustaceans\n*.rs @dotanuki-labs/crabbers\n"),
+        OFFLINE_CONFIG,
+    );
+    let without_codeowners = create_project(None, OFFLINE_CONFIG);
+
+    sut()
+        .args(["validate", "-p", with_issues.path().to_str().unwrap()])
+        .args(["-p", without_codeowners.path().to_str().unwrap()])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn exit_with_user_error_when_configuration_is_invalid() {
+    let project = create_project(
+        Some("*.rs @dotanuki-labs/rustaceans\n"),
+        "[general]\noffline-checks-only = 42\n",
+    );
+    let project_root = project.path().to_str().unwrap();
+
+    sut()
+        .args(["validate", "-p", project_root])
+        .assert()
+        .code(2)
+        .stderr(contains("invalid configuration"));
+}
+
+#[test]
+fn exit_with_user_error_when_flags_are_invalid() {
+    let project_root = find_project_root();
+
+    sut()
+        .args([
+            "validate",
+            "-p",
+            project_root.as_str(),
+            "--format",
+            "json",
+            "--format",
+            "junit",
+        ])
+        .assert()
+        .code(2)
+        .stderr(contains("writing several formats requires --output-dir"));
+}
+
+#[test]
+fn exit_with_infrastructure_failure_when_github_rejects_token() {
+    let config = indoc! {r#"
+        [general]
+        github-organization = "dotanuki-labs"
+
+        [ownership]
+        enforce-github-teams-owners = true
+    "#};
+
+    let project = create_project(Some("*.rs @dotanuki-labs/rustaceans\n"), config);
+    let project_root = project.path().to_str().unwrap();
+
+    let github = MockServer::start();
+    github.mock(|when, then| {
+        when.method(GET).path("/rate_limit");
+        then.status(401)
+            .header("content-type", "application/json")
+            .body(r#"{"message": "Bad credentials"}"#);
+    });
+
+    sut()
+        .env("GITHUB_TOKEN", "ghp_fake")
+        .env("GITHUB_API_URL", github.base_url())
+        .args(["validate", "-p", project_root])
+        .assert()
+        .code(3)
+        .stderr(contains("rejected by Github API"));
+}
+
+fn serve_remote_file(github: &MockServer, path: &str, contents: &str) {
+    let encoded = BASE64_STANDARD.encode(contents);
+    let route = format!("/repos/dotanuki-labs/canopus/contents/{path}");
+    let url = format!("https://api.github.com{route}");
+
+    github.mock(|when, then| {
+        when.method(GET).path(route);
+        then.status(200).header("content-type", "application/json").body(format!(
+            r#"{{ "name": "{path}", "path": "{path}", "sha": "abc", "size": {}, "url": "{url}", "type": "file", "encoding": "base64", "content": "{encoded}", "_links": {{ "self": "{url}" }} }}"#,
+            contents.len()
+        ));
+    });
+}
+
+#[test]
+fn never_run_external_checks_declared_by_remote_projects() {
+    let config = indoc! {r#"
+        [general]
+        github-organization = "dotanuki-labs"
+        offline-checks-only = true
+
+        [ownership]
+
+        [[checks.external]]
+        name = "remote-policy"
+        command = ["touch", "remote-policy-ran"]
+    "#};
+
+    let github = MockServer::start();
+    serve_remote_file(&github, ".github/CODEOWNERS", "*.rs @dotanuki-labs/rustaceans\n");
+    serve_remote_file(&github, ".github/canopus.toml", config);
+
+    github.mock(|when, then| {
+        when.method(GET).path("/repos/dotanuki-labs/canopus/git/trees/main");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{ "sha": "abc", "truncated": false, "tree": [{ "path": "main.rs", "mode": "100644", "type": "blob", "sha": "abc" }] }"#);
+    });
+
+    let working_dir = TempDir::new().unwrap();
+
+    sut()
+        .current_dir(working_dir.path())
+        .env_remove("GITHUB_TOKEN")
+        .env("GITHUB_API_URL", github.base_url())
+        .args(["validate", "--remote", "dotanuki-labs/canopus@main"])
+        .assert()
+        .success();
+
+    assertor::assert_that!(working_dir.child("remote-policy-ran").exists()).is_false();
+}
@@ -0,0 +1,86 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use assertor::{EqualityAssertion, VecAssertion};
+use canopus::core::models::ValidationOutcome;
+use canopus::core::models::checks::IssueSeverity;
+use canopus::core::models::config::CanopusConfig;
+use std::str::FromStr;
+
+#[test]
+fn validate_codeowners_contents_offline() {
+    let config = CanopusConfig::from_str(
+        r#"
+        [general]
+        github-organization = "dotanuki-labs"
+
+        [ownership]
+        enforce-one-owner-per-line = true
+        "#,
+    )
+    .unwrap();
+
+    let contents =
+        "*.rs @dotanuki-labs/rustaceans\n*.md @dotanuki-labs/writers @ubiratansoares\n*.rs @dotanuki-labs/crabbers\n";
+
+    let issues = match canopus::validate_offline(contents, &config).unwrap() {
+        ValidationOutcome::NoIssues => vec![],
+        ValidationOutcome::IssuesDetected(issues) => issues,
+    };
+
+    let flagged = issues
+        .iter()
+        .map(|issue| (issue.line, issue.kind.definition().name))
+        .collect::<Vec<_>>();
+
+    assertor::assert_that!(flagged)
+        .contains_exactly_in_order(vec![(0, "duplicate-ownership"), (1, "only-one-owner-per-entry")]);
+}
+
+#[test]
+fn honor_overrides_when_validating_offline() {
+    let config = CanopusConfig::from_str(
+        r#"
+        [general]
+        github-organization = "dotanuki-labs"
+
+        [ownership]
+        enforce-one-owner-per-line = true
+
+        [[overrides]]
+        patterns = ["*.md"]
+        disable = ["only-one-owner-per-entry"]
+        "#,
+    )
+    .unwrap();
+
+    let contents = "*.md @dotanuki-labs/writers @ubiratansoares\n";
+
+    let outcome = canopus::validate_offline(contents, &config).unwrap();
+
+    assertor::assert_that!(outcome).is_equal_to(ValidationOutcome::NoIssues);
+}
+
+#[test]
+fn report_every_issue_as_error_in_strict_mode() {
+    let mut config = CanopusConfig::from_str(
+        r#"
+        [general]
+        github-organization = "dotanuki-labs"
+
+        [ownership]
+        "#,
+    )
+    .unwrap();
+
+    config.strict = true;
+
+    let issues = match canopus::validate_offline("*.rs @ubiratansoares @ubiratansoares\n", &config).unwrap() {
+        ValidationOutcome::NoIssues => vec![],
+        ValidationOutcome::IssuesDetected(issues) => issues,
+    };
+
+    let severities = issues.iter().map(|issue| issue.severity).collect::<Vec<_>>();
+
+    assertor::assert_that!(severities).contains_exactly(vec![IssueSeverity::Error]);
+}
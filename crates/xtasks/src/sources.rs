@@ -9,6 +9,7 @@ use xshell::{Shell, cmd};
 pub fn check_source_files(shell: &Shell) -> anyhow::Result<()> {
     check_code_formatting(shell)?;
     check_code_smells(shell)?;
+    check_validation_core(shell)?;
     Ok(())
 }
 
@@ -42,3 +43,16 @@ fn check_code_smells(shell: &Shell) -> anyhow::Result<()> {
     .run()?;
     Ok(())
 }
+
+fn check_validation_core(shell: &Shell) -> anyhow::Result<()> {
+    println!();
+    println!("🔥 Checking validation core builds for WebAssembly without CLI dependencies");
+    println!();
+
+    cmd!(
+        shell,
+        "cargo clippy --package canopus --lib --no-default-features --target wasm32-unknown-unknown -- -D warnings"
+    )
+    .run()?;
+    Ok(())
+}